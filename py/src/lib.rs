@@ -0,0 +1,351 @@
+use pyo3::create_exception;
+use pyo3::exceptions::{PyException, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::{PyIterator, PyList};
+use sdcx::constraints::{Constraints, Factor};
+use sdcx::errors::InterpretError as SdcInterpretError;
+use sdcx::errors::ParseError as SdcParseError;
+use sdcx::file_db::Location as SdcLocation;
+use sdcx::sdc::{CommandExt, CommandSpec, Dialect, SdcVersion};
+
+create_exception!(sdcx, ParseError, PyException, "Error of parse");
+create_exception!(sdcx, InterpretError, PyException, "Error of interpretation");
+
+/// Location in the source file
+#[pyclass(frozen, get_all, skip_from_py_object, module = "sdcx")]
+#[derive(Clone)]
+struct Location {
+    file: String,
+    start_line: u32,
+    start_column: u32,
+    end_line: u32,
+    end_column: u32,
+}
+
+impl From<&SdcLocation> for Location {
+    fn from(value: &SdcLocation) -> Self {
+        Self {
+            file: value.file_name.display().to_string(),
+            start_line: value.start_line,
+            start_column: value.start_column,
+            end_line: value.end_line,
+            end_column: value.end_column,
+        }
+    }
+}
+
+#[pymethods]
+impl Location {
+    fn __repr__(&self) -> String {
+        format!("{}:{}:{}", self.file, self.start_line, self.start_column)
+    }
+}
+
+/// Error found by `Sdc.validate`
+#[pyclass(frozen, get_all, skip_from_py_object, module = "sdcx")]
+#[derive(Clone)]
+struct Diagnostic {
+    message: String,
+    location: Location,
+}
+
+#[pymethods]
+impl Diagnostic {
+    fn __repr__(&self) -> String {
+        format!("{}: {}", self.location.__repr__(), self.message)
+    }
+}
+
+/// SDC command
+#[pyclass(frozen, skip_from_py_object, module = "sdcx")]
+#[derive(Clone)]
+struct Command {
+    command: sdcx::sdc::Command,
+}
+
+#[pymethods]
+impl Command {
+    /// Kind like `CreateClock`
+    #[getter]
+    fn kind(&self) -> String {
+        format!("{:?}", self.command.kind())
+    }
+
+    /// Command name like `create_clock`
+    #[getter]
+    fn name(&self) -> String {
+        match &self.command {
+            sdcx::sdc::Command::Unknown(x) => x.name.clone(),
+            x => CommandSpec::of(x.kind())
+                .map(|x| x.name.to_string())
+                .unwrap_or_default(),
+        }
+    }
+
+    #[getter]
+    fn arguments(&self) -> Vec<String> {
+        self.command
+            .arguments()
+            .iter()
+            .map(|x| x.to_string())
+            .collect()
+    }
+
+    #[getter]
+    fn location(&self) -> Location {
+        (&self.command.location()).into()
+    }
+
+    /// Values of option or positional argument `name`
+    fn values(&self, name: &str) -> Vec<String> {
+        self.command
+            .values(name)
+            .iter()
+            .map(|x| x.to_string())
+            .collect()
+    }
+
+    /// Whether flag `name` is specified
+    fn flag(&self, name: &str) -> bool {
+        self.command.flag(name)
+    }
+
+    fn __str__(&self) -> String {
+        self.command.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("<Command {}>", self.command)
+    }
+}
+
+/// Clock defined by `create_clock`
+#[pyclass(frozen, get_all, skip_from_py_object, module = "sdcx")]
+#[derive(Clone)]
+struct Clock {
+    name: String,
+    /// Period in the time unit declared by `set_units`
+    period: f64,
+    /// Period in second
+    period_si: f64,
+    waveform: Vec<f64>,
+    source: Option<String>,
+    add: bool,
+    is_virtual: bool,
+}
+
+#[pymethods]
+impl Clock {
+    fn __repr__(&self) -> String {
+        format!("<Clock {} period={}>", self.name, self.period)
+    }
+}
+
+/// Clock defined by `create_generated_clock`
+#[pyclass(frozen, get_all, skip_from_py_object, module = "sdcx")]
+#[derive(Clone)]
+struct GeneratedClock {
+    name: String,
+    source: String,
+    source_objects: String,
+    master_clock: Option<String>,
+    divide_by: Option<i32>,
+    multiply_by: Option<i32>,
+    duty_cycle: Option<f32>,
+    edges: Vec<i32>,
+    edge_shift: Vec<f32>,
+    invert: bool,
+    add: bool,
+    combinational: bool,
+}
+
+#[pymethods]
+impl GeneratedClock {
+    fn __repr__(&self) -> String {
+        format!("<GeneratedClock {}>", self.name)
+    }
+}
+
+/// Parsed SDC
+#[pyclass(frozen, module = "sdcx")]
+struct Sdc {
+    sdc: sdcx::sdc::Sdc,
+}
+
+#[pymethods]
+impl Sdc {
+    /// Version declared by `set sdc_version`
+    #[getter]
+    fn version(&self) -> Option<String> {
+        self.sdc.version.map(|x| x.version_string().to_string())
+    }
+
+    #[getter]
+    fn dialect(&self) -> String {
+        self.sdc.dialect.name().to_string()
+    }
+
+    #[getter]
+    fn commands(&self) -> Vec<Command> {
+        self.sdc
+            .commands
+            .iter()
+            .map(|x| Command { command: x.clone() })
+            .collect()
+    }
+
+    /// Validate commands at `version`, or the declared version if omitted
+    #[pyo3(signature = (version = None))]
+    fn validate(&self, version: Option<&str>) -> PyResult<Vec<Diagnostic>> {
+        let version = version.map(parse_version).transpose()?;
+        Ok(self
+            .sdc
+            .validate(version)
+            .iter()
+            .map(|x| Diagnostic {
+                message: x.message(),
+                location: x.location().into(),
+            })
+            .collect())
+    }
+
+    /// Minimum SDC version at which all commands are supported
+    fn required_version(&self) -> Option<String> {
+        self.sdc
+            .required_version()
+            .map(|x| x.version.version_string().to_string())
+    }
+
+    /// Formatted text, and commands are sorted if `normalize` is true
+    #[pyo3(signature = (normalize = false))]
+    fn format(&self, normalize: bool) -> String {
+        let mut sdc = self.sdc.clone();
+        if normalize {
+            sdc.normalize();
+        }
+        sdc.to_string()
+    }
+
+    fn clocks(&self) -> PyResult<Vec<Clock>> {
+        let mut constraints = Constraints::from(self.sdc.clone());
+        let clocks = constraints.clocks().map_err(interpret_error)?;
+        Ok(clocks
+            .iter()
+            .map(|x| Clock {
+                name: x.name().to_string(),
+                period: x.period_quantity().value(),
+                period_si: x.period_quantity().to_si(),
+                waveform: x.waveform_quantity().iter().map(|x| x.value()).collect(),
+                source: x.source().as_ref().map(|x| x.name().to_string()),
+                add: x.add(),
+                is_virtual: x.is_virtual(),
+            })
+            .collect())
+    }
+
+    fn generated_clocks(&self) -> PyResult<Vec<GeneratedClock>> {
+        let constraints = Constraints::from(self.sdc.clone());
+        let clocks = constraints.generated_clocks().map_err(interpret_error)?;
+        Ok(clocks
+            .iter()
+            .map(|x| GeneratedClock {
+                name: x.name().to_string(),
+                source: x.source().name().to_string(),
+                source_objects: x.source_objects().name().to_string(),
+                master_clock: x.master_clock().clone(),
+                divide_by: match x.factor() {
+                    Some(Factor::Divide(x)) => Some(x),
+                    _ => None,
+                },
+                multiply_by: match x.factor() {
+                    Some(Factor::Multiply(x)) => Some(x),
+                    _ => None,
+                },
+                duty_cycle: x.duty_cycle(),
+                edges: x.edges().to_vec(),
+                edge_shift: x.edge_shift().to_vec(),
+                invert: x.invert(),
+                add: x.add(),
+                combinational: x.combinational(),
+            })
+            .collect())
+    }
+
+    fn __iter__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyIterator>> {
+        PyList::new(py, self.commands())?.try_iter()
+    }
+
+    fn __len__(&self) -> usize {
+        self.sdc.commands.len()
+    }
+
+    fn __str__(&self) -> String {
+        self.sdc.to_string()
+    }
+}
+
+fn parse_version(text: &str) -> PyResult<SdcVersion> {
+    text.try_into()
+        .map_err(|_| PyValueError::new_err(format!("Unknown version: {text}")))
+}
+
+fn parse_error(err: SdcParseError, filename: &str) -> PyErr {
+    let message = match err.location() {
+        Some(x) => format!(
+            "{filename}:{}:{}: {}",
+            x.start_line,
+            x.start_column,
+            err.message()
+        ),
+        None => format!("{filename}: {}", err.message()),
+    };
+    ParseError::new_err(message)
+}
+
+fn interpret_error(err: SdcInterpretError) -> PyErr {
+    let message = match err {
+        SdcInterpretError::Something(x) => format!(
+            "{}:{}:{}: Interpretation failed",
+            x.file_name.display(),
+            x.start_line,
+            x.start_column
+        ),
+        SdcInterpretError::ConflictingUnits(dimension, x) => format!(
+            "{}:{}:{}: {dimension:?} unit conflicts with former set_units",
+            x.file_name.display(),
+            x.start_line,
+            x.start_column
+        ),
+    };
+    InterpretError::new_err(message)
+}
+
+/// Parse SDC text, and the dialect is inferred from `filename` if omitted
+#[pyfunction]
+#[pyo3(signature = (text, filename = "", dialect = None))]
+fn parse(text: &str, filename: &str, dialect: Option<&str>) -> PyResult<Sdc> {
+    let dialect = match dialect {
+        Some(x) => Dialect::try_from(x)
+            .map_err(|_| PyValueError::new_err(format!("Unknown dialect: {x}")))?,
+        None if filename.ends_with(".xdc") => Dialect::Xdc,
+        None => Dialect::Sdc,
+    };
+    let sdc = sdcx::Parser::parse_with_dialect(text, &filename, dialect)
+        .map_err(|x| parse_error(x, filename))?;
+    Ok(Sdc { sdc })
+}
+
+#[pymodule]
+#[pyo3(name = "sdcx")]
+fn sdcx_py(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(parse, m)?)?;
+    m.add_class::<Sdc>()?;
+    m.add_class::<Command>()?;
+    m.add_class::<Location>()?;
+    m.add_class::<Diagnostic>()?;
+    m.add_class::<Clock>()?;
+    m.add_class::<GeneratedClock>()?;
+    m.add("ParseError", m.py().get_type::<ParseError>())?;
+    m.add("InterpretError", m.py().get_type::<InterpretError>())?;
+    Ok(())
+}
@@ -0,0 +1,61 @@
+import pytest
+import sdcx
+
+CODE = """set sdc_version 2.1
+set_units -time ps
+create_clock -period 1000 -name CLK [get_ports clk]
+create_generated_clock -name DIV -source [get_ports clk] -divide_by 2 [get_pins u_div/Q]
+set_input_delay 100 -clock CLK [get_ports din]
+report_timing
+"""
+
+
+def test_parse():
+    sdc = sdcx.parse(CODE, "test.sdc")
+    assert sdc.version == "2.1"
+    assert sdc.dialect == "sdc"
+    assert len(sdc) == 5
+
+    commands = list(sdc)
+    assert commands[1].kind == "CreateClock"
+    assert commands[1].name == "create_clock"
+    assert commands[1].arguments == ["1000", "CLK", "[get_ports clk]"]
+    assert commands[1].location.file == "test.sdc"
+    assert commands[1].location.start_line == 3
+    assert commands[3].values("clock") == ["CLK"]
+    assert commands[4].name == "report_timing"
+    assert str(commands[3]) == "set_input_delay -clock CLK 100 [get_ports din]"
+
+
+def test_validate():
+    sdc = sdcx.parse(CODE, "test.sdc")
+    assert [x.message for x in sdc.validate()] == ["Unknown command: report_timing"]
+    assert [x.location.start_line for x in sdc.validate("1.1")] == [2, 4, 6]
+    assert sdc.required_version() == "1.7"
+    with pytest.raises(ValueError):
+        sdc.validate("3.0")
+
+
+def test_format():
+    sdc = sdcx.parse("create_clock [get_ports clk] -period 10\n")
+    assert sdc.format() == "create_clock -period 10 [get_ports clk]\n"
+
+
+def test_clocks():
+    sdc = sdcx.parse(CODE, "test.sdc")
+    clock = sdc.clocks()[0]
+    assert clock.name == "CLK"
+    assert clock.period == 1000.0
+    assert clock.period_si == pytest.approx(1e-9)
+    assert clock.source == "clk"
+    assert not clock.is_virtual
+
+    clock = sdc.generated_clocks()[0]
+    assert clock.name == "DIV"
+    assert clock.divide_by == 2
+    assert clock.source == "clk"
+
+
+def test_error():
+    with pytest.raises(sdcx.ParseError, match="test.sdc:1:14: Syntax error"):
+        sdcx.parse("create_clock -period", "test.sdc")
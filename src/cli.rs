@@ -4,8 +4,17 @@ use flate2::read::GzDecoder;
 use flate2::write::GzEncoder;
 use flate2::Compression;
 use sdcx::constraints::Constraints;
+use sdcx::convert::Converter;
+use sdcx::design::{parse_liberty, Design};
 use sdcx::errors::Report;
 use sdcx::file_db::FileDb;
+use sdcx::lint::coverage::{netlist_ports, parse_port_list, IoCoverage};
+use sdcx::lint::{LintRule, Linter};
+use sdcx::promote::{Demoter, Promoter};
+use sdcx::query;
+use sdcx::rename::Renamer;
+use sdcx::sdc::{CommandSpec, Dialect};
+use sdcx::stats;
 use std::fs::File;
 use std::io::{BufReader, Read, Write};
 use std::path::{Path, PathBuf};
@@ -30,6 +39,33 @@ enum SubCommands {
 
     /// Dump elements of input file
     Dump(Dump),
+
+    /// Lint input file
+    Lint(Lint),
+
+    /// Report IO constraint coverage of input file
+    Coverage(Coverage),
+
+    /// Convert input file to another dialect
+    Convert(Convert),
+
+    /// Promote block-level input file to the top design
+    Promote(Promote),
+
+    /// Demote top-level input file to a block
+    Demote(Demote),
+
+    /// Explain SDC command
+    Explain(Explain),
+
+    /// Print commands matched by query
+    Query(Query),
+
+    /// Rename clocks, ports and instances in input file
+    Rename(Rename),
+
+    /// Print statistics of constraints
+    Stats(Stats),
 }
 
 #[derive(Debug, Parser)]
@@ -40,6 +76,10 @@ struct Fmt {
     /// Output file
     #[arg(short, long)]
     output: Option<PathBuf>,
+
+    /// SDC dialect
+    #[arg(long)]
+    dialect: Option<String>,
 }
 
 #[derive(Debug, Parser)]
@@ -50,6 +90,26 @@ struct Check {
     /// Force SDC version
     #[arg(long)]
     force_version: Option<String>,
+
+    /// Suggest the minimum SDC version required by the file
+    #[arg(long)]
+    suggest_version: bool,
+
+    /// SDC dialect
+    #[arg(long)]
+    dialect: Option<String>,
+
+    /// Verilog netlist to check object queries
+    #[arg(long, requires = "top")]
+    netlist: Option<PathBuf>,
+
+    /// Top module of netlist
+    #[arg(long, requires = "netlist")]
+    top: Option<String>,
+
+    /// Liberty library to check library objects
+    #[arg(long)]
+    liberty: Vec<PathBuf>,
 }
 
 #[derive(Debug, Parser)]
@@ -60,6 +120,176 @@ struct Dump {
     /// Show clock
     #[arg(long)]
     clock: bool,
+
+    /// SDC dialect
+    #[arg(long)]
+    dialect: Option<String>,
+}
+
+#[derive(Debug, Parser)]
+struct Lint {
+    /// SDC file
+    file: PathBuf,
+
+    /// Disable lint rule
+    #[arg(long)]
+    disable: Vec<String>,
+
+    /// Port list file to check port references
+    #[arg(long)]
+    ports: Option<PathBuf>,
+
+    /// SDC dialect
+    #[arg(long)]
+    dialect: Option<String>,
+}
+
+#[derive(Debug, Parser)]
+struct Coverage {
+    /// SDC file
+    file: PathBuf,
+
+    /// Port list file
+    #[arg(long, required_unless_present = "netlist", conflicts_with = "netlist")]
+    ports: Option<PathBuf>,
+
+    /// Verilog netlist to take ports of the top module from
+    #[arg(long, requires = "top")]
+    netlist: Option<PathBuf>,
+
+    /// Top module of netlist
+    #[arg(long, requires = "netlist")]
+    top: Option<String>,
+
+    /// SDC dialect
+    #[arg(long)]
+    dialect: Option<String>,
+}
+
+#[derive(Debug, Parser)]
+struct Convert {
+    /// SDC file
+    file: PathBuf,
+
+    /// Target dialect
+    #[arg(long)]
+    to: Option<String>,
+
+    /// Target SDC version
+    #[arg(long)]
+    target_version: Option<String>,
+
+    /// Output file
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+
+    /// SDC dialect
+    #[arg(long)]
+    dialect: Option<String>,
+}
+
+#[derive(Debug, Parser)]
+struct Promote {
+    /// SDC file
+    file: PathBuf,
+
+    /// Instance path of the block in the top design
+    #[arg(long)]
+    instance: String,
+
+    /// Output file
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+
+    /// SDC dialect
+    #[arg(long)]
+    dialect: Option<String>,
+}
+
+#[derive(Debug, Parser)]
+struct Demote {
+    /// SDC file
+    file: PathBuf,
+
+    /// Instance path of the block in the top design
+    #[arg(long)]
+    instance: String,
+
+    /// Output file
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+
+    /// SDC dialect
+    #[arg(long)]
+    dialect: Option<String>,
+}
+
+#[derive(Debug, Parser)]
+struct Explain {
+    /// Command name
+    command: Option<String>,
+
+    /// List commands and options available at the SDC version
+    #[arg(long, conflicts_with = "command")]
+    version: Option<String>,
+
+    /// SDC dialect
+    #[arg(long)]
+    dialect: Option<String>,
+}
+
+#[derive(Debug, Parser)]
+struct Query {
+    /// Query like `set_false_path && object=u_ddr`
+    #[arg(allow_hyphen_values = true)]
+    query: String,
+
+    /// SDC file
+    file: PathBuf,
+
+    /// SDC dialect
+    #[arg(long)]
+    dialect: Option<String>,
+}
+
+#[derive(Debug, Parser)]
+struct Rename {
+    /// SDC file
+    file: PathBuf,
+
+    /// Rename clock like `OLD=NEW`
+    #[arg(long)]
+    clock: Vec<String>,
+
+    /// Rename port like `OLD=NEW`
+    #[arg(long)]
+    port: Vec<String>,
+
+    /// Replace instance path prefix like `OLD=NEW`
+    #[arg(long)]
+    instance_prefix: Vec<String>,
+
+    /// Output file
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+
+    /// SDC dialect
+    #[arg(long)]
+    dialect: Option<String>,
+}
+
+#[derive(Debug, Parser)]
+struct Stats {
+    /// SDC file
+    file: PathBuf,
+
+    /// Print as JSON
+    #[arg(long)]
+    json: bool,
+
+    /// SDC dialect
+    #[arg(long)]
+    dialect: Option<String>,
 }
 
 // ---------------------------------------------------------------------------------------------------------------------
@@ -119,14 +349,35 @@ fn with_report<T, U: Report>(
     }
 }
 
+fn parse_dialect(dialect: &str) -> Result<Dialect> {
+    if let Ok(x) = dialect.try_into() {
+        Ok(x)
+    } else {
+        let dialects: Vec<_> = Dialect::all().iter().map(|x| x.name()).collect();
+        bail!(
+            "Unknown dialect: {dialect} (available: {})",
+            dialects.join(", ")
+        )
+    }
+}
+
+fn dialect(dialect: &Option<String>, file: &Path) -> Result<Dialect> {
+    if let Some(dialect) = dialect {
+        parse_dialect(dialect)
+    } else {
+        Ok(Dialect::from_path(file))
+    }
+}
+
 fn format(opt: &Fmt) -> Result<()> {
+    let dialect = dialect(&opt.dialect, &opt.file)?;
     let s = read_file(&opt.file)?;
 
     let mut files = FileDb::new();
     files.add(opt.file.display().to_string(), s.as_str());
 
     let mut sdc = with_report(
-        sdcx::Parser::parse(&s, &opt.file),
+        sdcx::Parser::parse_with_dialect(&s, &opt.file, dialect),
         &files,
         &format!("could not parse file: {}", opt.file.display()),
     )?;
@@ -155,8 +406,9 @@ fn check(opt: &Check) -> Result<()> {
         };
     }
 
+    let dialect = dialect(&opt.dialect, &opt.file)?;
     let sdc = with_report(
-        sdcx::Parser::parse(&s, &opt.file),
+        sdcx::Parser::parse_with_dialect(&s, &opt.file, dialect),
         &files,
         &format!("could not parse file: {}", opt.file.display()),
     )?;
@@ -165,17 +417,62 @@ fn check(opt: &Check) -> Result<()> {
         err.report(&files)?;
     }
 
+    if opt.suggest_version {
+        let declared = sdc
+            .version
+            .map(|x| format!("SDC {}", x.version_string()))
+            .unwrap_or("none".to_string());
+        if let Some(required) = sdc.required_version() {
+            println!(
+                "Required version: SDC {} (declared: {declared})",
+                required.version.version_string()
+            );
+            for reason in &required.reasons {
+                println!(
+                    "  {}:{}: {reason}",
+                    opt.file.display(),
+                    reason.location.start_line
+                );
+            }
+        } else {
+            println!("Required version: no version supports all commands (declared: {declared})");
+        }
+    }
+
+    let constraints: Constraints = sdc.into();
+
+    if let (Some(netlist), Some(top)) = (&opt.netlist, &opt.top) {
+        let design = Design::from_verilog(&read_file(netlist)?, top)
+            .with_context(|| format!("could not read netlist: {}", netlist.display()))?;
+        for err in constraints.check_design(&design) {
+            err.report(&files)?;
+        }
+    }
+
+    if !opt.liberty.is_empty() {
+        let mut libraries = vec![];
+        for liberty in &opt.liberty {
+            let mut x = parse_liberty(&read_file(liberty)?)
+                .with_context(|| format!("could not read liberty: {}", liberty.display()))?;
+            libraries.append(&mut x);
+        }
+        for err in constraints.check_library(&libraries) {
+            err.report(&files)?;
+        }
+    }
+
     Ok(())
 }
 
 fn dump(opt: &Dump) -> Result<()> {
+    let dialect = dialect(&opt.dialect, &opt.file)?;
     let s = read_file(&opt.file)?;
 
     let mut files = FileDb::new();
     files.add(opt.file.display().to_string(), s.as_str());
 
     let sdc = with_report(
-        sdcx::Parser::parse(&s, &opt.file),
+        sdcx::Parser::parse_with_dialect(&s, &opt.file, dialect),
         &files,
         &format!("could not parse file: {}", opt.file.display()),
     )?;
@@ -196,6 +493,300 @@ fn dump(opt: &Dump) -> Result<()> {
     Ok(())
 }
 
+fn lint(opt: &Lint) -> Result<()> {
+    let dialect = dialect(&opt.dialect, &opt.file)?;
+    let mut linter = Linter::new();
+    for rule in &opt.disable {
+        if let Ok(x) = rule.as_str().try_into() {
+            linter.disable(x);
+        } else {
+            let rules: Vec<_> = LintRule::all().iter().map(|x| x.name()).collect();
+            bail!(
+                "Unknown lint rule: {rule} (available: {})",
+                rules.join(", ")
+            )
+        }
+    }
+
+    if let Some(ports) = &opt.ports {
+        linter.ports(parse_port_list(&read_file(ports)?));
+    }
+
+    let s = read_file(&opt.file)?;
+
+    let mut files = FileDb::new();
+    files.add(opt.file.display().to_string(), s.as_str());
+
+    let sdc = with_report(
+        sdcx::Parser::parse_with_dialect(&s, &opt.file, dialect),
+        &files,
+        &format!("could not parse file: {}", opt.file.display()),
+    )?;
+
+    for err in linter.lint(&sdc) {
+        err.report(&files)?;
+    }
+
+    Ok(())
+}
+
+fn coverage(opt: &Coverage) -> Result<()> {
+    let dialect = dialect(&opt.dialect, &opt.file)?;
+    let ports = match (&opt.ports, &opt.netlist, &opt.top) {
+        (Some(ports), _, _) => parse_port_list(&read_file(ports)?),
+        (_, Some(netlist), Some(top)) => {
+            let design = Design::from_verilog(&read_file(netlist)?, top)
+                .with_context(|| format!("could not read netlist: {}", netlist.display()))?;
+            netlist_ports(&design)
+        }
+        _ => bail!("Either --ports or --netlist is required"),
+    };
+
+    let s = read_file(&opt.file)?;
+
+    let mut files = FileDb::new();
+    files.add(opt.file.display().to_string(), s.as_str());
+
+    let sdc = with_report(
+        sdcx::Parser::parse_with_dialect(&s, &opt.file, dialect),
+        &files,
+        &format!("could not parse file: {}", opt.file.display()),
+    )?;
+
+    let mut linter = Linter::new();
+    for rule in LintRule::all() {
+        linter.disable(*rule);
+    }
+    linter.enable(LintRule::UnknownPort).ports(ports.clone());
+    for err in linter.lint(&sdc) {
+        err.report(&files)?;
+    }
+
+    print!("{}", IoCoverage::new(&sdc, &ports));
+
+    Ok(())
+}
+
+fn convert(opt: &Convert) -> Result<()> {
+    if opt.to.is_none() && opt.target_version.is_none() {
+        bail!("Either --to or --target-version is required")
+    }
+
+    let dialect = dialect(&opt.dialect, &opt.file)?;
+
+    let to = match &opt.to {
+        Some(to) => match Dialect::try_from(to.as_str()) {
+            Ok(x) if Converter::targets().contains(&x) => x,
+            _ => {
+                let targets: Vec<_> = Converter::targets().iter().map(|x| x.name()).collect();
+                bail!("Unknown target: {to} (available: {})", targets.join(", "))
+            }
+        },
+        None => dialect,
+    };
+
+    let mut converter = Converter::new(to);
+    if let Some(target_version) = &opt.target_version {
+        if let Ok(x) = target_version.as_str().try_into() {
+            converter.target_version(x);
+        } else {
+            bail!("Unknown version: {target_version}")
+        };
+    }
+
+    let s = read_file(&opt.file)?;
+
+    let mut files = FileDb::new();
+    files.add(opt.file.display().to_string(), s.as_str());
+
+    let sdc = with_report(
+        sdcx::Parser::parse_with_dialect(&s, &opt.file, dialect),
+        &files,
+        &format!("could not parse file: {}", opt.file.display()),
+    )?;
+
+    let (conversion, errors) = converter.convert(&sdc);
+    for err in errors {
+        err.report(&files)?;
+    }
+
+    if let Some(output) = &opt.output {
+        write_file(output, &format!("{}", conversion))?;
+    } else {
+        println!("{}", conversion);
+    }
+    Ok(())
+}
+
+fn promote(opt: &Promote) -> Result<()> {
+    let dialect = dialect(&opt.dialect, &opt.file)?;
+    let s = read_file(&opt.file)?;
+
+    let mut files = FileDb::new();
+    files.add(opt.file.display().to_string(), s.as_str());
+
+    let sdc = with_report(
+        sdcx::Parser::parse_with_dialect(&s, &opt.file, dialect),
+        &files,
+        &format!("could not parse file: {}", opt.file.display()),
+    )?;
+
+    let (conversion, errors) = Promoter::new(&opt.instance).promote(&sdc);
+    for err in errors {
+        err.report(&files)?;
+    }
+
+    if let Some(output) = &opt.output {
+        write_file(output, &format!("{}", conversion))?;
+    } else {
+        println!("{}", conversion);
+    }
+    Ok(())
+}
+
+fn demote(opt: &Demote) -> Result<()> {
+    let dialect = dialect(&opt.dialect, &opt.file)?;
+    let s = read_file(&opt.file)?;
+
+    let mut files = FileDb::new();
+    files.add(opt.file.display().to_string(), s.as_str());
+
+    let sdc = with_report(
+        sdcx::Parser::parse_with_dialect(&s, &opt.file, dialect),
+        &files,
+        &format!("could not parse file: {}", opt.file.display()),
+    )?;
+
+    let (conversion, errors) = Demoter::new(&opt.instance).demote(&sdc);
+    for err in errors {
+        err.report(&files)?;
+    }
+
+    if let Some(output) = &opt.output {
+        write_file(output, &format!("{}", conversion))?;
+    } else {
+        println!("{}", conversion);
+    }
+    Ok(())
+}
+
+fn explain(opt: &Explain) -> Result<()> {
+    if let Some(version) = &opt.version {
+        let Ok(version) = version.as_str().try_into() else {
+            bail!("Unknown version: {version}")
+        };
+        for spec in CommandSpec::available(version) {
+            print!("{}", spec.usage_with_versions(version));
+        }
+    } else if let Some(command) = &opt.command {
+        let dialect = match &opt.dialect {
+            Some(x) => parse_dialect(x)?,
+            None => Dialect::Sdc,
+        };
+        let Some(spec) = CommandSpec::find(command, dialect) else {
+            bail!("Unknown command: {command}")
+        };
+        print!("{}", spec.explain());
+    } else {
+        bail!("Either command or --version is required")
+    }
+    Ok(())
+}
+
+fn query(opt: &Query) -> Result<()> {
+    let query = query::Query::parse(&opt.query).context("could not parse query")?;
+
+    let dialect = dialect(&opt.dialect, &opt.file)?;
+    let s = read_file(&opt.file)?;
+
+    let mut files = FileDb::new();
+    files.add(opt.file.display().to_string(), s.as_str());
+
+    let sdc = with_report(
+        sdcx::Parser::parse_with_dialect(&s, &opt.file, dialect),
+        &files,
+        &format!("could not parse file: {}", opt.file.display()),
+    )?;
+
+    for x in query.select(&sdc) {
+        println!(
+            "{}:{}: {}",
+            opt.file.display(),
+            x.location.start_line,
+            x.command
+        );
+    }
+    Ok(())
+}
+
+fn rename(opt: &Rename) -> Result<()> {
+    let pair = |x: &String| match x.split_once('=') {
+        Some((from, to)) if !from.is_empty() && !to.is_empty() => {
+            Ok((from.to_string(), to.to_string()))
+        }
+        _ => bail!("Invalid rename: {x} (expected OLD=NEW)"),
+    };
+
+    let mut renamer = Renamer::new();
+    for x in &opt.clock {
+        let (from, to) = pair(x)?;
+        renamer.clock(&from, &to);
+    }
+    for x in &opt.port {
+        let (from, to) = pair(x)?;
+        renamer.port(&from, &to);
+    }
+    for x in &opt.instance_prefix {
+        let (from, to) = pair(x)?;
+        renamer.instance_prefix(&from, &to);
+    }
+
+    let dialect = dialect(&opt.dialect, &opt.file)?;
+    let s = read_file(&opt.file)?;
+
+    let mut files = FileDb::new();
+    files.add(opt.file.display().to_string(), s.as_str());
+
+    let mut sdc = with_report(
+        sdcx::Parser::parse_with_dialect(&s, &opt.file, dialect),
+        &files,
+        &format!("could not parse file: {}", opt.file.display()),
+    )?;
+    for err in renamer.rename(&mut sdc) {
+        err.report(&files)?;
+    }
+
+    if let Some(output) = &opt.output {
+        write_file(output, &format!("{}", sdc))?;
+    } else {
+        println!("{}", sdc);
+    }
+    Ok(())
+}
+
+fn stats(opt: &Stats) -> Result<()> {
+    let dialect = dialect(&opt.dialect, &opt.file)?;
+    let s = read_file(&opt.file)?;
+
+    let mut files = FileDb::new();
+    files.add(opt.file.display().to_string(), s.as_str());
+
+    let sdc = with_report(
+        sdcx::Parser::parse_with_dialect(&s, &opt.file, dialect),
+        &files,
+        &format!("could not parse file: {}", opt.file.display()),
+    )?;
+
+    let stats = stats::Stats::new(&sdc);
+
+    if opt.json {
+        println!("{}", serde_json::to_string_pretty(&stats.to_json())?);
+    } else {
+        print!("{stats}");
+    }
+    Ok(())
+}
+
 // ---------------------------------------------------------------------------------------------------------------------
 // Main
 // ---------------------------------------------------------------------------------------------------------------------
@@ -207,6 +798,15 @@ fn main() -> Result<()> {
         SubCommands::Fmt(x) => format(&x)?,
         SubCommands::Check(x) => check(&x)?,
         SubCommands::Dump(x) => dump(&x)?,
+        SubCommands::Lint(x) => lint(&x)?,
+        SubCommands::Coverage(x) => coverage(&x)?,
+        SubCommands::Convert(x) => convert(&x)?,
+        SubCommands::Promote(x) => promote(&x)?,
+        SubCommands::Demote(x) => demote(&x)?,
+        SubCommands::Explain(x) => explain(&x)?,
+        SubCommands::Query(x) => query(&x)?,
+        SubCommands::Rename(x) => rename(&x)?,
+        SubCommands::Stats(x) => stats(&x)?,
     }
 
     Ok(())
@@ -1,10 +1,20 @@
 pub mod clock;
+pub mod io;
+mod library;
 pub mod object;
+mod resolve;
+pub mod scope;
+pub mod unit;
 
-use crate::errors::InterpretError;
+use crate::design::{Design, Library};
+use crate::errors::{InterpretError, ResolveError};
+use crate::sdc::util::CommandExt;
 use crate::sdc::{Command, CommandKind, Sdc};
 pub use clock::*;
+pub use io::*;
 pub use object::*;
+pub use scope::*;
+pub use unit::*;
 
 #[derive(Clone, Debug)]
 pub struct Constraints {
@@ -12,21 +22,127 @@ pub struct Constraints {
 }
 
 impl Constraints {
+    /// Units declared by `set_units`
+    ///
+    /// Units are applied to the whole file regardless of the position of `set_units`,
+    /// so a unit which conflicts with the one declared by a former `set_units` is an error.
+    pub fn units(&self) -> Result<Units, InterpretError> {
+        let mut ret = Units::default();
+        let mut declared = vec![];
+        for command in self.sdc.extract(CommandKind::SetUnits) {
+            if let Command::SetUnits(x) = command {
+                let mut units = ret;
+                units.apply(x)?;
+                for dimension in Units::dimensions(x) {
+                    if declared.contains(&dimension) && units.get(dimension) != ret.get(dimension) {
+                        return Err(InterpretError::ConflictingUnits(dimension, x.location()));
+                    }
+                    declared.push(dimension);
+                }
+                ret = units;
+            }
+        }
+        Ok(ret)
+    }
+
     pub fn clocks_mut(&mut self) -> Result<Vec<ClockMut<'_>>, InterpretError> {
+        let units = self.units()?;
         let mut ret = vec![];
         for clock in self.sdc.extract_mut(CommandKind::CreateClock) {
             if let Command::CreateClock(x) = clock {
-                ret.push(ClockMut::try_from(x)?);
+                let mut clock = ClockMut::try_from(x)?;
+                clock.set_units(&units);
+                ret.push(clock);
             }
         }
         Ok(ret)
     }
 
     pub fn clocks(&mut self) -> Result<Vec<Clock>, InterpretError> {
+        let units = self.units()?;
         let mut ret = vec![];
         for clock in self.sdc.extract(CommandKind::CreateClock) {
             if let Command::CreateClock(x) = clock {
-                ret.push(Clock::try_from(x)?);
+                let mut clock = Clock::try_from(x)?;
+                clock.set_units(&units);
+                ret.push(clock);
+            }
+        }
+        Ok(ret)
+    }
+
+    pub fn io_delays(&self) -> Result<Vec<IoDelay>, InterpretError> {
+        let units = self.units()?;
+        let mut ret = vec![];
+        for command in &self.sdc.commands {
+            let mut delay = match command {
+                Command::SetInputDelay(x) => IoDelay::try_from(x)?,
+                Command::SetOutputDelay(x) => IoDelay::try_from(x)?,
+                _ => continue,
+            };
+            delay.set_units(&units);
+            ret.push(delay);
+        }
+        Ok(ret)
+    }
+
+    pub fn loads(&self) -> Result<Vec<Load>, InterpretError> {
+        let units = self.units()?;
+        let mut ret = vec![];
+        for command in self.sdc.extract(CommandKind::SetLoad) {
+            if let Command::SetLoad(x) = command {
+                let mut load = Load::try_from(x)?;
+                load.set_units(&units);
+                ret.push(load);
+            }
+        }
+        Ok(ret)
+    }
+
+    /// Hierarchy separator declared by `set_hierarchy_separator`
+    pub fn hierarchy_separator(&self) -> String {
+        hierarchy_separator(&self.sdc).unwrap_or("/".into())
+    }
+
+    /// Resolve an object query like `get_pins` into objects of `design`.
+    ///
+    /// Returns `None` if `query` is not an object query.
+    pub fn resolve(&self, design: &Design, query: &Command) -> Option<Vec<Object>> {
+        resolve::resolve(design, query, &self.hierarchy_separator(), "")
+    }
+
+    /// Object queries with the scope set by `current_design` and `current_instance`
+    pub fn queries(&self) -> Vec<ScopedQuery<'_>> {
+        scope::queries(&self.sdc.commands, &self.hierarchy_separator())
+    }
+
+    /// Resolve a scoped object query into objects of `design`
+    pub fn resolve_scoped(&self, design: &Design, query: &ScopedQuery) -> Vec<Object> {
+        let instance = query.scope.path("/");
+        resolve::resolve(
+            design,
+            query.command,
+            &self.hierarchy_separator(),
+            &instance,
+        )
+        .unwrap_or_default()
+    }
+
+    /// Check object queries which match nothing in `design`
+    pub fn check_design(&self, design: &Design) -> Vec<ResolveError> {
+        resolve::check(design, &self.sdc, &self.hierarchy_separator())
+    }
+
+    /// Check library objects which are not found in `libraries`
+    pub fn check_library(&self, libraries: &[Library]) -> Vec<ResolveError> {
+        library::check(libraries, &self.sdc)
+    }
+
+    pub fn generated_clocks(&self) -> Result<Vec<GeneratedClock>, InterpretError> {
+        let mut ret = vec![];
+        for clock in self.sdc.extract(CommandKind::CreateGeneratedClock) {
+            if let Command::CreateGeneratedClock(x) = clock {
+                ret.push(GeneratedClock::try_from(x)?);
             }
         }
         Ok(ret)
@@ -51,7 +167,7 @@ mod tests {
     use crate::Parser;
 
     fn parse(code: &str) -> Constraints {
-        let sdc = Parser::parse(&code, &"").unwrap();
+        let sdc = Parser::parse(code, &"").unwrap();
         sdc.into()
     }
 
@@ -92,4 +208,77 @@ create_clock -period 1 [get_pins i_clk2] -waveform {1.0 2.0 3.0 4.0}
         assert_eq!(waveform[2], 3.0);
         assert_eq!(waveform[3], 4.0);
     }
+
+    #[test]
+    fn generated_clock() {
+        let code = r##"
+create_clock -period 10 -name CLK [get_ports i_clk0]
+create_generated_clock -name GCLK -source [get_ports i_clk0] -divide_by 2 -master_clock [get_clocks CLK] [get_pins div/Q]
+create_generated_clock -source [get_ports i_clk0] -edges {1 3 5} -edge_shift {0 0 1.5} [get_pins gen/Q]
+        "##;
+
+        let constraints = parse(code);
+        let clocks = constraints.generated_clocks().unwrap();
+
+        assert_eq!(clocks[0].name(), "GCLK");
+        assert_eq!(clocks[0].source(), &Object::Port("i_clk0".into()));
+        assert_eq!(clocks[0].source_objects(), &Object::Pin("div/Q".into()));
+        assert_eq!(clocks[0].master_clock(), &Some("CLK".into()));
+        assert_eq!(clocks[0].factor(), Some(Factor::Divide(2)));
+
+        assert_eq!(clocks[1].name(), "gen/Q");
+        assert_eq!(clocks[1].master_clock(), &None);
+        assert_eq!(clocks[1].factor(), None);
+        assert_eq!(clocks[1].edges(), &[1, 3, 5]);
+        assert_eq!(clocks[1].edge_shift(), &[0.0, 0.0, 1.5]);
+    }
+
+    #[test]
+    fn units() {
+        let code = r##"
+set_units -time 100ps -capacitance fF
+create_clock -period 10 -name CLK [get_ports i_clk0]
+set_input_delay 2 -clock CLK [get_ports {a b}]
+set_output_delay 3 -clock [get_clocks CLK] [get_ports c]
+set_load 5 [get_ports c]
+        "##;
+
+        let mut constraints = parse(code);
+        let units = constraints.units().unwrap();
+        assert_eq!(units.time, Unit::try_from("100ps").unwrap());
+
+        let clocks = constraints.clocks().unwrap();
+        let period = clocks[0].period_quantity();
+        assert!((period.to_si() - 1e-9).abs() < 1e-18);
+        let period = period.convert(Unit::try_from("ns").unwrap()).unwrap();
+        assert!((period.value() - 1.0).abs() < 1e-9);
+
+        let delays = constraints.io_delays().unwrap();
+        assert_eq!(delays[0].kind(), IoDelayKind::Input);
+        assert_eq!(delays[0].clock(), &Some("CLK".into()));
+        assert_eq!(
+            delays[0].objects(),
+            &[Object::Port("a".into()), Object::Port("b".into())]
+        );
+        assert_eq!(delays[1].kind(), IoDelayKind::Output);
+        assert_eq!(delays[1].clock(), &Some("CLK".into()));
+        assert!((delays[1].delay().to_si() - 3e-10).abs() < 1e-18);
+
+        let loads = constraints.loads().unwrap();
+        assert!((loads[0].value().to_si() - 5e-15).abs() < 1e-24);
+
+        let code = "set_units -time pF\n";
+        assert!(parse(code).units().is_err());
+
+        let code = "set_units -time ns\nset_units -capacitance fF -time ns\n";
+        let units = parse(code).units().unwrap();
+        assert_eq!(units.capacitance, Unit::try_from("fF").unwrap());
+
+        let code =
+            "set_units -time ns\ncreate_clock -period 10 [get_ports clk]\nset_units -time ps\n";
+        assert!(matches!(
+            parse(code).units(),
+            Err(InterpretError::ConflictingUnits(Dimension::Time, x)) if x.start_line == 3
+        ));
+    }
 }
@@ -1,7 +1,8 @@
-use crate::constraints::Object;
+use crate::constraints::{clock_names, list_items, Dimension, Object, Quantity, Unit, Units};
 use crate::errors::InterpretError;
 use crate::sdc::util::CommandExt;
-use crate::sdc::CreateClock;
+use crate::sdc::Argument;
+use crate::sdc::{CreateClock, CreateGeneratedClock};
 use std::fmt;
 
 /// Clock definition
@@ -11,6 +12,8 @@ pub struct Clock {
     source: Option<Object>,
     period: f32,
     waveform: Vec<f32>,
+    add: bool,
+    unit: Unit,
 }
 
 impl Clock {
@@ -86,6 +89,31 @@ impl Clock {
     pub fn waveform(&self) -> &[f32] {
         &self.waveform
     }
+
+    pub fn add(&self) -> bool {
+        self.add
+    }
+
+    pub fn is_virtual(&self) -> bool {
+        self.source.is_none()
+    }
+
+    /// Period with the time unit declared by `set_units`
+    pub fn period_quantity(&self) -> Quantity {
+        Quantity::new(self.period as f64, self.unit)
+    }
+
+    /// Waveform with the time unit declared by `set_units`
+    pub fn waveform_quantity(&self) -> Vec<Quantity> {
+        self.waveform
+            .iter()
+            .map(|x| Quantity::new(*x as f64, self.unit))
+            .collect()
+    }
+
+    pub(crate) fn set_units(&mut self, units: &Units) {
+        self.unit = units.get(Dimension::Time);
+    }
 }
 
 impl TryFrom<&CreateClock> for Clock {
@@ -101,6 +129,8 @@ impl TryFrom<&CreateClock> for Clock {
             source,
             period,
             waveform,
+            add: value.add,
+            unit: Units::default().time,
         })
     }
 }
@@ -109,7 +139,7 @@ impl fmt::Display for Clock {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut text = "Clock:".to_string();
         text.push_str(&format!(" name={}", self.name));
-        text.push_str(&format!(" period={}", self.period));
+        text.push_str(&format!(" period={}", self.period_quantity()));
         if let Some(source) = &self.source {
             text.push_str(&format!(" source={source}"));
         }
@@ -161,6 +191,27 @@ impl<'a> ClockMut<'a> {
         self.inner.waveform()
     }
 
+    pub fn add(&self) -> bool {
+        self.inner.add()
+    }
+
+    pub fn is_virtual(&self) -> bool {
+        self.inner.is_virtual()
+    }
+
+    pub fn period_quantity(&self) -> Quantity {
+        self.inner.period_quantity()
+    }
+
+    pub fn waveform_quantity(&self) -> Vec<Quantity> {
+        self.inner.waveform_quantity()
+    }
+
+    pub(crate) fn set_units(&mut self, units: &Units) {
+        self.inner.set_units(units);
+    }
+
+    /// Rename the definition only, use [`crate::rename::Renamer`] to update references
     pub fn rename(&mut self, name: &str) {
         self.command.name = Some(name.into());
     }
@@ -172,70 +223,198 @@ impl<'a> fmt::Display for ClockMut<'a> {
     }
 }
 
-///// GeneratedClock definition
-//#[derive(Clone, Debug)]
-//pub struct GeneratedClock {
-//    name: String,
-//    source: Option<Object>,
-//    master_pin: Object,
-//    master_clock: Option<String>,
-//    factor: Factor,
-//    duty_cycle: i32,
-//    edges: Vec<i32>,
-//    edge_shift: Vec<f32>,
-//    invert: bool,
-//    combinational: bool,
-//    comment: String,
-//}
-//
-//impl GeneratedClock {
-//    pub fn name(&self) -> &str {
-//        &self.name
-//    }
-//
-//    pub fn source(&self) -> &Option<Object> {
-//        &self.source
-//    }
-//
-//    pub fn master_pin(&self) -> &Object {
-//        &self.master_pin
-//    }
-//
-//    pub fn master_clock(&self) -> &Option<String> {
-//        &self.master_clock
-//    }
-//
-//    pub fn factor(&self) -> Factor {
-//        self.factor
-//    }
-//
-//    pub fn duty_cycle(&self) -> i32 {
-//        self.duty_cycle
-//    }
-//
-//    pub fn edges(&self) -> &[i32] {
-//        &self.edges
-//    }
-//
-//    pub fn edge_shift(&self) -> &[f32] {
-//        &self.edge_shift
-//    }
-//
-//    pub fn invert(&self) -> bool {
-//        self.invert
-//    }
-//
-//    pub fn combinational(&self) -> bool {
-//        self.combinational
-//    }
-//
-//    pub fn comment(&self) -> &str {
-//        &self.comment
-//    }
-//}
-//
-//#[derive(Copy, Clone, Debug)]
-//pub enum Factor {
-//    Multiply(i32),
-//    Divide(i32),
-//}
+/// GeneratedClock definition
+#[derive(Debug)]
+pub struct GeneratedClock {
+    name: String,
+    source: Object,
+    source_objects: Object,
+    master_clock: Option<String>,
+    factor: Option<Factor>,
+    duty_cycle: Option<f32>,
+    edges: Vec<i32>,
+    edge_shift: Vec<f32>,
+    invert: bool,
+    add: bool,
+    combinational: bool,
+}
+
+impl GeneratedClock {
+    fn interpret_name(command: &CreateGeneratedClock) -> Result<String, InterpretError> {
+        let location = command.location();
+        if let Some(name) = &command.name {
+            Ok(format!("{name}"))
+        } else {
+            match Self::interpret_source_objects(command)? {
+                Object::Pin(x) => Ok(x),
+                Object::Port(x) => Ok(x),
+                Object::Net(x) => Ok(x),
+                _ => Err(InterpretError::Something(location)),
+            }
+        }
+    }
+
+    fn interpret_source(command: &CreateGeneratedClock) -> Result<Object, InterpretError> {
+        (&command.source).try_into()
+    }
+
+    fn interpret_source_objects(command: &CreateGeneratedClock) -> Result<Object, InterpretError> {
+        (&command.source_objects).try_into()
+    }
+
+    fn interpret_master_clock(
+        command: &CreateGeneratedClock,
+    ) -> Result<Option<String>, InterpretError> {
+        if let Some(ref master_clock) = command.master_clock {
+            let mut names = clock_names(master_clock);
+            if names.len() == 1 {
+                Ok(names.pop())
+            } else {
+                Err(InterpretError::Something(master_clock.location()))
+            }
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn interpret_factor(command: &CreateGeneratedClock) -> Result<Option<Factor>, InterpretError> {
+        let parse = |x: &Argument| -> Result<i32, InterpretError> {
+            x.as_str()
+                .parse::<i32>()
+                .map_err(|_| InterpretError::Something(x.location()))
+        };
+        if let Some(ref x) = command.divide_by {
+            Ok(Some(Factor::Divide(parse(x)?)))
+        } else if let Some(ref x) = command.multiply_by {
+            Ok(Some(Factor::Multiply(parse(x)?)))
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn interpret_duty_cycle(command: &CreateGeneratedClock) -> Result<Option<f32>, InterpretError> {
+        if let Some(ref x) = command.duty_cycle {
+            match x.as_str().parse::<f32>() {
+                Ok(x) => Ok(Some(x)),
+                Err(_) => Err(InterpretError::Something(x.location())),
+            }
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn interpret_list<T: std::str::FromStr>(
+        arg: &Option<Argument>,
+    ) -> Result<Vec<T>, InterpretError> {
+        let mut ret = vec![];
+        if let Some(arg) = arg {
+            for x in list_items(arg) {
+                match x.parse::<T>() {
+                    Ok(x) => ret.push(x),
+                    Err(_) => return Err(InterpretError::Something(arg.location())),
+                }
+            }
+        }
+        Ok(ret)
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn source(&self) -> &Object {
+        &self.source
+    }
+
+    pub fn source_objects(&self) -> &Object {
+        &self.source_objects
+    }
+
+    pub fn master_clock(&self) -> &Option<String> {
+        &self.master_clock
+    }
+
+    pub fn factor(&self) -> Option<Factor> {
+        self.factor
+    }
+
+    pub fn duty_cycle(&self) -> Option<f32> {
+        self.duty_cycle
+    }
+
+    pub fn edges(&self) -> &[i32] {
+        &self.edges
+    }
+
+    pub fn edge_shift(&self) -> &[f32] {
+        &self.edge_shift
+    }
+
+    pub fn invert(&self) -> bool {
+        self.invert
+    }
+
+    pub fn add(&self) -> bool {
+        self.add
+    }
+
+    pub fn combinational(&self) -> bool {
+        self.combinational
+    }
+}
+
+impl TryFrom<&CreateGeneratedClock> for GeneratedClock {
+    type Error = InterpretError;
+
+    fn try_from(value: &CreateGeneratedClock) -> Result<Self, InterpretError> {
+        let name = Self::interpret_name(value)?;
+        let source = Self::interpret_source(value)?;
+        let source_objects = Self::interpret_source_objects(value)?;
+        let master_clock = Self::interpret_master_clock(value)?;
+        let factor = Self::interpret_factor(value)?;
+        let duty_cycle = Self::interpret_duty_cycle(value)?;
+        let edges = Self::interpret_list(&value.edges)?;
+        let edge_shift = Self::interpret_list(&value.edge_shift)?;
+        Ok(GeneratedClock {
+            name,
+            source,
+            source_objects,
+            master_clock,
+            factor,
+            duty_cycle,
+            edges,
+            edge_shift,
+            invert: value.invert,
+            add: value.add,
+            combinational: value.combinational,
+        })
+    }
+}
+
+impl fmt::Display for GeneratedClock {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut text = "GeneratedClock:".to_string();
+        text.push_str(&format!(" name={}", self.name));
+        text.push_str(&format!(" source={}", self.source));
+        text.push_str(&format!(" source_objects={}", self.source_objects));
+        if let Some(master_clock) = &self.master_clock {
+            text.push_str(&format!(" master_clock={master_clock}"));
+        }
+        match self.factor {
+            Some(Factor::Divide(x)) => text.push_str(&format!(" divide_by={x}")),
+            Some(Factor::Multiply(x)) => text.push_str(&format!(" multiply_by={x}")),
+            None => (),
+        }
+        if let Some(duty_cycle) = self.duty_cycle {
+            text.push_str(&format!(" duty_cycle={duty_cycle}"));
+        }
+        text.fmt(f)
+    }
+}
+
+/// Frequency factor of generated clock
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Factor {
+    Multiply(i32),
+    Divide(i32),
+}
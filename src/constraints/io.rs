@@ -0,0 +1,181 @@
+use crate::constraints::{clock_names, objects, Dimension, Object, Quantity, Units};
+use crate::errors::InterpretError;
+use crate::sdc::{Argument, SetInputDelay, SetLoad, SetOutputDelay};
+use std::fmt;
+
+fn interpret_value(arg: &Argument) -> Result<f64, InterpretError> {
+    arg.as_str()
+        .parse::<f64>()
+        .map_err(|_| InterpretError::Something(arg.location()))
+}
+
+/// Direction of IO delay
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum IoDelayKind {
+    Input,
+    Output,
+}
+
+/// IO delay definition
+#[derive(Debug)]
+pub struct IoDelay {
+    kind: IoDelayKind,
+    clock: Option<String>,
+    delay: Quantity,
+    min: bool,
+    max: bool,
+    objects: Vec<Object>,
+}
+
+impl IoDelay {
+    fn interpret(
+        kind: IoDelayKind,
+        clock: &Option<Argument>,
+        delay: &Argument,
+        min: bool,
+        max: bool,
+        objects_arg: &Argument,
+    ) -> Result<Self, InterpretError> {
+        let clock = clock
+            .as_ref()
+            .and_then(|x| clock_names(x).into_iter().next());
+        let delay = Quantity::new(interpret_value(delay)?, Units::default().time);
+        Ok(IoDelay {
+            kind,
+            clock,
+            delay,
+            min,
+            max,
+            objects: objects(objects_arg),
+        })
+    }
+
+    pub(crate) fn set_units(&mut self, units: &Units) {
+        self.delay = Quantity::new(self.delay.value(), units.get(Dimension::Time));
+    }
+
+    pub fn kind(&self) -> IoDelayKind {
+        self.kind
+    }
+
+    pub fn clock(&self) -> &Option<String> {
+        &self.clock
+    }
+
+    pub fn delay(&self) -> Quantity {
+        self.delay
+    }
+
+    pub fn min(&self) -> bool {
+        self.min
+    }
+
+    pub fn max(&self) -> bool {
+        self.max
+    }
+
+    pub fn objects(&self) -> &[Object] {
+        &self.objects
+    }
+}
+
+impl TryFrom<&SetInputDelay> for IoDelay {
+    type Error = InterpretError;
+
+    fn try_from(value: &SetInputDelay) -> Result<Self, InterpretError> {
+        Self::interpret(
+            IoDelayKind::Input,
+            &value.clock,
+            &value.delay_value,
+            value.min,
+            value.max,
+            &value.port_pin_list,
+        )
+    }
+}
+
+impl TryFrom<&SetOutputDelay> for IoDelay {
+    type Error = InterpretError;
+
+    fn try_from(value: &SetOutputDelay) -> Result<Self, InterpretError> {
+        Self::interpret(
+            IoDelayKind::Output,
+            &value.clock,
+            &value.delay_value,
+            value.min,
+            value.max,
+            &value.port_pin_list,
+        )
+    }
+}
+
+impl fmt::Display for IoDelay {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut text = match self.kind {
+            IoDelayKind::Input => "InputDelay:".to_string(),
+            IoDelayKind::Output => "OutputDelay:".to_string(),
+        };
+        text.push_str(&format!(" delay={}", self.delay));
+        if let Some(clock) = &self.clock {
+            text.push_str(&format!(" clock={clock}"));
+        }
+        for object in &self.objects {
+            text.push_str(&format!(" {object}"));
+        }
+        text.fmt(f)
+    }
+}
+
+/// Load definition
+#[derive(Debug)]
+pub struct Load {
+    value: Quantity,
+    min: bool,
+    max: bool,
+    objects: Vec<Object>,
+}
+
+impl Load {
+    pub(crate) fn set_units(&mut self, units: &Units) {
+        self.value = Quantity::new(self.value.value(), units.get(Dimension::Capacitance));
+    }
+
+    pub fn value(&self) -> Quantity {
+        self.value
+    }
+
+    pub fn min(&self) -> bool {
+        self.min
+    }
+
+    pub fn max(&self) -> bool {
+        self.max
+    }
+
+    pub fn objects(&self) -> &[Object] {
+        &self.objects
+    }
+}
+
+impl TryFrom<&SetLoad> for Load {
+    type Error = InterpretError;
+
+    fn try_from(value: &SetLoad) -> Result<Self, InterpretError> {
+        Ok(Load {
+            value: Quantity::new(interpret_value(&value.value)?, Units::default().capacitance),
+            min: value.min,
+            max: value.max,
+            objects: objects(&value.objects),
+        })
+    }
+}
+
+impl fmt::Display for Load {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut text = format!("Load: value={}", self.value);
+        for object in &self.objects {
+            text.push_str(&format!(" {object}"));
+        }
+        text.fmt(f)
+    }
+}
@@ -0,0 +1,269 @@
+use crate::constraints::{list_items, unquote};
+use crate::design::{LibCell, Library};
+use crate::errors::ResolveError;
+use crate::file_db::Location;
+use crate::matcher::{Matcher, MatcherBuilder};
+use crate::sdc::util::CommandExt;
+use crate::sdc::{Argument, Command, CommandKind, Sdc};
+
+fn matcher(pattern: &str, regexp: bool, nocase: bool) -> Option<Matcher> {
+    MatcherBuilder::new()
+        .regexp(regexp)
+        .nocase(nocase)
+        .build(pattern)
+        .ok()
+}
+
+/// Libraries specified by `-library`, or all libraries
+fn libraries<'a>(
+    libraries: &'a [Library],
+    library: &Option<Argument>,
+    location: &Location,
+    ret: &mut Vec<ResolveError>,
+) -> Vec<&'a Library> {
+    let Some(library) = library else {
+        return libraries.iter().collect();
+    };
+    let name = unquote(library);
+    let found: Vec<_> = libraries.iter().filter(|x| x.name == name).collect();
+    if found.is_empty() {
+        ret.push(ResolveError::UnknownLibraryObject(
+            "library".into(),
+            name,
+            location.clone(),
+        ));
+    }
+    found
+}
+
+fn check_names(
+    kind: &str,
+    names: Vec<String>,
+    exists: impl Fn(&str) -> bool,
+    location: &Location,
+    ret: &mut Vec<ResolveError>,
+) {
+    for name in names {
+        if !exists(&name) {
+            ret.push(ResolveError::UnknownLibraryObject(
+                kind.into(),
+                name,
+                location.clone(),
+            ));
+        }
+    }
+}
+
+fn check_queries(libraries: &[Library], sdc: &Sdc, ret: &mut Vec<ResolveError>) {
+    for command in sdc.extract(CommandKind::GetLibs) {
+        if let Command::GetLibs(x) = command {
+            let patterns = x.patterns.as_ref().map(list_items).unwrap_or_default();
+            let exists = |pattern: &str| match matcher(pattern, x.regexp, x.nocase) {
+                Some(matcher) => libraries.iter().any(|x| matcher.is_match(&x.name)),
+                None => true,
+            };
+            check_names("library", patterns, exists, &x.location(), ret);
+        }
+    }
+
+    for command in sdc.extract(CommandKind::GetLibCells) {
+        if let Command::GetLibCells(x) = command {
+            let separator = x.hsc.as_ref().map(unquote).unwrap_or("/".into());
+            let exists = |pattern: &str| {
+                let (lib, cell) = pattern.rsplit_once(&separator).unwrap_or(("*", pattern));
+                let lib = matcher(lib, x.regexp, x.nocase);
+                let cell = matcher(cell, x.regexp, x.nocase);
+                let (Some(lib), Some(cell)) = (lib, cell) else {
+                    return true;
+                };
+                libraries
+                    .iter()
+                    .filter(|x| lib.is_match(&x.name))
+                    .any(|x| x.cells.iter().any(|x| cell.is_match(&x.name)))
+            };
+            check_names(
+                "lib cell",
+                list_items(&x.patterns),
+                exists,
+                &x.location(),
+                ret,
+            );
+        }
+    }
+
+    for command in sdc.extract(CommandKind::GetLibPins) {
+        if let Command::GetLibPins(x) = command {
+            let exists = |pattern: &str| {
+                let mut items: Vec<_> = pattern.rsplitn(3, '/').collect();
+                items.resize(3, "*");
+                let pin = matcher(items[0], x.regexp, x.nocase);
+                let cell = matcher(items[1], x.regexp, x.nocase);
+                let lib = matcher(items[2], x.regexp, x.nocase);
+                let (Some(lib), Some(cell), Some(pin)) = (lib, cell, pin) else {
+                    return true;
+                };
+                libraries
+                    .iter()
+                    .filter(|x| lib.is_match(&x.name))
+                    .flat_map(|x| x.cells.iter())
+                    .filter(|x| cell.is_match(&x.name))
+                    .any(|x| x.pins.iter().any(|x| pin.is_match(&x.name)))
+            };
+            check_names(
+                "lib pin",
+                list_items(&x.patterns),
+                exists,
+                &x.location(),
+                ret,
+            );
+        }
+    }
+}
+
+/// Check library objects referenced by SDC commands
+pub(crate) fn check(libraries: &[Library], sdc: &Sdc) -> Vec<ResolveError> {
+    let mut ret = vec![];
+    check_queries(libraries, sdc, &mut ret);
+
+    for command in &sdc.commands {
+        let location = command.location();
+        match command {
+            Command::SetDrivingCell(x) => {
+                let libs = self::libraries(libraries, &x.library, &location, &mut ret);
+                let Argument::Word(lib_cell) = &x.lib_cell else {
+                    continue;
+                };
+                // Library can be specified as prefix like `lib/cell`
+                let (libs, name) = match lib_cell.text.rsplit_once('/') {
+                    Some((lib, cell)) => {
+                        (libs.into_iter().filter(|x| x.name == lib).collect(), cell)
+                    }
+                    None => (libs, lib_cell.text.as_str()),
+                };
+                let cells: Vec<&LibCell> = libs.iter().filter_map(|x| x.cell(name)).collect();
+                if cells.is_empty() {
+                    check_names(
+                        "lib cell",
+                        vec![name.into()],
+                        |_| false,
+                        &location,
+                        &mut ret,
+                    );
+                    continue;
+                }
+                let pins: Vec<_> = [&x.pin, &x.from_pin]
+                    .into_iter()
+                    .flatten()
+                    .map(unquote)
+                    .collect();
+                let exists = |pin: &str| cells.iter().any(|x| x.pin(pin).is_some());
+                check_names("lib pin", pins, exists, &location, &mut ret);
+            }
+            Command::SetOperatingConditions(x) => {
+                let mut libs = self::libraries(libraries, &x.library, &location, &mut ret);
+                for library in [&x.max_library, &x.min_library] {
+                    if library.is_some() {
+                        libs.append(&mut self::libraries(
+                            libraries, library, &location, &mut ret,
+                        ));
+                    }
+                }
+                let names = [&x.condition, &x.max, &x.min]
+                    .into_iter()
+                    .flatten()
+                    .map(unquote)
+                    .collect();
+                let exists = |name: &str| {
+                    libs.iter()
+                        .any(|x| x.operating_conditions.iter().any(|x| x == name))
+                };
+                check_names("operating condition", names, exists, &location, &mut ret);
+            }
+            Command::SetWireLoadModel(x) => {
+                let libs = self::libraries(libraries, &x.library, &location, &mut ret);
+                let exists =
+                    |name: &str| libs.iter().any(|x| x.wire_loads.iter().any(|x| x == name));
+                check_names(
+                    "wire load model",
+                    vec![unquote(&x.name)],
+                    exists,
+                    &location,
+                    &mut ret,
+                );
+            }
+            Command::SetWireLoadSelectionGroup(x) => {
+                let libs = self::libraries(libraries, &x.library, &location, &mut ret);
+                let exists = |name: &str| {
+                    libs.iter()
+                        .any(|x| x.wire_load_selections.iter().any(|x| x == name))
+                };
+                check_names(
+                    "wire load selection group",
+                    vec![unquote(&x.group_name)],
+                    exists,
+                    &location,
+                    &mut ret,
+                );
+            }
+            _ => (),
+        }
+    }
+
+    ret.sort_by(|a, b| a.location().cmp(b.location()));
+    ret
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::design::parse_liberty;
+    use crate::Parser;
+
+    #[test]
+    fn library() {
+        let lib = r##"
+library (typical) {
+    operating_conditions (typ) { }
+    wire_load (small) { }
+    cell (BUF) {
+        pin (A) { direction : input; }
+        pin (Y) { direction : output; }
+    }
+}
+"##;
+        let libraries = parse_liberty(lib).unwrap();
+
+        let code = r##"
+set_operating_conditions -library typical typ
+set_operating_conditions -library slow typ
+set_wire_load_model -name large
+set_driving_cell -lib_cell BUF -pin Y [all_inputs]
+set_driving_cell -lib_cell BUF -from_pin B -pin Y [all_inputs]
+set_driving_cell -lib_cell typical/INV [all_inputs]
+set_disable_timing [get_lib_pins typical/BUF/A]
+set_disable_timing [get_lib_pins */Z]
+set_driving_cell -lib_cell [get_lib_cells {BUF INV}] [all_inputs]
+"##;
+        let sdc = Parser::parse(code, &"").unwrap();
+        let errors = check(&libraries, &sdc);
+        let names: Vec<_> = errors
+            .iter()
+            .map(|x| match x {
+                ResolveError::UnknownLibraryObject(x, y, _) => format!("{x}: {y}"),
+                _ => unreachable!(),
+            })
+            .collect();
+        assert_eq!(
+            names,
+            vec![
+                "library: slow",
+                "operating condition: typ",
+                "wire load model: large",
+                "lib pin: B",
+                "lib cell: INV",
+                "lib pin: */Z",
+                "lib cell: INV",
+            ]
+        );
+    }
+}
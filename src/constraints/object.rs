@@ -1,5 +1,5 @@
 use crate::errors::InterpretError;
-use crate::sdc::{Argument, Command};
+use crate::sdc::{Argument, Command, GetCells, GetNets, GetPins, GetPorts, Sdc};
 use std::fmt;
 
 /// Object
@@ -11,6 +11,29 @@ pub enum Object {
     Cell(String),
 }
 
+impl Object {
+    pub fn name(&self) -> &str {
+        match self {
+            Object::Pin(x) => x,
+            Object::Port(x) => x,
+            Object::Net(x) => x,
+            Object::Cell(x) => x,
+        }
+    }
+}
+
+impl From<Object> for Command {
+    fn from(value: Object) -> Self {
+        // object query only with patterns has no validation error
+        match value {
+            Object::Pin(x) => GetPins::builder().patterns(x).build().unwrap().into(),
+            Object::Port(x) => GetPorts::builder().patterns(x).build().unwrap().into(),
+            Object::Net(x) => GetNets::builder().patterns(x).build().unwrap().into(),
+            Object::Cell(x) => GetCells::builder().patterns(x).build().unwrap().into(),
+        }
+    }
+}
+
 impl TryFrom<&Argument> for Object {
     type Error = InterpretError;
 
@@ -22,7 +45,7 @@ impl TryFrom<&Argument> for Object {
                     match x.patterns.clone().unwrap() {
                         Argument::Word(x) => return Ok(Object::Pin(x.text.clone())),
                         Argument::BraceGroup(x) => {
-                            let name = x.text[1..x.text.len() - 1].to_string();
+                            let name = x.text[1..x.text.len() - 1].trim().to_string();
                             if name.contains(char::is_whitespace) {
                                 return Err(InterpretError::Something(location));
                             }
@@ -35,7 +58,7 @@ impl TryFrom<&Argument> for Object {
                     match x.patterns.clone().unwrap() {
                         Argument::Word(x) => return Ok(Object::Port(x.text.clone())),
                         Argument::BraceGroup(x) => {
-                            let name = x.text[1..x.text.len() - 1].to_string();
+                            let name = x.text[1..x.text.len() - 1].trim().to_string();
                             if name.contains(char::is_whitespace) {
                                 return Err(InterpretError::Something(location));
                             }
@@ -48,7 +71,7 @@ impl TryFrom<&Argument> for Object {
                     match x.patterns.clone().unwrap() {
                         Argument::Word(x) => return Ok(Object::Net(x.text.clone())),
                         Argument::BraceGroup(x) => {
-                            let name = x.text[1..x.text.len() - 1].to_string();
+                            let name = x.text[1..x.text.len() - 1].trim().to_string();
                             if name.contains(char::is_whitespace) {
                                 return Err(InterpretError::Something(location));
                             }
@@ -61,7 +84,7 @@ impl TryFrom<&Argument> for Object {
                     match x.patterns.clone().unwrap() {
                         Argument::Word(x) => return Ok(Object::Cell(x.text.clone())),
                         Argument::BraceGroup(x) => {
-                            let name = x.text[1..x.text.len() - 1].to_string();
+                            let name = x.text[1..x.text.len() - 1].trim().to_string();
                             if name.contains(char::is_whitespace) {
                                 return Err(InterpretError::Something(location));
                             }
@@ -78,6 +101,73 @@ impl TryFrom<&Argument> for Object {
     }
 }
 
+/// Split a list argument like `{a b c}` into its items
+pub(crate) fn list_items(arg: &Argument) -> Vec<String> {
+    match arg {
+        Argument::Word(x) => vec![x.text.clone()],
+        Argument::BraceGroup(x) => split_group(&x.text),
+        Argument::StringGroup(x) => split_group(&x.text),
+        Argument::CommandSubstitution(_, _) => vec![],
+    }
+}
+
+fn split_group(text: &str) -> Vec<String> {
+    if text.len() < 2 {
+        return vec![];
+    }
+    text[1..text.len() - 1]
+        .split_whitespace()
+        .map(|x| x.to_string())
+        .collect()
+}
+
+/// Strip quotes or braces of a single word argument
+pub(crate) fn unquote(arg: &Argument) -> String {
+    arg.as_str()
+        .trim_matches(|c| c == '{' || c == '}' || c == '"')
+        .to_string()
+}
+
+/// Hierarchy separator specified by `set_hierarchy_separator`
+pub(crate) fn hierarchy_separator(sdc: &Sdc) -> Option<String> {
+    sdc.commands.iter().rev().find_map(|x| match x {
+        Command::SetHierarchySeparator(x) => Some(unquote(&x.separator)),
+        _ => None,
+    })
+}
+
+/// Collect objects from `[get_pins ...]`, `[get_ports ...]`, `[get_nets ...]` or `[get_cells ...]`
+pub(crate) fn objects(arg: &Argument) -> Vec<Object> {
+    let Argument::CommandSubstitution(x, _) = arg else {
+        return vec![];
+    };
+    let (patterns, f): (_, fn(String) -> Object) = match **x {
+        Command::GetPins(ref x) => (&x.patterns, Object::Pin),
+        Command::GetPorts(ref x) => (&x.patterns, Object::Port),
+        Command::GetNets(ref x) => (&x.patterns, Object::Net),
+        Command::GetCells(ref x) => (&x.patterns, Object::Cell),
+        _ => return vec![],
+    };
+    patterns
+        .as_ref()
+        .map(list_items)
+        .unwrap_or_default()
+        .into_iter()
+        .map(f)
+        .collect()
+}
+
+/// Collect clock names from `[get_clocks ...]` or a plain list of names
+pub(crate) fn clock_names(arg: &Argument) -> Vec<String> {
+    match arg {
+        Argument::CommandSubstitution(x, _) => match **x {
+            Command::GetClocks(ref x) => x.patterns.as_ref().map(list_items).unwrap_or_default(),
+            _ => vec![],
+        },
+        _ => list_items(arg),
+    }
+}
+
 impl fmt::Display for Object {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let text = match self {
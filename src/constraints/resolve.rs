@@ -0,0 +1,518 @@
+use crate::constraints::{list_items, scope, unquote, Object};
+use crate::design::Design;
+use crate::errors::ResolveError;
+use crate::lint::coverage::Direction;
+use crate::matcher::{base_name, Matcher, MatcherBuilder};
+use crate::sdc::util::CommandExt;
+use crate::sdc::{Argument, Command, Sdc};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Kind {
+    Port,
+    Cell,
+    Pin,
+    Net,
+}
+
+/// Options of object query
+struct Query<'a> {
+    kind: Kind,
+    hierarchical: bool,
+    regexp: bool,
+    nocase: bool,
+    separator: String,
+    of_objects: &'a Option<Argument>,
+    patterns: Vec<String>,
+}
+
+impl Query<'_> {
+    fn matcher(&self, pattern: &str) -> Option<Matcher> {
+        // -hierarchical matches at any hierarchy level,
+        // and -of_objects matches by the name within the parent
+        MatcherBuilder::new()
+            .regexp(self.regexp)
+            .nocase(self.nocase)
+            .hierarchical(self.hierarchical || self.of_objects.is_some())
+            .separator(&self.separator)
+            .build(pattern)
+            .ok()
+    }
+
+    fn matchers(&self) -> Vec<Matcher> {
+        self.patterns
+            .iter()
+            .filter_map(|x| self.matcher(x))
+            .collect()
+    }
+
+    /// Replace hierarchy separator by `/`
+    fn normalize(&self, pattern: &str) -> String {
+        if self.separator != "/" {
+            pattern.replace(&self.separator, "/")
+        } else {
+            pattern.to_string()
+        }
+    }
+
+    fn is_match(&self, matchers: &[Matcher], name: &str) -> bool {
+        if self.patterns.is_empty() {
+            return self.hierarchical || self.of_objects.is_some() || !name.contains('/');
+        }
+        matchers.iter().any(|x| x.is_match(name))
+    }
+}
+
+fn query<'a>(command: &'a Command, separator: &str) -> Option<Query<'a>> {
+    let hsc = |x: &Option<Argument>| x.as_ref().map(unquote);
+    let patterns = |x: &Option<Argument>| x.as_ref().map(list_items).unwrap_or_default();
+    let query = match command {
+        Command::GetPorts(x) => Query {
+            kind: Kind::Port,
+            hierarchical: false,
+            regexp: x.regexp,
+            nocase: false,
+            separator: separator.into(),
+            of_objects: &x.of_objects,
+            patterns: patterns(&x.patterns),
+        },
+        Command::GetCells(x) => Query {
+            kind: Kind::Cell,
+            hierarchical: x.hierarchical,
+            regexp: x.regexp,
+            nocase: x.nocase,
+            separator: hsc(&x.hsc).unwrap_or(separator.into()),
+            of_objects: &x.of_objects,
+            patterns: patterns(&x.patterns),
+        },
+        Command::GetPins(x) => Query {
+            kind: Kind::Pin,
+            hierarchical: x.hierarchical,
+            regexp: x.regexp,
+            nocase: x.nocase,
+            separator: hsc(&x.hsc).unwrap_or(separator.into()),
+            of_objects: &x.of_objects,
+            patterns: patterns(&x.patterns),
+        },
+        Command::GetNets(x) => Query {
+            kind: Kind::Net,
+            hierarchical: x.hierarchical,
+            regexp: x.regexp,
+            nocase: x.nocase,
+            separator: hsc(&x.hsc).unwrap_or(separator.into()),
+            of_objects: &x.of_objects,
+            patterns: patterns(&x.patterns),
+        },
+        _ => return None,
+    };
+    Some(query)
+}
+
+/// Objects which are matched by patterns of the query
+///
+/// Ports of a non-top `instance` are pins of the instance.
+fn candidates(design: &Design, query: &Query, separator: &str, instance: &str) -> Vec<Object> {
+    let ids = match query.of_objects {
+        Some(x) => related(
+            design,
+            query.kind,
+            &resolve_arg(design, x, separator, instance),
+        ),
+        None if query.kind == Kind::Port && !instance.is_empty() => {
+            return instance_pins(design, instance, None);
+        }
+        None => {
+            let len = match query.kind {
+                Kind::Port => design.ports.len(),
+                Kind::Cell => design.cells.len(),
+                Kind::Pin => design.pins.len(),
+                Kind::Net => design.nets.len(),
+            };
+            (0..len).collect()
+        }
+    };
+    ids.into_iter()
+        .map(|id| match query.kind {
+            Kind::Port => Object::Port(design.ports[id].name.clone()),
+            Kind::Cell => Object::Cell(design.cells[id].name.clone()),
+            Kind::Pin => Object::Pin(design.pins[id].name.clone()),
+            Kind::Net => Object::Net(design.nets[id].name.clone()),
+        })
+        .filter(|x| local(x.name(), instance).is_some())
+        .collect()
+}
+
+/// Name relative to `instance`
+fn local<'a>(name: &'a str, instance: &str) -> Option<&'a str> {
+    if instance.is_empty() {
+        Some(name)
+    } else {
+        name.strip_prefix(instance)?.strip_prefix('/')
+    }
+}
+
+/// Resolve an object query into design objects.
+///
+/// `instance` is the current instance joined by `/`, or empty at the top.
+/// Returns `None` if the command is not an object query.
+/// `-filter` is not evaluated.
+pub(crate) fn resolve(
+    design: &Design,
+    command: &Command,
+    separator: &str,
+    instance: &str,
+) -> Option<Vec<Object>> {
+    match command {
+        Command::AllInputs(_) => {
+            return Some(ports_by_direction(design, Direction::Input, instance))
+        }
+        Command::AllOutputs(_) => {
+            return Some(ports_by_direction(design, Direction::Output, instance))
+        }
+        _ => (),
+    }
+
+    let query = query(command, separator)?;
+    let matchers = query.matchers();
+    let mut ret = candidates(design, &query, separator, instance);
+    ret.retain(|x| local(x.name(), instance).is_some_and(|x| query.is_match(&matchers, x)));
+    Some(ret)
+}
+
+/// Check object queries which match nothing in `design`
+pub(crate) fn check(design: &Design, sdc: &Sdc, separator: &str) -> Vec<ResolveError> {
+    let mut ret = vec![];
+    for scoped in scope::queries(&sdc.commands, separator) {
+        let command = scoped.command;
+        let instance = scoped.scope.path("/");
+        let Some(query) = query(command, separator) else {
+            continue;
+        };
+        let quiet = match command {
+            Command::GetCells(x) => x.quiet,
+            Command::GetNets(x) => x.quiet,
+            Command::GetPins(x) => x.quiet,
+            Command::GetPorts(x) => x.quiet,
+            _ => false,
+        };
+        if quiet {
+            continue;
+        }
+
+        if let Some(x) = query.of_objects {
+            if resolve_arg(design, x, separator, &instance).is_empty() {
+                ret.push(ResolveError::EmptyOfObjects(
+                    x.to_string(),
+                    command.location(),
+                ));
+                continue;
+            }
+        }
+
+        let candidates = candidates(design, &query, separator, &instance);
+        let names: Vec<_> = candidates
+            .iter()
+            .filter_map(|x| local(x.name(), &instance))
+            .collect();
+        for pattern in &query.patterns {
+            let Some(matcher) = query.matcher(pattern) else {
+                continue;
+            };
+            if !names.iter().any(|x| matcher.is_match(x)) {
+                let nearest = nearest(&query.normalize(pattern), &names);
+                ret.push(ResolveError::NoMatch(
+                    pattern.clone(),
+                    nearest,
+                    command.location(),
+                ));
+            }
+        }
+    }
+    ret.sort_by(|a, b| a.location().cmp(b.location()));
+    ret
+}
+
+/// Up to 3 names which are similar to `pattern`
+fn nearest(pattern: &str, names: &[&str]) -> Vec<String> {
+    let pattern: String = pattern.chars().filter(|x| *x != '*' && *x != '?').collect();
+    let threshold = pattern.chars().count() / 3 + 1;
+    let mut list: Vec<_> = names
+        .iter()
+        .map(|name| {
+            let local = name.rsplit('/').next().unwrap_or(name);
+            let distance = [name, local, base_name(name)]
+                .iter()
+                .map(|x| distance(&pattern, x))
+                .min()
+                .unwrap_or_default();
+            (distance, name)
+        })
+        .filter(|x| x.0 <= threshold)
+        .collect();
+    list.sort_by_key(|x| x.0);
+    list.into_iter().take(3).map(|x| x.1.to_string()).collect()
+}
+
+/// Levenshtein distance
+fn distance(a: &str, b: &str) -> usize {
+    let b: Vec<_> = b.chars().collect();
+    let mut prev: Vec<_> = (0..=b.len()).collect();
+    for (i, x) in a.chars().enumerate() {
+        let mut curr = vec![i + 1; b.len() + 1];
+        for (j, y) in b.iter().enumerate() {
+            let cost = if x == *y { 0 } else { 1 };
+            curr[j + 1] = (prev[j] + cost).min(prev[j + 1] + 1).min(curr[j] + 1);
+        }
+        prev = curr;
+    }
+    prev[b.len()]
+}
+
+/// Resolve objects given by command substitution or names
+pub(crate) fn resolve_arg(
+    design: &Design,
+    arg: &Argument,
+    separator: &str,
+    instance: &str,
+) -> Vec<Object> {
+    if let Argument::CommandSubstitution(x, _) = arg {
+        return resolve(design, x, separator, instance).unwrap_or_default();
+    }
+
+    let mut ret = vec![];
+    for name in list_items(arg) {
+        let name = name.replace(separator, "/");
+        let name = if instance.is_empty() {
+            name
+        } else {
+            format!("{instance}/{name}")
+        };
+        if design.cell(&name).is_some() {
+            ret.push(Object::Cell(name));
+        } else if design.pin(&name).is_some() {
+            ret.push(Object::Pin(name));
+        } else if design.port(&name).is_some() {
+            ret.push(Object::Port(name));
+        } else if design.net(&name).is_some() {
+            ret.push(Object::Net(name));
+        }
+    }
+    ret
+}
+
+fn ports_by_direction(design: &Design, direction: Direction, instance: &str) -> Vec<Object> {
+    if !instance.is_empty() {
+        return instance_pins(design, instance, Some(direction));
+    }
+    design
+        .ports
+        .iter()
+        .filter(|x| has_direction(x.direction, Some(direction)))
+        .map(|x| Object::Port(x.name.clone()))
+        .collect()
+}
+
+/// Pins of `instance` which are ports within it
+fn instance_pins(design: &Design, instance: &str, direction: Option<Direction>) -> Vec<Object> {
+    let Some(cell) = design.cell(instance) else {
+        return vec![];
+    };
+    design.cells[cell]
+        .pins
+        .iter()
+        .map(|x| &design.pins[*x])
+        .filter(|x| has_direction(x.direction, direction))
+        .map(|x| Object::Pin(x.name.clone()))
+        .collect()
+}
+
+/// Unknown and inout direction are treated as both of input and output
+fn has_direction(actual: Option<Direction>, expected: Option<Direction>) -> bool {
+    match expected {
+        Some(expected) => {
+            matches!(actual, None | Some(Direction::Inout)) || actual == Some(expected)
+        }
+        None => true,
+    }
+}
+
+/// Objects of `kind` connected to `objects`
+fn related(design: &Design, kind: Kind, objects: &[Object]) -> Vec<usize> {
+    let mut ret = vec![];
+    for object in objects {
+        match (kind, object) {
+            (Kind::Cell, Object::Pin(x)) => {
+                ret.extend(design.pin(x).map(|x| design.pins[x].cell));
+            }
+            (Kind::Cell, Object::Net(x)) => {
+                if let Some(x) = design.net(x) {
+                    ret.extend(design.nets[x].pins.iter().map(|x| design.pins[*x].cell));
+                }
+            }
+            (Kind::Pin, Object::Cell(x)) => {
+                if let Some(x) = design.cell(x) {
+                    ret.extend(&design.cells[x].pins);
+                }
+            }
+            (Kind::Pin, Object::Net(x)) => {
+                if let Some(x) = design.net(x) {
+                    ret.extend(&design.nets[x].pins);
+                }
+            }
+            (Kind::Net, Object::Pin(x)) => {
+                ret.extend(design.pin(x).and_then(|x| design.pins[x].net));
+            }
+            (Kind::Net, Object::Port(x)) => {
+                ret.extend(design.port(x).map(|x| design.ports[x].net));
+            }
+            (Kind::Net, Object::Cell(x)) => {
+                if let Some(x) = design.cell(x) {
+                    let pins = &design.cells[x].pins;
+                    ret.extend(pins.iter().filter_map(|x| design.pins[*x].net));
+                }
+            }
+            (Kind::Port, Object::Net(x)) => {
+                if let Some(x) = design.net(x) {
+                    ret.extend(&design.nets[x].ports);
+                }
+            }
+            _ => (),
+        }
+    }
+    ret.sort();
+    ret.dedup();
+    ret
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sdc::Dialect;
+    use crate::Parser;
+
+    const NETLIST: &str = include_str!("../../testcase/netlist.v");
+
+    fn names(design: &Design, code: &str) -> Vec<String> {
+        let sdc = Parser::parse(&format!("{code}\n"), &"").unwrap();
+        let separator = crate::constraints::hierarchy_separator(&sdc).unwrap_or("/".into());
+        let command = sdc.commands.last().unwrap();
+        resolve(design, command, &separator, "")
+            .unwrap()
+            .iter()
+            .map(|x| x.name().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn query() {
+        let design = Design::from_verilog(NETLIST, "top").unwrap();
+
+        assert_eq!(names(&design, "get_ports din"), vec!["din[1]", "din[0]"]);
+        assert_eq!(names(&design, "get_ports {din[0]}"), vec!["din[0]"]);
+        assert_eq!(names(&design, "all_outputs"), vec!["dout"]);
+        assert_eq!(
+            names(&design, "get_cells *"),
+            vec!["u_ff0", "u_ff1", "u_sub"]
+        );
+        assert_eq!(names(&design, "get_cells -hier u_and"), vec!["u_sub/u_and"]);
+        assert_eq!(names(&design, "get_cells U_FF*"), Vec::<String>::new());
+        assert_eq!(
+            names(&design, "get_cells -nocase U_FF*"),
+            vec!["u_ff0", "u_ff1"]
+        );
+        assert_eq!(
+            names(&design, "get_cells -regexp {u_ff[0-9]}"),
+            vec!["u_ff0", "u_ff1"]
+        );
+        assert_eq!(names(&design, "get_pins -hier */Y"), vec!["u_sub/u_and/Y"]);
+        assert_eq!(
+            names(&design, "get_pins u_ff*/Q"),
+            vec!["u_ff0/Q", "u_ff1/Q"]
+        );
+        assert_eq!(
+            names(&design, "get_cells -of_objects [get_pins u_sub/u_and/*]"),
+            vec!["u_sub/u_and"]
+        );
+        assert_eq!(
+            names(&design, "get_cells -of_objects [get_nets clk]"),
+            vec!["u_ff0", "u_ff1"]
+        );
+        assert_eq!(
+            names(&design, "get_nets -of_objects [get_pins u_sub/u_and/B]"),
+            vec!["u_sub/a[0]"]
+        );
+        assert_eq!(
+            names(&design, "set_hierarchy_separator .\nget_pins u_sub.u_and.A"),
+            vec!["u_sub/u_and/A"]
+        );
+        assert_eq!(
+            names(&design, "get_pins -hsc | u_sub|u_and|A"),
+            vec!["u_sub/u_and/A"]
+        );
+    }
+
+    #[test]
+    fn no_match() {
+        let design = Design::from_verilog(NETLIST, "top").unwrap();
+        let code = r##"
+create_clock -period 10 [get_ports clk]
+set_input_delay 1 -clock clk [get_ports {din dinn}]
+set_false_path -to [get_pins u_ff0/DD]
+set_false_path -to [get_pins -quiet u_ff0/X]
+set_false_path -through [get_cells -of_objects [get_nets foo]]
+"##;
+        let sdc = Parser::parse_with_dialect(code, &"", Dialect::Xdc).unwrap();
+        let errors = check(&design, &sdc, "/");
+        let messages: Vec<_> = errors
+            .iter()
+            .map(|x| match x {
+                ResolveError::NoMatch(x, y, _) => format!("{x}: {}", y.join(" ")),
+                ResolveError::EmptyOfObjects(x, _) => x.clone(),
+                _ => unreachable!(),
+            })
+            .collect();
+        assert_eq!(
+            messages,
+            vec![
+                "dinn: din[1] din[0]",
+                "u_ff0/DD: u_ff0/D u_ff0/CK u_ff0/Q",
+                "[get_nets foo]",
+                "foo: ",
+            ]
+        );
+    }
+
+    #[test]
+    fn current_instance() {
+        let design = Design::from_verilog(NETLIST, "top").unwrap();
+        let code = r##"
+current_instance u_sub
+set_false_path -to [get_pins u_and/A]
+set_false_path -to [get_ports a]
+set_false_path -to [get_pins u_and/Z]
+set_input_delay 1 [all_inputs]
+current_instance ..
+set_false_path -to [get_pins u_ff0/D]
+current_instance u_sub
+current_design
+set_input_delay 1 [get_ports din]
+"##;
+        let sdc = Parser::parse(code, &"").unwrap();
+        let constraints: crate::constraints::Constraints = sdc.clone().into();
+        let counts: Vec<_> = constraints
+            .queries()
+            .iter()
+            .map(|x| constraints.resolve_scoped(&design, x).len())
+            .collect();
+        assert_eq!(counts, vec![1, 2, 0, 2, 1, 2]);
+
+        let errors = check(&design, &sdc, "/");
+        let messages: Vec<_> = errors
+            .iter()
+            .map(|x| match x {
+                ResolveError::NoMatch(x, y, _) => format!("{x}: {}", y.join(" ")),
+                _ => unreachable!(),
+            })
+            .collect();
+        assert_eq!(messages, vec!["u_and/Z: u_and/A u_and/B u_and/Y"]);
+    }
+}
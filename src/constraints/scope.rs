@@ -0,0 +1,225 @@
+use crate::constraints::{list_items, objects, unquote, Object};
+use crate::file_db::Location;
+use crate::sdc::util::{CommandExt, Extract};
+use crate::sdc::{Argument, Command, CommandKind};
+
+/// Hierarchical context set by `current_design` and `current_instance`
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Scope {
+    /// Path of the current instance from the top design, empty at the top
+    pub instance: Vec<String>,
+}
+
+impl Scope {
+    /// Update the scope by `current_design` or `current_instance`
+    pub fn apply(&mut self, command: &Command, separator: &str) {
+        match command {
+            Command::CurrentDesign(_) => self.instance.clear(),
+            Command::CurrentInstance(x) => match &x.instance {
+                Some(x) => self.enter(x, separator),
+                None => self.instance.clear(),
+            },
+            _ => (),
+        }
+    }
+
+    fn enter(&mut self, arg: &Argument, separator: &str) {
+        let path = match arg {
+            Argument::CommandSubstitution(_, _) => match objects(arg).first() {
+                Some(x) => x.name().to_string(),
+                None => return,
+            },
+            _ => unquote(arg),
+        };
+        // Leading separator specifies the path from the top design
+        let path = match path.strip_prefix(separator) {
+            Some(x) => {
+                self.instance.clear();
+                x
+            }
+            None => &path,
+        };
+        for name in path.split(separator) {
+            match name {
+                "" | "." => (),
+                ".." => {
+                    self.instance.pop();
+                }
+                x => self.instance.push(x.to_string()),
+            }
+        }
+    }
+
+    pub fn is_top(&self) -> bool {
+        self.instance.is_empty()
+    }
+
+    /// Path of the current instance joined by `separator`
+    pub fn path(&self, separator: &str) -> String {
+        self.instance.join(separator)
+    }
+
+    /// Fully-qualified name of `name` in the current instance
+    pub fn qualify(&self, name: &str, separator: &str) -> String {
+        if self.is_top() {
+            name.to_string()
+        } else {
+            format!("{}{separator}{name}", self.path(separator))
+        }
+    }
+}
+
+/// Object query with the scope where it is evaluated
+#[derive(Clone, Debug)]
+pub struct ScopedQuery<'a> {
+    pub command: &'a Command,
+    pub scope: Scope,
+    separator: String,
+}
+
+impl ScopedQuery<'_> {
+    pub fn location(&self) -> Location {
+        self.command.location()
+    }
+
+    /// Patterns of the query qualified by the current instance.
+    ///
+    /// Ports of a non-top instance are qualified as its pins.
+    /// Regexp patterns are prefixed by the escaped instance path.
+    /// Returns `None` for `-hierarchical` in a non-top instance
+    /// because it can't be expressed by a single qualified pattern.
+    pub fn qualified(&self) -> Option<Vec<Object>> {
+        let (patterns, regexp, hierarchical, f): (_, _, _, fn(String) -> Object) =
+            match self.command {
+                Command::GetCells(x) => (&x.patterns, x.regexp, x.hierarchical, Object::Cell),
+                Command::GetNets(x) => (&x.patterns, x.regexp, x.hierarchical, Object::Net),
+                Command::GetPins(x) => (&x.patterns, x.regexp, x.hierarchical, Object::Pin),
+                Command::GetPorts(x) if self.scope.is_top() => {
+                    (&x.patterns, x.regexp, x.hierarchical, Object::Port)
+                }
+                Command::GetPorts(x) => (&x.patterns, x.regexp, x.hierarchical, Object::Pin),
+                _ => return Some(vec![]),
+            };
+        if self.scope.is_top() {
+            let patterns = patterns.as_ref().map(list_items).unwrap_or_default();
+            return Some(patterns.into_iter().map(f).collect());
+        }
+        if hierarchical {
+            return None;
+        }
+        let patterns = patterns.as_ref().map(list_items).unwrap_or_default();
+        let prefix = format!("{}{}", self.scope.path(&self.separator), self.separator);
+        let prefix = regex::escape(&prefix);
+        let ret = patterns
+            .into_iter()
+            .map(|x| {
+                if regexp {
+                    f(format!("{prefix}{x}"))
+                } else {
+                    f(self.scope.qualify(&x, &self.separator))
+                }
+            })
+            .collect();
+        Some(ret)
+    }
+}
+
+/// Object queries in `commands` with the scope tracked through them
+pub(crate) fn queries<'a>(commands: &'a [Command], separator: &str) -> Vec<ScopedQuery<'a>> {
+    let mut scope = Scope::default();
+    let mut ret = vec![];
+    for command in commands {
+        for query in query_commands(command) {
+            ret.push(ScopedQuery {
+                command: query,
+                scope: scope.clone(),
+                separator: separator.to_string(),
+            });
+        }
+        // Queries in the argument of `current_instance` are evaluated in the previous scope
+        scope.apply(command, separator);
+    }
+    ret
+}
+
+/// Object queries including nested ones like `-of_objects`
+fn query_commands(command: &Command) -> Vec<&Command> {
+    let mut ret = vec![];
+    for kind in [
+        CommandKind::AllInputs,
+        CommandKind::AllOutputs,
+        CommandKind::GetCells,
+        CommandKind::GetNets,
+        CommandKind::GetPins,
+        CommandKind::GetPorts,
+    ] {
+        command.extract(kind, &mut ret);
+    }
+    ret.sort_by_key(|x| x.location());
+    ret
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Parser;
+
+    #[test]
+    fn qualified() {
+        let code = r##"
+set_false_path -to [get_pins ff/D]
+current_instance u_core
+set_false_path -to [get_pins ff/D]
+set_input_delay 1 [get_ports din]
+current_instance u_alu
+set_false_path -through [get_cells -of_objects [get_nets n1]]
+current_instance ..
+set_false_path -to [get_pins -regexp {ff[0-9]/D}]
+current_instance [get_cells u_io]
+set_false_path -to [get_pins ff/D]
+current_instance /u_top
+set_false_path -to [get_pins ff/D]
+current_design
+set_false_path -to [get_pins ff/D]
+"##;
+        let sdc = Parser::parse(code, &"").unwrap();
+        let names: Vec<_> = queries(&sdc.commands, "/")
+            .iter()
+            .flat_map(|x| x.qualified().unwrap())
+            .map(|x| x.to_string())
+            .collect();
+        assert_eq!(
+            names,
+            vec![
+                "pin(ff/D)",
+                "pin(u_core/ff/D)",
+                "pin(u_core/din)",
+                "net(u_core/u_alu/n1)",
+                "pin(u_core/ff[0-9]/D)",
+                "cell(u_core/u_io)",
+                "pin(u_core/u_io/ff/D)",
+                "pin(u_top/ff/D)",
+                "pin(ff/D)",
+            ]
+        );
+
+        // -hierarchical can't be qualified in a non-top instance
+        let code = r##"
+set_false_path -to [get_cells -hierarchical ff*]
+current_instance u_core
+set_false_path -to [get_cells -hierarchical ff*]
+set_false_path -to [get_pins -regexp {u_sub/ff.*}]
+"##;
+        let sdc = Parser::parse(code, &"").unwrap();
+        let queries = queries(&sdc.commands, "/");
+        assert_eq!(
+            queries[0].qualified(),
+            Some(vec![Object::Cell("ff*".into())])
+        );
+        assert_eq!(queries[1].qualified(), None);
+        assert_eq!(
+            queries[2].qualified(),
+            Some(vec![Object::Pin("u_core/u_sub/ff.*".into())])
+        );
+    }
+}
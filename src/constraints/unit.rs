@@ -0,0 +1,294 @@
+use crate::errors::InterpretError;
+use crate::sdc::{Argument, SetUnits};
+use std::fmt;
+
+/// Physical dimension of a value
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Dimension {
+    Time,
+    Capacitance,
+    Resistance,
+    Voltage,
+    Current,
+    Power,
+}
+
+impl Dimension {
+    /// SI base symbol
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            Dimension::Time => "s",
+            Dimension::Capacitance => "F",
+            Dimension::Resistance => "Ohm",
+            Dimension::Voltage => "V",
+            Dimension::Current => "A",
+            Dimension::Power => "W",
+        }
+    }
+}
+
+const PREFIXES: &[(char, i32)] = &[
+    ('f', -15),
+    ('p', -12),
+    ('n', -9),
+    ('u', -6),
+    ('m', -3),
+    ('k', 3),
+    ('M', 6),
+    ('G', 9),
+];
+
+/// Unit like `ns`, `pF` or `100ps`
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Unit {
+    dimension: Dimension,
+    exponent: i32,
+    multiplier: f64,
+}
+
+impl Unit {
+    pub fn new(dimension: Dimension, exponent: i32, multiplier: f64) -> Self {
+        Self {
+            dimension,
+            exponent,
+            multiplier,
+        }
+    }
+
+    pub fn dimension(&self) -> Dimension {
+        self.dimension
+    }
+
+    /// Scale factor to SI base unit
+    pub fn scale(&self) -> f64 {
+        self.multiplier * 10f64.powi(self.exponent)
+    }
+
+    /// Parse unit text of the specified dimension.
+    pub fn parse(dimension: Dimension, text: &str) -> Option<Self> {
+        let unit = Unit::try_from(text).ok()?;
+        (unit.dimension == dimension).then_some(unit)
+    }
+}
+
+impl TryFrom<&str> for Unit {
+    type Error = ();
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let text = value.trim();
+        let (dimension, text) = if let Some(x) = text
+            .strip_suffix("Ohm")
+            .or_else(|| text.strip_suffix("ohm"))
+        {
+            (Dimension::Resistance, x)
+        } else if let Some(x) = text.strip_suffix('s') {
+            (Dimension::Time, x)
+        } else if let Some(x) = text.strip_suffix('F') {
+            (Dimension::Capacitance, x)
+        } else if let Some(x) = text.strip_suffix('V') {
+            (Dimension::Voltage, x)
+        } else if let Some(x) = text.strip_suffix('A') {
+            (Dimension::Current, x)
+        } else if let Some(x) = text.strip_suffix('W') {
+            (Dimension::Power, x)
+        } else {
+            return Err(());
+        };
+
+        let (exponent, text) = match text.chars().last() {
+            Some(c) => match PREFIXES.iter().find(|(x, _)| *x == c) {
+                Some((_, exponent)) => (*exponent, &text[..text.len() - 1]),
+                None => (0, text),
+            },
+            None => (0, text),
+        };
+
+        let multiplier = if text.is_empty() {
+            1.0
+        } else {
+            text.parse::<f64>().map_err(|_| ())?
+        };
+
+        if multiplier <= 0.0 {
+            return Err(());
+        }
+
+        Ok(Unit::new(dimension, exponent, multiplier))
+    }
+}
+
+impl fmt::Display for Unit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let prefix = PREFIXES
+            .iter()
+            .find(|(_, x)| *x == self.exponent)
+            .map(|(x, _)| x.to_string())
+            .unwrap_or_default();
+        if self.multiplier != 1.0 {
+            self.multiplier.fmt(f)?;
+        }
+        write!(f, "{prefix}{}", self.dimension.symbol())
+    }
+}
+
+/// Value with unit
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Quantity {
+    value: f64,
+    unit: Unit,
+}
+
+impl Quantity {
+    pub fn new(value: f64, unit: Unit) -> Self {
+        Self { value, unit }
+    }
+
+    /// Value in the unit of this quantity
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    pub fn unit(&self) -> Unit {
+        self.unit
+    }
+
+    pub fn dimension(&self) -> Dimension {
+        self.unit.dimension
+    }
+
+    /// Value in SI base unit
+    pub fn to_si(&self) -> f64 {
+        self.value * self.unit.scale()
+    }
+
+    /// Convert to the specified unit, or `None` if the dimension differs.
+    pub fn convert(&self, unit: Unit) -> Option<Quantity> {
+        if self.unit.dimension != unit.dimension {
+            return None;
+        }
+        Some(Quantity::new(self.to_si() / unit.scale(), unit))
+    }
+}
+
+impl fmt::Display for Quantity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Fold the multiplier of `-time 100ps` form into the value
+        let unit = Unit::new(self.unit.dimension, self.unit.exponent, 1.0);
+        write!(f, "{}{unit}", self.value * self.unit.multiplier)
+    }
+}
+
+/// Units declared by `set_units`
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Units {
+    pub time: Unit,
+    pub capacitance: Unit,
+    pub resistance: Unit,
+    pub voltage: Unit,
+    pub current: Unit,
+    pub power: Unit,
+}
+
+/// SDC leaves units to the technology library, so these are used when no `set_units` is found.
+/// They are the conventional units of Liberty libraries:
+/// `time_unit : "1ns"`, `capacitive_load_unit (1, pf)`, `pulling_resistance_unit : "1kohm"`,
+/// `voltage_unit : "1V"`, `current_unit : "1mA"` and `leakage_power_unit : "1mW"`.
+impl Default for Units {
+    fn default() -> Self {
+        Self {
+            time: Unit::new(Dimension::Time, -9, 1.0),
+            capacitance: Unit::new(Dimension::Capacitance, -12, 1.0),
+            resistance: Unit::new(Dimension::Resistance, 3, 1.0),
+            voltage: Unit::new(Dimension::Voltage, 0, 1.0),
+            current: Unit::new(Dimension::Current, -3, 1.0),
+            power: Unit::new(Dimension::Power, -3, 1.0),
+        }
+    }
+}
+
+impl Units {
+    /// Apply units specified by `set_units`.
+    pub fn apply(&mut self, command: &SetUnits) -> Result<(), InterpretError> {
+        let list = [
+            (&command.time, &mut self.time),
+            (&command.capacitance, &mut self.capacitance),
+            (&command.resistance, &mut self.resistance),
+            (&command.voltage, &mut self.voltage),
+            (&command.current, &mut self.current),
+            (&command.power, &mut self.power),
+        ];
+        for (arg, unit) in list {
+            if let Some(arg) = arg {
+                *unit = Self::interpret(arg, unit.dimension)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Dimensions specified by `set_units`
+    pub(crate) fn dimensions(command: &SetUnits) -> Vec<Dimension> {
+        [
+            (&command.time, Dimension::Time),
+            (&command.capacitance, Dimension::Capacitance),
+            (&command.resistance, Dimension::Resistance),
+            (&command.voltage, Dimension::Voltage),
+            (&command.current, Dimension::Current),
+            (&command.power, Dimension::Power),
+        ]
+        .into_iter()
+        .filter(|x| x.0.is_some())
+        .map(|x| x.1)
+        .collect()
+    }
+
+    fn interpret(arg: &Argument, dimension: Dimension) -> Result<Unit, InterpretError> {
+        let text = arg
+            .as_str()
+            .trim_matches(|c| c == '{' || c == '}' || c == '"');
+        Unit::parse(dimension, text).ok_or(InterpretError::Something(arg.location()))
+    }
+
+    pub fn get(&self, dimension: Dimension) -> Unit {
+        match dimension {
+            Dimension::Time => self.time,
+            Dimension::Capacitance => self.capacitance,
+            Dimension::Resistance => self.resistance,
+            Dimension::Voltage => self.voltage,
+            Dimension::Current => self.current,
+            Dimension::Power => self.power,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn unit(text: &str) -> Unit {
+        Unit::try_from(text).unwrap()
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!(unit("ns"), Unit::new(Dimension::Time, -9, 1.0));
+        assert_eq!(unit("100ps"), Unit::new(Dimension::Time, -12, 100.0));
+        assert_eq!(unit("fF"), Unit::new(Dimension::Capacitance, -15, 1.0));
+        assert_eq!(unit("kOhm"), Unit::new(Dimension::Resistance, 3, 1.0));
+        assert_eq!(unit("mW"), Unit::new(Dimension::Power, -3, 1.0));
+        assert_eq!(unit("1.0V"), Unit::new(Dimension::Voltage, 0, 1.0));
+        assert!(Unit::try_from("xyz").is_err());
+        assert!(Unit::try_from("qs").is_err());
+        assert_eq!(Unit::parse(Dimension::Time, "pF"), None);
+    }
+
+    #[test]
+    fn convert() {
+        let x = Quantity::new(10.0, unit("100ps"));
+        assert!((x.to_si() - 1e-9).abs() < 1e-18);
+        assert_eq!(x.to_string(), "1000ps");
+
+        let y = x.convert(unit("ns")).unwrap();
+        assert!((y.value() - 1.0).abs() < 1e-9);
+        assert!(x.convert(unit("pF")).is_none());
+    }
+}
@@ -0,0 +1,583 @@
+use crate::constraints::{hierarchy_separator, unquote};
+use crate::errors::{ConvertError, ValidateError};
+use crate::file_db::Location;
+use crate::rename::map_object_args;
+use crate::sdc::util::{CommandExt, Extract, Validate};
+use crate::sdc::{Argument, Command, CommandKind, Dialect, Sdc, SdcVersion};
+use std::fmt;
+
+/// SDC commands which XDC doesn't support
+const UNSUPPORTED_IN_XDC: &[CommandKind] = &[
+    CommandKind::CreateVoltageArea,
+    CommandKind::SetClockGatingCheck,
+    CommandKind::SetDrive,
+    CommandKind::SetDrivingCell,
+    CommandKind::SetFanoutLoad,
+    CommandKind::SetIdealLatency,
+    CommandKind::SetIdealNetwork,
+    CommandKind::SetIdealTransition,
+    CommandKind::SetInputTransition,
+    CommandKind::SetLevelShifterStrategy,
+    CommandKind::SetLevelShifterThreshold,
+    CommandKind::SetMaxArea,
+    CommandKind::SetMaxCapacitance,
+    CommandKind::SetMaxDynamicPower,
+    CommandKind::SetMaxFanout,
+    CommandKind::SetMaxLeakagePower,
+    CommandKind::SetMaxTransition,
+    CommandKind::SetMinCapacitance,
+    CommandKind::SetMinPorosity,
+    CommandKind::SetMinPulseWidth,
+    CommandKind::SetOperatingConditions,
+    CommandKind::SetPortFanoutNumber,
+    CommandKind::SetResistance,
+    CommandKind::SetTimingDerate,
+    CommandKind::SetUnits,
+    CommandKind::SetVoltage,
+    CommandKind::SetWireLoadMinBlockSize,
+    CommandKind::SetWireLoadMode,
+    CommandKind::SetWireLoadModel,
+    CommandKind::SetWireLoadSelectionGroup,
+];
+
+/// Vendor specific commands which may appear as command substitution
+const VENDOR_QUERIES: &[CommandKind] = &[
+    CommandKind::GetFanouts,
+    CommandKind::GetIobanks,
+    CommandKind::GetKeepers,
+    CommandKind::GetPblocks,
+    CommandKind::GetProperty,
+    CommandKind::GetRegisters,
+    CommandKind::GetTimingPaths,
+];
+
+/// Object queries
+const QUERIES: &[CommandKind] = &[
+    CommandKind::GetCells,
+    CommandKind::GetClocks,
+    CommandKind::GetNets,
+    CommandKind::GetPins,
+    CommandKind::GetPorts,
+];
+
+/// Arguments which can be dropped without changing timing semantics
+const INFORMATIVE_ARGS: &[&str] = &["comment"];
+
+/// Converted command
+#[derive(Clone, Debug, PartialEq)]
+pub enum Converted {
+    Command(Command),
+    /// Command without equivalent in the target dialect
+    Commented(Command),
+}
+
+impl fmt::Display for Converted {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Converted::Command(x) => x.fmt(f),
+            Converted::Commented(x) => write!(f, "# [unsupported] {x}"),
+        }
+    }
+}
+
+/// Result of dialect conversion
+#[derive(Clone, Debug, PartialEq)]
+pub struct Conversion {
+    pub dialect: Dialect,
+    pub header: Vec<String>,
+    pub version: Option<SdcVersion>,
+    pub commands: Vec<Converted>,
+}
+
+impl fmt::Display for Conversion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for s in &self.header {
+            write!(f, "{}", s)?;
+        }
+        if let Some(version) = self.version {
+            writeln!(f, "{}", version)?;
+        }
+        for c in &self.commands {
+            writeln!(f, "{}", c)?;
+        }
+        Ok(())
+    }
+}
+
+/// Converter between SDC dialects and versions
+///
+/// Commands and arguments which have no equivalent in the target dialect or version
+/// are commented out or dropped, and reported as [`ConvertError`].
+pub struct Converter {
+    to: Dialect,
+    version: Option<SdcVersion>,
+}
+
+impl Converter {
+    pub fn new(to: Dialect) -> Self {
+        Self { to, version: None }
+    }
+
+    /// Down-level to the specified SDC version
+    pub fn target_version(&mut self, version: SdcVersion) -> &mut Self {
+        self.version = Some(version);
+        self
+    }
+
+    /// Dialects which can be specified as conversion target
+    pub fn targets() -> &'static [Dialect] {
+        &[Dialect::Sdc, Dialect::Xdc]
+    }
+
+    pub fn convert(&self, sdc: &Sdc) -> (Conversion, Vec<ConvertError>) {
+        let mut errors = vec![];
+        let separator = hierarchy_separator(sdc);
+
+        let mut commands = vec![];
+        for command in &sdc.commands {
+            // XDC uses "/" as hierarchy separator always
+            if self.to == Dialect::Xdc
+                && separator.is_some()
+                && matches!(command, Command::SetHierarchySeparator(_))
+            {
+                continue;
+            }
+
+            let mut command = command.clone();
+            let converted = self.convert_command(&mut command, separator.as_deref(), &mut errors)
+                && self.convert_version(&mut command, &mut errors);
+            if converted {
+                commands.push(Converted::Command(command));
+            } else {
+                commands.push(Converted::Commented(command));
+            }
+        }
+
+        let version = if self.to == Dialect::Xdc {
+            None
+        } else {
+            self.version.or(sdc.version)
+        };
+
+        let conversion = Conversion {
+            dialect: self.to,
+            header: sdc.header.clone(),
+            version,
+            commands,
+        };
+        (conversion, errors)
+    }
+
+    fn supported(&self, kind: CommandKind) -> bool {
+        let dialect = kind.dialect();
+        if dialect != Dialect::Sdc && dialect != self.to {
+            return false;
+        }
+        !(self.to == Dialect::Xdc && UNSUPPORTED_IN_XDC.contains(&kind))
+    }
+
+    /// Returns `false` if the command should be commented out.
+    fn convert_command(
+        &self,
+        command: &mut Command,
+        separator: Option<&str>,
+        errors: &mut Vec<ConvertError>,
+    ) -> bool {
+        if !self.supported(command.kind()) {
+            errors.push(ConvertError::CommentedOut(
+                command_name(command),
+                self.to,
+                command.location(),
+            ));
+            return false;
+        }
+
+        for kind in VENDOR_QUERIES {
+            let mut queries = vec![];
+            command.extract(*kind, &mut queries);
+            for query in queries {
+                if !self.supported(*kind) {
+                    errors.push(ConvertError::CommentedOut(
+                        command_name(query),
+                        self.to,
+                        query.location(),
+                    ));
+                    return false;
+                }
+            }
+        }
+
+        let location = command.location();
+        if let Command::SetClockGroups(x) = command {
+            if x.exclusive && self.to != Dialect::Quartus {
+                x.exclusive = false;
+                x.logically_exclusive = true;
+                errors.push(ConvertError::ReplacedArgument(
+                    "exclusive".into(),
+                    "logically_exclusive".into(),
+                    self.to,
+                    location.clone(),
+                ));
+            }
+        }
+
+        let mut drop = |name: &str, flag: &mut bool| {
+            if *flag {
+                *flag = false;
+                errors.push(ConvertError::DroppedArgument(
+                    name.into(),
+                    self.to,
+                    location.clone(),
+                ));
+            }
+        };
+
+        match command {
+            Command::SetClockGroups(x) => {
+                if self.to == Dialect::Xdc {
+                    drop("allow_paths", &mut x.allow_paths);
+                } else {
+                    drop("include_generated_clocks", &mut x.include_generated_clocks);
+                }
+            }
+            Command::SetMaxDelay(x) if self.to != Dialect::Xdc => {
+                drop("datapath_only", &mut x.datapath_only);
+            }
+            _ => (),
+        }
+
+        for kind in QUERIES {
+            let mut queries = vec![];
+            command.extract_mut(*kind, &mut queries);
+            for query in queries {
+                if !self.convert_query(query, separator, errors) {
+                    return false;
+                }
+            }
+        }
+
+        // Replace hierarchy separator of bare object names by "/"
+        if let (Dialect::Xdc, Some(separator)) = (self.to, separator) {
+            if separator != "/" {
+                map_object_args(command, &mut |x| replace_separator(x, separator));
+            }
+        }
+
+        true
+    }
+
+    /// Returns `false` if the command should be commented out.
+    fn convert_version(&self, command: &mut Command, errors: &mut Vec<ConvertError>) -> bool {
+        let Some(version) = self.version else {
+            return true;
+        };
+
+        // Aliases are always printed by the canonical names
+        command.unalias();
+
+        for err in command.validate(version) {
+            if let ValidateError::ArgUnsupportedVersion(_, location, name) = err {
+                if INFORMATIVE_ARGS.contains(&name.as_str()) && drop_informative(command, &name) {
+                    errors.push(ConvertError::DroppedVersionArgument(
+                        name, version, location,
+                    ));
+                }
+            }
+        }
+
+        for err in command.validate(version) {
+            match err {
+                ValidateError::CmdUnsupportedVersion(_, location) => {
+                    let name = unsupported_command_name(command, &location);
+                    errors.push(ConvertError::CmdUnsupportedVersion(name, version, location));
+                    return false;
+                }
+                ValidateError::ArgUnsupportedVersion(_, location, name) => {
+                    errors.push(ConvertError::ArgUnsupportedVersion(name, version, location));
+                    return false;
+                }
+                _ => (),
+            }
+        }
+
+        true
+    }
+
+    /// Returns `false` if the query has no equivalent.
+    fn convert_query(
+        &self,
+        query: &mut Command,
+        separator: Option<&str>,
+        errors: &mut Vec<ConvertError>,
+    ) -> bool {
+        let name = command_name(query);
+        let location = query.location();
+        // -of_objects of get_cells and get_nets is standard
+        let standard_of_objects = matches!(query, Command::GetCells(_) | Command::GetNets(_));
+
+        let (hierarchical, hsc, of_objects, filter, quiet, patterns) = match query {
+            Command::GetCells(x) => (
+                Some(&mut x.hierarchical),
+                Some(&mut x.hsc),
+                &mut x.of_objects,
+                &mut x.filter,
+                &mut x.quiet,
+                &mut x.patterns,
+            ),
+            Command::GetNets(x) => (
+                Some(&mut x.hierarchical),
+                Some(&mut x.hsc),
+                &mut x.of_objects,
+                &mut x.filter,
+                &mut x.quiet,
+                &mut x.patterns,
+            ),
+            Command::GetPins(x) => (
+                Some(&mut x.hierarchical),
+                Some(&mut x.hsc),
+                &mut x.of_objects,
+                &mut x.filter,
+                &mut x.quiet,
+                &mut x.patterns,
+            ),
+            Command::GetPorts(x) => (
+                None,
+                None,
+                &mut x.of_objects,
+                &mut x.filter,
+                &mut x.quiet,
+                &mut x.patterns,
+            ),
+            Command::GetClocks(x) => (
+                None,
+                None,
+                &mut x.of_objects,
+                &mut x.filter,
+                &mut x.quiet,
+                &mut x.patterns,
+            ),
+            _ => return true,
+        };
+
+        if self.to == Dialect::Xdc {
+            // Replace hierarchy separator by "/"
+            let hsc = hsc.and_then(|x| x.take());
+            let separator = hsc.as_ref().map(unquote).or(separator.map(String::from));
+            if let (Some(separator), Some(patterns)) = (separator, patterns) {
+                if separator != "/" {
+                    replace_separator(patterns, &separator);
+                }
+            }
+        } else {
+            let of_objects = of_objects.is_some() && !standard_of_objects;
+            for (arg, exist) in [("filter", filter.is_some()), ("of_objects", of_objects)] {
+                if exist {
+                    errors.push(ConvertError::CommentedOut(
+                        format!("{name} -{arg}"),
+                        self.to,
+                        location,
+                    ));
+                    return false;
+                }
+            }
+            *quiet = false;
+
+            // -hierarchical of XDC matches full hierarchical name
+            if let (Some(hierarchical), Some(patterns)) = (hierarchical, patterns) {
+                if *hierarchical && patterns.as_str().contains('/') {
+                    *hierarchical = false;
+                    errors.push(ConvertError::DroppedArgument(
+                        "hierarchical".into(),
+                        self.to,
+                        location,
+                    ));
+                }
+            }
+        }
+
+        true
+    }
+}
+
+/// Returns `true` if the informative argument is dropped.
+fn drop_informative(command: &mut Command, name: &str) -> bool {
+    let comment = match command {
+        Command::CreateClock(x) => &mut x.comment,
+        Command::CreateGeneratedClock(x) => &mut x.comment,
+        Command::GroupPath(x) => &mut x.comment,
+        Command::SetClockGroups(x) => &mut x.comment,
+        Command::SetFalsePath(x) => &mut x.comment,
+        Command::SetMaxDelay(x) => &mut x.comment,
+        Command::SetMinDelay(x) => &mut x.comment,
+        Command::SetMulticyclePath(x) => &mut x.comment,
+        _ => return false,
+    };
+    name == "comment" && comment.take().is_some()
+}
+
+/// Name of the command or the nested query at the location
+fn unsupported_command_name(command: &Command, location: &Location) -> String {
+    let mut list = vec![];
+    for kind in QUERIES.iter().chain(VENDOR_QUERIES) {
+        command.extract(*kind, &mut list);
+    }
+    command.extract(CommandKind::AllRegisters, &mut list);
+    list.iter()
+        .find(|x| &x.location() == location)
+        .map(|x| command_name(x))
+        .unwrap_or_else(|| command_name(command))
+}
+
+fn replace_separator(arg: &mut Argument, separator: &str) {
+    match arg {
+        Argument::Word(x) => x.text = x.text.replace(separator, "/"),
+        Argument::StringGroup(x) => x.text = x.text.replace(separator, "/"),
+        Argument::BraceGroup(x) => x.text = x.text.replace(separator, "/"),
+        Argument::CommandSubstitution(..) => (),
+    }
+}
+
+pub(crate) fn command_name(command: &Command) -> String {
+    let text = command.to_string();
+    text.split_whitespace()
+        .next()
+        .unwrap_or_default()
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Parser;
+
+    fn convert(code: &str, from: Dialect, to: Dialect) -> (String, Vec<ConvertError>) {
+        let sdc = Parser::parse_with_dialect(code, &"", from).unwrap();
+        let (conversion, errors) = Converter::new(to).convert(&sdc);
+        (conversion.to_string(), errors)
+    }
+
+    #[test]
+    fn sdc_to_xdc() {
+        let code = r##"set sdc_version 2.1
+set_hierarchy_separator |
+create_clock -period 10 [get_ports clk]
+set_input_delay 1 -clock clk [get_pins -hsc . u0.u1.a]
+set_false_path -to [get_pins u0|u2|b]
+set_false_path -from u0|u3|c -to [list u0|u4|d [get_pins u0|u5|e]]
+set_driving_cell -lib_cell BUF [all_inputs]
+set_clock_groups -asynchronous -allow_paths -group A -group B
+"##;
+        let (text, errors) = convert(code, Dialect::Sdc, Dialect::Xdc);
+        assert_eq!(
+            text,
+            r##"create_clock -period 10 [get_ports clk]
+set_input_delay -clock clk 1 [get_pins u0/u1/a]
+set_false_path -to [get_pins u0/u2/b]
+set_false_path -from u0/u3/c -to [list u0/u4/d [get_pins u0/u5/e]]
+# [unsupported] set_driving_cell -lib_cell BUF [all_inputs]
+set_clock_groups -group A -group B -asynchronous
+"##
+        );
+        assert_eq!(errors.len(), 2);
+        assert!(
+            matches!(&errors[0], ConvertError::CommentedOut(x, _, _) if x == "set_driving_cell")
+        );
+        assert!(matches!(&errors[1], ConvertError::DroppedArgument(x, _, _) if x == "allow_paths"));
+    }
+
+    #[test]
+    fn xdc_to_sdc() {
+        let code = r##"set_property IOSTANDARD LVCMOS33 [get_ports led]
+create_clock -period 10 [get_ports clk -quiet]
+set_max_delay -datapath_only -from A -to B 5
+set_false_path -to [get_cells -hier -filter {REF_NAME == FDRE}]
+set_false_path -to [get_cells -hier u_sync/sync_reg*]
+"##;
+        let (text, errors) = convert(code, Dialect::Xdc, Dialect::Sdc);
+        assert_eq!(
+            text,
+            r##"# [unsupported] set_property IOSTANDARD LVCMOS33 [get_ports led]
+create_clock -period 10 [get_ports clk]
+set_max_delay -from A -to B 5
+# [unsupported] set_false_path -to [get_cells -hierarchical -filter {REF_NAME == FDRE}]
+set_false_path -to [get_cells u_sync/sync_reg*]
+"##
+        );
+        let names: Vec<_> = errors
+            .iter()
+            .map(|x| match x {
+                ConvertError::CommentedOut(x, _, _) => x.clone(),
+                ConvertError::DroppedArgument(x, _, _) => format!("-{x}"),
+                _ => unreachable!(),
+            })
+            .collect();
+        assert_eq!(
+            names,
+            vec![
+                "set_property",
+                "-datapath_only",
+                "get_cells -filter",
+                "-hierarchical"
+            ]
+        );
+    }
+
+    #[test]
+    fn quartus_to_xdc() {
+        let code = "set_clock_groups -exclusive -group A -group B\n";
+        let (text, errors) = convert(code, Dialect::Quartus, Dialect::Xdc);
+        assert_eq!(
+            text,
+            "set_clock_groups -group A -group B -logically_exclusive\n"
+        );
+        assert_eq!(errors.len(), 1);
+        assert!(matches!(
+            &errors[0],
+            ConvertError::ReplacedArgument(x, y, Dialect::Xdc, _) if x == "exclusive" && y == "logically_exclusive"
+        ));
+    }
+
+    #[test]
+    fn target_version() {
+        let code = r##"set sdc_version 2.1
+create_clock -period 10 -comment "main clock" [get_port clk]
+set_false_path -comment "reset" -from [get_ports rst]
+set_clock_groups -asynchronous -group A -group B
+set_input_delay 1 -clock clk -reference_pin [get_pins u0/ck] [get_ports din]
+set_min_pulse_width 1 [get_clocks clk]
+"##;
+        let sdc = Parser::parse(code, &"").unwrap();
+        let mut converter = Converter::new(Dialect::Sdc);
+        converter.target_version(SdcVersion::SDC1_4);
+        let (conversion, errors) = converter.convert(&sdc);
+        assert_eq!(
+            conversion.to_string(),
+            r##"set sdc_version 1.4
+create_clock -period 10 [get_ports clk]
+set_false_path -from [get_ports rst]
+# [unsupported] set_clock_groups -group A -group B -asynchronous
+# [unsupported] set_input_delay -clock clk -reference_pin [get_pins u0/ck] 1 [get_ports din]
+# [unsupported] set_min_pulse_width 1 [get_clocks clk]
+"##
+        );
+        let names: Vec<_> = errors
+            .iter()
+            .map(|x| match x {
+                ConvertError::CmdUnsupportedVersion(x, _, _) => x.clone(),
+                ConvertError::ArgUnsupportedVersion(x, _, _) => format!("-{x}"),
+                ConvertError::DroppedVersionArgument(x, _, _) => format!("drop -{x}"),
+                _ => unreachable!(),
+            })
+            .collect();
+        assert_eq!(
+            names,
+            vec![
+                "drop -comment",
+                "drop -comment",
+                "set_clock_groups",
+                "-reference_pin",
+                "set_min_pulse_width"
+            ]
+        );
+    }
+}
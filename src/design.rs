@@ -0,0 +1,265 @@
+pub mod liberty;
+pub mod verilog;
+
+use crate::errors::DesignError;
+use crate::lint::coverage::Direction;
+pub use liberty::*;
+use std::collections::HashMap;
+pub use verilog::*;
+
+/// Primary port of design
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DesignPort {
+    pub name: String,
+    pub direction: Option<Direction>,
+    pub net: usize,
+}
+
+/// Hierarchical or leaf cell
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Cell {
+    pub name: String,
+    pub ref_name: String,
+    /// Cell is an instance of a module defined in the netlist
+    pub hierarchical: bool,
+    pub pins: Vec<usize>,
+}
+
+/// Pin of cell
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Pin {
+    pub name: String,
+    pub direction: Option<Direction>,
+    pub cell: usize,
+    pub net: Option<usize>,
+}
+
+/// Net
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Net {
+    pub name: String,
+    pub pins: Vec<usize>,
+    pub ports: Vec<usize>,
+}
+
+/// Design elaborated from netlist
+///
+/// Hierarchical names are joined by `/`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Design {
+    pub top: String,
+    pub ports: Vec<DesignPort>,
+    pub cells: Vec<Cell>,
+    pub pins: Vec<Pin>,
+    pub nets: Vec<Net>,
+    port_ids: HashMap<String, usize>,
+    cell_ids: HashMap<String, usize>,
+    pin_ids: HashMap<String, usize>,
+    net_ids: HashMap<String, usize>,
+}
+
+impl Design {
+    /// Elaborate `top` module
+    pub fn new(modules: &[Module], top: &str) -> Result<Self, DesignError> {
+        let modules: HashMap<_, _> = modules.iter().map(|x| (x.name.as_str(), x)).collect();
+        let module = modules
+            .get(top)
+            .ok_or_else(|| DesignError::UnknownTop(top.to_string()))?;
+
+        let mut ret = Design {
+            top: top.to_string(),
+            ..Default::default()
+        };
+
+        for port in &module.ports {
+            for name in bit_names(&port.name, port.range) {
+                let net = ret.add_net(name.clone());
+                let id = ret.ports.len();
+                ret.nets[net].ports.push(id);
+                ret.port_ids.insert(name.clone(), id);
+                ret.ports.push(DesignPort {
+                    name,
+                    direction: port.direction,
+                    net,
+                });
+            }
+        }
+
+        let mut stack = vec![top];
+        ret.elaborate(&modules, module, "", &mut stack)?;
+        Ok(ret)
+    }
+
+    /// Parse Verilog netlist and elaborate `top` module
+    pub fn from_verilog(text: &str, top: &str) -> Result<Self, DesignError> {
+        Self::new(&parse_verilog(text)?, top)
+    }
+
+    pub fn port(&self, name: &str) -> Option<usize> {
+        self.port_ids.get(name).copied()
+    }
+
+    pub fn cell(&self, name: &str) -> Option<usize> {
+        self.cell_ids.get(name).copied()
+    }
+
+    pub fn pin(&self, name: &str) -> Option<usize> {
+        self.pin_ids.get(name).copied()
+    }
+
+    pub fn net(&self, name: &str) -> Option<usize> {
+        self.net_ids.get(name).copied()
+    }
+
+    fn add_net(&mut self, name: String) -> usize {
+        if let Some(x) = self.net_ids.get(&name) {
+            return *x;
+        }
+        let id = self.nets.len();
+        self.net_ids.insert(name.clone(), id);
+        self.nets.push(Net {
+            name,
+            pins: vec![],
+            ports: vec![],
+        });
+        id
+    }
+
+    fn elaborate<'a>(
+        &mut self,
+        modules: &HashMap<&'a str, &'a Module>,
+        module: &Module,
+        prefix: &str,
+        stack: &mut Vec<&'a str>,
+    ) -> Result<(), DesignError> {
+        for port in &module.ports {
+            for name in bit_names(&port.name, port.range) {
+                self.add_net(format!("{prefix}{name}"));
+            }
+        }
+        for net in &module.nets {
+            for name in bit_names(&net.name, net.range) {
+                self.add_net(format!("{prefix}{name}"));
+            }
+        }
+
+        for instance in &module.instances {
+            let name = format!("{prefix}{}", instance.name);
+            let sub = modules.get(instance.ref_name.as_str()).copied();
+            let cell = self.cells.len();
+            self.cell_ids.insert(name.clone(), cell);
+            self.cells.push(Cell {
+                name: name.clone(),
+                ref_name: instance.ref_name.clone(),
+                hierarchical: sub.is_some(),
+                pins: vec![],
+            });
+
+            for (i, connection) in instance.connections.iter().enumerate() {
+                let (port, expression) = match (connection, sub) {
+                    (Connection::Named(x, y), _) => (x.clone(), y),
+                    (Connection::Ordered(y), Some(sub)) => match sub.ports.get(i) {
+                        Some(x) => (x.name.clone(), y),
+                        None => continue,
+                    },
+                    // Pin names of leaf cell are unknown without library
+                    (Connection::Ordered(_), None) => continue,
+                };
+
+                let bits = expression
+                    .as_ref()
+                    .map(|x| x.bits(module))
+                    .unwrap_or_default();
+                let declared = sub.and_then(|x| x.port(&port));
+                let range = match declared {
+                    Some(x) => x.range,
+                    None if bits.len() > 1 => Some((bits.len() as i64 - 1, 0)),
+                    None => None,
+                };
+                let direction = declared.and_then(|x| x.direction);
+
+                let names = bit_names(&port, range);
+                let offset = names.len().saturating_sub(bits.len());
+                for (j, pin_name) in names.into_iter().enumerate() {
+                    let net = j
+                        .checked_sub(offset)
+                        .and_then(|x| bits.get(x).cloned().flatten())
+                        .map(|x| self.add_net(format!("{prefix}{x}")));
+                    let pin = self.pins.len();
+                    let pin_name = format!("{name}/{pin_name}");
+                    self.pin_ids.insert(pin_name.clone(), pin);
+                    self.pins.push(Pin {
+                        name: pin_name,
+                        direction,
+                        cell,
+                        net,
+                    });
+                    self.cells[cell].pins.push(pin);
+                    if let Some(net) = net {
+                        self.nets[net].pins.push(pin);
+                    }
+                }
+            }
+
+            if let Some(sub) = sub {
+                if stack.contains(&sub.name.as_str()) {
+                    return Err(DesignError::RecursiveModule(sub.name.clone()));
+                }
+                stack.push(&sub.name);
+                self.elaborate(modules, sub, &format!("{name}/"), stack)?;
+                stack.pop();
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Bit-level names of bus from MSB to LSB
+fn bit_names(name: &str, range: Option<(i64, i64)>) -> Vec<String> {
+    match range {
+        Some((msb, lsb)) => verilog::range(msb, lsb)
+            .map(|i| format!("{name}[{i}]"))
+            .collect(),
+        None => vec![name.to_string()],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NETLIST: &str = include_str!("../testcase/netlist.v");
+
+    #[test]
+    fn elaborate() {
+        let design = Design::from_verilog(NETLIST, "top").unwrap();
+
+        let ports: Vec<_> = design.ports.iter().map(|x| x.name.as_str()).collect();
+        assert_eq!(ports, vec!["clk", "din[1]", "din[0]", "dout"]);
+
+        let cells: Vec<_> = design.cells.iter().map(|x| x.name.as_str()).collect();
+        assert_eq!(cells, vec!["u_ff0", "u_ff1", "u_sub", "u_sub/u_and"]);
+        assert!(design.cells[2].hierarchical);
+
+        let pin = &design.pins[design.pin("u_sub/a[1]").unwrap()];
+        assert_eq!(pin.direction, Some(Direction::Input));
+        assert_eq!(design.nets[pin.net.unwrap()].name, "n[1]");
+
+        let net = &design.nets[design.net("u_sub/a[0]").unwrap()];
+        let pins: Vec<_> = net
+            .pins
+            .iter()
+            .map(|x| design.pins[*x].name.as_str())
+            .collect();
+        assert_eq!(pins, vec!["u_sub/u_and/B"]);
+
+        let net = &design.nets[design.net("clk").unwrap()];
+        assert_eq!(net.ports.len(), 1);
+        assert_eq!(net.pins.len(), 2);
+
+        assert!(matches!(
+            Design::from_verilog(NETLIST, "foo"),
+            Err(DesignError::UnknownTop(_))
+        ));
+    }
+}
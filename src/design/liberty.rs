@@ -0,0 +1,359 @@
+use crate::errors::DesignError;
+use crate::lint::coverage::Direction;
+
+/// Liberty library
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Library {
+    pub name: String,
+    pub cells: Vec<LibCell>,
+    pub operating_conditions: Vec<String>,
+    pub wire_loads: Vec<String>,
+    pub wire_load_selections: Vec<String>,
+}
+
+impl Library {
+    pub fn cell(&self, name: &str) -> Option<&LibCell> {
+        self.cells.iter().find(|x| x.name == name)
+    }
+}
+
+/// Cell of library
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct LibCell {
+    pub name: String,
+    pub pins: Vec<LibPin>,
+}
+
+impl LibCell {
+    pub fn pin(&self, name: &str) -> Option<&LibPin> {
+        self.pins.iter().find(|x| x.name == name)
+    }
+}
+
+/// Pin of library cell
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LibPin {
+    pub name: String,
+    pub direction: Option<Direction>,
+}
+
+/// Generic liberty group like `cell (name) { ... }`
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+struct Group {
+    name: String,
+    args: Vec<String>,
+    attributes: Vec<(String, String)>,
+    groups: Vec<Group>,
+}
+
+impl Group {
+    fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|x| x.0 == name)
+            .map(|x| x.1.as_str())
+    }
+
+    fn groups<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a Group> {
+        self.groups.iter().filter(move |x| x.name == name)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Token {
+    Word(String),
+    Symbol(char),
+}
+
+fn tokenize(text: &str) -> Vec<(Token, usize)> {
+    let mut ret = vec![];
+    let mut line = 1;
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\n' => line += 1,
+            '\\' => (),
+            c if c.is_whitespace() => (),
+            '/' if chars.peek() == Some(&'*') => {
+                chars.next();
+                let mut prev = ' ';
+                for c in chars.by_ref() {
+                    if c == '\n' {
+                        line += 1;
+                    }
+                    if prev == '*' && c == '/' {
+                        break;
+                    }
+                    prev = c;
+                }
+            }
+            '/' if chars.peek() == Some(&'/') => {
+                for c in chars.by_ref() {
+                    if c == '\n' {
+                        line += 1;
+                        break;
+                    }
+                }
+            }
+            '"' => {
+                let start = line;
+                let mut text = String::new();
+                for c in chars.by_ref() {
+                    match c {
+                        '"' => break,
+                        '\n' => line += 1,
+                        _ => (),
+                    }
+                    text.push(c);
+                }
+                ret.push((Token::Word(text), start));
+            }
+            '(' | ')' | '{' | '}' | ':' | ';' | ',' => ret.push((Token::Symbol(c), line)),
+            c => {
+                let mut text = c.to_string();
+                while let Some(c) = chars.peek() {
+                    if c.is_whitespace() || "(){}:;,\"".contains(*c) {
+                        break;
+                    }
+                    text.push(*c);
+                    chars.next();
+                }
+                ret.push((Token::Word(text), line));
+            }
+        }
+    }
+    ret
+}
+
+struct Parser {
+    tokens: Vec<(Token, usize)>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos).map(|x| &x.0)
+    }
+
+    fn error<T>(&self, msg: &str) -> Result<T, DesignError> {
+        let line = self
+            .tokens
+            .get(self.pos)
+            .or(self.tokens.last())
+            .map(|x| x.1)
+            .unwrap_or(1);
+        Err(DesignError::Syntax(msg.to_string(), line))
+    }
+
+    fn is_symbol(&self, c: char) -> bool {
+        self.peek() == Some(&Token::Symbol(c))
+    }
+
+    fn symbol(&mut self, c: char) -> Result<(), DesignError> {
+        if self.is_symbol(c) {
+            self.pos += 1;
+            Ok(())
+        } else {
+            self.error(&format!("'{c}' is expected"))
+        }
+    }
+
+    fn word(&mut self) -> Result<String, DesignError> {
+        match self.peek() {
+            Some(Token::Word(x)) => {
+                let ret = x.clone();
+                self.pos += 1;
+                Ok(ret)
+            }
+            _ => self.error("word is expected"),
+        }
+    }
+
+    /// Statements until `}` or end of input
+    fn statements(&mut self, group: &mut Group) -> Result<(), DesignError> {
+        while self.peek().is_some() && !self.is_symbol('}') {
+            let name = self.word()?;
+            if self.is_symbol(':') {
+                // Simple attribute
+                self.pos += 1;
+                let mut value = vec![];
+                while let Some(Token::Word(x)) = self.peek() {
+                    value.push(x.clone());
+                    self.pos += 1;
+                }
+                if self.is_symbol(';') {
+                    self.pos += 1;
+                }
+                group.attributes.push((name, value.join(" ")));
+            } else if self.is_symbol('(') {
+                self.pos += 1;
+                let mut args = vec![];
+                while !self.is_symbol(')') {
+                    match self.peek() {
+                        Some(Token::Word(_)) => args.push(self.word()?),
+                        Some(Token::Symbol(',')) => self.pos += 1,
+                        _ => return self.error("')' is expected"),
+                    }
+                }
+                self.pos += 1;
+                if self.is_symbol('{') {
+                    self.pos += 1;
+                    let mut child = Group {
+                        name,
+                        args,
+                        ..Default::default()
+                    };
+                    self.statements(&mut child)?;
+                    self.symbol('}')?;
+                    group.groups.push(child);
+                } else if self.is_symbol(';') {
+                    // Complex attribute
+                    self.pos += 1;
+                }
+            } else {
+                return self.error("':' or '(' is expected");
+            }
+        }
+        Ok(())
+    }
+}
+
+fn pins(group: &Group) -> Vec<LibPin> {
+    let direction = |x: &Group| match x.attribute("direction") {
+        Some("input") => Some(Direction::Input),
+        Some("output") => Some(Direction::Output),
+        Some("inout") => Some(Direction::Inout),
+        _ => None,
+    };
+
+    let mut ret = vec![];
+    for x in &group.groups {
+        match x.name.as_str() {
+            "pin" => {
+                for name in &x.args {
+                    ret.push(LibPin {
+                        name: name.clone(),
+                        direction: direction(x),
+                    });
+                }
+            }
+            "bus" | "bundle" => {
+                for name in &x.args {
+                    ret.push(LibPin {
+                        name: name.clone(),
+                        direction: direction(x),
+                    });
+                }
+                ret.append(&mut pins(x));
+            }
+            _ => (),
+        }
+    }
+    ret
+}
+
+/// Parse libraries in Liberty format.
+///
+/// Only names of cells, pins, operating conditions and wire-load models are captured.
+pub fn parse_liberty(text: &str) -> Result<Vec<Library>, DesignError> {
+    let mut parser = Parser {
+        tokens: tokenize(text),
+        pos: 0,
+    };
+    let mut root = Group::default();
+    parser.statements(&mut root)?;
+    if parser.peek().is_some() {
+        return parser.error("unexpected '}'");
+    }
+
+    let names = |x: &Group, name| -> Vec<String> {
+        x.groups(name)
+            .filter_map(|x| x.args.first().cloned())
+            .collect()
+    };
+
+    let mut ret = vec![];
+    for group in root.groups("library") {
+        let cells = group
+            .groups("cell")
+            .filter_map(|x| {
+                x.args.first().map(|name| LibCell {
+                    name: name.clone(),
+                    pins: pins(x),
+                })
+            })
+            .collect();
+        ret.push(Library {
+            name: group.args.first().cloned().unwrap_or_default(),
+            cells,
+            operating_conditions: names(group, "operating_conditions"),
+            wire_loads: names(group, "wire_load"),
+            wire_load_selections: names(group, "wire_load_selection"),
+        });
+    }
+    Ok(ret)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        let code = r##"
+/* comment */
+library (typical) {
+    delay_model : table_lookup;
+    time_unit : "1ns";
+    capacitive_load_unit (1, pf);
+    operating_conditions (typ_1p0v_25c) {
+        process : 1;
+        voltage : 1.0;
+    }
+    wire_load ("small") {
+        resistance : 0.1 ;
+    }
+    wire_load_selection (sel) {
+        wire_load_from_area (0, 100, "small");
+    }
+    cell (BUF) {
+        area : 1.0;
+        pin (A) { direction : input; capacitance : 0.01; }
+        pin (Y) {
+            direction : output;
+            function : "A";
+            timing () {
+                related_pin : "A";
+                cell_rise (scalar) { values ("0.1"); }
+            }
+        }
+    }
+    cell (DFF) {
+        pin (D, CK) { direction : input; }
+        bus (Q) {
+            direction : output;
+            pin (Q[0]) { }
+        }
+    }
+}
+"##;
+        let libs = parse_liberty(code).unwrap();
+        assert_eq!(libs.len(), 1);
+        let lib = &libs[0];
+        assert_eq!(lib.name, "typical");
+        assert_eq!(lib.operating_conditions, vec!["typ_1p0v_25c"]);
+        assert_eq!(lib.wire_loads, vec!["small"]);
+        assert_eq!(lib.wire_load_selections, vec!["sel"]);
+        assert_eq!(lib.cells.len(), 2);
+
+        let buf = lib.cell("BUF").unwrap();
+        assert_eq!(buf.pin("A").unwrap().direction, Some(Direction::Input));
+        assert_eq!(buf.pin("Y").unwrap().direction, Some(Direction::Output));
+
+        let dff = lib.cell("DFF").unwrap();
+        let pins: Vec<_> = dff.pins.iter().map(|x| x.name.as_str()).collect();
+        assert_eq!(pins, vec!["D", "CK", "Q", "Q[0]"]);
+
+        assert!(parse_liberty("library (x) {\n cell (A) {\n").is_err());
+    }
+}
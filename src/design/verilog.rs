@@ -0,0 +1,631 @@
+use crate::errors::DesignError;
+use crate::lint::coverage::Direction;
+
+/// Verilog module
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Module {
+    pub name: String,
+    pub ports: Vec<ModulePort>,
+    pub nets: Vec<ModuleNet>,
+    pub instances: Vec<Instance>,
+}
+
+impl Module {
+    pub fn port(&self, name: &str) -> Option<&ModulePort> {
+        self.ports.iter().find(|x| x.name == name)
+    }
+
+    /// Range of the port or net
+    pub fn range(&self, name: &str) -> Option<(i64, i64)> {
+        if let Some(x) = self.port(name) {
+            x.range
+        } else {
+            self.nets
+                .iter()
+                .find(|x| x.name == name)
+                .and_then(|x| x.range)
+        }
+    }
+}
+
+/// Port declaration
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ModulePort {
+    pub name: String,
+    pub direction: Option<Direction>,
+    pub range: Option<(i64, i64)>,
+}
+
+/// Net declaration
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ModuleNet {
+    pub name: String,
+    pub range: Option<(i64, i64)>,
+}
+
+/// Module or cell instance
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Instance {
+    pub ref_name: String,
+    pub name: String,
+    pub connections: Vec<Connection>,
+}
+
+/// Port connection of instance
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Connection {
+    Named(String, Option<Expression>),
+    Ordered(Option<Expression>),
+}
+
+/// Expression connected to instance port
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Expression {
+    Identifier(String),
+    Index(String, i64),
+    Slice(String, i64, i64),
+    Constant(Option<usize>),
+    Concatenation(Vec<Expression>),
+}
+
+impl Expression {
+    /// Bit-level net names from MSB to LSB.
+    ///
+    /// Constant bits are `None`.
+    pub fn bits(&self, module: &Module) -> Vec<Option<String>> {
+        match self {
+            Expression::Identifier(x) => match module.range(x) {
+                Some((msb, lsb)) => range(msb, lsb).map(|i| Some(format!("{x}[{i}]"))).collect(),
+                None => vec![Some(x.clone())],
+            },
+            Expression::Index(x, i) => vec![Some(format!("{x}[{i}]"))],
+            Expression::Slice(x, msb, lsb) => range(*msb, *lsb)
+                .map(|i| Some(format!("{x}[{i}]")))
+                .collect(),
+            Expression::Constant(x) => vec![None; x.unwrap_or(1)],
+            Expression::Concatenation(x) => x.iter().flat_map(|x| x.bits(module)).collect(),
+        }
+    }
+}
+
+/// Indices from `msb` to `lsb`
+pub(crate) fn range(msb: i64, lsb: i64) -> Box<dyn Iterator<Item = i64>> {
+    if msb >= lsb {
+        Box::new((lsb..=msb).rev())
+    } else {
+        Box::new(msb..=lsb)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Token {
+    Identifier(String),
+    Number(String),
+    Symbol(char),
+}
+
+struct Lexer<'a> {
+    text: &'a [u8],
+    pos: usize,
+    line: usize,
+}
+
+impl<'a> Lexer<'a> {
+    fn new(text: &'a str) -> Self {
+        Self {
+            text: text.as_bytes(),
+            pos: 0,
+            line: 1,
+        }
+    }
+
+    fn peek_byte(&self, offset: usize) -> Option<u8> {
+        self.text.get(self.pos + offset).copied()
+    }
+
+    fn bump(&mut self) -> Option<u8> {
+        let c = self.peek_byte(0)?;
+        if c == b'\n' {
+            self.line += 1;
+        }
+        self.pos += 1;
+        Some(c)
+    }
+
+    fn skip_until(&mut self, end: &[u8]) {
+        while self.pos < self.text.len() && !self.text[self.pos..].starts_with(end) {
+            self.bump();
+        }
+        for _ in 0..end.len() {
+            self.bump();
+        }
+    }
+
+    fn skip_trivia(&mut self) {
+        while let Some(c) = self.peek_byte(0) {
+            match (c, self.peek_byte(1)) {
+                (c, _) if c.is_ascii_whitespace() => {
+                    self.bump();
+                }
+                (b'/', Some(b'/')) | (b'`', _) => self.skip_until(b"\n"),
+                (b'/', Some(b'*')) => self.skip_until(b"*/"),
+                (b'(', Some(b'*')) if self.peek_byte(2) != Some(b')') => self.skip_until(b"*)"),
+                _ => break,
+            }
+        }
+    }
+
+    fn take_while<F: Fn(u8) -> bool>(&mut self, f: F) -> String {
+        let start = self.pos;
+        while self.peek_byte(0).map(&f).unwrap_or(false) {
+            self.bump();
+        }
+        String::from_utf8_lossy(&self.text[start..self.pos]).into_owned()
+    }
+
+    fn next_token(&mut self) -> Option<(Token, usize)> {
+        self.skip_trivia();
+        let line = self.line;
+        let c = self.peek_byte(0)?;
+        let token = if c == b'\\' {
+            // Escaped identifier is terminated by whitespace
+            self.bump();
+            Token::Identifier(self.take_while(|x| !x.is_ascii_whitespace()))
+        } else if c.is_ascii_alphabetic() || c == b'_' || c == b'$' {
+            Token::Identifier(
+                self.take_while(|x| x.is_ascii_alphanumeric() || x == b'_' || x == b'$'),
+            )
+        } else if c.is_ascii_digit() || c == b'\'' {
+            let mut text = self.take_while(|x| x.is_ascii_digit() || x == b'_');
+            if self.peek_byte(0) == Some(b'\'') {
+                text.push_str(&self.take_while(|x| {
+                    x.is_ascii_alphanumeric() || x == b'\'' || x == b'_' || x == b'?'
+                }));
+            }
+            Token::Number(text)
+        } else {
+            self.bump();
+            Token::Symbol(c as char)
+        };
+        Some((token, line))
+    }
+}
+
+struct Parser {
+    tokens: Vec<(Token, usize)>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos).map(|x| &x.0)
+    }
+
+    fn line(&self) -> usize {
+        self.tokens
+            .get(self.pos)
+            .or(self.tokens.last())
+            .map(|x| x.1)
+            .unwrap_or(1)
+    }
+
+    fn error<T>(&self, msg: &str) -> Result<T, DesignError> {
+        Err(DesignError::Syntax(msg.to_string(), self.line()))
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let ret = self.tokens.get(self.pos).map(|x| x.0.clone());
+        self.pos += 1;
+        ret
+    }
+
+    fn is_symbol(&self, c: char) -> bool {
+        self.peek() == Some(&Token::Symbol(c))
+    }
+
+    fn is_keyword(&self, keyword: &str) -> bool {
+        matches!(self.peek(), Some(Token::Identifier(x)) if x == keyword)
+    }
+
+    fn symbol(&mut self, c: char) -> Result<(), DesignError> {
+        if self.is_symbol(c) {
+            self.pos += 1;
+            Ok(())
+        } else {
+            self.error(&format!("'{c}' is expected"))
+        }
+    }
+
+    fn identifier(&mut self) -> Result<String, DesignError> {
+        match self.next() {
+            Some(Token::Identifier(x)) => Ok(x),
+            _ => {
+                self.pos -= 1;
+                self.error("identifier is expected")
+            }
+        }
+    }
+
+    fn number(&mut self) -> Result<i64, DesignError> {
+        let minus = self.is_symbol('-');
+        if minus {
+            self.pos += 1;
+        }
+        match self.next() {
+            Some(Token::Number(x)) => match x.replace('_', "").parse::<i64>() {
+                Ok(x) if minus => Ok(-x),
+                Ok(x) => Ok(x),
+                Err(_) => self.error("integer is expected"),
+            },
+            _ => {
+                self.pos -= 1;
+                self.error("integer is expected")
+            }
+        }
+    }
+
+    /// Skip a balanced group which starts at the current `open` symbol
+    fn skip_group(&mut self, open: char, close: char) -> Result<(), DesignError> {
+        self.symbol(open)?;
+        let mut depth = 1;
+        while depth > 0 {
+            match self.next() {
+                Some(Token::Symbol(x)) if x == open => depth += 1,
+                Some(Token::Symbol(x)) if x == close => depth -= 1,
+                Some(_) => (),
+                None => return self.error(&format!("'{close}' is expected")),
+            }
+        }
+        Ok(())
+    }
+
+    fn skip_statement(&mut self) -> Result<(), DesignError> {
+        loop {
+            match self.next() {
+                Some(Token::Symbol(';')) => return Ok(()),
+                Some(_) => (),
+                None => return self.error("';' is expected"),
+            }
+        }
+    }
+
+    fn range(&mut self) -> Result<Option<(i64, i64)>, DesignError> {
+        if !self.is_symbol('[') {
+            return Ok(None);
+        }
+        self.symbol('[')?;
+        let msb = self.number()?;
+        self.symbol(':')?;
+        let lsb = self.number()?;
+        self.symbol(']')?;
+        Ok(Some((msb, lsb)))
+    }
+
+    fn direction(&self) -> Option<Direction> {
+        match self.peek() {
+            Some(Token::Identifier(x)) => match x.as_str() {
+                "input" => Some(Direction::Input),
+                "output" => Some(Direction::Output),
+                "inout" => Some(Direction::Inout),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    fn skip_net_type(&mut self) {
+        while let Some(Token::Identifier(x)) = self.peek() {
+            if matches!(x.as_str(), "wire" | "reg" | "logic" | "tri" | "signed") {
+                self.pos += 1;
+            } else {
+                break;
+            }
+        }
+    }
+
+    fn modules(&mut self) -> Result<Vec<Module>, DesignError> {
+        let mut ret = vec![];
+        while let Some(token) = self.peek() {
+            match token {
+                Token::Identifier(x) if x == "module" || x == "macromodule" => {
+                    self.pos += 1;
+                    ret.push(self.module()?);
+                }
+                _ => return self.error("module is expected"),
+            }
+        }
+        Ok(ret)
+    }
+
+    fn module(&mut self) -> Result<Module, DesignError> {
+        let mut module = Module {
+            name: self.identifier()?,
+            ..Default::default()
+        };
+
+        if self.is_symbol('#') {
+            self.pos += 1;
+            self.skip_group('(', ')')?;
+        }
+        if self.is_symbol('(') {
+            self.port_list(&mut module)?;
+        }
+        self.symbol(';')?;
+
+        loop {
+            let Some(Token::Identifier(keyword)) = self.peek().cloned() else {
+                return self.error("endmodule is expected");
+            };
+            match keyword.as_str() {
+                "endmodule" => {
+                    self.pos += 1;
+                    break;
+                }
+                "input" | "output" | "inout" => {
+                    let direction = self.direction();
+                    self.pos += 1;
+                    self.skip_net_type();
+                    let range = self.range()?;
+                    for name in self.identifier_list()? {
+                        declare_port(&mut module, name, direction, range);
+                    }
+                }
+                "wire" | "reg" | "logic" | "tri" | "supply0" | "supply1" | "wand" | "wor" => {
+                    self.pos += 1;
+                    self.skip_net_type();
+                    let range = self.range()?;
+                    for name in self.identifier_list()? {
+                        if module.port(&name).is_none() {
+                            module.nets.push(ModuleNet { name, range });
+                        }
+                    }
+                }
+                "specify" => {
+                    while !self.is_keyword("endspecify") {
+                        if self.next().is_none() {
+                            return self.error("endspecify is expected");
+                        }
+                    }
+                    self.pos += 1;
+                }
+                "assign" | "parameter" | "localparam" | "defparam" | "timeunit"
+                | "timeprecision" => self.skip_statement()?,
+                _ => self.instances(&mut module)?,
+            }
+        }
+
+        Ok(module)
+    }
+
+    fn port_list(&mut self, module: &mut Module) -> Result<(), DesignError> {
+        self.symbol('(')?;
+        let mut direction = None;
+        let mut range = None;
+        while !self.is_symbol(')') {
+            if let Some(x) = self.direction() {
+                // ANSI style port declaration
+                self.pos += 1;
+                self.skip_net_type();
+                direction = Some(x);
+                range = self.range()?;
+            }
+            let name = self.identifier()?;
+            declare_port(module, name, direction, range);
+            if !self.is_symbol(')') {
+                self.symbol(',')?;
+            }
+        }
+        self.symbol(')')
+    }
+
+    /// Comma separated identifiers terminated by `;`
+    fn identifier_list(&mut self) -> Result<Vec<String>, DesignError> {
+        let mut ret = vec![];
+        loop {
+            ret.push(self.identifier()?);
+            if self.is_symbol('=') {
+                // Net declaration assignment
+                while !self.is_symbol(',') && !self.is_symbol(';') {
+                    if self.next().is_none() {
+                        return self.error("';' is expected");
+                    }
+                }
+            }
+            if self.is_symbol(',') {
+                self.pos += 1;
+            } else {
+                self.symbol(';')?;
+                return Ok(ret);
+            }
+        }
+    }
+
+    fn instances(&mut self, module: &mut Module) -> Result<(), DesignError> {
+        let ref_name = self.identifier()?;
+        if self.is_symbol('#') {
+            self.pos += 1;
+            self.skip_group('(', ')')?;
+        }
+        loop {
+            let name = self.identifier()?;
+            if let Some((msb, lsb)) = self.range()? {
+                return self.error(&format!(
+                    "instance array is not supported: {name}[{msb}:{lsb}]"
+                ));
+            }
+            let connections = self.connections()?;
+            module.instances.push(Instance {
+                ref_name: ref_name.clone(),
+                name,
+                connections,
+            });
+            if self.is_symbol(',') {
+                self.pos += 1;
+            } else {
+                return self.symbol(';');
+            }
+        }
+    }
+
+    fn connections(&mut self) -> Result<Vec<Connection>, DesignError> {
+        let mut ret = vec![];
+        self.symbol('(')?;
+        while !self.is_symbol(')') {
+            if self.is_symbol('.') {
+                self.pos += 1;
+                let name = self.identifier()?;
+                self.symbol('(')?;
+                let expression = if self.is_symbol(')') {
+                    None
+                } else {
+                    Some(self.expression()?)
+                };
+                self.symbol(')')?;
+                ret.push(Connection::Named(name, expression));
+            } else if self.is_symbol(',') {
+                ret.push(Connection::Ordered(None));
+            } else {
+                ret.push(Connection::Ordered(Some(self.expression()?)));
+            }
+            if !self.is_symbol(')') {
+                self.symbol(',')?;
+            }
+        }
+        self.symbol(')')?;
+        Ok(ret)
+    }
+
+    fn expression(&mut self) -> Result<Expression, DesignError> {
+        match self.next() {
+            Some(Token::Identifier(x)) => {
+                if !self.is_symbol('[') {
+                    return Ok(Expression::Identifier(x));
+                }
+                self.pos += 1;
+                let msb = self.number()?;
+                let ret = if self.is_symbol(':') {
+                    self.pos += 1;
+                    Expression::Slice(x, msb, self.number()?)
+                } else {
+                    Expression::Index(x, msb)
+                };
+                self.symbol(']')?;
+                Ok(ret)
+            }
+            Some(Token::Number(x)) => {
+                let width = match x.split_once('\'') {
+                    Some((width, _)) => width.parse().ok(),
+                    None => None,
+                };
+                Ok(Expression::Constant(width))
+            }
+            Some(Token::Symbol('{')) => {
+                let mut ret = vec![];
+                loop {
+                    ret.push(self.expression()?);
+                    if self.is_symbol(',') {
+                        self.pos += 1;
+                    } else {
+                        self.symbol('}')?;
+                        return Ok(Expression::Concatenation(ret));
+                    }
+                }
+            }
+            _ => {
+                self.pos -= 1;
+                self.error("expression is expected")
+            }
+        }
+    }
+}
+
+fn declare_port(
+    module: &mut Module,
+    name: String,
+    direction: Option<Direction>,
+    range: Option<(i64, i64)>,
+) {
+    if let Some(x) = module.ports.iter_mut().find(|x| x.name == name) {
+        x.direction = direction.or(x.direction);
+        x.range = range.or(x.range);
+    } else {
+        module.ports.push(ModulePort {
+            name,
+            direction,
+            range,
+        });
+    }
+}
+
+/// Parse modules of a structural Verilog netlist.
+///
+/// Only the subset used by gate-level netlists is supported:
+/// module, port, net and instance declarations.
+/// Behavioral statements like `assign` are ignored.
+pub fn parse_verilog(text: &str) -> Result<Vec<Module>, DesignError> {
+    let mut lexer = Lexer::new(text);
+    let mut tokens = vec![];
+    while let Some(x) = lexer.next_token() {
+        tokens.push(x);
+    }
+    let mut parser = Parser { tokens, pos: 0 };
+    parser.modules()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        let code = r##"
+// comment
+`timescale 1ns/1ps
+module top (clk, din, dout);
+    input clk;
+    input [1:0] din;
+    output dout;
+    wire [1:0] n;
+    wire \esc[0] ;
+    (* keep *)
+    DFF u_ff0 (.CK(clk), .D(din[0]), .Q(n[0]));
+    DFF u_ff1 (.CK(clk), .D(din[1]), .Q(n[1])), u_ff2 (.CK(clk), .D(1'b0), .Q());
+    sub #(.W(2)) u_sub (.a(n), .y(dout));
+endmodule
+
+module sub (input [1:0] a, output y);
+    AND2 u_and (a[1], a[0], y);
+    assign unused = a[0];
+endmodule
+"##;
+        let modules = parse_verilog(code).unwrap();
+        assert_eq!(modules.len(), 2);
+
+        let top = &modules[0];
+        assert_eq!(top.name, "top");
+        assert_eq!(top.ports.len(), 3);
+        assert_eq!(top.port("din").unwrap().range, Some((1, 0)));
+        assert_eq!(top.port("dout").unwrap().direction, Some(Direction::Output));
+        assert_eq!(top.nets[1].name, "esc[0]");
+        assert_eq!(top.instances.len(), 4);
+        assert_eq!(top.instances[2].name, "u_ff2");
+        assert_eq!(
+            top.instances[2].connections[1],
+            Connection::Named("D".into(), Some(Expression::Constant(Some(1))))
+        );
+        assert_eq!(
+            top.instances[3].connections[0],
+            Connection::Named("a".into(), Some(Expression::Identifier("n".into())))
+        );
+        let bits = Expression::Identifier("n".into()).bits(top);
+        assert_eq!(bits, vec![Some("n[1]".into()), Some("n[0]".into())]);
+
+        let sub = &modules[1];
+        assert_eq!(sub.port("a").unwrap().direction, Some(Direction::Input));
+        assert_eq!(sub.port("y").unwrap().direction, Some(Direction::Output));
+        assert_eq!(sub.instances[0].connections.len(), 3);
+
+        let err = parse_verilog("module top (a);\n  input a\nendmodule\n").unwrap_err();
+        assert!(matches!(err, DesignError::Syntax(_, 3)));
+    }
+}
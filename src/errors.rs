@@ -1,5 +1,7 @@
+use crate::constraints::Dimension;
 use crate::file_db::{FileDb, Location};
-use crate::sdc::{Argument, SdcVersion};
+use crate::lint::LintRule;
+use crate::sdc::{Argument, Dialect, SdcVersion};
 use codespan_reporting::diagnostic::{Diagnostic, Label};
 use codespan_reporting::term::{self, termcolor::StandardStream};
 use parol_runtime::{LexerError, ParolError, ParserError, Span, SyntaxError};
@@ -37,6 +39,32 @@ impl Report for ParseError {
 }
 
 impl ParseError {
+    /// Short description of the error
+    pub fn message(&self) -> String {
+        match self {
+            ParseError::LexicalError(_) => "Lexical error".to_string(),
+            ParseError::SyntaxError(_) => "Syntax error".to_string(),
+            ParseError::SemanticError(x) => x.message(),
+        }
+    }
+
+    /// Location of the error, `None` if it is unknown
+    pub fn location(&self) -> Option<Location> {
+        match self {
+            ParseError::SyntaxError(ParserError::SyntaxErrors { entries }) => {
+                entries.first().map(|x| match x.unexpected_tokens.first() {
+                    Some(token) => (&token.token).into(),
+                    None => (&*x.error_location).into(),
+                })
+            }
+            ParseError::SyntaxError(ParserError::UnprocessedInput { last_token, .. }) => {
+                Some((&**last_token).into())
+            }
+            ParseError::SemanticError(x) => Some(x.location()),
+            _ => None,
+        }
+    }
+
     fn report_lexical_error(
         err: &LexerError,
         writer: &StandardStream,
@@ -287,181 +315,606 @@ pub enum SemanticError {
     Interpret(Location),
 }
 
+impl SemanticError {
+    /// Short description of the error
+    pub fn message(&self) -> String {
+        match self {
+            SemanticError::WrongArgument(_) => "Wrong argument".to_string(),
+            SemanticError::DuplicatedArgument(_) => "Duplicated arguments".to_string(),
+            SemanticError::MissingOptArgument(_) => "Missing argument".to_string(),
+            SemanticError::MissingPosArgument(_) => "Missing positional argument".to_string(),
+            SemanticError::TooManyArgument(_) => "Too many argument".to_string(),
+            SemanticError::MissingMandatoryArgument(x, _) => {
+                format!("Missing mandatory argument: {x}")
+            }
+            SemanticError::SdcVersionPlacement(_) => {
+                "SDC version should be set at the beginning of file".to_string()
+            }
+            SemanticError::UnknownVersion(_) => "Unknown SDC version".to_string(),
+            SemanticError::AmbiguousOption(_) => "Ambiguous option".to_string(),
+            SemanticError::Interpret(_) => "Interpretation failed".to_string(),
+        }
+    }
+
+    pub fn location(&self) -> Location {
+        match self {
+            SemanticError::WrongArgument(x) => x.location(),
+            SemanticError::DuplicatedArgument(x) => x.location(),
+            SemanticError::MissingOptArgument(x) => x.location(),
+            SemanticError::MissingPosArgument(x) => x.clone(),
+            SemanticError::TooManyArgument(x) => x.clone(),
+            SemanticError::MissingMandatoryArgument(_, x) => x.clone(),
+            SemanticError::SdcVersionPlacement(x) => x.clone(),
+            SemanticError::UnknownVersion(x) => x.clone(),
+            SemanticError::AmbiguousOption(x) => x.clone(),
+            SemanticError::Interpret(x) => x.clone(),
+        }
+    }
+}
+
 impl Report for SemanticError {
     fn report(self, files: &FileDb<String, &str>) -> anyhow::Result<()> {
         let writer = StandardStream::stderr(term::termcolor::ColorChoice::Auto);
         let config = term::Config::default();
 
+        let (range, file_id) = self.location().range_file(files);
+        let diag = Diagnostic::error()
+            .with_message(self.message())
+            .with_code("sdcx::errors::SemanticError")
+            .with_labels(vec![Label::primary(file_id, range).with_message("Found")]);
+        term::emit(&mut writer.lock(), &config, files, &diag)?;
+        Ok(())
+    }
+}
+
+/// Validate Error
+#[derive(Debug, Error)]
+pub enum ValidateError {
+    #[error("UnknownCommand: {0}")]
+    UnknownCommand(String, Location),
+
+    #[error("CmdUnsupportedVersion")]
+    CmdUnsupportedVersion(SdcVersion, Location),
+
+    #[error("ArgUnsupportedVersion")]
+    ArgUnsupportedVersion(SdcVersion, Location, String),
+
+    #[error("ArgumentCombination")]
+    ArgumentCombination(Location),
+
+    #[error("Incompatible: {0}")]
+    Incompatible(String, Dialect, Location),
+}
+
+impl ValidateError {
+    /// Short description of the error
+    pub fn message(&self) -> String {
         match self {
-            SemanticError::WrongArgument(x) => {
-                let (range, file_id) = x.location().range_file(files);
-                let diag = Diagnostic::error()
-                    .with_message("Wrong argument")
-                    .with_code("sdcx::errors::SemanticError")
-                    .with_labels(vec![Label::primary(file_id, range).with_message("Found")]);
-                Ok(term::emit(&mut writer.lock(), &config, files, &diag)?)
+            ValidateError::UnknownCommand(x, _) => format!("Unknown command: {x}"),
+            ValidateError::CmdUnsupportedVersion(x, _) => {
+                format!("Unsupported command at SDC {}", x.version_string())
             }
-            SemanticError::DuplicatedArgument(x) => {
-                let (range, file_id) = x.location().range_file(files);
-                let diag = Diagnostic::error()
-                    .with_message("Duplicated arguments")
-                    .with_code("sdcx::errors::SemanticError")
-                    .with_labels(vec![Label::primary(file_id, range).with_message("Found")]);
-                Ok(term::emit(&mut writer.lock(), &config, files, &diag)?)
+            ValidateError::ArgUnsupportedVersion(x, _, name) => {
+                format!(
+                    "Unsupported argument \"-{name}\" at SDC {}",
+                    x.version_string()
+                )
             }
-            SemanticError::MissingOptArgument(x) => {
-                let (range, file_id) = x.location().range_file(files);
-                let diag = Diagnostic::error()
-                    .with_message("Missing argument")
-                    .with_code("sdcx::errors::SemanticError")
-                    .with_labels(vec![Label::primary(file_id, range).with_message("Found")]);
-                Ok(term::emit(&mut writer.lock(), &config, files, &diag)?)
+            ValidateError::ArgumentCombination(_) => "Forbidden argument combination".to_string(),
+            ValidateError::Incompatible(name, dialect, _) => {
+                format!("\"{name}\" is not supported by {dialect}")
             }
-            SemanticError::MissingPosArgument(x) => {
+        }
+    }
+
+    pub fn location(&self) -> &Location {
+        match self {
+            ValidateError::UnknownCommand(_, x) => x,
+            ValidateError::CmdUnsupportedVersion(_, x) => x,
+            ValidateError::ArgUnsupportedVersion(_, x, _) => x,
+            ValidateError::ArgumentCombination(x) => x,
+            ValidateError::Incompatible(_, _, x) => x,
+        }
+    }
+
+    pub fn report(self, files: &FileDb<String, &str>) -> anyhow::Result<()> {
+        let writer = StandardStream::stderr(term::termcolor::ColorChoice::Auto);
+        let config = term::Config::default();
+
+        let diag = match self {
+            ValidateError::Incompatible(..) => Diagnostic::warning(),
+            _ => Diagnostic::error(),
+        };
+        let (range, file_id) = self.location().range_file(files);
+        let diag = diag
+            .with_message(self.message())
+            .with_code("sdcx::errors::ValidateError")
+            .with_labels(vec![Label::primary(file_id, range).with_message("Found")]);
+        term::emit(&mut writer.lock(), &config, files, &diag)?;
+        Ok(())
+    }
+}
+
+/// Interpret Error
+#[derive(Debug, Error)]
+pub enum InterpretError {
+    #[error("Something")]
+    Something(Location),
+    #[error("ConflictingUnits")]
+    ConflictingUnits(Dimension, Location),
+}
+
+impl Report for InterpretError {
+    fn report(self, files: &FileDb<String, &str>) -> anyhow::Result<()> {
+        let writer = StandardStream::stderr(term::termcolor::ColorChoice::Auto);
+        let config = term::Config::default();
+
+        match self {
+            InterpretError::Something(x) => {
                 let (range, file_id) = x.range_file(files);
                 let diag = Diagnostic::error()
-                    .with_message("Missing positional argument")
-                    .with_code("sdcx::errors::SemanticError")
+                    .with_message("TODO")
+                    .with_code("sdcx::errors::InterpretError")
                     .with_labels(vec![Label::primary(file_id, range).with_message("Found")]);
                 Ok(term::emit(&mut writer.lock(), &config, files, &diag)?)
             }
-            SemanticError::TooManyArgument(x) => {
+            InterpretError::ConflictingUnits(dimension, x) => {
                 let (range, file_id) = x.range_file(files);
                 let diag = Diagnostic::error()
-                    .with_message("Too many argument")
-                    .with_code("sdcx::errors::SemanticError")
+                    .with_message(format!(
+                        "{dimension:?} unit conflicts with former set_units"
+                    ))
+                    .with_code("sdcx::errors::InterpretError")
                     .with_labels(vec![Label::primary(file_id, range).with_message("Found")]);
                 Ok(term::emit(&mut writer.lock(), &config, files, &diag)?)
             }
-            SemanticError::MissingMandatoryArgument(name, location) => {
+        }
+    }
+}
+
+/// Convert Error
+#[derive(Debug, Error)]
+pub enum ConvertError {
+    #[error("CommentedOut: {0}")]
+    CommentedOut(String, Dialect, Location),
+
+    #[error("DroppedArgument: {0}")]
+    DroppedArgument(String, Dialect, Location),
+
+    #[error("ReplacedArgument: {0}")]
+    ReplacedArgument(String, String, Dialect, Location),
+
+    #[error("CmdUnsupportedVersion: {0}")]
+    CmdUnsupportedVersion(String, SdcVersion, Location),
+
+    #[error("ArgUnsupportedVersion: {0}")]
+    ArgUnsupportedVersion(String, SdcVersion, Location),
+
+    #[error("DroppedVersionArgument: {0}")]
+    DroppedVersionArgument(String, SdcVersion, Location),
+}
+
+impl ConvertError {
+    pub fn location(&self) -> &Location {
+        match self {
+            ConvertError::CommentedOut(_, _, x) => x,
+            ConvertError::DroppedArgument(_, _, x) => x,
+            ConvertError::ReplacedArgument(_, _, _, x) => x,
+            ConvertError::CmdUnsupportedVersion(_, _, x) => x,
+            ConvertError::ArgUnsupportedVersion(_, _, x) => x,
+            ConvertError::DroppedVersionArgument(_, _, x) => x,
+        }
+    }
+}
+
+impl Report for ConvertError {
+    fn report(self, files: &FileDb<String, &str>) -> anyhow::Result<()> {
+        let writer = StandardStream::stderr(term::termcolor::ColorChoice::Auto);
+        let config = term::Config::default();
+
+        let diag = match self {
+            ConvertError::CommentedOut(name, dialect, location) => {
                 let (range, file_id) = location.range_file(files);
-                let diag = Diagnostic::error()
-                    .with_message(format!("Missing mandatory argument: {name}"))
-                    .with_code("sdcx::errors::SemanticError")
-                    .with_labels(vec![Label::primary(file_id, range).with_message("Found")]);
-                Ok(term::emit(&mut writer.lock(), &config, files, &diag)?)
+                Diagnostic::warning()
+                    .with_message(format!(
+                        "\"{name}\" has no equivalent in {dialect} and is commented out"
+                    ))
+                    .with_labels(vec![Label::primary(file_id, range).with_message("Found")])
             }
-            SemanticError::SdcVersionPlacement(location) => {
+            ConvertError::DroppedArgument(name, dialect, location) => {
                 let (range, file_id) = location.range_file(files);
-                let diag = Diagnostic::error()
-                    .with_message("SDC version should be set at the beginning of file")
-                    .with_code("sdcx::errors::SemanticError")
-                    .with_labels(vec![Label::primary(file_id, range).with_message("Found")]);
-                Ok(term::emit(&mut writer.lock(), &config, files, &diag)?)
+                Diagnostic::warning()
+                    .with_message(format!(
+                        "\"-{name}\" has no equivalent in {dialect} and is dropped"
+                    ))
+                    .with_labels(vec![Label::primary(file_id, range).with_message("Found")])
             }
-            SemanticError::UnknownVersion(location) => {
+            ConvertError::ReplacedArgument(name, replaced, dialect, location) => {
                 let (range, file_id) = location.range_file(files);
-                let diag = Diagnostic::error()
-                    .with_message("Unknown SDC version")
-                    .with_code("sdcx::errors::SemanticError")
-                    .with_labels(vec![Label::primary(file_id, range).with_message("Found")]);
-                Ok(term::emit(&mut writer.lock(), &config, files, &diag)?)
+                Diagnostic::warning()
+                    .with_message(format!(
+                        "\"-{name}\" has no equivalent in {dialect} and is replaced by \"-{replaced}\""
+                    ))
+                    .with_labels(vec![Label::primary(file_id, range).with_message("Found")])
             }
-            SemanticError::AmbiguousOption(x) => {
-                let (range, file_id) = x.range_file(files);
-                let diag = Diagnostic::error()
-                    .with_message("Ambiguous option")
-                    .with_code("sdcx::errors::SemanticError")
-                    .with_labels(vec![Label::primary(file_id, range).with_message("Found")]);
-                Ok(term::emit(&mut writer.lock(), &config, files, &diag)?)
+            ConvertError::CmdUnsupportedVersion(name, version, location) => {
+                let (range, file_id) = location.range_file(files);
+                Diagnostic::warning()
+                    .with_message(format!(
+                        "\"{name}\" is unsupported at SDC {} and is commented out",
+                        version.version_string()
+                    ))
+                    .with_labels(vec![Label::primary(file_id, range).with_message("Found")])
             }
-            SemanticError::Interpret(x) => {
-                let (range, file_id) = x.range_file(files);
-                let diag = Diagnostic::error()
-                    .with_message("Interpretation failed")
-                    .with_code("sdcx::errors::SemanticError")
-                    .with_labels(vec![Label::primary(file_id, range).with_message("Found")]);
-                Ok(term::emit(&mut writer.lock(), &config, files, &diag)?)
+            ConvertError::ArgUnsupportedVersion(name, version, location) => {
+                let (range, file_id) = location.range_file(files);
+                Diagnostic::warning()
+                    .with_message(format!(
+                        "\"-{name}\" is unsupported at SDC {} and the command is commented out",
+                        version.version_string()
+                    ))
+                    .with_labels(vec![Label::primary(file_id, range).with_message("Found")])
+            }
+            ConvertError::DroppedVersionArgument(name, version, location) => {
+                let (range, file_id) = location.range_file(files);
+                Diagnostic::warning()
+                    .with_message(format!(
+                        "\"-{name}\" is unsupported at SDC {} and is dropped",
+                        version.version_string()
+                    ))
+                    .with_labels(vec![Label::primary(file_id, range).with_message("Found")])
             }
+        };
+
+        let diag = diag.with_code("sdcx::errors::ConvertError");
+        term::emit(&mut writer.lock(), &config, files, &diag)?;
+        Ok(())
+    }
+}
+
+/// Rename Error
+#[derive(Debug, Error)]
+pub enum RenameError {
+    #[error("UnrenamedPattern: {0}")]
+    UnrenamedPattern(String, String, String, Location),
+}
+
+impl RenameError {
+    pub fn location(&self) -> &Location {
+        match self {
+            RenameError::UnrenamedPattern(_, _, _, x) => x,
         }
     }
 }
 
-/// Validate Error
+impl Report for RenameError {
+    fn report(self, files: &FileDb<String, &str>) -> anyhow::Result<()> {
+        let writer = StandardStream::stderr(term::termcolor::ColorChoice::Auto);
+        let config = term::Config::default();
+
+        let diag = match self {
+            RenameError::UnrenamedPattern(pattern, from, to, location) => {
+                let (range, file_id) = location.range_file(files);
+                Diagnostic::warning()
+                    .with_message(format!(
+                        "Pattern \"{pattern}\" matches \"{from}\", but is not rewritten to match \"{to}\""
+                    ))
+                    .with_labels(vec![Label::primary(file_id, range).with_message("Found")])
+            }
+        };
+
+        let diag = diag.with_code("sdcx::errors::RenameError");
+        term::emit(&mut writer.lock(), &config, files, &diag)?;
+        Ok(())
+    }
+}
+
+/// Design Error
 #[derive(Debug, Error)]
-pub enum ValidateError {
-    #[error("UnknownCommand: {0}")]
-    UnknownCommand(String, Location),
+pub enum DesignError {
+    #[error("syntax error at line {1}: {0}")]
+    Syntax(String, usize),
 
-    #[error("CmdUnsupportedVersion")]
-    CmdUnsupportedVersion(SdcVersion, Location),
+    #[error("unknown top module: {0}")]
+    UnknownTop(String),
 
-    #[error("ArgUnsupportedVersion")]
-    ArgUnsupportedVersion(SdcVersion, Location, String),
+    #[error("recursive instantiation: {0}")]
+    RecursiveModule(String),
+}
 
-    #[error("ArgumentCombination")]
-    ArgumentCombination(Location),
+/// Resolve Error
+#[derive(Debug, Error)]
+pub enum ResolveError {
+    #[error("NoMatch: {0}")]
+    NoMatch(String, Vec<String>, Location),
+
+    #[error("EmptyOfObjects: {0}")]
+    EmptyOfObjects(String, Location),
+
+    #[error("UnknownLibraryObject: {1}")]
+    UnknownLibraryObject(String, String, Location),
 }
 
-impl ValidateError {
-    pub fn report(self, files: &FileDb<String, &str>) -> anyhow::Result<()> {
+impl ResolveError {
+    pub fn location(&self) -> &Location {
+        match self {
+            ResolveError::NoMatch(_, _, x) => x,
+            ResolveError::EmptyOfObjects(_, x) => x,
+            ResolveError::UnknownLibraryObject(_, _, x) => x,
+        }
+    }
+}
+
+impl Report for ResolveError {
+    fn report(self, files: &FileDb<String, &str>) -> anyhow::Result<()> {
         let writer = StandardStream::stderr(term::termcolor::ColorChoice::Auto);
         let config = term::Config::default();
 
-        match self {
-            ValidateError::UnknownCommand(_, location) => {
+        let diag = match self {
+            ResolveError::NoMatch(pattern, nearest, location) => {
                 let (range, file_id) = location.range_file(files);
-                let diag = Diagnostic::error()
-                    .with_message("Unknown command")
-                    .with_code("sdcx::errors::ValidateError")
+                let diag = Diagnostic::warning()
+                    .with_message(format!("Pattern \"{pattern}\" matches no object"))
                     .with_labels(vec![Label::primary(file_id, range).with_message("Found")]);
-                Ok(term::emit(&mut writer.lock(), &config, files, &diag)?)
+                if nearest.is_empty() {
+                    diag
+                } else {
+                    diag.with_notes(vec![format!("Nearest: {}", nearest.join(", "))])
+                }
             }
-            ValidateError::CmdUnsupportedVersion(version, location) => {
+            ResolveError::EmptyOfObjects(name, location) => {
                 let (range, file_id) = location.range_file(files);
-                let diag = Diagnostic::error()
+                Diagnostic::warning()
+                    .with_message(format!("-of_objects of \"{name}\" is empty"))
+                    .with_labels(vec![Label::primary(file_id, range).with_message("Found")])
+            }
+            ResolveError::UnknownLibraryObject(kind, name, location) => {
+                let (range, file_id) = location.range_file(files);
+                Diagnostic::warning()
+                    .with_message(format!("Unknown {kind} \"{name}\""))
+                    .with_labels(vec![Label::primary(file_id, range).with_message("Found")])
+            }
+        };
+
+        let diag = diag.with_code("sdcx::errors::ResolveError");
+        term::emit(&mut writer.lock(), &config, files, &diag)?;
+        Ok(())
+    }
+}
+
+/// Lint Error
+#[derive(Debug, Error)]
+pub enum LintError {
+    #[error("ClockPeriod: {0}")]
+    ClockPeriod(String, Location),
+
+    #[error("WaveformEdgeCount: {0}")]
+    WaveformEdgeCount(String, Location),
+
+    #[error("WaveformRange: {0}")]
+    WaveformRange(String, Location),
+
+    #[error("WaveformOrder: {0}")]
+    WaveformOrder(String, Location),
+
+    #[error("DutyCycle: {0}")]
+    DutyCycle(String, f32, Location),
+
+    #[error("DuplicatedClockSource: {0}")]
+    DuplicatedClockSource(String, String, Location, Location),
+
+    #[error("GeneratedClockSource: {0}")]
+    GeneratedClockSource(String, String, Location),
+
+    #[error("PropagatedVirtualClock: {0}")]
+    PropagatedVirtualClock(String, Location),
+
+    #[error("UnknownPort: {0}")]
+    UnknownPort(String, Location),
+}
+
+impl LintError {
+    pub fn rule(&self) -> LintRule {
+        match self {
+            LintError::ClockPeriod(..) => LintRule::ClockPeriod,
+            LintError::WaveformEdgeCount(..) => LintRule::WaveformEdgeCount,
+            LintError::WaveformRange(..) => LintRule::WaveformRange,
+            LintError::WaveformOrder(..) => LintRule::WaveformOrder,
+            LintError::DutyCycle(..) => LintRule::DutyCycle,
+            LintError::DuplicatedClockSource(..) => LintRule::DuplicatedClockSource,
+            LintError::GeneratedClockSource(..) => LintRule::GeneratedClockSource,
+            LintError::PropagatedVirtualClock(..) => LintRule::PropagatedVirtualClock,
+            LintError::UnknownPort(..) => LintRule::UnknownPort,
+        }
+    }
+
+    pub fn location(&self) -> &Location {
+        match self {
+            LintError::ClockPeriod(_, x) => x,
+            LintError::WaveformEdgeCount(_, x) => x,
+            LintError::WaveformRange(_, x) => x,
+            LintError::WaveformOrder(_, x) => x,
+            LintError::DutyCycle(_, _, x) => x,
+            LintError::DuplicatedClockSource(_, _, x, _) => x,
+            LintError::GeneratedClockSource(_, _, x) => x,
+            LintError::PropagatedVirtualClock(_, x) => x,
+            LintError::UnknownPort(_, x) => x,
+        }
+    }
+}
+
+impl Report for LintError {
+    fn report(self, files: &FileDb<String, &str>) -> anyhow::Result<()> {
+        let writer = StandardStream::stderr(term::termcolor::ColorChoice::Auto);
+        let config = term::Config::default();
+        let code = format!("sdcx::lint::{}", self.rule());
+
+        let diag = match self {
+            LintError::ClockPeriod(name, location) => {
+                let (range, file_id) = location.range_file(files);
+                Diagnostic::warning()
+                    .with_message(format!("Clock \"{name}\" has zero or negative period"))
+                    .with_labels(vec![Label::primary(file_id, range).with_message("Found")])
+            }
+            LintError::WaveformEdgeCount(name, location) => {
+                let (range, file_id) = location.range_file(files);
+                Diagnostic::warning()
+                    .with_message(format!("Waveform of clock \"{name}\" has odd edge count"))
+                    .with_labels(vec![Label::primary(file_id, range).with_message("Found")])
+            }
+            LintError::WaveformRange(name, location) => {
+                let (range, file_id) = location.range_file(files);
+                Diagnostic::warning()
                     .with_message(format!(
-                        "Unsupported command at SDC {}",
-                        version.version_string()
+                        "Waveform of clock \"{name}\" has edges outside of [0, 2 * period)"
                     ))
-                    .with_code("sdcx::errors::ValidateError")
-                    .with_labels(vec![Label::primary(file_id, range).with_message("Found")]);
-                Ok(term::emit(&mut writer.lock(), &config, files, &diag)?)
+                    .with_labels(vec![Label::primary(file_id, range).with_message("Found")])
             }
-            ValidateError::ArgUnsupportedVersion(version, location, name) => {
+            LintError::WaveformOrder(name, location) => {
                 let (range, file_id) = location.range_file(files);
-                let diag = Diagnostic::error()
+                Diagnostic::warning()
+                    .with_message(format!("Waveform of clock \"{name}\" is not increasing"))
+                    .with_labels(vec![Label::primary(file_id, range).with_message("Found")])
+            }
+            LintError::DutyCycle(name, duty, location) => {
+                let (range, file_id) = location.range_file(files);
+                Diagnostic::warning()
                     .with_message(format!(
-                        "Unsupported argument \"-{name}\" at {}",
-                        version.version_string()
+                        "Clock \"{name}\" has unusual duty cycle: {}%",
+                        duty * 100.0
                     ))
-                    .with_code("sdcx::errors::ValidateError")
-                    .with_labels(vec![Label::primary(file_id, range).with_message("Found")]);
-                Ok(term::emit(&mut writer.lock(), &config, files, &diag)?)
+                    .with_labels(vec![Label::primary(file_id, range).with_message("Found")])
             }
-            ValidateError::ArgumentCombination(x) => {
-                let (range, file_id) = x.range_file(files);
-                let diag = Diagnostic::error()
-                    .with_message("Forbidden argument combination")
-                    .with_code("sdcx::errors::ValidateError")
-                    .with_labels(vec![Label::primary(file_id, range).with_message("Found")]);
-                Ok(term::emit(&mut writer.lock(), &config, files, &diag)?)
+            LintError::DuplicatedClockSource(name, source, location, previous) => {
+                let (range, file_id) = location.range_file(files);
+                let (prev_range, prev_file_id) = previous.range_file(files);
+                Diagnostic::warning()
+                    .with_message(format!(
+                        "Clock \"{name}\" is defined on {source} which already has a clock without -add"
+                    ))
+                    .with_labels(vec![
+                        Label::primary(file_id, range).with_message("Found"),
+                        Label::secondary(prev_file_id, prev_range)
+                            .with_message("Previous definition"),
+                    ])
             }
-        }
+            LintError::GeneratedClockSource(name, source, location) => {
+                let (range, file_id) = location.range_file(files);
+                Diagnostic::warning()
+                    .with_message(format!(
+                        "Source {source} of generated clock \"{name}\" is not the source of any master clock"
+                    ))
+                    .with_labels(vec![Label::primary(file_id, range).with_message("Found")])
+            }
+            LintError::PropagatedVirtualClock(name, location) => {
+                let (range, file_id) = location.range_file(files);
+                Diagnostic::warning()
+                    .with_message(format!("Virtual clock \"{name}\" is set as propagated"))
+                    .with_labels(vec![Label::primary(file_id, range).with_message("Found")])
+            }
+            LintError::UnknownPort(name, location) => {
+                let (range, file_id) = location.range_file(files);
+                Diagnostic::warning()
+                    .with_message(format!("Port \"{name}\" is not found in the port list"))
+                    .with_labels(vec![Label::primary(file_id, range).with_message("Found")])
+            }
+        };
+
+        let diag = diag.with_code(code);
+        term::emit(&mut writer.lock(), &config, files, &diag)?;
+        Ok(())
     }
 }
 
-/// Interpret Error
+/// Hierarchy Error
 #[derive(Debug, Error)]
-pub enum InterpretError {
-    #[error("Something")]
-    Something(Location),
+pub enum HierarchyError {
+    #[error("Unsupported: {0}")]
+    Unsupported(String, Location),
+
+    #[error("OutsideInstance: {0}")]
+    OutsideInstance(String, String, Location),
+
+    #[error("VirtualClock: {0}")]
+    VirtualClock(String, Location),
 }
 
-impl Report for InterpretError {
+impl HierarchyError {
+    pub fn location(&self) -> &Location {
+        match self {
+            HierarchyError::Unsupported(_, x) => x,
+            HierarchyError::OutsideInstance(_, _, x) => x,
+            HierarchyError::VirtualClock(_, x) => x,
+        }
+    }
+}
+
+impl Report for HierarchyError {
     fn report(self, files: &FileDb<String, &str>) -> anyhow::Result<()> {
         let writer = StandardStream::stderr(term::termcolor::ColorChoice::Auto);
         let config = term::Config::default();
 
-        match self {
-            InterpretError::Something(x) => {
-                let (range, file_id) = x.range_file(files);
-                let diag = Diagnostic::error()
-                    .with_message("TODO")
-                    .with_code("sdcx::errors::InterpretError")
-                    .with_labels(vec![Label::primary(file_id, range).with_message("Found")]);
-                Ok(term::emit(&mut writer.lock(), &config, files, &diag)?)
+        let diag = match self {
+            HierarchyError::Unsupported(name, location) => {
+                let (range, file_id) = location.range_file(files);
+                Diagnostic::warning()
+                    .with_message(format!(
+                        "\"{name}\" can't be rewritten across hierarchy and is commented out"
+                    ))
+                    .with_labels(vec![Label::primary(file_id, range).with_message("Found")])
             }
-        }
+            HierarchyError::OutsideInstance(name, instance, location) => {
+                let (range, file_id) = location.range_file(files);
+                Diagnostic::warning()
+                    .with_message(format!(
+                        "\"{name}\" is outside of instance \"{instance}\" and is commented out"
+                    ))
+                    .with_labels(vec![Label::primary(file_id, range).with_message("Found")])
+            }
+            HierarchyError::VirtualClock(name, location) => {
+                let (range, file_id) = location.range_file(files);
+                Diagnostic::warning()
+                    .with_message(format!(
+                        "Source of clock \"{name}\" is outside of instance and the clock becomes virtual"
+                    ))
+                    .with_labels(vec![Label::primary(file_id, range).with_message("Found")])
+            }
+        };
+
+        let diag = diag.with_code("sdcx::errors::HierarchyError");
+        term::emit(&mut writer.lock(), &config, files, &diag)?;
+        Ok(())
     }
 }
+
+/// Build Error
+#[derive(Debug, Error)]
+pub enum BuildError {
+    #[error("MissingArgument: {0}")]
+    MissingArgument(String),
+
+    #[error("Invalid: {0}")]
+    Invalid(ValidateError),
+}
+
+/// Query Error
+#[derive(Debug, Error)]
+pub enum QueryError {
+    #[error("Unexpected token \"{0}\" at {1}")]
+    UnexpectedToken(String, usize),
+
+    #[error("Unexpected end of query")]
+    UnexpectedEnd,
+
+    #[error("Unknown command: {0}")]
+    UnknownCommand(String),
+
+    #[error("Unknown key: {0}")]
+    UnknownKey(String),
+
+    #[error("Invalid pattern: {0}")]
+    InvalidPattern(String),
+
+    #[error("Invalid line range: {0}")]
+    InvalidRange(String),
+}
@@ -92,7 +92,7 @@ impl Location {
         Location {
             start_byte: from.start_byte,
             start_line: from.start_line,
-            start_column: to.start_column,
+            start_column: from.start_column,
             end_line: to.end_line,
             end_column: to.end_column,
             length: to.start_byte - from.start_byte + to.length,
@@ -138,3 +138,35 @@ impl From<&parol_runtime::Location> for Location {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_to() {
+        let from = Location {
+            start_byte: 14,
+            start_line: 2,
+            start_column: 5,
+            end_line: 2,
+            end_column: 8,
+            length: 3,
+            ..Default::default()
+        };
+        let to = Location {
+            start_byte: 30,
+            start_line: 3,
+            start_column: 1,
+            end_line: 3,
+            end_column: 4,
+            length: 3,
+            ..Default::default()
+        };
+        let location = Location::from_to(&from, &to);
+        assert_eq!((location.start_line, location.start_column), (2, 5));
+        assert_eq!((location.end_line, location.end_column), (3, 4));
+        assert_eq!(location.start_byte, 14);
+        assert_eq!(location.length, 19);
+    }
+}
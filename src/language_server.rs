@@ -0,0 +1,620 @@
+use crate::constraints::{unquote, Clock, GeneratedClock};
+use crate::errors::ParseError;
+use crate::file_db::Location;
+use crate::matcher::Matcher;
+use crate::sdc::util::CommandExt;
+use crate::sdc::{Command, CommandSpec, Dialect, Sdc, SdcVersion, VersionRange, COMMAND_SPECS};
+use crate::Parser;
+use lsp_server::{Connection, ErrorCode, Message, Notification, Request, Response};
+use lsp_types::notification::{
+    DidChangeTextDocument, DidCloseTextDocument, DidOpenTextDocument,
+    Notification as LspNotification, PublishDiagnostics,
+};
+use lsp_types::request::{Completion, Formatting, GotoDefinition, HoverRequest};
+use lsp_types::{
+    CompletionItem, CompletionItemKind, CompletionOptions, CompletionResponse, Diagnostic,
+    DiagnosticSeverity, GotoDefinitionResponse, Hover, HoverContents, HoverProviderCapability,
+    MarkupContent, MarkupKind, OneOf, Position, PublishDiagnosticsParams, Range,
+    ServerCapabilities, TextDocumentSyncCapability, TextDocumentSyncKind, TextEdit, Uri,
+};
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+/// Text document opened by the client
+#[derive(Clone, Debug)]
+pub struct Document {
+    text: String,
+    path: PathBuf,
+    dialect: Dialect,
+}
+
+impl Document {
+    /// Document of `path`, whose dialect is implied by the extension
+    pub fn new(text: &str, path: PathBuf) -> Self {
+        let dialect = Dialect::from_path(&path);
+        Self::with_dialect(text, path, dialect)
+    }
+
+    pub fn with_dialect(text: &str, path: PathBuf, dialect: Dialect) -> Self {
+        Self {
+            text: text.to_string(),
+            path,
+            dialect,
+        }
+    }
+
+    fn parse(&self) -> Result<Sdc, ParseError> {
+        parse(&self.text, &self.path, self.dialect)
+    }
+
+    /// Errors of parse and validation
+    pub fn diagnostics(&self) -> Vec<Diagnostic> {
+        let sdc = match self.parse() {
+            Ok(x) => x,
+            Err(x) => {
+                let range = match x.location() {
+                    Some(x) => self.range(&x),
+                    None => Range::default(),
+                };
+                return vec![diagnostic(range, x.message())];
+            }
+        };
+        sdc.validate(None)
+            .iter()
+            .map(|x| diagnostic(self.range(x.location()), x.message()))
+            .collect()
+    }
+
+    /// Description of the command or option at `position`
+    pub fn hover(&self, position: Position) -> Option<Hover> {
+        let offset = self.offset(position);
+        let (start, end) = word_at(&self.text, offset)?;
+        let word = &self.text[start..end];
+        let (command_start, command_end) = command_at(&self.text, offset);
+        let command = &self.text[command_start..command_end];
+        let (name, option) = if start == command_start {
+            (word, None)
+        } else if word.starts_with('-') {
+            (command.split_whitespace().next()?, Some(word))
+        } else {
+            return None;
+        };
+        let spec = CommandSpec::find(name, self.dialect)?;
+
+        let value = match option {
+            Some(x) => {
+                let option = spec.option(x)?;
+                let mut value = format!("**{name} {}**\n\n{}", option.name, option.help);
+                if let Some(x) = spec.option_versions(option) {
+                    value.push_str(&format!("\n\n{}", introduced(x)));
+                }
+                value
+            }
+            None => {
+                let mut value =
+                    format!("**{name}**\n\n{}\n\n```\n{}\n```", spec.help, spec.usage());
+                match spec.versions {
+                    Some(x) => value.push_str(&format!("\n\n{}", introduced(x))),
+                    None => value.push_str(&format!("\n\n{} extension", spec.dialect())),
+                }
+                value
+            }
+        };
+        Some(Hover {
+            contents: HoverContents::Markup(MarkupContent {
+                kind: MarkupKind::Markdown,
+                value,
+            }),
+            range: Some(Range::new(self.position(start), self.position(end))),
+        })
+    }
+
+    /// Command names at the beginning of command, or option names of the command
+    pub fn completion(&self, position: Position) -> Vec<CompletionItem> {
+        let offset = self.offset(position);
+        let start = word_at(&self.text, offset).map_or(offset, |x| x.0);
+        let prefix = &self.text[start..offset];
+        let (command_start, command_end) = command_at(&self.text, offset);
+
+        if start == command_start {
+            COMMAND_SPECS
+                .iter()
+                .filter(|x| x.is_supported(self.dialect))
+                .flat_map(|x| {
+                    std::iter::once(&x.name)
+                        .chain(x.aliases)
+                        .map(move |y| (*y, x))
+                })
+                .filter(|(x, _)| x.starts_with(prefix))
+                .map(|(x, y)| completion_item(x, y.help, CompletionItemKind::FUNCTION))
+                .collect()
+        } else if prefix.starts_with('-') {
+            let command = &self.text[command_start..command_end];
+            let spec = command
+                .split_whitespace()
+                .next()
+                .and_then(|x| CommandSpec::find(x, self.dialect));
+            spec.iter()
+                .flat_map(|x| x.options.iter())
+                .filter(|x| x.name.starts_with(prefix))
+                .filter(|x| x.dialect.is_none() || x.dialect == Some(self.dialect))
+                .map(|x| completion_item(x.name, x.help, CompletionItemKind::PROPERTY))
+                .collect()
+        } else {
+            vec![]
+        }
+    }
+
+    /// Range of `create_clock` defining the clock referred by `get_clocks` at `position`
+    pub fn definition(&self, position: Position) -> Option<Range> {
+        let offset = self.offset(position);
+        let (start, end) = word_at(&self.text, offset)?;
+        let (command_start, command_end) = command_at(&self.text, offset);
+        let command = &self.text[command_start..command_end];
+        if start == command_start || command.split_whitespace().next() != Some("get_clocks") {
+            return None;
+        }
+        let matcher = Matcher::new(&self.text[start..end]).ok()?;
+
+        let sdc = self.parse().ok()?;
+        let command = sdc
+            .commands
+            .iter()
+            .find(|x| clock_name(x).map(|x| matcher.is_match(&x)).unwrap_or(false))?;
+        Some(self.range(&command.location()))
+    }
+
+    /// Whole document formatted by `Display` of `Sdc`
+    pub fn formatting(&self) -> Option<Vec<TextEdit>> {
+        let sdc = self.parse().ok()?;
+        let range = Range::new(Position::default(), self.position(self.text.len()));
+        Some(vec![TextEdit::new(range, format!("{sdc}"))])
+    }
+
+    fn range(&self, location: &Location) -> Range {
+        let range: std::ops::Range<usize> = location.into();
+        Range::new(self.position(range.start), self.position(range.end))
+    }
+
+    /// Byte offset into LSP position counted by UTF-16 code unit
+    fn position(&self, offset: usize) -> Position {
+        let offset = offset.min(self.text.len());
+        let head = &self.text[..offset];
+        let line = head.matches('\n').count();
+        let column = head[head.rfind('\n').map_or(0, |x| x + 1)..]
+            .encode_utf16()
+            .count();
+        Position::new(line as u32, column as u32)
+    }
+
+    fn offset(&self, position: Position) -> usize {
+        let mut start = 0;
+        for _ in 0..position.line {
+            match self.text[start..].find('\n') {
+                Some(x) => start += x + 1,
+                None => return self.text.len(),
+            }
+        }
+        let mut column = 0;
+        for (i, c) in self.text[start..].char_indices() {
+            if column >= position.character as usize || c == '\n' {
+                return start + i;
+            }
+            column += c.len_utf16();
+        }
+        self.text.len()
+    }
+}
+
+fn parse(text: &str, path: &PathBuf, dialect: Dialect) -> Result<Sdc, ParseError> {
+    if text.ends_with('\n') {
+        Parser::parse_with_dialect(text, path, dialect)
+    } else {
+        Parser::parse_with_dialect(&format!("{text}\n"), path, dialect)
+    }
+}
+
+fn is_word(c: char) -> bool {
+    !c.is_whitespace() && !matches!(c, '[' | ']' | '{' | '}' | '"' | ';')
+}
+
+/// Byte range of the word around `offset`
+fn word_at(text: &str, offset: usize) -> Option<(usize, usize)> {
+    let start = text[..offset]
+        .char_indices()
+        .rev()
+        .find(|(_, c)| !is_word(*c))
+        .map_or(0, |(i, c)| i + c.len_utf8());
+    let end = text[offset..]
+        .char_indices()
+        .find(|(_, c)| !is_word(*c))
+        .map_or(text.len(), |(i, _)| offset + i);
+    (start < end).then_some((start, end))
+}
+
+/// Byte range of the innermost command around `offset`
+///
+/// The command ends at newline without continuation, `;` or unbalanced `]`.
+fn command_at(text: &str, offset: usize) -> (usize, usize) {
+    let bytes = text.as_bytes();
+    let is_end = |i: usize| match bytes[i] {
+        b'\n' => i == 0 || bytes[i - 1] != b'\\',
+        b';' => true,
+        _ => false,
+    };
+
+    let mut depth = 0;
+    let mut start = 0;
+    for i in (0..offset).rev() {
+        match bytes[i] {
+            b']' => depth += 1,
+            b'[' if depth == 0 => {
+                start = i + 1;
+                break;
+            }
+            b'[' => depth -= 1,
+            _ if depth == 0 && is_end(i) => {
+                start = i + 1;
+                break;
+            }
+            _ => (),
+        }
+    }
+
+    let mut depth = 0;
+    let mut end = text.len();
+    for (i, x) in bytes.iter().enumerate().skip(start) {
+        match x {
+            b'[' => depth += 1,
+            b']' if depth == 0 => {
+                end = i;
+                break;
+            }
+            b']' => depth -= 1,
+            _ if depth == 0 && is_end(i) => {
+                end = i;
+                break;
+            }
+            _ => (),
+        }
+    }
+
+    let start = start + text[start..end].len() - text[start..end].trim_start().len();
+    (start, end)
+}
+
+fn introduced(versions: VersionRange) -> String {
+    if versions.to == SdcVersion::SDC2_1 {
+        format!("Introduced in SDC {}", versions.from.version_string())
+    } else {
+        format!(
+            "Introduced in SDC {}, removed after SDC {}",
+            versions.from.version_string(),
+            versions.to.version_string()
+        )
+    }
+}
+
+fn clock_name(command: &Command) -> Option<String> {
+    match command {
+        Command::CreateClock(x) => x
+            .name
+            .as_ref()
+            .map(unquote)
+            .or_else(|| Clock::try_from(x).ok().map(|x| x.name().to_string())),
+        Command::CreateGeneratedClock(x) => x.name.as_ref().map(unquote).or_else(|| {
+            GeneratedClock::try_from(x)
+                .ok()
+                .map(|x| x.name().to_string())
+        }),
+        _ => None,
+    }
+}
+
+fn completion_item(label: &str, detail: &str, kind: CompletionItemKind) -> CompletionItem {
+    CompletionItem {
+        label: label.to_string(),
+        kind: Some(kind),
+        detail: Some(detail.to_string()),
+        ..Default::default()
+    }
+}
+
+fn diagnostic(range: Range, message: String) -> Diagnostic {
+    Diagnostic {
+        range,
+        severity: Some(DiagnosticSeverity::ERROR),
+        source: Some("sdcx".to_string()),
+        message,
+        ..Default::default()
+    }
+}
+
+/// Capabilities provided by [`run`]
+pub fn capabilities() -> ServerCapabilities {
+    ServerCapabilities {
+        text_document_sync: Some(TextDocumentSyncCapability::Kind(TextDocumentSyncKind::FULL)),
+        hover_provider: Some(HoverProviderCapability::Simple(true)),
+        completion_provider: Some(CompletionOptions {
+            trigger_characters: Some(vec!["-".to_string()]),
+            ..Default::default()
+        }),
+        definition_provider: Some(OneOf::Left(true)),
+        document_formatting_provider: Some(OneOf::Left(true)),
+        ..Default::default()
+    }
+}
+
+/// Language server handling messages of `connection` until shutdown
+///
+/// The dialect of all documents can be given by `initializationOptions` like `{"dialect": "quartus"}`.
+/// Otherwise it is implied by the file extension.
+pub fn run(connection: &Connection) -> anyhow::Result<()> {
+    let params = connection.initialize(serde_json::to_value(capabilities())?)?;
+    let dialect = initialization_dialect(&params)?;
+
+    let mut documents: HashMap<String, Document> = HashMap::new();
+    for message in &connection.receiver {
+        match message {
+            Message::Request(x) => {
+                if connection.handle_shutdown(&x)? {
+                    break;
+                }
+                let response = request(&documents, x);
+                connection.sender.send(Message::Response(response))?;
+            }
+            Message::Notification(x) => {
+                if let Some(x) = notification(&mut documents, dialect, x)? {
+                    connection.sender.send(Message::Notification(x))?;
+                }
+            }
+            Message::Response(_) => (),
+        }
+    }
+    Ok(())
+}
+
+fn request(documents: &HashMap<String, Document>, request: Request) -> Response {
+    let document = |x: &Uri| documents.get(x.as_str());
+    respond::<HoverRequest>(&request, |x| {
+        let x = x.text_document_position_params;
+        document(&x.text_document.uri)?.hover(x.position)
+    })
+    .or_else(|| {
+        respond::<Completion>(&request, |x| {
+            let x = x.text_document_position;
+            let items = document(&x.text_document.uri)?.completion(x.position);
+            Some(CompletionResponse::Array(items))
+        })
+    })
+    .or_else(|| {
+        respond::<GotoDefinition>(&request, |x| {
+            let x = x.text_document_position_params;
+            let range = document(&x.text_document.uri)?.definition(x.position)?;
+            Some(GotoDefinitionResponse::Scalar(lsp_types::Location::new(
+                x.text_document.uri,
+                range,
+            )))
+        })
+    })
+    .or_else(|| respond::<Formatting>(&request, |x| document(&x.text_document.uri)?.formatting()))
+    .unwrap_or_else(|| {
+        Response::new_err(
+            request.id.clone(),
+            ErrorCode::MethodNotFound as i32,
+            format!("Unsupported request: {}", request.method),
+        )
+    })
+}
+
+fn respond<R: lsp_types::request::Request>(
+    request: &Request,
+    f: impl FnOnce(R::Params) -> R::Result,
+) -> Option<Response> {
+    if request.method != R::METHOD {
+        return None;
+    }
+    let response = match serde_json::from_value(request.params.clone()) {
+        Ok(x) => Response::new_ok(request.id.clone(), f(x)),
+        Err(x) => Response::new_err(
+            request.id.clone(),
+            ErrorCode::InvalidParams as i32,
+            x.to_string(),
+        ),
+    };
+    Some(response)
+}
+
+/// Update documents, and return diagnostics to be published
+fn notification(
+    documents: &mut HashMap<String, Document>,
+    dialect: Option<Dialect>,
+    notification: Notification,
+) -> anyhow::Result<Option<Notification>> {
+    let document = |text: &str, uri: &Uri| {
+        let path = path(uri);
+        let dialect = dialect.unwrap_or(Dialect::from_path(&path));
+        Document::with_dialect(text, path, dialect)
+    };
+    let (uri, diagnostics) = match notification.method.as_str() {
+        DidOpenTextDocument::METHOD => {
+            let params: <DidOpenTextDocument as LspNotification>::Params =
+                serde_json::from_value(notification.params)?;
+            let uri = params.text_document.uri;
+            let document = document(&params.text_document.text, &uri);
+            let diagnostics = document.diagnostics();
+            documents.insert(uri.as_str().to_string(), document);
+            (uri, diagnostics)
+        }
+        DidChangeTextDocument::METHOD => {
+            let params: <DidChangeTextDocument as LspNotification>::Params =
+                serde_json::from_value(notification.params)?;
+            let uri = params.text_document.uri;
+            let Some(change) = params.content_changes.last() else {
+                return Ok(None);
+            };
+            let document = document(&change.text, &uri);
+            let diagnostics = document.diagnostics();
+            documents.insert(uri.as_str().to_string(), document);
+            (uri, diagnostics)
+        }
+        DidCloseTextDocument::METHOD => {
+            let params: <DidCloseTextDocument as LspNotification>::Params =
+                serde_json::from_value(notification.params)?;
+            let uri = params.text_document.uri;
+            documents.remove(uri.as_str());
+            (uri, vec![])
+        }
+        _ => return Ok(None),
+    };
+    let params = PublishDiagnosticsParams::new(uri, diagnostics, None);
+    Ok(Some(Notification::new(
+        PublishDiagnostics::METHOD.to_string(),
+        params,
+    )))
+}
+
+/// Dialect given by `initializationOptions` of initialize params
+fn initialization_dialect(params: &serde_json::Value) -> anyhow::Result<Option<Dialect>> {
+    let dialect = params
+        .get("initializationOptions")
+        .and_then(|x| x.get("dialect"))
+        .and_then(|x| x.as_str());
+    match dialect {
+        Some(x) => match Dialect::try_from(x) {
+            Ok(x) => Ok(Some(x)),
+            Err(_) => anyhow::bail!("Unknown dialect: {x}"),
+        },
+        None => Ok(None),
+    }
+}
+
+fn path(uri: &Uri) -> PathBuf {
+    PathBuf::from(uri.path().as_str())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn document(text: &str) -> Document {
+        Document::new(text, PathBuf::from("test.sdc"))
+    }
+
+    fn hover(document: &Document, line: u32, character: u32) -> String {
+        match document.hover(Position::new(line, character)) {
+            Some(Hover {
+                contents: HoverContents::Markup(x),
+                ..
+            }) => x.value,
+            _ => String::new(),
+        }
+    }
+
+    fn completion(document: &Document, line: u32, character: u32) -> Vec<String> {
+        document
+            .completion(Position::new(line, character))
+            .into_iter()
+            .map(|x| x.label)
+            .collect()
+    }
+
+    #[test]
+    fn diagnostics() {
+        let doc = document("create_clock -period 10 -period 20 [get_ports clk]\n");
+        let diagnostics = doc.diagnostics();
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].message, "Duplicated arguments");
+        assert_eq!(
+            diagnostics[0].range,
+            Range::new(Position::new(0, 24), Position::new(0, 31))
+        );
+
+        let doc = document("set sdc_version 1.3\ncreate_clock -period 10 -add [get_ports clk]");
+        let diagnostics = doc.diagnostics();
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(
+            diagnostics[0].message,
+            "Unsupported argument \"-add\" at SDC 1.3"
+        );
+        assert_eq!(diagnostics[0].range.start, Position::new(1, 0));
+
+        assert!(document("create_clock -period 10 [get_ports clk]")
+            .diagnostics()
+            .is_empty());
+    }
+
+    #[test]
+    fn dialect() {
+        let params = serde_json::json!({"initializationOptions": {"dialect": "quartus"}});
+        let dialect = initialization_dialect(&params).unwrap();
+        assert_eq!(dialect, Some(Dialect::Quartus));
+        assert_eq!(
+            initialization_dialect(&serde_json::json!({})).unwrap(),
+            None
+        );
+        let params = serde_json::json!({"initializationOptions": {"dialect": "foo"}});
+        assert!(initialization_dialect(&params).is_err());
+
+        let text = "derive_pll_clocks\n";
+        assert!(!document(text).diagnostics().is_empty());
+        let doc = Document::with_dialect(text, PathBuf::from("test.sdc"), Dialect::Quartus);
+        assert!(doc.diagnostics().is_empty());
+    }
+
+    #[test]
+    fn hover_and_completion() {
+        let doc = document(
+            "create_clock -period 10 -add [get_ports clk]\nset_input_delay 1 -clock clk [all_inputs -ed]\nset_",
+        );
+        assert!(hover(&doc, 0, 3).starts_with("**create_clock**\n\nCreate a clock"));
+        assert!(hover(&doc, 0, 3).contains("create_clock -period <number> [-name <string>]"));
+        assert!(hover(&doc, 0, 3).ends_with("Introduced in SDC 1.1"));
+        assert_eq!(
+            hover(&doc, 0, 26),
+            "**create_clock -add**\n\nAdd to existing definitions instead of overwriting\n\nIntroduced in SDC 1.4"
+        );
+        assert!(hover(&doc, 0, 33).starts_with("**get_ports**"));
+        assert_eq!(hover(&doc, 0, 21), "");
+        assert_eq!(
+            hover(&doc, 1, 20),
+            "**set_input_delay -clock**\n\nRelative clock\n\nIntroduced in SDC 1.1"
+        );
+
+        assert!(completion(&doc, 2, 4).contains(&"set_input_delay".to_string()));
+        assert!(!completion(&doc, 2, 4).contains(&"create_clock".to_string()));
+        assert_eq!(completion(&doc, 1, 43), vec!["-edge_triggered"]);
+        assert_eq!(completion(&doc, 1, 17), Vec::<String>::new());
+    }
+
+    #[test]
+    fn definition() {
+        let doc = document(
+            "create_clock -period 10 [get_ports clk]\ncreate_clock -period 5 -name vclk\nset_input_delay 1 -clock [get_clocks vclk] [get_ports din]\nset_false_path -from [get_clocks {clk}]\n",
+        );
+        assert_eq!(
+            doc.definition(Position::new(2, 40)),
+            Some(Range::new(Position::new(1, 0), Position::new(1, 33)))
+        );
+        assert_eq!(
+            doc.definition(Position::new(3, 35)),
+            Some(Range::new(Position::new(0, 0), Position::new(0, 39)))
+        );
+        assert_eq!(doc.definition(Position::new(2, 2)), None);
+    }
+
+    #[test]
+    fn formatting() {
+        let doc = document("create_clock   -period 10 [get_ports   clk]");
+        let edits = doc.formatting().unwrap();
+        assert_eq!(
+            edits[0].range,
+            Range::new(Position::new(0, 0), Position::new(0, 43))
+        );
+        assert_eq!(
+            edits[0].new_text,
+            "create_clock -period 10 [get_ports clk]\n"
+        );
+        assert!(document("create_clock -period").formatting().is_none());
+    }
+}
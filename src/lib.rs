@@ -1,8 +1,19 @@
 pub mod constraints;
+pub mod convert;
+pub mod design;
 pub mod errors;
 pub mod file_db;
+#[cfg(feature = "lsp")]
+pub mod language_server;
+pub mod lint;
+pub mod matcher;
 pub(crate) mod parser;
+pub mod promote;
+pub mod query;
+pub mod rename;
 pub mod sdc;
+pub mod stats;
+pub mod visit;
 pub use parser::Parser;
 #[cfg(test)]
 mod tests;
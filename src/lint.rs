@@ -0,0 +1,243 @@
+pub mod clock;
+pub mod coverage;
+
+use crate::errors::LintError;
+use crate::lint::coverage::Port;
+use crate::sdc::Sdc;
+use std::collections::BTreeSet;
+use std::fmt;
+
+/// Lint rule
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LintRule {
+    /// Clock period is zero or negative
+    ClockPeriod,
+    /// Waveform has odd edge count
+    WaveformEdgeCount,
+    /// Waveform has edges outside of `[0, 2 * period)`
+    WaveformRange,
+    /// Waveform is not increasing
+    WaveformOrder,
+    /// Duty cycle is outside of the expected range
+    DutyCycle,
+    /// Clocks are defined on the same source without `-add`
+    DuplicatedClockSource,
+    /// `-source` of generated clock is not the source of any master clock
+    GeneratedClockSource,
+    /// `set_propagated_clock` is applied to virtual clock
+    PropagatedVirtualClock,
+    /// `get_ports` pattern matches no port of the port list
+    UnknownPort,
+}
+
+impl LintRule {
+    pub fn all() -> &'static [LintRule] {
+        &[
+            LintRule::ClockPeriod,
+            LintRule::WaveformEdgeCount,
+            LintRule::WaveformRange,
+            LintRule::WaveformOrder,
+            LintRule::DutyCycle,
+            LintRule::DuplicatedClockSource,
+            LintRule::GeneratedClockSource,
+            LintRule::PropagatedVirtualClock,
+            LintRule::UnknownPort,
+        ]
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            LintRule::ClockPeriod => "clock_period",
+            LintRule::WaveformEdgeCount => "waveform_edge_count",
+            LintRule::WaveformRange => "waveform_range",
+            LintRule::WaveformOrder => "waveform_order",
+            LintRule::DutyCycle => "duty_cycle",
+            LintRule::DuplicatedClockSource => "duplicated_clock_source",
+            LintRule::GeneratedClockSource => "generated_clock_source",
+            LintRule::PropagatedVirtualClock => "propagated_virtual_clock",
+            LintRule::UnknownPort => "unknown_port",
+        }
+    }
+}
+
+impl fmt::Display for LintRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.name().fmt(f)
+    }
+}
+
+impl TryFrom<&str> for LintRule {
+    type Error = ();
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        LintRule::all()
+            .iter()
+            .find(|x| x.name() == value)
+            .copied()
+            .ok_or(())
+    }
+}
+
+/// Linter
+#[derive(Clone, Debug)]
+pub struct Linter {
+    rules: BTreeSet<LintRule>,
+    duty_cycle_range: (f32, f32),
+    ports: Option<Vec<Port>>,
+}
+
+impl Default for Linter {
+    fn default() -> Self {
+        Self {
+            rules: LintRule::all().iter().copied().collect(),
+            duty_cycle_range: (0.2, 0.8),
+            ports: None,
+        }
+    }
+}
+
+impl Linter {
+    /// Create a new linter with all rules enabled.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn enable(&mut self, rule: LintRule) -> &mut Self {
+        self.rules.insert(rule);
+        self
+    }
+
+    pub fn disable(&mut self, rule: LintRule) -> &mut Self {
+        self.rules.remove(&rule);
+        self
+    }
+
+    pub fn is_enabled(&self, rule: LintRule) -> bool {
+        self.rules.contains(&rule)
+    }
+
+    /// Set the acceptable duty cycle range as ratio (e.g. `(0.2, 0.8)`).
+    pub fn duty_cycle_range(&mut self, min: f32, max: f32) -> &mut Self {
+        self.duty_cycle_range = (min, max);
+        self
+    }
+
+    /// Set the primary ports of the design to check port references.
+    pub fn ports(&mut self, ports: Vec<Port>) -> &mut Self {
+        self.ports = Some(ports);
+        self
+    }
+
+    pub fn lint(&self, sdc: &Sdc) -> Vec<LintError> {
+        let mut ret = vec![];
+        clock::lint(self, sdc, &mut ret);
+        coverage::lint(self, sdc, &mut ret);
+        ret.retain(|x| self.is_enabled(x.rule()));
+        ret
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Parser;
+
+    fn lint(code: &str) -> Vec<LintRule> {
+        let sdc = Parser::parse(code, &"").unwrap();
+        Linter::new().lint(&sdc).iter().map(|x| x.rule()).collect()
+    }
+
+    #[test]
+    fn rule_name() {
+        for rule in LintRule::all() {
+            assert_eq!(LintRule::try_from(rule.name()), Ok(*rule));
+        }
+        assert!(LintRule::try_from("unknown").is_err());
+    }
+
+    #[test]
+    fn clean() {
+        let code = r##"
+create_clock -period 10 -name CLK [get_ports i_clk]
+create_clock -period 10 -name VCLK
+create_generated_clock -name GCLK -source [get_ports i_clk] -divide_by 2 [get_pins div/Q]
+set_propagated_clock [get_clocks CLK]
+        "##;
+        assert!(lint(code).is_empty());
+    }
+
+    #[test]
+    fn waveform() {
+        let code = "create_clock -period 10 -waveform {0 5 7} [get_ports a]\n";
+        assert_eq!(lint(code), vec![LintRule::WaveformEdgeCount]);
+
+        let code = "create_clock -period 10 -waveform {0 25} [get_ports a]\n";
+        assert_eq!(
+            lint(code),
+            vec![LintRule::WaveformRange, LintRule::DutyCycle]
+        );
+
+        let code = "create_clock -period 10 -waveform {5 0} [get_ports a]\n";
+        assert_eq!(
+            lint(code),
+            vec![LintRule::WaveformOrder, LintRule::DutyCycle]
+        );
+
+        let code = "create_clock -period 10 -waveform {0 1} [get_ports a]\n";
+        assert_eq!(lint(code), vec![LintRule::DutyCycle]);
+
+        let code = "create_clock -period 0 [get_ports a]\n";
+        assert_eq!(lint(code), vec![LintRule::ClockPeriod]);
+    }
+
+    #[test]
+    fn clock_source() {
+        let code = r##"
+create_clock -period 10 -name A [get_ports a]
+create_clock -period 20 -name B [get_ports a]
+create_clock -period 30 -name C -add [get_ports a]
+        "##;
+        assert_eq!(lint(code), vec![LintRule::DuplicatedClockSource]);
+
+        let code = r##"
+create_clock -period 10 -name A [get_ports a]
+create_generated_clock -name G -source [get_ports b] -divide_by 2 [get_pins div/Q]
+        "##;
+        assert_eq!(lint(code), vec![LintRule::GeneratedClockSource]);
+
+        let code = r##"
+create_clock -period 10 -name A [get_ports a]
+create_clock -period 10 -name B [get_ports b]
+create_generated_clock -name G -source [get_ports b] -master_clock A -divide_by 2 [get_pins div/Q]
+        "##;
+        assert_eq!(lint(code), vec![LintRule::GeneratedClockSource]);
+
+        let code = r##"
+create_clock -period 10 -name A [get_ports { a }]
+create_generated_clock -name G -source [get_ports a] -divide_by 2 [get_pins div/Q]
+        "##;
+        assert!(lint(code).is_empty());
+    }
+
+    #[test]
+    fn propagated_virtual_clock() {
+        let code = r##"
+create_clock -period 10 -name VCLK
+set_propagated_clock [get_clocks VCLK]
+        "##;
+        assert_eq!(lint(code), vec![LintRule::PropagatedVirtualClock]);
+    }
+
+    #[test]
+    fn disable() {
+        let code = "create_clock -period 10 -waveform {0 1} [get_ports a]\n";
+        let sdc = Parser::parse(code, &"").unwrap();
+        let mut linter = Linter::new();
+        linter.disable(LintRule::DutyCycle);
+        assert!(linter.lint(&sdc).is_empty());
+        linter
+            .duty_cycle_range(0.05, 0.95)
+            .enable(LintRule::DutyCycle);
+        assert!(linter.lint(&sdc).is_empty());
+    }
+}
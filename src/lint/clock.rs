@@ -0,0 +1,171 @@
+use crate::constraints::{clock_names, Clock, GeneratedClock, Object};
+use crate::errors::LintError;
+use crate::file_db::Location;
+use crate::lint::Linter;
+use crate::sdc::util::CommandExt;
+use crate::sdc::{Command, CommandKind, Sdc};
+
+pub(crate) fn lint(linter: &Linter, sdc: &Sdc, ret: &mut Vec<LintError>) {
+    let mut clocks = vec![];
+    for command in sdc.extract(CommandKind::CreateClock) {
+        if let Command::CreateClock(x) = command {
+            // Clocks which can't be interpreted are reported by other layers.
+            if let Ok(clock) = Clock::try_from(x) {
+                clocks.push((clock, x.location()));
+            }
+        }
+    }
+
+    let mut generated_clocks = vec![];
+    for command in sdc.extract(CommandKind::CreateGeneratedClock) {
+        if let Command::CreateGeneratedClock(x) = command {
+            if let Ok(clock) = GeneratedClock::try_from(x) {
+                generated_clocks.push((clock, x.location()));
+            }
+        }
+    }
+
+    for (clock, location) in &clocks {
+        check_waveform(linter, clock, location, ret);
+    }
+
+    for (clock, location) in &generated_clocks {
+        if let Some(duty_cycle) = clock.duty_cycle() {
+            check_duty_cycle(linter, clock.name(), duty_cycle / 100.0, location, ret);
+        }
+    }
+
+    check_clock_source(&clocks, &generated_clocks, ret);
+    check_generated_clock_source(&clocks, &generated_clocks, ret);
+    check_propagated_clock(sdc, &clocks, ret);
+}
+
+fn check_waveform(linter: &Linter, clock: &Clock, location: &Location, ret: &mut Vec<LintError>) {
+    let name = clock.name().to_string();
+    let period = *clock.period();
+    let waveform = clock.waveform();
+
+    if period <= 0.0 {
+        ret.push(LintError::ClockPeriod(name, location.clone()));
+        return;
+    }
+
+    if waveform.is_empty() {
+        return;
+    }
+
+    if !waveform.len().is_multiple_of(2) {
+        ret.push(LintError::WaveformEdgeCount(name.clone(), location.clone()));
+    }
+
+    if waveform.iter().any(|x| *x < 0.0 || *x >= 2.0 * period) {
+        ret.push(LintError::WaveformRange(name.clone(), location.clone()));
+    }
+
+    if waveform.windows(2).any(|x| x[1] <= x[0]) {
+        ret.push(LintError::WaveformOrder(name.clone(), location.clone()));
+    }
+
+    if waveform.len() >= 2 {
+        let duty_cycle = (waveform[1] - waveform[0]) / period;
+        check_duty_cycle(linter, &name, duty_cycle, location, ret);
+    }
+}
+
+fn check_duty_cycle(
+    linter: &Linter,
+    name: &str,
+    duty_cycle: f32,
+    location: &Location,
+    ret: &mut Vec<LintError>,
+) {
+    let (min, max) = linter.duty_cycle_range;
+    if duty_cycle < min || max < duty_cycle {
+        ret.push(LintError::DutyCycle(
+            name.to_string(),
+            duty_cycle,
+            location.clone(),
+        ));
+    }
+}
+
+fn check_clock_source(
+    clocks: &[(Clock, Location)],
+    generated_clocks: &[(GeneratedClock, Location)],
+    ret: &mut Vec<LintError>,
+) {
+    let mut sources = vec![];
+    for (clock, location) in clocks {
+        if let Some(source) = clock.source() {
+            sources.push((clock.name(), source, clock.add(), location));
+        }
+    }
+    for (clock, location) in generated_clocks {
+        sources.push((clock.name(), clock.source_objects(), clock.add(), location));
+    }
+    sources.sort_by(|a, b| a.3.cmp(b.3));
+
+    let mut defined: Vec<(&Object, &Location)> = vec![];
+    for (name, source, add, location) in sources {
+        if let Some((_, previous)) = defined.iter().find(|(x, _)| *x == source) {
+            if !add {
+                ret.push(LintError::DuplicatedClockSource(
+                    name.to_string(),
+                    source.to_string(),
+                    location.clone(),
+                    (*previous).clone(),
+                ));
+            }
+        } else {
+            defined.push((source, location));
+        }
+    }
+}
+
+fn check_generated_clock_source(
+    clocks: &[(Clock, Location)],
+    generated_clocks: &[(GeneratedClock, Location)],
+    ret: &mut Vec<LintError>,
+) {
+    let mut masters = vec![];
+    for (clock, _) in clocks {
+        if let Some(source) = clock.source() {
+            masters.push((clock.name(), source));
+        }
+    }
+    for (clock, _) in generated_clocks {
+        masters.push((clock.name(), clock.source_objects()));
+    }
+
+    for (clock, location) in generated_clocks {
+        let found = masters.iter().any(|(name, source)| {
+            let name_match = match clock.master_clock() {
+                Some(x) => x == name,
+                None => true,
+            };
+            name_match && *source == clock.source()
+        });
+        if !found {
+            ret.push(LintError::GeneratedClockSource(
+                clock.name().to_string(),
+                clock.source().to_string(),
+                location.clone(),
+            ));
+        }
+    }
+}
+
+fn check_propagated_clock(sdc: &Sdc, clocks: &[(Clock, Location)], ret: &mut Vec<LintError>) {
+    for command in sdc.extract(CommandKind::SetPropagatedClock) {
+        if let Command::SetPropagatedClock(x) = command {
+            for name in clock_names(&x.object_list) {
+                let is_virtual = clocks
+                    .iter()
+                    .any(|(clock, _)| clock.name() == name && clock.is_virtual());
+                if is_virtual {
+                    ret.push(LintError::PropagatedVirtualClock(name, x.location()));
+                }
+            }
+        }
+    }
+}
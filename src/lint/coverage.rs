@@ -0,0 +1,398 @@
+use crate::constraints::{list_items, Object};
+use crate::design::Design;
+use crate::errors::LintError;
+use crate::file_db::Location;
+use crate::lint::Linter;
+use crate::matcher::{Matcher, MatcherBuilder};
+use crate::sdc::util::CommandExt;
+use crate::sdc::{Argument, Command, CommandKind, GetPorts, Sdc};
+use std::fmt;
+
+/// Port direction
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Direction {
+    Input,
+    Output,
+    Inout,
+}
+
+impl fmt::Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Direction::Input => "input".fmt(f),
+            Direction::Output => "output".fmt(f),
+            Direction::Inout => "inout".fmt(f),
+        }
+    }
+}
+
+/// Primary port of the design
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Port {
+    pub name: String,
+    pub direction: Option<Direction>,
+}
+
+impl Port {
+    fn is_input(&self) -> bool {
+        matches!(
+            self.direction,
+            None | Some(Direction::Input) | Some(Direction::Inout)
+        )
+    }
+
+    fn is_output(&self) -> bool {
+        matches!(
+            self.direction,
+            None | Some(Direction::Output) | Some(Direction::Inout)
+        )
+    }
+}
+
+/// Parse a plain text port list.
+///
+/// Each line has a port name optionally preceded by its direction,
+/// like `input clk` or `output data[7:0]`.
+/// Bus ranges are expanded to bits, and `#` starts a comment.
+pub fn parse_port_list(text: &str) -> Vec<Port> {
+    let mut ret = vec![];
+    for line in text.lines() {
+        let line = match line.find('#') {
+            Some(x) => &line[..x],
+            None => line,
+        };
+        let mut words = line.split_whitespace();
+        let (direction, name) = match (words.next(), words.next()) {
+            (Some("input"), Some(x)) => (Some(Direction::Input), x),
+            (Some("output"), Some(x)) => (Some(Direction::Output), x),
+            (Some("inout"), Some(x)) => (Some(Direction::Inout), x),
+            (Some(x), _) => (None, x),
+            _ => continue,
+        };
+        for name in expand_bus(name) {
+            ret.push(Port { name, direction });
+        }
+    }
+    ret
+}
+
+/// Primary ports of the top module of a netlist
+pub fn netlist_ports(design: &Design) -> Vec<Port> {
+    design
+        .ports
+        .iter()
+        .map(|x| Port {
+            name: x.name.clone(),
+            direction: x.direction,
+        })
+        .collect()
+}
+
+fn expand_bus(name: &str) -> Vec<String> {
+    if let Some(x) = name.strip_suffix(']') {
+        if let Some((base, range)) = x.rsplit_once('[') {
+            if let Some((msb, lsb)) = range.split_once(':') {
+                if let (Ok(msb), Ok(lsb)) = (msb.parse::<i32>(), lsb.parse::<i32>()) {
+                    let bits: Vec<_> = if msb >= lsb {
+                        (lsb..=msb).rev().collect()
+                    } else {
+                        (msb..=lsb).collect()
+                    };
+                    return bits.iter().map(|x| format!("{base}[{x}]")).collect();
+                }
+            }
+        }
+    }
+    vec![name.to_string()]
+}
+
+/// IO constraints applied to a port
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PortCoverage {
+    pub port: Port,
+    /// Port is a source of `create_clock`
+    pub clock: bool,
+    pub input_delay: bool,
+    pub output_delay: bool,
+    pub drive: bool,
+    pub load: bool,
+}
+
+impl PortCoverage {
+    /// Names of the missing constraint kinds, considering the port direction.
+    pub fn missing(&self) -> Vec<&'static str> {
+        let mut ret = vec![];
+        let delay = match self.port.direction {
+            Some(Direction::Input) => self.input_delay,
+            Some(Direction::Output) => self.output_delay,
+            _ => self.input_delay || self.output_delay,
+        };
+        if !delay && !self.clock {
+            ret.push("delay");
+        }
+        if self.port.is_input() && !self.drive {
+            ret.push("drive");
+        }
+        if self.port.is_output() && !self.load {
+            ret.push("load");
+        }
+        ret
+    }
+}
+
+/// IO constraint coverage report
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct IoCoverage {
+    pub ports: Vec<PortCoverage>,
+    /// `get_ports` patterns and bare port names which match no port
+    pub unknown_ports: Vec<(String, Location)>,
+}
+
+impl IoCoverage {
+    pub fn new(sdc: &Sdc, ports: &[Port]) -> Self {
+        let mut coverage: Vec<_> = ports
+            .iter()
+            .map(|x| PortCoverage {
+                port: x.clone(),
+                clock: false,
+                input_delay: false,
+                output_delay: false,
+                drive: false,
+                load: false,
+            })
+            .collect();
+
+        let mut unknown_ports = vec![];
+        for command in &sdc.commands {
+            let (arg, field): (_, fn(&mut PortCoverage) -> &mut bool) = match command {
+                Command::CreateClock(x) => match &x.source_objects {
+                    Some(arg) => (arg, |x| &mut x.clock),
+                    None => continue,
+                },
+                Command::SetInputDelay(x) => (&x.port_pin_list, |x| &mut x.input_delay),
+                Command::SetOutputDelay(x) => (&x.port_pin_list, |x| &mut x.output_delay),
+                Command::SetDrivingCell(x) => (&x.port_list, |x| &mut x.drive),
+                Command::SetDrive(x) => (&x.port_list, |x| &mut x.drive),
+                Command::SetInputTransition(x) => (&x.port_list, |x| &mut x.drive),
+                Command::SetLoad(x) => (&x.objects, |x| &mut x.load),
+                _ => continue,
+            };
+            for object in resolve_ports(arg, ports) {
+                if let Object::Port(name) = object {
+                    for x in coverage.iter_mut().filter(|x| x.port.name == name) {
+                        *field(x) = true;
+                    }
+                }
+            }
+            for name in bare_port_names(arg) {
+                if !ports.iter().any(|p| p.name == name) {
+                    unknown_ports.push((name, command.location()));
+                }
+            }
+        }
+
+        for command in sdc.extract(CommandKind::GetPorts) {
+            if let Command::GetPorts(x) = command {
+                for (pattern, matcher) in port_matchers(x) {
+                    let found = match matcher {
+                        Some(m) => ports.iter().any(|p| m.is_match(&p.name)),
+                        None => false,
+                    };
+                    if !found {
+                        unknown_ports.push((pattern, x.location()));
+                    }
+                }
+            }
+        }
+
+        unknown_ports.sort_by(|x, y| x.1.cmp(&y.1));
+
+        Self {
+            ports: coverage,
+            unknown_ports,
+        }
+    }
+
+    /// Ports which lack any expected constraint
+    pub fn uncovered(&self) -> impl Iterator<Item = &PortCoverage> {
+        self.ports.iter().filter(|x| !x.missing().is_empty())
+    }
+}
+
+impl fmt::Display for IoCoverage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for x in self.uncovered() {
+            let direction = x
+                .port
+                .direction
+                .map(|x| x.to_string())
+                .unwrap_or("port".to_string());
+            writeln!(
+                f,
+                "{direction} {}: missing {}",
+                x.port.name,
+                x.missing().join(", ")
+            )?;
+        }
+        let total = self.ports.len();
+        let covered = total - self.uncovered().count();
+        writeln!(f, "{covered}/{total} ports are fully constrained")
+    }
+}
+
+/// Resolve port objects referenced by `arg`.
+///
+/// `[get_ports ...]`, `[all_inputs]`, `[all_outputs]`, `[list ...]` of them and bare port names are resolved.
+pub(crate) fn resolve_ports(arg: &Argument, ports: &[Port]) -> Vec<Object> {
+    let matched: Vec<_> = match arg {
+        Argument::CommandSubstitution(command, _) => match **command {
+            Command::GetPorts(ref x) => {
+                let matchers: Vec<_> = port_matchers(x).into_iter().flat_map(|x| x.1).collect();
+                ports
+                    .iter()
+                    .filter(|p| matchers.iter().any(|m| m.is_match(&p.name)))
+                    .collect()
+            }
+            Command::AllInputs(_) => ports.iter().filter(|p| p.is_input()).collect(),
+            Command::AllOutputs(_) => ports.iter().filter(|p| p.is_output()).collect(),
+            Command::List(ref x) => {
+                return x
+                    .args
+                    .iter()
+                    .flat_map(|x| resolve_ports(x, ports))
+                    .collect();
+            }
+            _ => vec![],
+        },
+        _ => {
+            let matchers: Vec<_> = list_items(arg)
+                .iter()
+                .flat_map(|x| Matcher::new(x))
+                .collect();
+            ports
+                .iter()
+                .filter(|p| matchers.iter().any(|m| m.is_match(&p.name)))
+                .collect()
+        }
+    };
+    matched
+        .into_iter()
+        .map(|x| Object::Port(x.name.clone()))
+        .collect()
+}
+
+/// Bare names in `arg` and its `[list ...]` which are expected to be ports
+///
+/// Names including the hierarchy separator `/` are pins and not included.
+fn bare_port_names(arg: &Argument) -> Vec<String> {
+    match arg {
+        Argument::CommandSubstitution(command, _) => match **command {
+            Command::List(ref x) => x.args.iter().flat_map(bare_port_names).collect(),
+            _ => vec![],
+        },
+        _ => list_items(arg)
+            .into_iter()
+            .filter(|x| !x.contains('/') && !x.contains(['*', '?']))
+            .collect(),
+    }
+}
+
+/// Matchers of `get_ports` patterns, `None` if the pattern is invalid
+fn port_matchers(query: &GetPorts) -> Vec<(String, Option<Matcher>)> {
+    let mut builder = MatcherBuilder::new();
+    builder.regexp(query.regexp).nocase(query.nocase);
+    let patterns = query.patterns.as_ref().map(list_items).unwrap_or_default();
+    patterns
+        .into_iter()
+        .map(|x| {
+            let matcher = builder.build(&x).ok();
+            (x, matcher)
+        })
+        .collect()
+}
+
+pub(crate) fn lint(linter: &Linter, sdc: &Sdc, ret: &mut Vec<LintError>) {
+    if let Some(ports) = &linter.ports {
+        let coverage = IoCoverage::new(sdc, ports);
+        for (name, location) in coverage.unknown_ports {
+            ret.push(LintError::UnknownPort(name, location));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Parser;
+
+    #[test]
+    fn port_list() {
+        let ports = parse_port_list("# comment\ninput clk\noutput data[1:0]\nrst\n");
+        assert_eq!(ports.len(), 4);
+        assert_eq!(ports[0].direction, Some(Direction::Input));
+        assert_eq!(ports[1].name, "data[1]");
+        assert_eq!(ports[2].name, "data[0]");
+        assert_eq!(ports[2].direction, Some(Direction::Output));
+        assert_eq!(ports[3].direction, None);
+    }
+
+    #[test]
+    fn netlist() {
+        let design = Design::from_verilog(
+            "module top (input clk, input [1:0] din, output dout);\nendmodule\n",
+            "top",
+        )
+        .unwrap();
+        let ports = netlist_ports(&design);
+        let names: Vec<_> = ports.iter().map(|x| x.name.as_str()).collect();
+        assert_eq!(names, vec!["clk", "din[1]", "din[0]", "dout"]);
+        assert_eq!(ports[3].direction, Some(Direction::Output));
+    }
+
+    #[test]
+    fn coverage() {
+        let ports = parse_port_list("input clk\ninput din\ninput en\noutput dout[1:0]\n");
+        let code = r##"
+create_clock -period 10 [get_ports clk]
+set_input_delay 1 -clock clk [get_ports din]
+set_driving_cell -lib_cell BUF [all_inputs]
+set_output_delay 1 -clock clk [get_ports dout*]
+set_load 0.1 [get_ports {dout[0]}]
+set_false_path -from [get_ports rst_n]
+        "##;
+        let sdc = Parser::parse(code, &"").unwrap();
+        let coverage = IoCoverage::new(&sdc, &ports);
+
+        let uncovered: Vec<_> = coverage
+            .uncovered()
+            .map(|x| (x.port.name.as_str(), x.missing()))
+            .collect();
+        assert_eq!(
+            uncovered,
+            vec![("en", vec!["delay"]), ("dout[1]", vec!["load"]),]
+        );
+
+        let unknown: Vec<_> = coverage.unknown_ports.iter().map(|x| &x.0).collect();
+        assert_eq!(unknown, vec!["rst_n"]);
+    }
+
+    #[test]
+    fn nested_and_bare() {
+        let ports = parse_port_list("input clk\ninput a\ninput b\ninput EN\noutput dout\n");
+        let code = r##"
+create_clock -period 10 [get_ports clk]
+set_input_delay 1 -clock clk [list [get_ports a] [get_ports b]]
+set_input_delay 1 -clock clk [get_ports -nocase en]
+set_driving_cell -lib_cell BUF [all_inputs]
+set_output_delay 1 -clock clk dout
+set_load 0.1 {dout}
+set_input_transition 0.2 [list a [get_ports b] c]
+set_load 0.1 u_core/dout
+        "##;
+        let sdc = Parser::parse(code, &"").unwrap();
+        let coverage = IoCoverage::new(&sdc, &ports);
+        assert_eq!(coverage.uncovered().count(), 0);
+        let unknown: Vec<_> = coverage.unknown_ports.iter().map(|x| &x.0).collect();
+        assert_eq!(unknown, vec!["c"]);
+    }
+}
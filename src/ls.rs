@@ -0,0 +1,10 @@
+use anyhow::Result;
+use lsp_server::Connection;
+
+fn main() -> Result<()> {
+    let (connection, io_threads) = Connection::stdio();
+    sdcx::language_server::run(&connection)?;
+    drop(connection);
+    io_threads.join()?;
+    Ok(())
+}
@@ -0,0 +1,205 @@
+use crate::design::verilog::range;
+use regex::Regex;
+
+/// Matcher of object names by SDC pattern
+///
+/// Hierarchical names are expected to be joined by `/`.
+#[derive(Clone, Debug)]
+pub struct Matcher {
+    regex: Regex,
+    hierarchical: bool,
+}
+
+impl Matcher {
+    /// Glob matcher with default options
+    pub fn new(pattern: &str) -> Result<Self, regex::Error> {
+        MatcherBuilder::new().build(pattern)
+    }
+
+    /// Whether `name` is matched.
+    ///
+    /// A pattern without bit select also matches all bits of a bus (e.g. `data` matches `data[0]`).
+    pub fn is_match(&self, name: &str) -> bool {
+        let mut targets = vec![name];
+        if self.hierarchical {
+            targets.extend(name.match_indices('/').map(|(i, _)| &name[i + 1..]));
+        }
+        targets
+            .iter()
+            .any(|x| self.regex.is_match(x) || self.regex.is_match(base_name(x)))
+    }
+}
+
+/// Builder of [`Matcher`] corresponding to options of `get_*` commands
+#[derive(Clone, Debug)]
+pub struct MatcherBuilder {
+    regexp: bool,
+    nocase: bool,
+    hierarchical: bool,
+    separator: String,
+}
+
+impl Default for MatcherBuilder {
+    fn default() -> Self {
+        Self {
+            regexp: false,
+            nocase: false,
+            hierarchical: false,
+            separator: "/".to_string(),
+        }
+    }
+}
+
+impl MatcherBuilder {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Treat patterns as regular expressions like `-regexp`
+    pub fn regexp(&mut self, value: bool) -> &mut Self {
+        self.regexp = value;
+        self
+    }
+
+    /// Case-insensitive match like `-nocase`
+    pub fn nocase(&mut self, value: bool) -> &mut Self {
+        self.nocase = value;
+        self
+    }
+
+    /// Match at any hierarchy level like `-hierarchical`
+    pub fn hierarchical(&mut self, value: bool) -> &mut Self {
+        self.hierarchical = value;
+        self
+    }
+
+    /// Hierarchy separator used in glob patterns like `-hsc`
+    pub fn separator(&mut self, value: &str) -> &mut Self {
+        self.separator = value.to_string();
+        self
+    }
+
+    pub fn build(&self, pattern: &str) -> Result<Matcher, regex::Error> {
+        let pattern = if self.regexp {
+            format!("^(?:{pattern})$")
+        } else {
+            self.glob_to_regex(pattern)
+        };
+        let pattern = if self.nocase {
+            format!("(?i){pattern}")
+        } else {
+            pattern
+        };
+        Ok(Matcher {
+            regex: Regex::new(&pattern)?,
+            hierarchical: self.hierarchical,
+        })
+    }
+
+    /// Wildcard `*` and `?` don't match hierarchy separator
+    fn glob_to_regex(&self, pattern: &str) -> String {
+        let mut ret = "^".to_string();
+        let mut rest = pattern;
+        while let Some(c) = rest.chars().next() {
+            if !self.separator.is_empty() && rest.starts_with(&self.separator) {
+                ret.push('/');
+                rest = &rest[self.separator.len()..];
+                continue;
+            }
+            rest = &rest[c.len_utf8()..];
+            match c {
+                '*' => ret.push_str("[^/]*"),
+                '?' => ret.push_str("[^/]"),
+                '\\' => {
+                    if let Some(c) = rest.chars().next() {
+                        ret.push_str(&regex::escape(&c.to_string()));
+                        rest = &rest[c.len_utf8()..];
+                    }
+                }
+                '[' => match rest
+                    .find(']')
+                    .and_then(|i| Some((i, bus_range(&rest[..i])?)))
+                {
+                    Some((i, x)) => {
+                        ret.push_str(&x);
+                        rest = &rest[i + 1..];
+                    }
+                    None => ret.push_str(r"\["),
+                },
+                c => ret.push_str(&regex::escape(&c.to_string())),
+            }
+        }
+        ret.push('$');
+        ret
+    }
+}
+
+/// Bit range like `3:1` into regex matching `[3]`, `[2]` and `[1]`
+fn bus_range(text: &str) -> Option<String> {
+    let (msb, lsb) = text.split_once(':')?;
+    let msb = msb.trim().parse().ok()?;
+    let lsb = lsb.trim().parse().ok()?;
+    let bits: Vec<_> = range(msb, lsb).map(|x| x.to_string()).collect();
+    Some(format!(r"\[(?:{})\]", bits.join("|")))
+}
+
+/// Bus name without bit select (e.g. `data` of `data[0]`)
+pub(crate) fn base_name(name: &str) -> &str {
+    match name.rfind('[') {
+        Some(x) if name.ends_with(']') => &name[..x],
+        _ => name,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn is_match(builder: &MatcherBuilder, pattern: &str, name: &str) -> bool {
+        builder.build(pattern).unwrap().is_match(name)
+    }
+
+    #[test]
+    fn glob() {
+        let builder = MatcherBuilder::new();
+        assert!(is_match(&builder, "u_ff*", "u_ff0"));
+        assert!(is_match(&builder, "u_ff?/Q", "u_ff0/Q"));
+        assert!(!is_match(&builder, "u_*", "u_sub/u_and"));
+        assert!(is_match(&builder, "*/*", "u_sub/u_and"));
+        assert!(!is_match(&builder, "U_FF0", "u_ff0"));
+
+        assert!(is_match(&builder, "din", "din[1]"));
+        assert!(is_match(&builder, "din[0]", "din[0]"));
+        assert!(is_match(&builder, "din[*]", "din[1]"));
+        assert!(is_match(&builder, "din[3:2]", "din[2]"));
+        assert!(is_match(&builder, "din[2:3]", "din[3]"));
+        assert!(!is_match(&builder, "din[3:2]", "din[1]"));
+        assert!(is_match(&builder, r"din\[1\]", "din[1]"));
+        assert!(!is_match(&builder, r"din\[1:0\]", "din[1]"));
+        assert!(is_match(&builder, "a[b", "a[b"));
+    }
+
+    #[test]
+    fn options() {
+        let mut builder = MatcherBuilder::new();
+        builder.nocase(true);
+        assert!(is_match(&builder, "U_FF*", "u_ff0"));
+
+        let mut builder = MatcherBuilder::new();
+        builder.separator(".");
+        assert!(is_match(&builder, "u_sub.u_and", "u_sub/u_and"));
+        assert!(!is_match(&builder, "u_sub.*", "u_sub/u_and/A"));
+
+        let mut builder = MatcherBuilder::new();
+        builder.hierarchical(true);
+        assert!(is_match(&builder, "u_and", "u_sub/u_and"));
+        assert!(is_match(&builder, "*/A", "u_sub/u_and/A"));
+        assert!(!is_match(&builder, "u_sub", "u_sub/u_and"));
+
+        let mut builder = MatcherBuilder::new();
+        builder.regexp(true);
+        assert!(is_match(&builder, "u_ff[0-9]", "u_ff0"));
+        assert!(!is_match(&builder, "u_ff", "u_ff0"));
+        assert!(builder.build("u_ff[").is_err());
+    }
+}
@@ -6,7 +6,7 @@ pub mod sdc_parser;
 use crate::errors::ParseError;
 use crate::parser::sdc_grammar::SdcGrammar;
 use crate::parser::sdc_parser::parse;
-use crate::sdc::Sdc;
+use crate::sdc::{Dialect, Sdc};
 use parol_runtime::ParolError;
 use std::path::Path;
 
@@ -16,7 +16,16 @@ pub struct Parser {}
 impl Parser {
     #[allow(clippy::result_large_err)]
     pub fn parse<T: AsRef<Path>>(input: &str, file: &T) -> Result<Sdc, ParseError> {
-        let mut grammar = SdcGrammar::new();
+        Self::parse_with_dialect(input, file, Dialect::Sdc)
+    }
+
+    #[allow(clippy::result_large_err)]
+    pub fn parse_with_dialect<T: AsRef<Path>>(
+        input: &str,
+        file: &T,
+        dialect: Dialect,
+    ) -> Result<Sdc, ParseError> {
+        let mut grammar = SdcGrammar::with_dialect(dialect);
         match parse(input, file, &mut grammar) {
             Err(ParolError::LexerError(x)) => return Err(ParseError::LexicalError(x)),
             Err(ParolError::ParserError(x)) => return Err(ParseError::SyntaxError(x)),
@@ -1,6 +1,6 @@
 use crate::errors::SemanticError;
 use crate::parser::sdc_grammar_trait::*;
-use crate::sdc::Sdc;
+use crate::sdc::{Dialect, Sdc};
 use parol_runtime::ParolError;
 use std::fmt::{Debug, Display, Error, Formatter};
 
@@ -8,11 +8,15 @@ use std::fmt::{Debug, Display, Error, Formatter};
 pub struct SdcGrammar<'a> {
     pub ast: Option<Source<'a>>,
     pub sdc: Option<Result<Sdc, SemanticError>>,
+    pub dialect: Dialect,
 }
 
 impl SdcGrammar<'_> {
-    pub fn new() -> Self {
-        SdcGrammar::default()
+    pub fn with_dialect(dialect: Dialect) -> Self {
+        SdcGrammar {
+            dialect,
+            ..Default::default()
+        }
     }
 }
 
@@ -35,7 +39,7 @@ impl<'a> SdcGrammarTrait<'a> for SdcGrammar<'a> {
     /// Semantic action for non-terminal 'Source'
     fn source(&mut self, arg: &Source<'a>) -> Result<(), ParolError> {
         self.ast = Some(arg.clone());
-        self.sdc = Some(arg.try_into());
+        self.sdc = Some(Sdc::from_grammar(arg, self.dialect));
         Ok(())
     }
 }
@@ -0,0 +1,584 @@
+use crate::constraints::{hierarchy_separator, list_items, unquote, Clock, GeneratedClock, Scope};
+use crate::convert::{command_name, Conversion, Converted};
+use crate::errors::HierarchyError;
+use crate::matcher::Matcher;
+use crate::rename::{map_items, map_object_args, rename_clocks};
+use crate::sdc::argument::ArgumentWord;
+use crate::sdc::util::{CommandExt, Extract};
+use crate::sdc::{Argument, Command, CommandKind, Sdc};
+
+/// Object queries which are rewritten
+///
+/// `get_ports` is the last because it may be replaced by `get_pins`.
+const QUERIES: &[CommandKind] = &[
+    CommandKind::GetCells,
+    CommandKind::GetNets,
+    CommandKind::GetPins,
+    CommandKind::GetPorts,
+];
+
+/// Promoter of block-level SDC to the top design
+///
+/// Objects are prefixed by the instance path, block ports become hierarchical pins,
+/// and clocks defined in the block are renamed to `<instance>/<clock>` to avoid collisions.
+/// `current_instance` in the block is flattened.
+/// If the instance is empty, only `current_instance` is flattened.
+pub struct Promoter {
+    instance: String,
+}
+
+impl Promoter {
+    /// `instance` is joined by the hierarchy separator of SDC
+    pub fn new(instance: &str) -> Self {
+        Self {
+            instance: instance.to_string(),
+        }
+    }
+
+    pub fn promote(&self, sdc: &Sdc) -> (Conversion, Vec<HierarchyError>) {
+        let mut errors = vec![];
+        let separator = hierarchy_separator(sdc).unwrap_or("/".into());
+        let clocks = defined_clocks(sdc);
+
+        let mut scope = Scope::default();
+        let mut commands = vec![];
+        for command in &sdc.commands {
+            if matches!(
+                command,
+                Command::CurrentDesign(_) | Command::CurrentInstance(_)
+            ) {
+                scope.apply(command, &separator);
+                continue;
+            }
+
+            let prefix = match (self.instance.is_empty(), scope.is_top()) {
+                (_, true) => self.instance.clone(),
+                (true, false) => scope.path(&separator),
+                (false, false) => format!("{}{separator}{}", self.instance, scope.path(&separator)),
+            };
+
+            let mut command = command.clone();
+            if self.promote_command(&mut command, &prefix, &separator, &clocks, &mut errors) {
+                commands.push(Converted::Command(command));
+            } else {
+                commands.push(Converted::Commented(command));
+            }
+        }
+
+        (conversion(sdc, commands), errors)
+    }
+
+    /// Returns `false` if the command should be commented out.
+    fn promote_command(
+        &self,
+        command: &mut Command,
+        prefix: &str,
+        separator: &str,
+        clocks: &[String],
+        errors: &mut Vec<HierarchyError>,
+    ) -> bool {
+        if !self.instance.is_empty() {
+            let rename = |name: &str| {
+                let defined = match Matcher::new(name) {
+                    Ok(x) => clocks.iter().any(|y| x.is_match(y)),
+                    Err(_) => clocks.iter().any(|y| y == name),
+                };
+                if defined {
+                    format!("{}{separator}{name}", self.instance)
+                } else {
+                    name.to_string()
+                }
+            };
+            rename_clocks(command, &rename);
+        }
+
+        if prefix.is_empty() {
+            return true;
+        }
+
+        // Ports of the block can't be selected by direction at the top
+        for kind in [CommandKind::AllInputs, CommandKind::AllOutputs] {
+            let mut list = vec![];
+            command.extract(kind, &mut list);
+            if let Some(x) = list.first() {
+                errors.push(HierarchyError::Unsupported(command_name(x), x.location()));
+                return false;
+            }
+        }
+
+        for kind in QUERIES {
+            let mut queries = vec![];
+            command.extract_mut(*kind, &mut queries);
+            for query in queries {
+                if !promote_query(query, prefix, separator, errors) {
+                    return false;
+                }
+            }
+        }
+
+        // Bare names may be clocks, which are already renamed if defined in the block
+        map_object_args(command, &mut |arg| {
+            map_items(arg, |x| {
+                let clock = clocks.iter().any(|y| y == x);
+                match (clock, self.instance.is_empty()) {
+                    (true, true) => x.to_string(),
+                    (true, false) => format!("{}{separator}{x}", self.instance),
+                    (false, _) => format!("{prefix}{separator}{x}"),
+                }
+            })
+        });
+        true
+    }
+}
+
+/// Returns `false` if the query can't be promoted.
+fn promote_query(
+    query: &mut Command,
+    prefix: &str,
+    separator: &str,
+    errors: &mut Vec<HierarchyError>,
+) -> bool {
+    let name = command_name(query);
+    let location = query.location();
+    let is_port = matches!(query, Command::GetPorts(_));
+    let (hierarchical, hsc, regexp, of_objects, patterns) = match query {
+        Command::GetCells(x) => (
+            x.hierarchical,
+            &x.hsc,
+            x.regexp,
+            x.of_objects.is_some(),
+            &mut x.patterns,
+        ),
+        Command::GetNets(x) => (
+            x.hierarchical,
+            &x.hsc,
+            x.regexp,
+            x.of_objects.is_some(),
+            &mut x.patterns,
+        ),
+        Command::GetPins(x) => (
+            x.hierarchical,
+            &x.hsc,
+            x.regexp,
+            x.of_objects.is_some(),
+            &mut x.patterns,
+        ),
+        Command::GetPorts(x) => (
+            x.hierarchical,
+            &None,
+            x.regexp,
+            x.of_objects.is_some(),
+            &mut x.patterns,
+        ),
+        _ => return true,
+    };
+
+    if hierarchical {
+        errors.push(HierarchyError::Unsupported(
+            format!("{name} -hierarchical"),
+            location,
+        ));
+        return false;
+    }
+
+    // Patterns with -of_objects are matched by the name within the parent
+    if of_objects {
+        if is_port {
+            errors.push(HierarchyError::Unsupported(
+                format!("{name} -of_objects"),
+                location,
+            ));
+            return false;
+        }
+        return true;
+    }
+
+    let sep = hsc.as_ref().map(unquote).unwrap_or(separator.into());
+    let prefix = prefix.replace(separator, &sep);
+    let qualify = |x: &str| {
+        if regexp {
+            format!("{}{}{x}", regex::escape(&prefix), regex::escape(&sep))
+        } else {
+            format!("{prefix}{sep}{x}")
+        }
+    };
+    match patterns {
+        Some(x) => map_items(x, qualify),
+        None => {
+            let word = ArgumentWord::new(&format!("{prefix}{sep}*"), &location);
+            *patterns = Some(Argument::Word(word));
+        }
+    }
+
+    query.ports_to_pins();
+    true
+}
+
+/// Demoter of top-level SDC to a block
+///
+/// Only commands whose objects are inside the instance are kept, with the instance path stripped.
+/// Pins on the boundary of the instance become ports, and the instance prefix of clock names
+/// is removed. Clocks whose source is outside of the instance become virtual clocks,
+/// so that IO delays kept in the block still refer to them.
+/// IO delays are not derived from paths at the top.
+pub struct Demoter {
+    instance: String,
+}
+
+impl Demoter {
+    /// `instance` is joined by the hierarchy separator of SDC
+    pub fn new(instance: &str) -> Self {
+        Self {
+            instance: instance.to_string(),
+        }
+    }
+
+    pub fn demote(&self, sdc: &Sdc) -> (Conversion, Vec<HierarchyError>) {
+        let mut errors = vec![];
+        let separator = hierarchy_separator(sdc).unwrap_or("/".into());
+
+        let mut scope = Scope::default();
+        let mut commands = vec![];
+        for command in &sdc.commands {
+            if matches!(
+                command,
+                Command::CurrentDesign(_) | Command::CurrentInstance(_)
+            ) {
+                scope.apply(command, &separator);
+                continue;
+            }
+
+            let mut command = command.clone();
+            if self.demote_command(&mut command, &scope, &separator, &mut errors) {
+                commands.push(Converted::Command(command));
+            } else {
+                commands.push(Converted::Commented(command));
+            }
+        }
+
+        (conversion(sdc, commands), errors)
+    }
+
+    /// Returns `false` if the command should be commented out.
+    fn demote_command(
+        &self,
+        command: &mut Command,
+        scope: &Scope,
+        separator: &str,
+        errors: &mut Vec<HierarchyError>,
+    ) -> bool {
+        let prefix = format!("{}{separator}", self.instance);
+        rename_clocks(command, &|x| {
+            x.strip_prefix(&prefix).unwrap_or(x).to_string()
+        });
+
+        let is_clock = matches!(command, Command::CreateClock(_));
+        if is_clock && !self.demote_queries(&mut command.clone(), scope, separator, &mut vec![]) {
+            if let Command::CreateClock(x) = command {
+                let location = x.location();
+                let name = x
+                    .name
+                    .as_ref()
+                    .map(unquote)
+                    .or_else(|| Clock::try_from(&*x).ok().map(|x| x.name().to_string()))
+                    .unwrap_or_default();
+                x.name = Some(Argument::Word(ArgumentWord::new(&name, &location)));
+                x.source_objects = None;
+                errors.push(HierarchyError::VirtualClock(name, location));
+            }
+        }
+
+        if scope.is_top() {
+            for kind in [CommandKind::AllInputs, CommandKind::AllOutputs] {
+                let mut list = vec![];
+                command.extract(kind, &mut list);
+                if let Some(x) = list.first() {
+                    errors.push(HierarchyError::OutsideInstance(
+                        command_name(x),
+                        self.instance.clone(),
+                        x.location(),
+                    ));
+                    return false;
+                }
+            }
+        }
+
+        self.demote_queries(command, scope, separator, errors)
+    }
+
+    /// Returns `false` if any query is outside of the instance.
+    fn demote_queries(
+        &self,
+        command: &mut Command,
+        scope: &Scope,
+        separator: &str,
+        errors: &mut Vec<HierarchyError>,
+    ) -> bool {
+        // Queries replaced by `get_ports` are not demoted again
+        let mut done = vec![];
+        for kind in QUERIES {
+            let mut queries = vec![];
+            command.extract_mut(*kind, &mut queries);
+            for query in queries {
+                let location = query.location();
+                if done.contains(&location) {
+                    continue;
+                }
+                done.push(location);
+                if !self.demote_query(query, scope, separator, errors) {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+
+    /// Returns `false` if the query is outside of the instance.
+    fn demote_query(
+        &self,
+        query: &mut Command,
+        scope: &Scope,
+        separator: &str,
+        errors: &mut Vec<HierarchyError>,
+    ) -> bool {
+        let name = command_name(query);
+        let location = query.location();
+        let is_port = matches!(query, Command::GetPorts(_));
+        let is_pin = matches!(query, Command::GetPins(_)) || (is_port && !scope.is_top());
+        let (hierarchical, hsc, regexp, of_objects, patterns) = match query {
+            Command::GetCells(x) => (
+                x.hierarchical,
+                &x.hsc,
+                x.regexp,
+                x.of_objects.is_some(),
+                &mut x.patterns,
+            ),
+            Command::GetNets(x) => (
+                x.hierarchical,
+                &x.hsc,
+                x.regexp,
+                x.of_objects.is_some(),
+                &mut x.patterns,
+            ),
+            Command::GetPins(x) => (
+                x.hierarchical,
+                &x.hsc,
+                x.regexp,
+                x.of_objects.is_some(),
+                &mut x.patterns,
+            ),
+            Command::GetPorts(x) => (
+                x.hierarchical,
+                &None,
+                x.regexp,
+                x.of_objects.is_some(),
+                &mut x.patterns,
+            ),
+            _ => return true,
+        };
+
+        // -hierarchical matches objects in the block as well
+        if hierarchical || of_objects {
+            return true;
+        }
+
+        let outside = |object: String| {
+            HierarchyError::OutsideInstance(object, self.instance.clone(), location.clone())
+        };
+        let Some(arg) = patterns else {
+            errors.push(outside(name));
+            return false;
+        };
+        if is_port && scope.is_top() {
+            errors.push(outside(format!("{name} {}", list_items(arg).join(" "))));
+            return false;
+        }
+
+        let sep = hsc.as_ref().map(unquote).unwrap_or(separator.into());
+        let escape = |x: &str| {
+            if regexp {
+                regex::escape(x)
+            } else {
+                x.to_string()
+            }
+        };
+        let path = escape(&scope.path(&sep));
+        let prefix = escape(&format!("{}{sep}", self.instance.replace(separator, &sep)));
+        let sep = escape(&sep);
+
+        let mut names = vec![];
+        for item in list_items(arg) {
+            let qualified = if scope.is_top() {
+                item.clone()
+            } else {
+                format!("{path}{sep}{item}")
+            };
+            match qualified.strip_prefix(&prefix) {
+                Some(x) => names.push(x.to_string()),
+                None => {
+                    errors.push(outside(item));
+                    return false;
+                }
+            }
+        }
+
+        let boundary = names.iter().filter(|x| !x.contains(&sep)).count();
+        let to_port = if !is_pin {
+            false
+        } else if boundary == names.len() {
+            true
+        } else if boundary == 0 {
+            false
+        } else {
+            // A query can't select ports and pins at once
+            errors.push(HierarchyError::Unsupported(name, location));
+            return false;
+        };
+
+        let mut names = names.into_iter();
+        map_items(arg, |_| names.next().unwrap_or_default());
+        if to_port {
+            query.pins_to_ports();
+        } else {
+            query.ports_to_pins();
+        }
+        true
+    }
+}
+
+fn conversion(sdc: &Sdc, commands: Vec<Converted>) -> Conversion {
+    Conversion {
+        dialect: sdc.dialect,
+        header: sdc.header.clone(),
+        version: sdc.version,
+        commands,
+    }
+}
+
+/// Names of clocks defined by `create_clock` and `create_generated_clock`
+fn defined_clocks(sdc: &Sdc) -> Vec<String> {
+    let mut ret = vec![];
+    for command in &sdc.commands {
+        let name = match command {
+            Command::CreateClock(x) => x
+                .name
+                .as_ref()
+                .map(unquote)
+                .or_else(|| Clock::try_from(x).ok().map(|x| x.name().to_string())),
+            Command::CreateGeneratedClock(x) => x.name.as_ref().map(unquote).or_else(|| {
+                GeneratedClock::try_from(x)
+                    .ok()
+                    .map(|x| x.name().to_string())
+            }),
+            _ => None,
+        };
+        ret.extend(name);
+    }
+    ret
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Parser;
+
+    fn promote(code: &str, instance: &str) -> (String, Vec<HierarchyError>) {
+        let sdc = Parser::parse(code, &"").unwrap();
+        let (conversion, errors) = Promoter::new(instance).promote(&sdc);
+        (conversion.to_string(), errors)
+    }
+
+    fn demote(code: &str, instance: &str) -> (String, Vec<HierarchyError>) {
+        let sdc = Parser::parse(code, &"").unwrap();
+        let (conversion, errors) = Demoter::new(instance).demote(&sdc);
+        (conversion.to_string(), errors)
+    }
+
+    #[test]
+    fn promote_block() {
+        let code = r##"create_clock -name clk -period 10 [get_ports clk_in]
+create_generated_clock -name div -source [get_ports clk_in] -divide_by 2 [get_pins u_div/Q]
+set_input_delay 1 -clock clk [get_ports {din[0] din[1]}]
+set_false_path -from [get_clocks cl*] -to [get_clocks ext]
+set_false_path -through [get_nets -of_objects [get_cells u_ff*]]
+current_instance u_sub
+set_max_delay 2 -to [get_pins u_ff/D]
+set_max_delay 2 -to [get_ports x]
+current_instance
+set_false_path -to [get_cells -hierarchical ff*]
+set_driving_cell -lib_cell BUF [all_inputs]
+set_false_path -from clk -to u_ff/D
+set_false_path -through {u_a/Y u_b/Y} -to [list u_ff/D [get_pins u_ff/E]]
+"##;
+        let (text, errors) = promote(code, "u_blk");
+        assert_eq!(
+            text,
+            r##"create_clock -period 10 -name u_blk/clk [get_pins u_blk/clk_in]
+create_generated_clock -name u_blk/div -source [get_pins u_blk/clk_in] -divide_by 2 [get_pins u_blk/u_div/Q]
+set_input_delay -clock u_blk/clk 1 [get_pins {u_blk/din[0] u_blk/din[1]}]
+set_false_path -from [get_clocks u_blk/cl*] -to [get_clocks ext]
+set_false_path -through [get_nets -of_objects [get_cells u_blk/u_ff*]]
+set_max_delay -to [get_pins u_blk/u_sub/u_ff/D] 2
+set_max_delay -to [get_pins u_blk/u_sub/x] 2
+# [unsupported] set_false_path -to [get_cells -hierarchical ff*]
+# [unsupported] set_driving_cell -lib_cell BUF [all_inputs]
+set_false_path -from u_blk/clk -to u_blk/u_ff/D
+set_false_path -to [list u_blk/u_ff/D [get_pins u_blk/u_ff/E]] -through {u_blk/u_a/Y u_blk/u_b/Y}
+"##
+        );
+        let names: Vec<_> = errors
+            .iter()
+            .map(|x| match x {
+                HierarchyError::Unsupported(x, _) => x.clone(),
+                _ => unreachable!(),
+            })
+            .collect();
+        assert_eq!(names, vec!["get_cells -hierarchical", "all_inputs"]);
+
+        // Only current_instance is flattened without instance
+        let (text, errors) = promote(code, "");
+        assert!(text.contains("set_input_delay -clock clk 1 [get_ports {din[0] din[1]}]"));
+        assert!(text.contains("set_max_delay -to [get_pins u_sub/x] 2"));
+        assert!(text.contains("set_driving_cell -lib_cell BUF [all_inputs]"));
+        assert!(errors.is_empty());
+    }
+
+    #[test]
+    fn demote_block() {
+        let code = r##"create_clock -name CLK -period 10 [get_ports clk]
+create_clock -name u_blk/bclk -period 5 [get_pins u_blk/u_pll/out]
+set_input_delay 1 -clock CLK [get_pins u_blk/din]
+set_false_path -to [get_pins u_blk/u_ff/D]
+set_false_path -to [get_pins u_other/u_ff/D]
+set_false_path -to [get_pins {u_blk/din u_blk/u_ff/D}]
+current_instance u_blk
+set_max_delay 2 -to [get_ports dout]
+set_load 1 [all_outputs]
+"##;
+        let (text, errors) = demote(code, "u_blk");
+        assert_eq!(
+            text,
+            r##"create_clock -period 10 -name CLK
+create_clock -period 5 -name bclk [get_pins u_pll/out]
+set_input_delay -clock CLK 1 [get_ports din]
+set_false_path -to [get_pins u_ff/D]
+# [unsupported] set_false_path -to [get_pins u_other/u_ff/D]
+# [unsupported] set_false_path -to [get_pins {u_blk/din u_blk/u_ff/D}]
+set_max_delay -to [get_ports dout] 2
+set_load 1 [all_outputs]
+"##
+        );
+        let names: Vec<_> = errors
+            .iter()
+            .map(|x| match x {
+                HierarchyError::VirtualClock(x, _) => format!("virtual {x}"),
+                HierarchyError::OutsideInstance(x, _, _) => format!("outside {x}"),
+                HierarchyError::Unsupported(x, _) => x.clone(),
+            })
+            .collect();
+        assert_eq!(
+            names,
+            vec!["virtual CLK", "outside u_other/u_ff/D", "get_pins"]
+        );
+    }
+}
@@ -0,0 +1,401 @@
+use crate::constraints::object::list_items;
+use crate::constraints::{hierarchy_separator, unquote};
+use crate::errors::QueryError;
+use crate::file_db::Location;
+use crate::matcher::Matcher;
+use crate::rename::object_args;
+use crate::sdc::util::{CommandExt, Extract};
+use crate::sdc::{Argument, Command, CommandKind, CommandSpec, Sdc, COMMAND_SPECS};
+
+/// Query selecting commands
+///
+/// A query is a combination of terms by `&&`, `||`, `!` and parentheses.
+/// Juxtaposed terms are combined by `&&`.
+///
+/// * `set_false_path` : command name or its glob pattern
+/// * `-through` : the option is specified
+/// * `-clock=CLK_A` : a value of the option matches the glob pattern
+/// * `object=u_ddr` : an object query or a bare object name matches the pattern or is under the hierarchy
+///
+/// Hierarchy of `object=` is split by the separator of `set_hierarchy_separator`, or `-hsc` of the object query.
+/// * `line=10`, `line=10..20` : the command starts in the line range
+#[derive(Clone, Debug)]
+pub struct Query {
+    expr: Expr,
+}
+
+/// Command selected by query
+#[derive(Clone, Debug)]
+pub struct Selected<'a> {
+    pub command: &'a Command,
+    pub location: Location,
+}
+
+#[derive(Clone, Debug)]
+enum Expr {
+    Kind(Vec<CommandKind>),
+    Option(String),
+    Value(String, Matcher),
+    Object(Matcher),
+    Line(u32, u32),
+    Not(Box<Expr>),
+    And(Box<Expr>, Box<Expr>),
+    Or(Box<Expr>, Box<Expr>),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+    LParen,
+    RParen,
+    Not,
+    And,
+    Or,
+    Term(String),
+}
+
+/// Object queries searched by `object=`
+const OBJECT_KINDS: [CommandKind; 4] = [
+    CommandKind::GetCells,
+    CommandKind::GetNets,
+    CommandKind::GetPins,
+    CommandKind::GetPorts,
+];
+
+impl Query {
+    pub fn parse(text: &str) -> Result<Self, QueryError> {
+        let tokens = tokenize(text);
+        let mut parser = QueryParser { tokens, pos: 0 };
+        let expr = parser.or()?;
+        if let Some((token, pos)) = parser.tokens.get(parser.pos) {
+            return Err(QueryError::UnexpectedToken(token.to_string(), *pos));
+        }
+        Ok(Self { expr })
+    }
+
+    /// Whether `command` is matched, assuming the hierarchy separator is `/`
+    pub fn is_match(&self, command: &Command) -> bool {
+        self.expr.is_match(command, "/")
+    }
+
+    /// Commands matched by the query
+    pub fn select<'a>(&self, sdc: &'a Sdc) -> Vec<Selected<'a>> {
+        let separator = hierarchy_separator(sdc).unwrap_or("/".into());
+        sdc.commands
+            .iter()
+            .filter(|x| self.expr.is_match(x, &separator))
+            .map(|command| Selected {
+                command,
+                location: command.location(),
+            })
+            .collect()
+    }
+}
+
+impl Expr {
+    fn is_match(&self, command: &Command, separator: &str) -> bool {
+        match self {
+            Expr::Kind(x) => x.contains(&command.kind()),
+            Expr::Option(x) => {
+                let name = option_name(command, x);
+                command.flag(&name) || !command.values(&name).is_empty()
+            }
+            Expr::Value(x, matcher) => {
+                let name = option_name(command, x);
+                command
+                    .values(&name)
+                    .into_iter()
+                    .flat_map(names)
+                    .any(|x| matcher.is_match(&x))
+            }
+            Expr::Object(matcher) => {
+                let queried = OBJECT_KINDS.iter().any(|kind| {
+                    let mut list = vec![];
+                    command.extract(*kind, &mut list);
+                    list.iter().any(|x| {
+                        let separator = match x.values("hsc").first() {
+                            Some(hsc) => unquote(hsc),
+                            None => separator.to_string(),
+                        };
+                        x.values("patterns")
+                            .into_iter()
+                            .flat_map(list_items)
+                            .any(|x| is_under(matcher, &x, &separator))
+                    })
+                });
+                queried
+                    || object_args(command)
+                        .into_iter()
+                        .flat_map(list_items)
+                        .any(|x| is_under(matcher, &x, separator))
+            }
+            Expr::Line(from, to) => {
+                let line = command.location().start_line;
+                *from <= line && line <= *to
+            }
+            Expr::Not(x) => !x.is_match(command, separator),
+            Expr::And(x, y) => x.is_match(command, separator) && y.is_match(command, separator),
+            Expr::Or(x, y) => x.is_match(command, separator) || y.is_match(command, separator),
+        }
+    }
+}
+
+/// Full option name resolved from abbreviation
+fn option_name(command: &Command, text: &str) -> String {
+    CommandSpec::of(command.kind())
+        .and_then(|x| x.option(text))
+        .map(|x| x.name.to_string())
+        .unwrap_or(text.to_string())
+}
+
+/// Names in the argument including object queries in it
+fn names(arg: &Argument) -> Vec<String> {
+    match arg {
+        Argument::CommandSubstitution(x, _) => x.arguments().into_iter().flat_map(names).collect(),
+        _ => list_items(arg),
+    }
+}
+
+/// Whether `name` or its parent hierarchy split by `separator` is matched
+fn is_under(matcher: &Matcher, name: &str, separator: &str) -> bool {
+    matcher.is_match(name)
+        || name
+            .match_indices(separator)
+            .any(|(i, _)| matcher.is_match(&name[..i]))
+}
+
+impl std::fmt::Display for Token {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Token::LParen => "(".fmt(f),
+            Token::RParen => ")".fmt(f),
+            Token::Not => "!".fmt(f),
+            Token::And => "&&".fmt(f),
+            Token::Or => "||".fmt(f),
+            Token::Term(x) => x.fmt(f),
+        }
+    }
+}
+
+fn tokenize(text: &str) -> Vec<(Token, usize)> {
+    let mut ret = vec![];
+    let mut chars = text.char_indices().peekable();
+    while let Some((pos, c)) = chars.next() {
+        let token = match c {
+            x if x.is_whitespace() => continue,
+            '(' => Token::LParen,
+            ')' => Token::RParen,
+            '!' => Token::Not,
+            '&' if text[pos..].starts_with("&&") => {
+                chars.next();
+                Token::And
+            }
+            '|' if text[pos..].starts_with("||") => {
+                chars.next();
+                Token::Or
+            }
+            _ => {
+                let mut term = String::new();
+                let mut quoted = false;
+                let mut end = text.len();
+                for (i, x) in text[pos..].char_indices() {
+                    let rest = &text[pos + i..];
+                    let delimiter = x.is_whitespace()
+                        || x == '('
+                        || x == ')'
+                        || rest.starts_with("&&")
+                        || rest.starts_with("||");
+                    if delimiter && !quoted {
+                        end = pos + i;
+                        break;
+                    }
+                    if x == '"' {
+                        quoted = !quoted;
+                    } else {
+                        term.push(x);
+                    }
+                }
+                while chars.next_if(|(i, _)| *i < end).is_some() {}
+                Token::Term(term)
+            }
+        };
+        ret.push((token, pos));
+    }
+    ret
+}
+
+struct QueryParser {
+    tokens: Vec<(Token, usize)>,
+    pos: usize,
+}
+
+impl QueryParser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos).map(|x| &x.0)
+    }
+
+    fn next(&mut self) -> Result<(Token, usize), QueryError> {
+        let ret = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        ret.ok_or(QueryError::UnexpectedEnd)
+    }
+
+    fn or(&mut self) -> Result<Expr, QueryError> {
+        let mut ret = self.and()?;
+        while self.peek() == Some(&Token::Or) {
+            self.pos += 1;
+            ret = Expr::Or(Box::new(ret), Box::new(self.and()?));
+        }
+        Ok(ret)
+    }
+
+    fn and(&mut self) -> Result<Expr, QueryError> {
+        let mut ret = self.unary()?;
+        loop {
+            match self.peek() {
+                Some(Token::And) => self.pos += 1,
+                Some(Token::Not | Token::LParen | Token::Term(_)) => (),
+                _ => break,
+            }
+            ret = Expr::And(Box::new(ret), Box::new(self.unary()?));
+        }
+        Ok(ret)
+    }
+
+    fn unary(&mut self) -> Result<Expr, QueryError> {
+        match self.next()? {
+            (Token::Not, _) => Ok(Expr::Not(Box::new(self.unary()?))),
+            (Token::LParen, _) => {
+                let ret = self.or()?;
+                match self.next()? {
+                    (Token::RParen, _) => Ok(ret),
+                    (x, pos) => Err(QueryError::UnexpectedToken(x.to_string(), pos)),
+                }
+            }
+            (Token::Term(x), _) => term(&x),
+            (x, pos) => Err(QueryError::UnexpectedToken(x.to_string(), pos)),
+        }
+    }
+}
+
+fn term(text: &str) -> Result<Expr, QueryError> {
+    let matcher = |x: &str| Matcher::new(x).map_err(|_| QueryError::InvalidPattern(x.into()));
+
+    if text.starts_with('-') {
+        match text.split_once('=') {
+            Some((name, pattern)) => Ok(Expr::Value(name.into(), matcher(pattern)?)),
+            None => Ok(Expr::Option(text.into())),
+        }
+    } else if let Some((key, value)) = text.split_once('=') {
+        match key {
+            "object" => Ok(Expr::Object(matcher(value)?)),
+            "line" => {
+                let (from, to) = value.split_once("..").unwrap_or((value, value));
+                match (from.parse(), to.parse()) {
+                    (Ok(from), Ok(to)) => Ok(Expr::Line(from, to)),
+                    _ => Err(QueryError::InvalidRange(value.into())),
+                }
+            }
+            _ => Err(QueryError::UnknownKey(key.into())),
+        }
+    } else {
+        let matcher = matcher(text)?;
+        let kinds: Vec<_> = COMMAND_SPECS
+            .iter()
+            .filter(|x| matcher.is_match(x.name) || x.aliases.iter().any(|x| matcher.is_match(x)))
+            .map(|x| x.kind)
+            .collect();
+        if kinds.is_empty() {
+            Err(QueryError::UnknownCommand(text.into()))
+        } else {
+            Ok(Expr::Kind(kinds))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Parser;
+
+    const CODE: &str = "create_clock -period 10 -name CLK_A [get_ports clk_a]
+create_clock -period 5 -name CLK_B [get_ports clk_b]
+set_input_delay 1 -clock CLK_A [get_ports din]
+set_input_delay 2 -clock [get_clocks CLK_B] -max [get_ports din]
+set_false_path -from [get_cells u_ddr/u_phy/ff] -to [get_pins u_core/ff/D]
+set_false_path -through [get_pins u_ddr_ctrl/a]
+set_max_delay 3 -ignore_clock_latency -from [get_clocks CLK_A]
+set_false_path -to u_ddr/ff/D
+set_multicycle_path 2 -to [list u_ddr/ff/D u_core/ff/D]
+";
+
+    fn select(query: &str) -> Vec<u32> {
+        let sdc = Parser::parse(CODE, &"").unwrap();
+        let query = Query::parse(query).unwrap();
+        query
+            .select(&sdc)
+            .iter()
+            .map(|x| x.location.start_line)
+            .collect()
+    }
+
+    #[test]
+    fn query() {
+        assert_eq!(select("set_false_path"), vec![5, 6, 8]);
+        assert_eq!(select("set_*_delay"), vec![3, 4, 7]);
+        assert_eq!(select("set_input_delay && -clock=CLK_A"), vec![3]);
+        assert_eq!(select("set_input_delay -clock=CLK_*"), vec![3, 4]);
+        assert_eq!(select("set_input_delay && -max"), vec![4]);
+        assert_eq!(select("set_input_delay && !-ma"), vec![3]);
+        assert_eq!(select("set_false_path && object=u_ddr"), vec![5, 8]);
+        assert_eq!(select("object=u_ddr*"), vec![5, 6, 8, 9]);
+        assert_eq!(select("object=clk_?"), vec![1, 2]);
+        assert_eq!(
+            select("(create_clock || set_max_delay) && line=2..7"),
+            vec![2, 7]
+        );
+        assert_eq!(select("line=3"), vec![3]);
+        assert_eq!(select("-ignore_clock_latency || -through"), vec![6, 7]);
+        assert_eq!(select("create_clock -name=\"CLK_B\""), vec![2]);
+    }
+
+    #[test]
+    fn separator() {
+        let code = r##"set_hierarchy_separator |
+set_false_path -to u_ddr|ff|D
+set_false_path -to [get_pins -hsc . u_ddr.ff.D]
+set_false_path -to [get_pins u_core|ff|D]
+"##;
+        let sdc = Parser::parse(code, &"").unwrap();
+        let query = Query::parse("object=u_ddr").unwrap();
+        let lines: Vec<_> = query
+            .select(&sdc)
+            .iter()
+            .map(|x| x.location.start_line)
+            .collect();
+        assert_eq!(lines, vec![2, 3]);
+    }
+
+    #[test]
+    fn error() {
+        assert!(matches!(
+            Query::parse("set_false_path &&"),
+            Err(QueryError::UnexpectedEnd)
+        ));
+        assert!(matches!(
+            Query::parse("set_false_path )"),
+            Err(QueryError::UnexpectedToken(x, 15)) if x == ")"
+        ));
+        assert!(matches!(
+            Query::parse("set_flase_path"),
+            Err(QueryError::UnknownCommand(_))
+        ));
+        assert!(matches!(
+            Query::parse("file=a.sdc"),
+            Err(QueryError::UnknownKey(_))
+        ));
+        assert!(matches!(
+            Query::parse("line=a..b"),
+            Err(QueryError::InvalidRange(_))
+        ));
+    }
+}
@@ -0,0 +1,434 @@
+use crate::constraints::{hierarchy_separator, list_items, unquote};
+use crate::errors::RenameError;
+use crate::matcher::MatcherBuilder;
+use crate::sdc::util::{CommandExt, Extract};
+use crate::sdc::{Argument, Command, CommandKind, CommandSpec, Sdc, ValueType};
+
+/// Options which take clock names
+const CLOCK_OPTIONS: &[&str] = &[
+    "clock",
+    "clocks",
+    "rise_clock",
+    "fall_clock",
+    "master_clock",
+];
+
+/// Renamer of clocks, ports and instances
+///
+/// Definitions and all references including nested object queries are rewritten.
+/// Bare object names like `set_false_path -to u_core/ff/D` are rewritten as ports and instance paths.
+/// Names are compared as written, so glob and regexp patterns are not rewritten.
+/// Such patterns of `get_clocks` and `get_ports` which matched a renamed name are returned as warnings.
+#[derive(Clone, Debug, Default)]
+pub struct Renamer {
+    clocks: Vec<(String, String)>,
+    ports: Vec<(String, String)>,
+    instance_prefixes: Vec<(String, String)>,
+}
+
+impl Renamer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Rename clock `from` to `to`
+    pub fn clock(&mut self, from: &str, to: &str) -> &mut Self {
+        self.clocks.push((from.into(), to.into()));
+        self
+    }
+
+    /// Rename port `from` to `to` including its bits
+    pub fn port(&mut self, from: &str, to: &str) -> &mut Self {
+        self.ports.push((from.into(), to.into()));
+        self
+    }
+
+    /// Replace instance path prefix `from` by `to`
+    pub fn instance_prefix(&mut self, from: &str, to: &str) -> &mut Self {
+        self.instance_prefixes.push((from.into(), to.into()));
+        self
+    }
+
+    pub fn rename(&self, sdc: &mut Sdc) -> Vec<RenameError> {
+        let mut ret = vec![];
+        let separator = hierarchy_separator(sdc).unwrap_or("/".into());
+        for command in &mut sdc.commands {
+            ret.append(&mut self.unrenamed_patterns(command));
+            self.rename_command(command, &separator);
+        }
+        ret
+    }
+
+    /// Patterns of `get_clocks` and `get_ports` which match a name to be renamed, but not the new name
+    fn unrenamed_patterns(&self, command: &Command) -> Vec<RenameError> {
+        let mut ret = vec![];
+        for (kind, names) in [
+            (CommandKind::GetClocks, &self.clocks),
+            (CommandKind::GetPorts, &self.ports),
+        ] {
+            if names.is_empty() {
+                continue;
+            }
+            let mut queries = vec![];
+            command.extract(kind, &mut queries);
+            for query in queries {
+                let regexp = query.flag("regexp");
+                let mut builder = MatcherBuilder::new();
+                builder.regexp(regexp).nocase(query.flag("nocase"));
+                for pattern in query.values("patterns").into_iter().flat_map(list_items) {
+                    if !regexp && !pattern.contains(['*', '?']) {
+                        continue;
+                    }
+                    let Ok(matcher) = builder.build(&pattern) else {
+                        continue;
+                    };
+                    for (from, to) in names {
+                        if matcher.is_match(from) && !matcher.is_match(to) {
+                            ret.push(RenameError::UnrenamedPattern(
+                                pattern.clone(),
+                                from.clone(),
+                                to.clone(),
+                                query.location(),
+                            ));
+                        }
+                    }
+                }
+            }
+        }
+        ret
+    }
+
+    fn rename_command(&self, command: &mut Command, separator: &str) {
+        if !self.clocks.is_empty() {
+            rename_clocks(
+                command,
+                &|x| match self.clocks.iter().find(|(from, _)| from == x) {
+                    Some((_, to)) => to.clone(),
+                    None => x.to_string(),
+                },
+            );
+        }
+
+        if !self.ports.is_empty() {
+            let mut queries = vec![];
+            command.extract_mut(CommandKind::GetPorts, &mut queries);
+            for query in queries {
+                if let Command::GetPorts(x) = query {
+                    if !x.regexp {
+                        let rename = |x: &str| self.rename_port(x);
+                        x.patterns.iter_mut().for_each(|x| map_items(x, rename));
+                    }
+                }
+            }
+        }
+
+        if !self.ports.is_empty() || !self.instance_prefixes.is_empty() {
+            map_object_args(command, &mut |x| {
+                map_items(x, |x| {
+                    let x = self.rename_port(x);
+                    self.rename_instance(&x, separator)
+                })
+            });
+        }
+
+        if !self.instance_prefixes.is_empty() {
+            if let Command::CurrentInstance(x) = command {
+                let rename = |x: &str| self.rename_instance(x, separator);
+                x.instance.iter_mut().for_each(|x| map_items(x, rename));
+            }
+
+            for kind in [
+                CommandKind::GetCells,
+                CommandKind::GetNets,
+                CommandKind::GetPins,
+            ] {
+                let mut queries = vec![];
+                command.extract_mut(kind, &mut queries);
+                for query in queries {
+                    let (regexp, hsc, patterns) = match query {
+                        Command::GetCells(x) => (x.regexp, &x.hsc, &mut x.patterns),
+                        Command::GetNets(x) => (x.regexp, &x.hsc, &mut x.patterns),
+                        Command::GetPins(x) => (x.regexp, &x.hsc, &mut x.patterns),
+                        _ => continue,
+                    };
+                    if regexp {
+                        continue;
+                    }
+                    let separator = hsc.as_ref().map(unquote).unwrap_or(separator.into());
+                    let rename = |x: &str| self.rename_instance(x, &separator);
+                    patterns.iter_mut().for_each(|x| map_items(x, rename));
+                }
+            }
+        }
+    }
+
+    fn rename_port(&self, name: &str) -> String {
+        for (from, to) in &self.ports {
+            if name == from {
+                return to.clone();
+            }
+            if let Some(bit) = name.strip_prefix(from.as_str()) {
+                if bit.starts_with('[') {
+                    return format!("{to}{bit}");
+                }
+            }
+        }
+        name.to_string()
+    }
+
+    fn rename_instance(&self, name: &str, separator: &str) -> String {
+        for (from, to) in &self.instance_prefixes {
+            if name == from {
+                return to.clone();
+            }
+            if let Some(rest) = name.strip_prefix(&format!("{from}{separator}")) {
+                return format!("{to}{separator}{rest}");
+            }
+        }
+        name.to_string()
+    }
+}
+
+/// Rename clock names of definitions, `get_clocks` and clock arguments including nested commands
+pub(crate) fn rename_clocks(command: &mut Command, rename: &dyn Fn(&str) -> String) {
+    match command {
+        Command::CreateClock(x) => x.name.iter_mut().for_each(|x| map_items(x, rename)),
+        Command::CreateGeneratedClock(x) => x.name.iter_mut().for_each(|x| map_items(x, rename)),
+        Command::GetClocks(x) if !x.regexp => {
+            x.patterns.iter_mut().for_each(|x| map_items(x, rename))
+        }
+        _ => (),
+    }
+
+    for name in clock_arg_names(command) {
+        for arg in command.values_mut(name) {
+            map_bare_items(arg, &mut |x| map_items(x, rename));
+        }
+    }
+
+    for arg in command.arguments_mut() {
+        if let Argument::CommandSubstitution(x, _) = arg {
+            rename_clocks(x.as_mut(), rename);
+        }
+    }
+}
+
+/// Apply `f` to bare object names in arguments of `command` and its nested commands
+///
+/// Object queries like `get_pins` are not visited, but bare names in their options are.
+/// Arguments which may take clock names are not visited.
+pub(crate) fn map_object_args(command: &mut Command, f: &mut dyn FnMut(&mut Argument)) {
+    for name in object_arg_names(command) {
+        for arg in command.values_mut(name) {
+            map_bare_items(arg, f);
+        }
+    }
+    for arg in command.arguments_mut() {
+        if let Argument::CommandSubstitution(x, _) = arg {
+            map_object_args(x.as_mut(), f);
+        }
+    }
+}
+
+/// Bare object names in arguments of `command` and its nested commands
+///
+/// Object queries like `get_pins` are not included, but bare names in their options are.
+/// Arguments which may take clock names are not included.
+pub(crate) fn object_args(command: &Command) -> Vec<&Argument> {
+    let mut ret = vec![];
+    for name in object_arg_names(command) {
+        for arg in command.values(name) {
+            bare_items(arg, &mut ret);
+        }
+    }
+    for arg in command.arguments() {
+        if let Argument::CommandSubstitution(x, _) = arg {
+            ret.append(&mut object_args(x));
+        }
+    }
+    ret
+}
+
+/// Names of arguments which take design objects other than clocks
+fn object_arg_names(command: &Command) -> Vec<&'static str> {
+    let kind = command.kind();
+    arg_names(command)
+        .into_iter()
+        .filter(|(name, value)| *value == ValueType::Objects && !is_clock_arg(kind, name))
+        .map(|x| x.0)
+        .collect()
+}
+
+/// Names of arguments which may take clock names
+fn clock_arg_names(command: &Command) -> Vec<&'static str> {
+    let kind = command.kind();
+    arg_names(command)
+        .into_iter()
+        .filter(|(name, value)| *value != ValueType::Number && is_clock_arg(kind, name))
+        .map(|x| x.0)
+        .collect()
+}
+
+/// Names and value types of options and positional arguments
+fn arg_names(command: &Command) -> Vec<(&'static str, ValueType)> {
+    let Some(spec) = CommandSpec::of(command.kind()) else {
+        return vec![];
+    };
+    let options = spec
+        .options
+        .iter()
+        .filter_map(|x| Some((x.name.trim_start_matches('-'), x.value?)));
+    let arguments = spec.arguments.iter().map(|x| (x.name, x.value));
+    options.chain(arguments).collect()
+}
+
+/// Whether argument `name` of `kind` may take clock names
+fn is_clock_arg(kind: CommandKind, name: &str) -> bool {
+    match kind {
+        _ if CLOCK_OPTIONS.contains(&name) || name == "clock_list" => true,
+        CommandKind::SetClockGroups => name == "group",
+        // -from and -to are clocks, and object_list may be clocks or pins
+        CommandKind::SetClockUncertainty => true,
+        CommandKind::SetClockLatency | CommandKind::SetPropagatedClock => name == "object_list",
+        _ => false,
+    }
+}
+
+/// Apply `f` to bare names in `arg` including items of nested `list`
+fn map_bare_items(arg: &mut Argument, f: &mut dyn FnMut(&mut Argument)) {
+    match arg {
+        Argument::CommandSubstitution(x, _) => {
+            if let Command::List(x) = x.as_mut() {
+                x.args.iter_mut().for_each(|x| map_bare_items(x, f));
+            }
+        }
+        x => f(x),
+    }
+}
+
+/// Bare names in `arg` including items of nested `list`
+fn bare_items<'a>(arg: &'a Argument, ret: &mut Vec<&'a Argument>) {
+    match arg {
+        Argument::CommandSubstitution(x, _) => {
+            if let Command::List(x) = x.as_ref() {
+                x.args.iter().for_each(|x| bare_items(x, ret));
+            }
+        }
+        x => ret.push(x),
+    }
+}
+
+/// Rewrite each item of a list argument
+pub(crate) fn map_items(arg: &mut Argument, mut f: impl FnMut(&str) -> String) {
+    let items: Vec<_> = list_items(arg).iter().map(|x| f(x)).collect();
+    match arg {
+        Argument::Word(x) => x.text = items.join(" "),
+        Argument::BraceGroup(x) => x.text = format!("{{{}}}", items.join(" ")),
+        Argument::StringGroup(x) => x.text = format!("\"{}\"", items.join(" ")),
+        Argument::CommandSubstitution(..) => (),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Parser;
+
+    fn rename(code: &str, renamer: &Renamer) -> String {
+        let mut sdc = Parser::parse(code, &"").unwrap();
+        renamer.rename(&mut sdc);
+        sdc.to_string()
+    }
+
+    #[test]
+    fn clock() {
+        let code = r##"create_clock -name CLK -period 10 [get_ports clk]
+create_generated_clock -name DIV -source [get_ports clk] -master_clock CLK -divide_by 2 [get_pins u_div/Q]
+set_input_delay 1 -clock CLK [get_ports din]
+set_output_delay 1 -clock [get_clocks CLK] [get_ports dout]
+set_clock_groups -asynchronous -group {CLK DIV} -group [get_clocks {EXT}]
+set_clock_uncertainty 0.1 -from CLK -rise_to [get_clocks CLK]
+set_false_path -from [get_clocks CL*] -to [get_clocks {CLK DIV}]
+set_max_delay 1 -from [all_registers -clock CLK]
+set_clock_latency 0.5 -clock [list CLK DIV] [get_pins u_div/Q]
+set_propagated_clock CLK
+set_clock_latency -source 0.2 {CLK u_div/Q}
+set_clock_uncertainty 0.1 [list DIV]
+set_clock_transition 0.1 CLK
+"##;
+        let mut renamer = Renamer::new();
+        renamer.clock("CLK", "CLK_A").clock("DIV", "CLK_A_DIV");
+        assert_eq!(
+            rename(code, &renamer),
+            r##"create_clock -period 10 -name CLK_A [get_ports clk]
+create_generated_clock -name CLK_A_DIV -source [get_ports clk] -divide_by 2 -master_clock CLK_A [get_pins u_div/Q]
+set_input_delay -clock CLK_A 1 [get_ports din]
+set_output_delay -clock [get_clocks CLK_A] 1 [get_ports dout]
+set_clock_groups -group {CLK_A CLK_A_DIV} -group [get_clocks {EXT}] -asynchronous
+set_clock_uncertainty -from CLK_A -rise_to [get_clocks CLK_A] 0.1
+set_false_path -from [get_clocks CL*] -to [get_clocks {CLK_A CLK_A_DIV}]
+set_max_delay -from [all_registers -clock CLK_A] 1
+set_clock_latency -clock [list CLK_A CLK_A_DIV] 0.5 [get_pins u_div/Q]
+set_propagated_clock CLK_A
+set_clock_latency -source 0.2 {CLK_A u_div/Q}
+set_clock_uncertainty 0.1 [list CLK_A_DIV]
+set_clock_transition 0.1 CLK_A
+"##
+        );
+    }
+
+    #[test]
+    fn unrenamed_pattern() {
+        let code = r##"set_false_path -from [get_clocks CL*] -to [get_clocks -regexp {^DIV$}]
+set_false_path -from [get_clocks CLK_*] -to [get_ports din*]
+"##;
+        let mut sdc = Parser::parse(code, &"").unwrap();
+        let mut renamer = Renamer::new();
+        renamer.clock("CLK", "SYS").clock("DIV", "SYS_DIV");
+        renamer.port("din", "data_in");
+        let errors: Vec<_> = renamer
+            .rename(&mut sdc)
+            .into_iter()
+            .map(|x| match x {
+                RenameError::UnrenamedPattern(pattern, from, _, location) => {
+                    (pattern, from, location.start_line)
+                }
+            })
+            .collect();
+        assert_eq!(
+            errors,
+            vec![
+                ("CL*".to_string(), "CLK".to_string(), 1),
+                ("^DIV$".to_string(), "DIV".to_string(), 1),
+                ("din*".to_string(), "din".to_string(), 2),
+            ]
+        );
+    }
+
+    #[test]
+    fn port_and_instance() {
+        let code = r##"set_input_delay 1 -clock CLK [get_ports {din din_valid}]
+set_false_path -from [get_ports din[0]] -through [get_pins u_core/u_ff/D]
+set_false_path -through [get_nets -of_objects [get_cells u_core]]
+set_false_path -through [get_pins -regexp u_core/.*]
+current_instance u_core/u_sub
+set_false_path -from din[1] -to u_core/ff/D
+set_multicycle_path 2 -through [list u_core u_core_ext/a [get_pins u_core/b]]
+"##;
+        let mut renamer = Renamer::new();
+        renamer
+            .port("din", "data_in")
+            .instance_prefix("u_core", "u_top/u_core");
+        assert_eq!(
+            rename(code, &renamer),
+            r##"set_input_delay -clock CLK 1 [get_ports {data_in din_valid}]
+set_false_path -from [get_ports data_in[0]] -through [get_pins u_top/u_core/u_ff/D]
+set_false_path -through [get_nets -of_objects [get_cells u_top/u_core]]
+set_false_path -through [get_pins -regexp u_core/.*]
+current_instance u_top/u_core/u_sub
+set_false_path -from data_in[1] -to u_top/u_core/ff/D
+set_multicycle_path -through [list u_top/u_core u_core_ext/a [get_pins u_top/u_core/b]] 2
+"##
+        );
+    }
+}
@@ -1,19 +1,33 @@
 pub(crate) mod argument;
 pub(crate) mod command;
+pub(crate) mod opensta;
+pub(crate) mod quartus;
+pub(crate) mod spec;
+pub(crate) mod synopsys;
 pub(crate) mod util;
+pub(crate) mod xdc;
 
 use crate::errors::{SemanticError, ValidateError};
+use crate::file_db::Location;
 use crate::parser::sdc_grammar_trait as grammar;
-use crate::sdc::util::{CommandExt, Extract, Validate};
+use crate::sdc::util::{Extract, Validate};
 pub use argument::Argument;
 pub use command::*;
+pub use opensta::*;
+pub use quartus::*;
+pub use spec::*;
 use std::fmt;
+use std::path::Path;
+pub use synopsys::*;
+pub use util::CommandExt;
+pub use xdc::*;
 
 /// SDC
 #[derive(Clone, Debug, Default, PartialEq)]
 pub struct Sdc {
     pub header: Vec<String>,
     pub version: Option<SdcVersion>,
+    pub dialect: Dialect,
     pub commands: Vec<Command>,
 }
 
@@ -25,9 +39,54 @@ impl Sdc {
         for command in &self.commands {
             ret.append(&mut command.validate(version));
         }
+        if self.dialect == Dialect::OpenSta {
+            opensta::compatibility(self, &mut ret);
+        }
         ret
     }
 
+    /// Minimum SDC version at which all commands and arguments are supported
+    ///
+    /// Returns `None` if no version supports all of them.
+    pub fn required_version(&self) -> Option<RequiredVersion> {
+        let unsupported = |version: SdcVersion| {
+            let mut ret = vec![];
+            for command in &self.commands {
+                for err in command.validate(version) {
+                    let (location, argument) = match err {
+                        ValidateError::CmdUnsupportedVersion(_, x) => (x, None),
+                        ValidateError::ArgUnsupportedVersion(_, x, y) => (x, Some(y)),
+                        _ => continue,
+                    };
+                    let kind = find_kind(command, &location).unwrap_or(command.kind());
+                    ret.push(VersionReason {
+                        kind,
+                        argument,
+                        location,
+                    });
+                }
+            }
+            ret
+        };
+
+        let versions = SdcVersion::all();
+        let index = versions.iter().position(|x| unsupported(*x).is_empty())?;
+        let reasons = match index {
+            0 => vec![],
+            _ => unsupported(versions[index - 1]),
+        };
+        Some(RequiredVersion {
+            version: versions[index],
+            reasons,
+        })
+    }
+
+    /// Append a command
+    pub fn push(&mut self, command: impl Into<Command>) -> &mut Self {
+        self.commands.push(command.into());
+        self
+    }
+
     pub fn normalize(&mut self) {
         let mut buf = vec![];
         let mut ret = vec![];
@@ -87,14 +146,26 @@ impl TryFrom<&grammar::Source<'_>> for Sdc {
     type Error = SemanticError;
 
     fn try_from(value: &grammar::Source<'_>) -> Result<Self, SemanticError> {
-        let mut sdc = Sdc::default();
+        Sdc::from_grammar(value, Dialect::Sdc)
+    }
+}
+
+impl Sdc {
+    pub(crate) fn from_grammar(
+        value: &grammar::Source<'_>,
+        dialect: Dialect,
+    ) -> Result<Self, SemanticError> {
+        let mut sdc = Sdc {
+            dialect,
+            ..Default::default()
+        };
         let mut is_header = true;
         let mut is_first_command = true;
         for source in &value.source_list {
             match source.source_list_group.as_ref() {
                 grammar::SourceListGroup::CommandLine(x) => {
                     is_header = false;
-                    let command = x.command_line.command.as_ref().try_into()?;
+                    let command = Command::from_grammar(x.command_line.command.as_ref(), dialect)?;
 
                     match command {
                         Command::Set(x) if x.variable_name.as_str() == "sdc_version" => {
@@ -131,6 +202,44 @@ impl TryFrom<&grammar::Source<'_>> for Sdc {
     }
 }
 
+/// Kind of the command at `location` including nested commands
+fn find_kind(command: &Command, location: &Location) -> Option<CommandKind> {
+    if &command.location() == location {
+        return Some(command.kind());
+    }
+    command.arguments().into_iter().find_map(|x| match x {
+        Argument::CommandSubstitution(x, _) => find_kind(x, location),
+        _ => None,
+    })
+}
+
+/// Minimum SDC version required by [`Sdc`]
+#[derive(Clone, Debug, PartialEq)]
+pub struct RequiredVersion {
+    pub version: SdcVersion,
+    /// Commands and arguments unsupported by the previous version
+    pub reasons: Vec<VersionReason>,
+}
+
+/// Command or argument which requires a newer SDC version
+#[derive(Clone, Debug, PartialEq)]
+pub struct VersionReason {
+    pub kind: CommandKind,
+    /// Argument name, `None` if the command itself is unsupported
+    pub argument: Option<String>,
+    pub location: Location,
+}
+
+impl fmt::Display for VersionReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = CommandSpec::of(self.kind).map(|x| x.name).unwrap_or("");
+        match &self.argument {
+            Some(x) => write!(f, "{name} -{x}"),
+            None => name.fmt(f),
+        }
+    }
+}
+
 /// SDC version
 #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
 pub enum SdcVersion {
@@ -159,10 +268,31 @@ pub enum SdcVersion {
 }
 
 impl SdcVersion {
+    pub fn all() -> &'static [SdcVersion] {
+        &[
+            SdcVersion::SDC1_1,
+            SdcVersion::SDC1_2,
+            SdcVersion::SDC1_3,
+            SdcVersion::SDC1_4,
+            SdcVersion::SDC1_5,
+            SdcVersion::SDC1_6,
+            SdcVersion::SDC1_7,
+            SdcVersion::SDC1_8,
+            SdcVersion::SDC1_9,
+            SdcVersion::SDC2_0,
+            SdcVersion::SDC2_1,
+        ]
+    }
+
     pub fn within(&self, from: SdcVersion, to: SdcVersion) -> (bool, SdcVersion) {
         (&from <= self && self <= &to, *self)
     }
 
+    /// Condition independent of version for commands which are not defined by SDC
+    pub(crate) fn any(&self) -> (bool, SdcVersion) {
+        (true, *self)
+    }
+
     pub fn version_string(&self) -> &str {
         match self {
             SdcVersion::SDC1_1 => "1.1",
@@ -218,3 +348,68 @@ impl TryFrom<&str> for SdcVersion {
         }
     }
 }
+
+/// SDC dialect
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Dialect {
+    /// Standard SDC
+    #[default]
+    Sdc,
+    /// Xilinx Design Constraints
+    Xdc,
+    /// Intel Quartus SDC extensions
+    Quartus,
+    /// Synopsys PrimeTime / Design Compiler extensions
+    Synopsys,
+    /// OpenSTA / OpenROAD
+    OpenSta,
+}
+
+impl Dialect {
+    pub fn all() -> &'static [Dialect] {
+        &[
+            Dialect::Sdc,
+            Dialect::Xdc,
+            Dialect::Quartus,
+            Dialect::Synopsys,
+            Dialect::OpenSta,
+        ]
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Dialect::Sdc => "sdc",
+            Dialect::Xdc => "xdc",
+            Dialect::Quartus => "quartus",
+            Dialect::Synopsys => "synopsys",
+            Dialect::OpenSta => "opensta",
+        }
+    }
+
+    /// Dialect implied by the file extension: `.xdc` is XDC, and others are SDC
+    pub fn from_path(path: &Path) -> Self {
+        if path.extension().map(|x| x.to_str()) == Some(Some("xdc")) {
+            Dialect::Xdc
+        } else {
+            Dialect::Sdc
+        }
+    }
+}
+
+impl fmt::Display for Dialect {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.name().fmt(f)
+    }
+}
+
+impl TryFrom<&str> for Dialect {
+    type Error = ();
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Dialect::all()
+            .iter()
+            .find(|x| x.name() == value)
+            .copied()
+            .ok_or(())
+    }
+}
@@ -3,7 +3,7 @@ use crate::errors::ValidateError;
 use crate::file_db::Location;
 use crate::parser::sdc_grammar_trait as grammar;
 use crate::sdc::util::CommandExt;
-use crate::sdc::{Command, CommandKind, SdcVersion, Validate};
+use crate::sdc::{Command, CommandKind, Dialect, List, SdcVersion, Validate};
 use std::fmt;
 
 /// Argument
@@ -68,12 +68,103 @@ impl fmt::Display for Argument {
 }
 
 impl From<&str> for Argument {
+    /// Word including whitespaces or Tcl special characters is enclosed by braces.
+    /// Word whose braces can't be enclosed, like `{a` or `{a} {b}`, is quoted instead.
     fn from(value: &str) -> Self {
         let location = Location::default();
-        Argument::Word(ArgumentWord {
-            text: value.into(),
-            location,
-        })
+        let special = |c: char| c.is_whitespace() || "[]{}$;\"\\".contains(c);
+        if braced(value) {
+            Argument::BraceGroup(ArgumentBraceGroup {
+                text: value.into(),
+                location,
+            })
+        } else if value.contains(['{', '}']) || value.ends_with('\\') {
+            let escaped = value.replace('\\', "\\\\").replace('"', "\\\"");
+            Argument::StringGroup(ArgumentStringGroup {
+                text: format!("\"{escaped}\""),
+                location,
+            })
+        } else if value.is_empty() || value.contains(special) {
+            Argument::BraceGroup(ArgumentBraceGroup {
+                text: format!("{{{value}}}"),
+                location,
+            })
+        } else {
+            Argument::Word(ArgumentWord {
+                text: value.into(),
+                location,
+            })
+        }
+    }
+}
+
+/// Whether every `{` in `value` is closed by `}`
+fn balanced_braces(value: &str) -> bool {
+    let mut depth = 0;
+    for c in value.chars() {
+        match c {
+            '{' => depth += 1,
+            '}' if depth == 0 => return false,
+            '}' => depth -= 1,
+            _ => (),
+        }
+    }
+    depth == 0
+}
+
+/// Whether `value` is a single brace group like `{a b}`, but not `{a} {b}`
+fn braced(value: &str) -> bool {
+    let Some(inner) = value.strip_prefix('{').and_then(|x| x.strip_suffix('}')) else {
+        return false;
+    };
+    balanced_braces(inner)
+}
+
+impl From<String> for Argument {
+    fn from(value: String) -> Self {
+        value.as_str().into()
+    }
+}
+
+impl From<f64> for Argument {
+    fn from(value: f64) -> Self {
+        value.to_string().into()
+    }
+}
+
+impl From<i32> for Argument {
+    fn from(value: i32) -> Self {
+        value.to_string().into()
+    }
+}
+
+impl<T: Into<Command>> From<T> for Argument {
+    fn from(value: T) -> Self {
+        Argument::CommandSubstitution(Box::new(value.into()), Location::default())
+    }
+}
+
+impl Argument {
+    /// List of values like `{0 5}`, or `[list ...]` if it includes commands
+    pub fn list<T: Into<Argument>>(values: impl IntoIterator<Item = T>) -> Argument {
+        let values: Vec<Argument> = values.into_iter().map(|x| x.into()).collect();
+        if values
+            .iter()
+            .any(|x| matches!(x, Argument::CommandSubstitution(_, _)))
+        {
+            let mut list = List::builder();
+            for x in values {
+                list.args(x);
+            }
+            // list command has no validation error
+            list.build().unwrap().into()
+        } else {
+            let text: Vec<_> = values.iter().map(|x| x.to_string()).collect();
+            Argument::BraceGroup(ArgumentBraceGroup {
+                text: format!("{{{}}}", text.join(" ")),
+                location: Location::default(),
+            })
+        }
     }
 }
 
@@ -81,6 +172,15 @@ impl TryFrom<&grammar::Argument<'_>> for Argument {
     type Error = SemanticError;
 
     fn try_from(value: &grammar::Argument) -> Result<Self, SemanticError> {
+        Argument::from_grammar(value, Dialect::Sdc)
+    }
+}
+
+impl Argument {
+    pub(crate) fn from_grammar(
+        value: &grammar::Argument,
+        dialect: Dialect,
+    ) -> Result<Self, SemanticError> {
         match value {
             grammar::Argument::TokenWord(x) => {
                 let text = x.token_word.term_word.term_word.text().to_string();
@@ -141,7 +241,10 @@ impl TryFrom<&grammar::Argument<'_>> for Argument {
                 let location = Location::from_to(&start, &end);
 
                 Ok(Self::CommandSubstitution(
-                    Box::new(x.command_substitution.command.as_ref().try_into()?),
+                    Box::new(Command::from_grammar(
+                        x.command_substitution.command.as_ref(),
+                        dialect,
+                    )?),
                     location,
                 ))
             }
@@ -5,9 +5,13 @@ use crate::errors::ValidateError;
 use crate::file_db::Location;
 use crate::parser::sdc_grammar_trait as grammar;
 use crate::sdc::argument::ArgumentWord;
+use crate::sdc::opensta::*;
+use crate::sdc::quartus::*;
+use crate::sdc::synopsys::*;
 use crate::sdc::util::*;
+use crate::sdc::xdc::*;
 use crate::sdc::SdcVersion::*;
-use crate::sdc::{Argument, SdcVersion};
+use crate::sdc::{Argument, CommandSpec, Dialect, SdcVersion, VersionRange};
 use regex::Regex;
 use std::fmt;
 use std::sync::OnceLock;
@@ -15,6 +19,7 @@ use std::sync::OnceLock;
 /// SDC kind
 #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
 pub enum CommandKind {
+    AddCellsToPblock,
     AllClocks,
     AllInputs,
     AllOutputs,
@@ -22,29 +27,52 @@ pub enum CommandKind {
     Argument,
     CreateClock,
     CreateGeneratedClock,
+    CreatePblock,
     CreateVoltageArea,
     CurrentDesign,
     CurrentInstance,
+    DeriveClockUncertainty,
+    DerivePllClocks,
     Expr,
     GetCells,
     GetClocks,
+    GetFanouts,
+    GetIobanks,
+    GetKeepers,
     GetLibCells,
     GetLibPins,
     GetLibs,
     GetNets,
+    GetPblocks,
     GetPins,
     GetPorts,
+    GetProperty,
+    GetRegisters,
+    GetTimingPaths,
     GroupPath,
     List,
+    RemoveCaseAnalysis,
+    RemoveClock,
+    RemoveClockGroups,
+    RemoveDisableTiming,
+    RemoveGeneratedClock,
+    RemoveInputDelay,
+    RemoveOutputDelay,
+    ResizePblock,
     Set,
+    SetAnnotatedDelay,
+    SetAppVar,
+    SetAssignedDelay,
     SetCaseAnalysis,
     SetClockGatingCheck,
+    SetClockGatingStyle,
     SetClockGroups,
     SetClockLatency,
     SetClockSense,
     SetClockTransition,
     SetClockUncertainty,
     SetDataCheck,
+    SetDisableInferredClockGating,
     SetDisableTiming,
     SetDrive,
     SetDrivingCell,
@@ -56,6 +84,7 @@ pub enum CommandKind {
     SetIdealTransition,
     SetInputDelay,
     SetInputTransition,
+    SetLatchLoopBreaker,
     SetLevelShifterStrategy,
     SetLevelShifterThreshold,
     SetLoad,
@@ -77,10 +106,13 @@ pub enum CommandKind {
     SetMulticyclePath,
     SetOperatingConditions,
     SetOutputDelay,
+    SetPathMargin,
     SetPortFanoutNumber,
     SetPropagatedClock,
+    SetProperty,
     SetResistance,
     SetSense,
+    SetTimeFormat,
     SetTimingDerate,
     SetUnits,
     SetVoltage,
@@ -91,38 +123,100 @@ pub enum CommandKind {
     Unknown,
 }
 
+impl CommandKind {
+    /// Dialect which defines this command
+    pub fn dialect(&self) -> Dialect {
+        match self {
+            CommandKind::AddCellsToPblock
+            | CommandKind::CreatePblock
+            | CommandKind::GetIobanks
+            | CommandKind::GetPblocks
+            | CommandKind::GetProperty
+            | CommandKind::ResizePblock
+            | CommandKind::SetProperty => Dialect::Xdc,
+            CommandKind::DeriveClockUncertainty
+            | CommandKind::DerivePllClocks
+            | CommandKind::GetFanouts
+            | CommandKind::GetKeepers
+            | CommandKind::GetRegisters
+            | CommandKind::SetTimeFormat => Dialect::Quartus,
+            CommandKind::GetTimingPaths
+            | CommandKind::RemoveCaseAnalysis
+            | CommandKind::RemoveClock
+            | CommandKind::RemoveClockGroups
+            | CommandKind::RemoveDisableTiming
+            | CommandKind::RemoveGeneratedClock
+            | CommandKind::RemoveInputDelay
+            | CommandKind::RemoveOutputDelay
+            | CommandKind::SetAnnotatedDelay
+            | CommandKind::SetAppVar
+            | CommandKind::SetClockGatingStyle
+            | CommandKind::SetLatchLoopBreaker
+            | CommandKind::SetPathMargin => Dialect::Synopsys,
+            CommandKind::SetAssignedDelay | CommandKind::SetDisableInferredClockGating => {
+                Dialect::OpenSta
+            }
+            _ => Dialect::Sdc,
+        }
+    }
+}
+
 /// SDC command
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
 pub enum Command {
+    AddCellsToPblock(AddCellsToPblock),
     AllClocks(AllClocks),
     AllInputs(AllInputs),
     AllOutputs(AllOutputs),
     AllRegisters(AllRegisters),
     CreateClock(CreateClock),
     CreateGeneratedClock(CreateGeneratedClock),
+    CreatePblock(CreatePblock),
     CreateVoltageArea(CreateVoltageArea),
     CurrentDesign(CurrentDesign),
     CurrentInstance(CurrentInstance),
+    DeriveClockUncertainty(DeriveClockUncertainty),
+    DerivePllClocks(DerivePllClocks),
     Expr(Expr),
     GetCells(GetCells),
     GetClocks(GetClocks),
+    GetFanouts(GetFanouts),
+    GetIobanks(GetIobanks),
+    GetKeepers(GetKeepers),
     GetLibCells(GetLibCells),
     GetLibPins(GetLibPins),
     GetLibs(GetLibs),
     GetNets(GetNets),
+    GetPblocks(GetPblocks),
     GetPins(GetPins),
     GetPorts(GetPorts),
+    GetProperty(GetProperty),
+    GetRegisters(GetRegisters),
+    GetTimingPaths(GetTimingPaths),
     GroupPath(GroupPath),
     List(List),
+    RemoveCaseAnalysis(RemoveCaseAnalysis),
+    RemoveClock(RemoveClock),
+    RemoveClockGroups(RemoveClockGroups),
+    RemoveDisableTiming(RemoveDisableTiming),
+    RemoveGeneratedClock(RemoveGeneratedClock),
+    RemoveInputDelay(RemoveInputDelay),
+    RemoveOutputDelay(RemoveOutputDelay),
+    ResizePblock(ResizePblock),
     Set(Set),
+    SetAnnotatedDelay(SetAnnotatedDelay),
+    SetAppVar(SetAppVar),
+    SetAssignedDelay(SetAssignedDelay),
     SetCaseAnalysis(SetCaseAnalysis),
     SetClockGatingCheck(SetClockGatingCheck),
+    SetClockGatingStyle(SetClockGatingStyle),
     SetClockGroups(SetClockGroups),
     SetClockLatency(SetClockLatency),
     SetClockSense(SetClockSense),
     SetClockTransition(SetClockTransition),
     SetClockUncertainty(SetClockUncertainty),
     SetDataCheck(SetDataCheck),
+    SetDisableInferredClockGating(SetDisableInferredClockGating),
     SetDisableTiming(SetDisableTiming),
     SetDrive(SetDrive),
     SetDrivingCell(SetDrivingCell),
@@ -134,6 +228,7 @@ pub enum Command {
     SetIdealTransition(SetIdealTransition),
     SetInputDelay(SetInputDelay),
     SetInputTransition(SetInputTransition),
+    SetLatchLoopBreaker(SetLatchLoopBreaker),
     SetLevelShifterStrategy(SetLevelShifterStrategy),
     SetLevelShifterThreshold(SetLevelShifterThreshold),
     SetLoad(SetLoad),
@@ -155,10 +250,13 @@ pub enum Command {
     SetMulticyclePath(SetMulticyclePath),
     SetOperatingConditions(SetOperatingConditions),
     SetOutputDelay(SetOutputDelay),
+    SetPathMargin(SetPathMargin),
     SetPortFanoutNumber(SetPortFanoutNumber),
     SetPropagatedClock(SetPropagatedClock),
+    SetProperty(SetProperty),
     SetResistance(SetResistance),
     SetSense(SetSense),
+    SetTimeFormat(SetTimeFormat),
     SetTimingDerate(SetTimingDerate),
     SetUnits(SetUnits),
     SetVoltage(SetVoltage),
@@ -172,35 +270,59 @@ pub enum Command {
 macro_rules! match_command {
     ($self: ident, |$x:ident| $e: expr) => {
         match $self {
+            Command::AddCellsToPblock($x)          => $e,
             Command::AllClocks($x)                 => $e,
             Command::AllInputs($x)                 => $e,
             Command::AllOutputs($x)                => $e,
             Command::AllRegisters($x)              => $e,
             Command::CreateClock($x)               => $e,
             Command::CreateGeneratedClock($x)      => $e,
+            Command::CreatePblock($x)              => $e,
             Command::CreateVoltageArea($x)         => $e,
             Command::CurrentDesign($x)             => $e,
             Command::CurrentInstance($x)           => $e,
+            Command::DeriveClockUncertainty($x)    => $e,
+            Command::DerivePllClocks($x)           => $e,
             Command::Expr($x)                      => $e,
             Command::GetCells($x)                  => $e,
             Command::GetClocks($x)                 => $e,
+            Command::GetFanouts($x)                => $e,
+            Command::GetIobanks($x)                => $e,
+            Command::GetKeepers($x)                => $e,
             Command::GetLibCells($x)               => $e,
             Command::GetLibPins($x)                => $e,
             Command::GetLibs($x)                   => $e,
             Command::GetNets($x)                   => $e,
+            Command::GetPblocks($x)                => $e,
             Command::GetPins($x)                   => $e,
             Command::GetPorts($x)                  => $e,
+            Command::GetProperty($x)               => $e,
+            Command::GetRegisters($x)              => $e,
+            Command::GetTimingPaths($x)            => $e,
             Command::GroupPath($x)                 => $e,
             Command::List($x)                      => $e,
+            Command::RemoveCaseAnalysis($x)        => $e,
+            Command::RemoveClock($x)               => $e,
+            Command::RemoveClockGroups($x)         => $e,
+            Command::RemoveDisableTiming($x)       => $e,
+            Command::RemoveGeneratedClock($x)      => $e,
+            Command::RemoveInputDelay($x)          => $e,
+            Command::RemoveOutputDelay($x)         => $e,
+            Command::ResizePblock($x)              => $e,
             Command::Set($x)                       => $e,
+            Command::SetAnnotatedDelay($x)         => $e,
+            Command::SetAppVar($x)                 => $e,
+            Command::SetAssignedDelay($x)          => $e,
             Command::SetCaseAnalysis($x)           => $e,
             Command::SetClockGatingCheck($x)       => $e,
+            Command::SetClockGatingStyle($x)       => $e,
             Command::SetClockGroups($x)            => $e,
             Command::SetClockLatency($x)           => $e,
             Command::SetClockSense($x)             => $e,
             Command::SetClockTransition($x)        => $e,
             Command::SetClockUncertainty($x)       => $e,
             Command::SetDataCheck($x)              => $e,
+            Command::SetDisableInferredClockGating($x)=> $e,
             Command::SetDisableTiming($x)          => $e,
             Command::SetDrive($x)                  => $e,
             Command::SetDrivingCell($x)            => $e,
@@ -212,6 +334,7 @@ macro_rules! match_command {
             Command::SetIdealTransition($x)        => $e,
             Command::SetInputDelay($x)             => $e,
             Command::SetInputTransition($x)        => $e,
+            Command::SetLatchLoopBreaker($x)       => $e,
             Command::SetLevelShifterStrategy($x)   => $e,
             Command::SetLevelShifterThreshold($x)  => $e,
             Command::SetLoad($x)                   => $e,
@@ -233,10 +356,13 @@ macro_rules! match_command {
             Command::SetMulticyclePath($x)         => $e,
             Command::SetOperatingConditions($x)    => $e,
             Command::SetOutputDelay($x)            => $e,
+            Command::SetPathMargin($x)             => $e,
             Command::SetPortFanoutNumber($x)       => $e,
             Command::SetPropagatedClock($x)        => $e,
+            Command::SetProperty($x)               => $e,
             Command::SetResistance($x)             => $e,
             Command::SetSense($x)                  => $e,
+            Command::SetTimeFormat($x)             => $e,
             Command::SetTimingDerate($x)           => $e,
             Command::SetUnits($x)                  => $e,
             Command::SetVoltage($x)                => $e,
@@ -249,6 +375,33 @@ macro_rules! match_command {
     }
 }
 
+impl Command {
+    /// Arguments of the command
+    pub fn arguments(&self) -> Vec<&Argument> {
+        match_command!(self, |x| { x.arguments() })
+    }
+
+    /// Mutable arguments of the command
+    pub fn arguments_mut(&mut self) -> Vec<&mut Argument> {
+        match_command!(self, |x| { x.arguments_mut() })
+    }
+
+    /// Values of option or positional argument `name`
+    pub fn values(&self, name: &str) -> Vec<&Argument> {
+        match_command!(self, |x| { x.values(name) })
+    }
+
+    /// Mutable values of option or positional argument `name`
+    pub fn values_mut(&mut self, name: &str) -> Vec<&mut Argument> {
+        match_command!(self, |x| { x.values_mut(name) })
+    }
+
+    /// Whether flag `name` is specified
+    pub fn flag(&self, name: &str) -> bool {
+        match_command!(self, |x| { x.flag(name) })
+    }
+}
+
 impl fmt::Display for Command {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match_command!(self, |x| { x.fmt(f) })
@@ -267,13 +420,18 @@ impl CommandExt for Command {
 
 impl Validate for Command {
     fn validate(&self, version: SdcVersion) -> Vec<ValidateError> {
-        match_command!(self, |x| { x.validate(version) })
+        let mut ret = self.validate_versions(version);
+        ret.append(&mut match_command!(self, |x| { x.validate(version) }));
+        ret
     }
 }
 
 impl Extract for Command {
     fn extract<'a>(&'a self, kind: CommandKind, list: &mut Vec<&'a Command>) {
         match self {
+            Command::AddCellsToPblock(_) if kind == CommandKind::AddCellsToPblock => {
+                list.push(self)
+            }
             Command::AllClocks(_) if kind == CommandKind::AllClocks => list.push(self),
             Command::AllInputs(_) if kind == CommandKind::AllInputs => list.push(self),
             Command::AllOutputs(_) if kind == CommandKind::AllOutputs => list.push(self),
@@ -282,27 +440,69 @@ impl Extract for Command {
             Command::CreateGeneratedClock(_) if kind == CommandKind::CreateGeneratedClock => {
                 list.push(self)
             }
+            Command::CreatePblock(_) if kind == CommandKind::CreatePblock => list.push(self),
             Command::CreateVoltageArea(_) if kind == CommandKind::CreateVoltageArea => {
                 list.push(self)
             }
             Command::CurrentDesign(_) if kind == CommandKind::CurrentDesign => list.push(self),
             Command::CurrentInstance(_) if kind == CommandKind::CurrentInstance => list.push(self),
+            Command::DeriveClockUncertainty(_) if kind == CommandKind::DeriveClockUncertainty => {
+                list.push(self)
+            }
+            Command::DerivePllClocks(_) if kind == CommandKind::DerivePllClocks => list.push(self),
             Command::Expr(_) if kind == CommandKind::Expr => list.push(self),
             Command::GetCells(_) if kind == CommandKind::GetCells => list.push(self),
             Command::GetClocks(_) if kind == CommandKind::GetClocks => list.push(self),
+            Command::GetFanouts(_) if kind == CommandKind::GetFanouts => list.push(self),
+            Command::GetIobanks(_) if kind == CommandKind::GetIobanks => list.push(self),
+            Command::GetKeepers(_) if kind == CommandKind::GetKeepers => list.push(self),
             Command::GetLibCells(_) if kind == CommandKind::GetLibCells => list.push(self),
             Command::GetLibPins(_) if kind == CommandKind::GetLibPins => list.push(self),
             Command::GetLibs(_) if kind == CommandKind::GetLibs => list.push(self),
             Command::GetNets(_) if kind == CommandKind::GetNets => list.push(self),
+            Command::GetPblocks(_) if kind == CommandKind::GetPblocks => list.push(self),
             Command::GetPins(_) if kind == CommandKind::GetPins => list.push(self),
             Command::GetPorts(_) if kind == CommandKind::GetPorts => list.push(self),
+            Command::GetProperty(_) if kind == CommandKind::GetProperty => list.push(self),
+            Command::GetRegisters(_) if kind == CommandKind::GetRegisters => list.push(self),
+            Command::GetTimingPaths(_) if kind == CommandKind::GetTimingPaths => list.push(self),
             Command::GroupPath(_) if kind == CommandKind::GroupPath => list.push(self),
             Command::List(_) if kind == CommandKind::List => list.push(self),
+            Command::RemoveCaseAnalysis(_) if kind == CommandKind::RemoveCaseAnalysis => {
+                list.push(self)
+            }
+            Command::RemoveClock(_) if kind == CommandKind::RemoveClock => list.push(self),
+            Command::RemoveClockGroups(_) if kind == CommandKind::RemoveClockGroups => {
+                list.push(self)
+            }
+            Command::RemoveDisableTiming(_) if kind == CommandKind::RemoveDisableTiming => {
+                list.push(self)
+            }
+            Command::RemoveGeneratedClock(_) if kind == CommandKind::RemoveGeneratedClock => {
+                list.push(self)
+            }
+            Command::RemoveInputDelay(_) if kind == CommandKind::RemoveInputDelay => {
+                list.push(self)
+            }
+            Command::RemoveOutputDelay(_) if kind == CommandKind::RemoveOutputDelay => {
+                list.push(self)
+            }
+            Command::ResizePblock(_) if kind == CommandKind::ResizePblock => list.push(self),
             Command::Set(_) if kind == CommandKind::Set => list.push(self),
+            Command::SetAnnotatedDelay(_) if kind == CommandKind::SetAnnotatedDelay => {
+                list.push(self)
+            }
+            Command::SetAppVar(_) if kind == CommandKind::SetAppVar => list.push(self),
+            Command::SetAssignedDelay(_) if kind == CommandKind::SetAssignedDelay => {
+                list.push(self)
+            }
             Command::SetCaseAnalysis(_) if kind == CommandKind::SetCaseAnalysis => list.push(self),
             Command::SetClockGatingCheck(_) if kind == CommandKind::SetClockGatingCheck => {
                 list.push(self)
             }
+            Command::SetClockGatingStyle(_) if kind == CommandKind::SetClockGatingStyle => {
+                list.push(self)
+            }
             Command::SetClockGroups(_) if kind == CommandKind::SetClockGroups => list.push(self),
             Command::SetClockLatency(_) if kind == CommandKind::SetClockLatency => list.push(self),
             Command::SetClockSense(_) if kind == CommandKind::SetClockSense => list.push(self),
@@ -313,6 +513,11 @@ impl Extract for Command {
                 list.push(self)
             }
             Command::SetDataCheck(_) if kind == CommandKind::SetDataCheck => list.push(self),
+            Command::SetDisableInferredClockGating(_)
+                if kind == CommandKind::SetDisableInferredClockGating =>
+            {
+                list.push(self)
+            }
             Command::SetDisableTiming(_) if kind == CommandKind::SetDisableTiming => {
                 list.push(self)
             }
@@ -332,6 +537,9 @@ impl Extract for Command {
             Command::SetInputTransition(_) if kind == CommandKind::SetInputTransition => {
                 list.push(self)
             }
+            Command::SetLatchLoopBreaker(_) if kind == CommandKind::SetLatchLoopBreaker => {
+                list.push(self)
+            }
             Command::SetLevelShifterStrategy(_) if kind == CommandKind::SetLevelShifterStrategy => {
                 list.push(self)
             }
@@ -377,14 +585,17 @@ impl Extract for Command {
                 list.push(self)
             }
             Command::SetOutputDelay(_) if kind == CommandKind::SetOutputDelay => list.push(self),
+            Command::SetPathMargin(_) if kind == CommandKind::SetPathMargin => list.push(self),
             Command::SetPortFanoutNumber(_) if kind == CommandKind::SetPortFanoutNumber => {
                 list.push(self)
             }
             Command::SetPropagatedClock(_) if kind == CommandKind::SetPropagatedClock => {
                 list.push(self)
             }
+            Command::SetProperty(_) if kind == CommandKind::SetProperty => list.push(self),
             Command::SetResistance(_) if kind == CommandKind::SetResistance => list.push(self),
             Command::SetSense(_) if kind == CommandKind::SetSense => list.push(self),
+            Command::SetTimeFormat(_) if kind == CommandKind::SetTimeFormat => list.push(self),
             Command::SetTimingDerate(_) if kind == CommandKind::SetTimingDerate => list.push(self),
             Command::SetUnits(_) if kind == CommandKind::SetUnits => list.push(self),
             Command::SetVoltage(_) if kind == CommandKind::SetVoltage => list.push(self),
@@ -409,6 +620,9 @@ impl Extract for Command {
 
     fn extract_mut<'a>(&'a mut self, kind: CommandKind, list: &mut Vec<&'a mut Command>) {
         match self {
+            Command::AddCellsToPblock(_) if kind == CommandKind::AddCellsToPblock => {
+                list.push(self)
+            }
             Command::AllClocks(_) if kind == CommandKind::AllClocks => list.push(self),
             Command::AllInputs(_) if kind == CommandKind::AllInputs => list.push(self),
             Command::AllOutputs(_) if kind == CommandKind::AllOutputs => list.push(self),
@@ -417,27 +631,69 @@ impl Extract for Command {
             Command::CreateGeneratedClock(_) if kind == CommandKind::CreateGeneratedClock => {
                 list.push(self)
             }
+            Command::CreatePblock(_) if kind == CommandKind::CreatePblock => list.push(self),
             Command::CreateVoltageArea(_) if kind == CommandKind::CreateVoltageArea => {
                 list.push(self)
             }
             Command::CurrentDesign(_) if kind == CommandKind::CurrentDesign => list.push(self),
             Command::CurrentInstance(_) if kind == CommandKind::CurrentInstance => list.push(self),
+            Command::DeriveClockUncertainty(_) if kind == CommandKind::DeriveClockUncertainty => {
+                list.push(self)
+            }
+            Command::DerivePllClocks(_) if kind == CommandKind::DerivePllClocks => list.push(self),
             Command::Expr(_) if kind == CommandKind::Expr => list.push(self),
             Command::GetCells(_) if kind == CommandKind::GetCells => list.push(self),
             Command::GetClocks(_) if kind == CommandKind::GetClocks => list.push(self),
+            Command::GetFanouts(_) if kind == CommandKind::GetFanouts => list.push(self),
+            Command::GetIobanks(_) if kind == CommandKind::GetIobanks => list.push(self),
+            Command::GetKeepers(_) if kind == CommandKind::GetKeepers => list.push(self),
             Command::GetLibCells(_) if kind == CommandKind::GetLibCells => list.push(self),
             Command::GetLibPins(_) if kind == CommandKind::GetLibPins => list.push(self),
             Command::GetLibs(_) if kind == CommandKind::GetLibs => list.push(self),
             Command::GetNets(_) if kind == CommandKind::GetNets => list.push(self),
+            Command::GetPblocks(_) if kind == CommandKind::GetPblocks => list.push(self),
             Command::GetPins(_) if kind == CommandKind::GetPins => list.push(self),
             Command::GetPorts(_) if kind == CommandKind::GetPorts => list.push(self),
+            Command::GetProperty(_) if kind == CommandKind::GetProperty => list.push(self),
+            Command::GetRegisters(_) if kind == CommandKind::GetRegisters => list.push(self),
+            Command::GetTimingPaths(_) if kind == CommandKind::GetTimingPaths => list.push(self),
             Command::GroupPath(_) if kind == CommandKind::GroupPath => list.push(self),
             Command::List(_) if kind == CommandKind::List => list.push(self),
+            Command::RemoveCaseAnalysis(_) if kind == CommandKind::RemoveCaseAnalysis => {
+                list.push(self)
+            }
+            Command::RemoveClock(_) if kind == CommandKind::RemoveClock => list.push(self),
+            Command::RemoveClockGroups(_) if kind == CommandKind::RemoveClockGroups => {
+                list.push(self)
+            }
+            Command::RemoveDisableTiming(_) if kind == CommandKind::RemoveDisableTiming => {
+                list.push(self)
+            }
+            Command::RemoveGeneratedClock(_) if kind == CommandKind::RemoveGeneratedClock => {
+                list.push(self)
+            }
+            Command::RemoveInputDelay(_) if kind == CommandKind::RemoveInputDelay => {
+                list.push(self)
+            }
+            Command::RemoveOutputDelay(_) if kind == CommandKind::RemoveOutputDelay => {
+                list.push(self)
+            }
+            Command::ResizePblock(_) if kind == CommandKind::ResizePblock => list.push(self),
             Command::Set(_) if kind == CommandKind::Set => list.push(self),
+            Command::SetAnnotatedDelay(_) if kind == CommandKind::SetAnnotatedDelay => {
+                list.push(self)
+            }
+            Command::SetAppVar(_) if kind == CommandKind::SetAppVar => list.push(self),
+            Command::SetAssignedDelay(_) if kind == CommandKind::SetAssignedDelay => {
+                list.push(self)
+            }
             Command::SetCaseAnalysis(_) if kind == CommandKind::SetCaseAnalysis => list.push(self),
             Command::SetClockGatingCheck(_) if kind == CommandKind::SetClockGatingCheck => {
                 list.push(self)
             }
+            Command::SetClockGatingStyle(_) if kind == CommandKind::SetClockGatingStyle => {
+                list.push(self)
+            }
             Command::SetClockGroups(_) if kind == CommandKind::SetClockGroups => list.push(self),
             Command::SetClockLatency(_) if kind == CommandKind::SetClockLatency => list.push(self),
             Command::SetClockSense(_) if kind == CommandKind::SetClockSense => list.push(self),
@@ -448,6 +704,11 @@ impl Extract for Command {
                 list.push(self)
             }
             Command::SetDataCheck(_) if kind == CommandKind::SetDataCheck => list.push(self),
+            Command::SetDisableInferredClockGating(_)
+                if kind == CommandKind::SetDisableInferredClockGating =>
+            {
+                list.push(self)
+            }
             Command::SetDisableTiming(_) if kind == CommandKind::SetDisableTiming => {
                 list.push(self)
             }
@@ -467,6 +728,9 @@ impl Extract for Command {
             Command::SetInputTransition(_) if kind == CommandKind::SetInputTransition => {
                 list.push(self)
             }
+            Command::SetLatchLoopBreaker(_) if kind == CommandKind::SetLatchLoopBreaker => {
+                list.push(self)
+            }
             Command::SetLevelShifterStrategy(_) if kind == CommandKind::SetLevelShifterStrategy => {
                 list.push(self)
             }
@@ -512,14 +776,17 @@ impl Extract for Command {
                 list.push(self)
             }
             Command::SetOutputDelay(_) if kind == CommandKind::SetOutputDelay => list.push(self),
+            Command::SetPathMargin(_) if kind == CommandKind::SetPathMargin => list.push(self),
             Command::SetPortFanoutNumber(_) if kind == CommandKind::SetPortFanoutNumber => {
                 list.push(self)
             }
             Command::SetPropagatedClock(_) if kind == CommandKind::SetPropagatedClock => {
                 list.push(self)
             }
+            Command::SetProperty(_) if kind == CommandKind::SetProperty => list.push(self),
             Command::SetResistance(_) if kind == CommandKind::SetResistance => list.push(self),
             Command::SetSense(_) if kind == CommandKind::SetSense => list.push(self),
+            Command::SetTimeFormat(_) if kind == CommandKind::SetTimeFormat => list.push(self),
             Command::SetTimingDerate(_) if kind == CommandKind::SetTimingDerate => list.push(self),
             Command::SetUnits(_) if kind == CommandKind::SetUnits => list.push(self),
             Command::SetVoltage(_) if kind == CommandKind::SetVoltage => list.push(self),
@@ -620,12 +887,21 @@ impl TryFrom<&grammar::Command<'_>> for Command {
     type Error = SemanticError;
 
     fn try_from(value: &grammar::Command<'_>) -> Result<Self, SemanticError> {
+        Command::from_grammar(value, Dialect::Sdc)
+    }
+}
+
+impl Command {
+    pub(crate) fn from_grammar(
+        value: &grammar::Command<'_>,
+        dialect: Dialect,
+    ) -> Result<Self, SemanticError> {
         let command = value.token_word.term_word.term_word.text();
         let start: Location = (&value.token_word.term_word.term_word.location).into();
 
         let mut args: Vec<Argument> = vec![];
         for arg in &value.command_list {
-            args.push(arg.argument.as_ref().try_into()?);
+            args.push(Argument::from_grammar(arg.argument.as_ref(), dialect)?);
         }
 
         let args = adjust_bus_notation(args);
@@ -637,42 +913,78 @@ impl TryFrom<&grammar::Command<'_>> for Command {
         };
 
         match command {
+            "add_cells_to_pblock" if dialect == Dialect::Xdc => add_cells_to_pblock(args, loc),
             "all_clocks" => all_clocks(args, loc),
             "all_inputs" => all_inputs(args, loc),
             "all_outputs" => all_outputs(args, loc),
             "all_registers" => all_registers(args, loc),
             "create_clock" => create_clock(args, loc),
-            "create_generated_clock" => create_generated_clock(args, loc),
+            "create_generated_clock" => create_generated_clock(args, loc, dialect),
+            "create_pblock" if dialect == Dialect::Xdc => create_pblock(args, loc),
             "create_voltage_area" => create_voltage_area(args, loc),
             "current_design" => current_design(args, loc),
             "current_instance" => current_instance(args, loc),
+            "derive_clock_uncertainty" if dialect == Dialect::Quartus => {
+                derive_clock_uncertainty(args, loc)
+            }
+            "derive_pll_clocks" if dialect == Dialect::Quartus => derive_pll_clocks(args, loc),
             "expr" => expr(args, loc),
-            "get_cell" => get_cells(args, loc, true),
-            "get_cells" => get_cells(args, loc, false),
-            "get_clocks" => get_clocks(args, loc),
+            "get_cell" => get_cells(args, loc, true, dialect),
+            "get_cells" => get_cells(args, loc, false, dialect),
+            "get_clocks" => get_clocks(args, loc, dialect),
+            "get_fanouts" if dialect == Dialect::Quartus => get_fanouts(args, loc),
+            "get_iobanks" if dialect == Dialect::Xdc => get_iobanks(args, loc),
+            "get_keepers" if dialect == Dialect::Quartus => get_keepers(args, loc),
             "get_lib_cell" => get_lib_cells(args, loc, true),
             "get_lib_cells" => get_lib_cells(args, loc, false),
             "get_lib_pin" => get_lib_pins(args, loc, true),
             "get_lib_pins" => get_lib_pins(args, loc, false),
             "get_libs" => get_libs(args, loc),
-            "get_net" => get_nets(args, loc, true),
-            "get_nets" => get_nets(args, loc, false),
-            "get_pin" => get_pins(args, loc, true),
-            "get_pins" => get_pins(args, loc, false),
-            "get_port" => get_ports(args, loc, true),
-            "get_ports" => get_ports(args, loc, false),
+            "get_net" => get_nets(args, loc, true, dialect),
+            "get_nets" => get_nets(args, loc, false, dialect),
+            "get_pblocks" if dialect == Dialect::Xdc => get_pblocks(args, loc),
+            "get_pin" => get_pins(args, loc, true, dialect),
+            "get_pins" => get_pins(args, loc, false, dialect),
+            "get_port" => get_ports(args, loc, true, dialect),
+            "get_ports" => get_ports(args, loc, false, dialect),
+            "get_property" if dialect == Dialect::Xdc => get_property(args, loc),
+            "get_registers" if dialect == Dialect::Quartus => get_registers(args, loc),
+            "get_timing_paths" if dialect == Dialect::Synopsys => get_timing_paths(args, loc),
             "group_path" => group_path(args, loc),
             "list" => list(args, loc),
+            "remove_case_analysis" if dialect == Dialect::Synopsys => {
+                remove_case_analysis(args, loc)
+            }
+            "remove_clock" if dialect == Dialect::Synopsys => remove_clock(args, loc),
+            "remove_clock_groups" if dialect == Dialect::Synopsys => remove_clock_groups(args, loc),
+            "remove_disable_timing" if dialect == Dialect::Synopsys => {
+                remove_disable_timing(args, loc)
+            }
+            "remove_generated_clock" if dialect == Dialect::Synopsys => {
+                remove_generated_clock(args, loc)
+            }
+            "remove_input_delay" if dialect == Dialect::Synopsys => remove_input_delay(args, loc),
+            "remove_output_delay" if dialect == Dialect::Synopsys => remove_output_delay(args, loc),
+            "resize_pblock" if dialect == Dialect::Xdc => resize_pblock(args, loc),
             "set" => set(args, loc),
+            "set_annotated_delay" if dialect == Dialect::Synopsys => set_annotated_delay(args, loc),
+            "set_app_var" if dialect == Dialect::Synopsys => set_app_var(args, loc),
+            "set_assigned_delay" if dialect == Dialect::OpenSta => set_assigned_delay(args, loc),
             "set_case_analysis" => set_case_analysis(args, loc),
             "set_clock_gating_check" => set_clock_gating_check(args, loc),
-            "set_clock_group" => set_clock_groups(args, loc, true),
-            "set_clock_groups" => set_clock_groups(args, loc, false),
+            "set_clock_group" => set_clock_groups(args, loc, true, dialect),
+            "set_clock_gating_style" if dialect == Dialect::Synopsys => {
+                set_clock_gating_style(args, loc)
+            }
+            "set_clock_groups" => set_clock_groups(args, loc, false, dialect),
             "set_clock_latency" => set_clock_latency(args, loc),
-            "set_clock_sense" => set_clock_sense(args, loc),
+            "set_clock_sense" => set_clock_sense(args, loc, dialect),
             "set_clock_transition" => set_clock_transition(args, loc),
             "set_clock_uncertainty" => set_clock_uncertainty(args, loc),
             "set_data_check" => set_data_check(args, loc),
+            "set_disable_inferred_clock_gating" if dialect == Dialect::OpenSta => {
+                set_disable_inferred_clock_gating(args, loc)
+            }
             "set_disable_timing" => set_disable_timing(args, loc),
             "set_drive" => set_drive(args, loc),
             "set_driving_cell" => set_driving_cell(args, loc),
@@ -682,8 +994,11 @@ impl TryFrom<&grammar::Command<'_>> for Command {
             "set_ideal_latency" => set_ideal_latency(args, loc),
             "set_ideal_network" => set_ideal_network(args, loc),
             "set_ideal_transition" => set_ideal_transition(args, loc),
-            "set_input_delay" => set_input_delay(args, loc),
+            "set_input_delay" => set_input_delay(args, loc, dialect),
             "set_input_transition" => set_input_transition(args, loc),
+            "set_latch_loop_breaker" if dialect == Dialect::Synopsys => {
+                set_latch_loop_breaker(args, loc)
+            }
             "set_level_shifter_strategy" => set_level_shifter_strategy(args, loc),
             "set_level_shifter_threshold" => set_level_shifter_threshold(args, loc),
             "set_load" => set_load(args, loc),
@@ -692,7 +1007,7 @@ impl TryFrom<&grammar::Command<'_>> for Command {
             "set_logic_zero" => set_logic_zero(args, loc),
             "set_max_area" => set_max_area(args, loc),
             "set_max_capacitance" => set_max_capacitance(args, loc),
-            "set_max_delay" => set_max_delay(args, loc),
+            "set_max_delay" => set_max_delay(args, loc, dialect),
             "set_max_dynamic_power" => set_max_dynamic_power(args, loc),
             "set_max_fanout" => set_max_fanout(args, loc),
             "set_max_leakage_power" => set_max_leakage_power(args, loc),
@@ -704,11 +1019,14 @@ impl TryFrom<&grammar::Command<'_>> for Command {
             "set_min_pulse_width" => set_min_pulse_width(args, loc),
             "set_multicycle_path" => set_multicycle_path(args, loc),
             "set_operating_conditions" => set_operating_conditions(args, loc),
-            "set_output_delay" => set_output_delay(args, loc),
+            "set_output_delay" => set_output_delay(args, loc, dialect),
+            "set_path_margin" if dialect == Dialect::Synopsys => set_path_margin(args, loc),
             "set_port_fanout_number" => set_port_fanout_number(args, loc),
             "set_propagated_clock" => set_propagated_clock(args, loc),
+            "set_property" if dialect == Dialect::Xdc => set_property(args, loc),
             "set_resistance" => set_resistance(args, loc),
             "set_sense" => set_sense(args, loc),
+            "set_time_format" if dialect == Dialect::Quartus => set_time_format(args, loc),
             "set_timing_derate" => set_timing_derate(args, loc),
             "set_unit" => set_units(args, loc, true),
             "set_units" => set_units(args, loc, false),
@@ -720,6 +1038,149 @@ impl TryFrom<&grammar::Command<'_>> for Command {
             x => unknown(x, args, loc),
         }
     }
+
+    /// Replace alias names (e.g. `get_cell`) by the canonical names
+    pub(crate) fn unalias(&mut self) {
+        let kinds = [
+            CommandKind::GetCells,
+            CommandKind::GetLibCells,
+            CommandKind::GetLibPins,
+            CommandKind::GetNets,
+            CommandKind::GetPins,
+            CommandKind::GetPorts,
+            CommandKind::SetClockGroups,
+            CommandKind::SetUnits,
+        ];
+        for kind in kinds {
+            let mut list = vec![];
+            self.extract_mut(kind, &mut list);
+            for command in list {
+                match command {
+                    Command::GetCells(x) => x.alias = false,
+                    Command::GetLibCells(x) => x.alias = false,
+                    Command::GetLibPins(x) => x.alias = false,
+                    Command::GetNets(x) => x.alias = false,
+                    Command::GetPins(x) => x.alias = false,
+                    Command::GetPorts(x) => x.alias = false,
+                    Command::SetClockGroups(x) => x.alias = false,
+                    Command::SetUnits(x) => x.alias = false,
+                    _ => (),
+                }
+            }
+        }
+    }
+
+    /// Whether the command is written by the alias name
+    fn is_alias(&self) -> bool {
+        match self {
+            Command::GetCells(x) => x.alias,
+            Command::GetLibCells(x) => x.alias,
+            Command::GetLibPins(x) => x.alias,
+            Command::GetNets(x) => x.alias,
+            Command::GetPins(x) => x.alias,
+            Command::GetPorts(x) => x.alias,
+            Command::SetClockGroups(x) => x.alias,
+            Command::SetUnits(x) => x.alias,
+            _ => false,
+        }
+    }
+
+    /// Check the command and options against versions of [`CommandSpec`]
+    fn validate_versions(&self, version: SdcVersion) -> Vec<ValidateError> {
+        let mut ret = vec![];
+        let Some(spec) = CommandSpec::of(self.kind()) else {
+            return ret;
+        };
+        // Commands of dialect extension have no version
+        let Some(versions) = spec.versions else {
+            return ret;
+        };
+
+        // OpenSTA keeps set_clock_sense after SDC 2.1
+        let versions = match self {
+            Command::SetClockSense(x) if x.dialect == Dialect::OpenSta => {
+                VersionRange::new(versions.from, SDC2_1)
+            }
+            _ => versions,
+        };
+        let location = self.location();
+        if !versions.contains(version) {
+            ret.push(ValidateError::CmdUnsupportedVersion(
+                version,
+                location.clone(),
+            ));
+        }
+        if self.is_alias() && spec.alias_versions.is_some_and(|x| !x.contains(version)) {
+            ret.push(ValidateError::CmdUnsupportedVersion(
+                version,
+                location.clone(),
+            ));
+        }
+
+        for option in spec.options.iter().filter(|x| x.dialect.is_none()) {
+            let Some(versions) = option.versions else {
+                continue;
+            };
+            let name = option.name.trim_start_matches('-');
+            // Synopsys allows -reference_pin regardless of sdc_version
+            let any_version = name == "reference_pin"
+                && match self {
+                    Command::SetInputDelay(x) => x.dialect == Dialect::Synopsys,
+                    Command::SetOutputDelay(x) => x.dialect == Dialect::Synopsys,
+                    _ => false,
+                };
+            let exist = if option.is_flag() {
+                self.flag(name)
+            } else {
+                !self.values(name).is_empty()
+            };
+            if exist && !any_version && !versions.contains(version) {
+                ret.push(ValidateError::ArgUnsupportedVersion(
+                    version,
+                    location.clone(),
+                    name.into(),
+                ));
+            }
+        }
+        ret
+    }
+
+    /// Replace `get_ports` by `get_pins` with the same options
+    pub(crate) fn ports_to_pins(&mut self) {
+        if let Command::GetPorts(x) = self {
+            *self = Command::GetPins(GetPins {
+                hierarchical: x.hierarchical,
+                hsc: None,
+                regexp: x.regexp,
+                nocase: x.nocase,
+                filter: x.filter.take(),
+                of_objects: x.of_objects.take(),
+                quiet: x.quiet,
+                patterns: x.patterns.take(),
+                location: x.location.clone(),
+                alias: false,
+            });
+        }
+    }
+
+    /// Replace `get_pins` by `get_ports`
+    ///
+    /// `-hsc` is dropped because `get_ports` doesn't have it.
+    pub(crate) fn pins_to_ports(&mut self) {
+        if let Command::GetPins(x) = self {
+            *self = Command::GetPorts(GetPorts {
+                hierarchical: x.hierarchical,
+                regexp: x.regexp,
+                nocase: x.nocase,
+                filter: x.filter.take(),
+                of_objects: x.of_objects.take(),
+                quiet: x.quiet,
+                patterns: x.patterns.take(),
+                location: x.location.clone(),
+                alias: false,
+            });
+        }
+    }
 }
 
 /// all_clocks
@@ -728,6 +1189,17 @@ pub struct AllClocks {
     location: Location,
 }
 
+builder!(
+    AllClocks,
+    AllClocksBuilder {
+        mandatory: [],
+        optional: [],
+        flag: [],
+        repeatable: [],
+        extra: [],
+    }
+);
+
 impl fmt::Display for AllClocks {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let text = "all_clocks".to_string();
@@ -746,10 +1218,8 @@ impl CommandExt for AllClocks {
 }
 
 impl Validate for AllClocks {
-    fn validate(&self, version: SdcVersion) -> Vec<ValidateError> {
-        let mut ret = vec![];
-        self.cmd_supported_version(&mut ret, version.within(SDC1_1, SDC2_1));
-        ret
+    fn validate(&self, _version: SdcVersion) -> Vec<ValidateError> {
+        vec![]
     }
 }
 
@@ -772,6 +1242,17 @@ pub struct AllInputs {
     location: Location,
 }
 
+builder!(
+    AllInputs,
+    AllInputsBuilder {
+        mandatory: [],
+        optional: [clock],
+        flag: [level_sensitive, edge_triggered],
+        repeatable: [],
+        extra: [],
+    }
+);
+
 impl fmt::Display for AllInputs {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut text = "all_inputs".to_string();
@@ -795,7 +1276,6 @@ impl CommandExt for AllInputs {
 impl Validate for AllInputs {
     fn validate(&self, version: SdcVersion) -> Vec<ValidateError> {
         let mut ret = vec![];
-        self.cmd_supported_version(&mut ret, version.within(SDC1_1, SDC2_1));
         self.arg_comb2(
             &mut ret,
             version.within(SDC1_1, SDC2_1),
@@ -824,8 +1304,7 @@ fn all_inputs(args: Vec<Argument>, location: Location) -> Result<Command, Semant
     let mut clock = None;
 
     static DICT: OnceLock<LazyDict> = OnceLock::new();
-    let dict =
-        DICT.get_or_init(|| LazyDict::new(&["-level_sensitive", "-edge_triggered", "-clock"]));
+    let dict = DICT.get_or_init(|| LazyDict::from_kind(CommandKind::AllInputs));
 
     let mut iter = args.into_iter();
     while let Some(arg) = iter.next() {
@@ -854,6 +1333,17 @@ pub struct AllOutputs {
     location: Location,
 }
 
+builder!(
+    AllOutputs,
+    AllOutputsBuilder {
+        mandatory: [],
+        optional: [clock],
+        flag: [level_sensitive, edge_triggered],
+        repeatable: [],
+        extra: [],
+    }
+);
+
 impl fmt::Display for AllOutputs {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut text = "all_outputs".to_string();
@@ -877,7 +1367,6 @@ impl CommandExt for AllOutputs {
 impl Validate for AllOutputs {
     fn validate(&self, version: SdcVersion) -> Vec<ValidateError> {
         let mut ret = vec![];
-        self.cmd_supported_version(&mut ret, version.within(SDC1_1, SDC2_1));
         self.arg_comb2(
             &mut ret,
             version.within(SDC1_2, SDC2_1),
@@ -906,8 +1395,7 @@ fn all_outputs(args: Vec<Argument>, location: Location) -> Result<Command, Seman
     let mut clock = None;
 
     static DICT: OnceLock<LazyDict> = OnceLock::new();
-    let dict =
-        DICT.get_or_init(|| LazyDict::new(&["-level_sensitive", "-edge_triggered", "-clock"]));
+    let dict = DICT.get_or_init(|| LazyDict::from_kind(CommandKind::AllOutputs));
 
     let mut iter = args.into_iter();
     while let Some(arg) = iter.next() {
@@ -946,6 +1434,28 @@ pub struct AllRegisters {
     location: Location,
 }
 
+builder!(
+    AllRegisters,
+    AllRegistersBuilder {
+        mandatory: [],
+        optional: [clock, rise_clock, fall_clock],
+        flag: [
+            no_hierarchy,
+            cells,
+            data_pins,
+            clock_pins,
+            slave_clock_pins,
+            async_pins,
+            output_pins,
+            level_sensitive,
+            edge_triggered,
+            master_slave,
+        ],
+        repeatable: [],
+        extra: [],
+    }
+);
+
 impl fmt::Display for AllRegisters {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut text = "all_registers".to_string();
@@ -978,7 +1488,6 @@ impl CommandExt for AllRegisters {
 impl Validate for AllRegisters {
     fn validate(&self, version: SdcVersion) -> Vec<ValidateError> {
         let mut ret = vec![];
-        self.cmd_supported_version(&mut ret, version.within(SDC1_7, SDC2_1));
         validate_opt(&mut ret, version, &self.clock);
         validate_opt(&mut ret, version, &self.rise_clock);
         validate_opt(&mut ret, version, &self.fall_clock);
@@ -1016,23 +1525,7 @@ fn all_registers(args: Vec<Argument>, location: Location) -> Result<Command, Sem
     let mut master_slave = false;
 
     static DICT: OnceLock<LazyDict> = OnceLock::new();
-    let dict = DICT.get_or_init(|| {
-        LazyDict::new(&[
-            "-no_hierarchy",
-            "-clock",
-            "-rise_clock",
-            "-fall_clock",
-            "-cells",
-            "-data_pins",
-            "-clock_pins",
-            "-slave_clock_pins",
-            "-async_pins",
-            "-output_pins",
-            "-level_sensitive",
-            "-edge_triggered",
-            "-master_slave",
-        ])
-    });
+    let dict = DICT.get_or_init(|| LazyDict::from_kind(CommandKind::AllRegisters));
 
     let mut iter = args.into_iter();
     while let Some(arg) = iter.next() {
@@ -1084,6 +1577,17 @@ pub struct CreateClock {
     location: Location,
 }
 
+builder!(
+    CreateClock,
+    CreateClockBuilder {
+        mandatory: [period],
+        optional: [name, waveform, comment, source_objects],
+        flag: [add],
+        repeatable: [],
+        extra: [],
+    }
+);
+
 impl fmt::Display for CreateClock {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut text = "create_clock".to_string();
@@ -1110,14 +1614,6 @@ impl CommandExt for CreateClock {
 impl Validate for CreateClock {
     fn validate(&self, version: SdcVersion) -> Vec<ValidateError> {
         let mut ret = vec![];
-        self.cmd_supported_version(&mut ret, version.within(SDC1_1, SDC2_1));
-        self.arg_supported_version(&mut ret, version.within(SDC1_4, SDC2_1), &self.add, "add");
-        self.arg_supported_version(
-            &mut ret,
-            version.within(SDC1_9, SDC2_1),
-            &self.comment,
-            "comment",
-        );
         self.arg_comb2(
             &mut ret,
             version.within(SDC1_2, SDC2_1),
@@ -1161,8 +1657,7 @@ fn create_clock(args: Vec<Argument>, location: Location) -> Result<Command, Sema
     let mut source_objects = None;
 
     static DICT: OnceLock<LazyDict> = OnceLock::new();
-    let dict =
-        DICT.get_or_init(|| LazyDict::new(&["-period", "-name", "-waveform", "-add", "-comment"]));
+    let dict = DICT.get_or_init(|| LazyDict::from_kind(CommandKind::CreateClock));
 
     let mut iter = args.into_iter();
     while let Some(arg) = iter.next() {
@@ -1206,8 +1701,17 @@ pub struct CreateGeneratedClock {
     pub comment: Option<Argument>,
     pub source_objects: Argument,
     location: Location,
+    dialect: Dialect,
 }
 
+builder!(CreateGeneratedClock, CreateGeneratedClockBuilder {
+    mandatory: [source, source_objects],
+    optional: [name, edges, divide_by, multiply_by, duty_cycle, edge_shift, master_clock, comment],
+    flag: [invert, add, combinational],
+    repeatable: [],
+    extra: [dialect: Dialect],
+});
+
 impl fmt::Display for CreateGeneratedClock {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut text = "create_generated_clock".to_string();
@@ -1241,35 +1745,16 @@ impl CommandExt for CreateGeneratedClock {
 impl Validate for CreateGeneratedClock {
     fn validate(&self, version: SdcVersion) -> Vec<ValidateError> {
         let mut ret = vec![];
-        self.cmd_supported_version(&mut ret, version.within(SDC1_3, SDC2_1));
-        self.arg_supported_version(&mut ret, version.within(SDC1_4, SDC2_1), &self.add, "add");
-        self.arg_supported_version(
-            &mut ret,
-            version.within(SDC1_4, SDC2_1),
-            &self.master_clock,
-            "master_clock",
-        );
-        self.arg_supported_version(
-            &mut ret,
-            // spec shows -combinational was removed at SDC2.1, but
-            // actual implementations shows it is keeped.
-            version.within(SDC1_7, SDC2_1),
-            &self.combinational,
-            "combinational",
-        );
-        self.arg_supported_version(
-            &mut ret,
-            version.within(SDC1_9, SDC2_1),
-            &self.comment,
-            "comment",
-        );
-        self.arg_comb2(
-            &mut ret,
-            version.within(SDC1_3, SDC2_1),
-            &self.multiply_by,
-            &self.divide_by,
-            |a, b| !(a & b),
-        );
+        // Quartus allows -multiply_by with -divide_by
+        if self.dialect != Dialect::Quartus {
+            self.arg_comb2(
+                &mut ret,
+                version.within(SDC1_3, SDC2_1),
+                &self.multiply_by,
+                &self.divide_by,
+                |a, b| !(a & b),
+            );
+        }
         validate_opt(&mut ret, version, &self.name);
         validate_arg(&mut ret, version, &self.source);
         validate_opt(&mut ret, version, &self.edges);
@@ -1315,6 +1800,7 @@ impl Extract for CreateGeneratedClock {
 fn create_generated_clock(
     args: Vec<Argument>,
     location: Location,
+    dialect: Dialect,
 ) -> Result<Command, SemanticError> {
     let mut name = None;
     let mut source = None;
@@ -1331,22 +1817,7 @@ fn create_generated_clock(
     let mut source_objects = None;
 
     static DICT: OnceLock<LazyDict> = OnceLock::new();
-    let dict = DICT.get_or_init(|| {
-        LazyDict::new(&[
-            "-name",
-            "-source",
-            "-edges",
-            "-divide_by",
-            "-multiply_by",
-            "-duty_cycle",
-            "-invert",
-            "-edge_shift",
-            "-add",
-            "-master_clock",
-            "-combinational",
-            "-comment",
-        ])
-    });
+    let dict = DICT.get_or_init(|| LazyDict::from_kind(CommandKind::CreateGeneratedClock));
 
     let mut iter = args.into_iter();
     while let Some(arg) = iter.next() {
@@ -1385,6 +1856,7 @@ fn create_generated_clock(
         comment,
         source_objects,
         location,
+        dialect,
     }))
 }
 
@@ -1399,6 +1871,17 @@ pub struct CreateVoltageArea {
     location: Location,
 }
 
+builder!(
+    CreateVoltageArea,
+    CreateVoltageAreaBuilder {
+        mandatory: [name, cell_list],
+        optional: [coordinate, guard_band_x, guard_band_y],
+        flag: [],
+        repeatable: [],
+        extra: [],
+    }
+);
+
 impl fmt::Display for CreateVoltageArea {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut text = "create_voltage_area".to_string();
@@ -1424,7 +1907,6 @@ impl CommandExt for CreateVoltageArea {
 impl Validate for CreateVoltageArea {
     fn validate(&self, version: SdcVersion) -> Vec<ValidateError> {
         let mut ret = vec![];
-        self.cmd_supported_version(&mut ret, version.within(SDC1_6, SDC2_1));
         validate_arg(&mut ret, version, &self.name);
         validate_opt(&mut ret, version, &self.coordinate);
         validate_opt(&mut ret, version, &self.guard_band_x);
@@ -1460,8 +1942,7 @@ fn create_voltage_area(args: Vec<Argument>, location: Location) -> Result<Comman
     let mut cell_list = None;
 
     static DICT: OnceLock<LazyDict> = OnceLock::new();
-    let dict = DICT
-        .get_or_init(|| LazyDict::new(&["-name", "-coordinate", "-guard_band_x", "-guard_band_y"]));
+    let dict = DICT.get_or_init(|| LazyDict::from_kind(CommandKind::CreateVoltageArea));
 
     let mut iter = args.into_iter();
     while let Some(arg) = iter.next() {
@@ -1493,6 +1974,17 @@ pub struct CurrentDesign {
     location: Location,
 }
 
+builder!(
+    CurrentDesign,
+    CurrentDesignBuilder {
+        mandatory: [],
+        optional: [],
+        flag: [],
+        repeatable: [],
+        extra: [],
+    }
+);
+
 impl fmt::Display for CurrentDesign {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let text = "current_design".to_string();
@@ -1511,10 +2003,8 @@ impl CommandExt for CurrentDesign {
 }
 
 impl Validate for CurrentDesign {
-    fn validate(&self, version: SdcVersion) -> Vec<ValidateError> {
-        let mut ret = vec![];
-        self.cmd_supported_version(&mut ret, version.within(SDC1_1, SDC2_1));
-        ret
+    fn validate(&self, _version: SdcVersion) -> Vec<ValidateError> {
+        vec![]
     }
 }
 
@@ -1535,6 +2025,17 @@ pub struct CurrentInstance {
     location: Location,
 }
 
+builder!(
+    CurrentInstance,
+    CurrentInstanceBuilder {
+        mandatory: [],
+        optional: [instance],
+        flag: [],
+        repeatable: [],
+        extra: [],
+    }
+);
+
 impl fmt::Display for CurrentInstance {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut text = "current_instance".to_string();
@@ -1556,7 +2057,6 @@ impl CommandExt for CurrentInstance {
 impl Validate for CurrentInstance {
     fn validate(&self, version: SdcVersion) -> Vec<ValidateError> {
         let mut ret = vec![];
-        self.cmd_supported_version(&mut ret, version.within(SDC1_1, SDC2_1));
         validate_opt(&mut ret, version, &self.instance);
         ret
     }
@@ -1593,6 +2093,17 @@ pub struct Expr {
     location: Location,
 }
 
+builder!(
+    Expr,
+    ExprBuilder {
+        mandatory: [],
+        optional: [],
+        flag: [],
+        repeatable: [args],
+        extra: [],
+    }
+);
+
 impl fmt::Display for Expr {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut text = "expr".to_string();
@@ -1616,7 +2127,6 @@ impl CommandExt for Expr {
 impl Validate for Expr {
     fn validate(&self, version: SdcVersion) -> Vec<ValidateError> {
         let mut ret = vec![];
-        self.cmd_supported_version(&mut ret, version.within(SDC1_1, SDC2_1));
         validate_vec(&mut ret, version, &self.args);
         ret
     }
@@ -1654,11 +2164,21 @@ pub struct GetCells {
     pub nocase: bool,
     pub hsc: Option<Argument>,
     pub of_objects: Option<Argument>,
+    pub filter: Option<Argument>,
+    pub quiet: bool,
     pub patterns: Option<Argument>,
     location: Location,
     alias: bool,
 }
 
+builder!(GetCells, GetCellsBuilder {
+    mandatory: [],
+    optional: [hsc, of_objects, filter, patterns],
+    flag: [hierarchical, regexp, nocase, quiet],
+    repeatable: [],
+    extra: [alias: bool],
+});
+
 impl fmt::Display for GetCells {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut text = "get_cells".to_string();
@@ -1667,6 +2187,8 @@ impl fmt::Display for GetCells {
         text.push_str(&fmt_named_flg(self.nocase, "nocase"));
         text.push_str(&fmt_named_opt_arg(&self.hsc, "hsc"));
         text.push_str(&fmt_named_opt_arg(&self.of_objects, "of_objects"));
+        text.push_str(&fmt_named_opt_arg(&self.filter, "filter"));
+        text.push_str(&fmt_named_flg(self.quiet, "quiet"));
         text.push_str(&fmt_opt_arg(&self.patterns));
         text.fmt(f)
     }
@@ -1685,21 +2207,6 @@ impl CommandExt for GetCells {
 impl Validate for GetCells {
     fn validate(&self, version: SdcVersion) -> Vec<ValidateError> {
         let mut ret = vec![];
-        self.cmd_supported_version(&mut ret, version.within(SDC1_1, SDC2_1));
-        self.alias_supported_version(&mut ret, version.within(SDC1_5, SDC2_1), self.alias);
-        self.arg_supported_version(&mut ret, version.within(SDC1_2, SDC2_1), &self.hsc, "hsc");
-        self.arg_supported_version(
-            &mut ret,
-            version.within(SDC1_5, SDC2_1),
-            &self.regexp,
-            "regexp",
-        );
-        self.arg_supported_version(
-            &mut ret,
-            version.within(SDC1_5, SDC2_1),
-            &self.nocase,
-            "nocase",
-        );
         self.arg_comb3(
             &mut ret,
             version.within(SDC1_1, SDC1_1),
@@ -1724,6 +2231,7 @@ impl Validate for GetCells {
         );
         validate_opt(&mut ret, version, &self.hsc);
         validate_opt(&mut ret, version, &self.of_objects);
+        validate_opt(&mut ret, version, &self.filter);
         validate_opt(&mut ret, version, &self.patterns);
         ret
     }
@@ -1733,12 +2241,14 @@ impl Extract for GetCells {
     fn extract<'a>(&'a self, kind: CommandKind, list: &mut Vec<&'a Command>) {
         Self::extract_opt(kind, list, &self.hsc);
         Self::extract_opt(kind, list, &self.of_objects);
+        Self::extract_opt(kind, list, &self.filter);
         Self::extract_opt(kind, list, &self.patterns);
     }
 
     fn extract_mut<'a>(&'a mut self, kind: CommandKind, list: &mut Vec<&'a mut Command>) {
         Self::extract_mut_opt(kind, list, &mut self.hsc);
         Self::extract_mut_opt(kind, list, &mut self.of_objects);
+        Self::extract_mut_opt(kind, list, &mut self.filter);
         Self::extract_mut_opt(kind, list, &mut self.patterns);
     }
 }
@@ -1747,18 +2257,19 @@ fn get_cells(
     args: Vec<Argument>,
     location: Location,
     alias: bool,
+    dialect: Dialect,
 ) -> Result<Command, SemanticError> {
     let mut hierarchical = false;
     let mut regexp = false;
     let mut nocase = false;
     let mut hsc = None;
     let mut of_objects = None;
+    let mut filter = None;
+    let mut quiet = false;
     let mut patterns = None;
 
     static DICT: OnceLock<LazyDict> = OnceLock::new();
-    let dict = DICT.get_or_init(|| {
-        LazyDict::new(&["-hierarchical", "-regexp", "-nocase", "-hsc", "-of_objects"])
-    });
+    let dict = DICT.get_or_init(|| LazyDict::from_kind(CommandKind::GetCells));
 
     let mut iter = args.into_iter();
     while let Some(arg) = iter.next() {
@@ -1768,6 +2279,10 @@ fn get_cells(
             x if x.m("-nocase") => nocase = opt_flg(arg, nocase)?,
             x if x.m("-hsc") => hsc = opt_arg(arg, iter.next(), hsc)?,
             x if x.m("-of_objects") => of_objects = opt_arg(arg, iter.next(), of_objects)?,
+            x if x.m("-filter") && dialect == Dialect::Xdc => {
+                filter = opt_arg(arg, iter.next(), filter)?
+            }
+            x if x.m("-quiet") && dialect == Dialect::Xdc => quiet = opt_flg(arg, quiet)?,
             _ => patterns = pos_args1(Some(arg), patterns, &location)?,
         }
     }
@@ -1778,6 +2293,8 @@ fn get_cells(
         nocase,
         hsc,
         of_objects,
+        filter,
+        quiet,
         patterns,
         location,
         alias,
@@ -1789,15 +2306,32 @@ fn get_cells(
 pub struct GetClocks {
     pub regexp: bool,
     pub nocase: bool,
+    pub filter: Option<Argument>,
+    pub of_objects: Option<Argument>,
+    pub quiet: bool,
     pub patterns: Option<Argument>,
     location: Location,
 }
 
+builder!(
+    GetClocks,
+    GetClocksBuilder {
+        mandatory: [],
+        optional: [filter, of_objects, patterns],
+        flag: [regexp, nocase, quiet],
+        repeatable: [],
+        extra: [],
+    }
+);
+
 impl fmt::Display for GetClocks {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut text = "get_clocks".to_string();
         text.push_str(&fmt_named_flg(self.regexp, "regexp"));
         text.push_str(&fmt_named_flg(self.nocase, "nocase"));
+        text.push_str(&fmt_named_opt_arg(&self.filter, "filter"));
+        text.push_str(&fmt_named_opt_arg(&self.of_objects, "of_objects"));
+        text.push_str(&fmt_named_flg(self.quiet, "quiet"));
         text.push_str(&fmt_opt_arg(&self.patterns));
         text.fmt(f)
     }
@@ -1816,25 +2350,14 @@ impl CommandExt for GetClocks {
 impl Validate for GetClocks {
     fn validate(&self, version: SdcVersion) -> Vec<ValidateError> {
         let mut ret = vec![];
-        self.cmd_supported_version(&mut ret, version.within(SDC1_1, SDC2_1));
-        self.arg_supported_version(
-            &mut ret,
-            version.within(SDC1_5, SDC2_1),
-            &self.regexp,
-            "regexp",
-        );
-        self.arg_supported_version(
-            &mut ret,
-            version.within(SDC1_5, SDC2_1),
-            &self.nocase,
-            "nocase",
-        );
         self.arg_comb1(
             &mut ret,
             version.within(SDC1_1, SDC1_4),
             &self.patterns,
             |a| a,
         );
+        validate_opt(&mut ret, version, &self.filter);
+        validate_opt(&mut ret, version, &self.of_objects);
         validate_opt(&mut ret, version, &self.patterns);
         ret
     }
@@ -1842,27 +2365,45 @@ impl Validate for GetClocks {
 
 impl Extract for GetClocks {
     fn extract<'a>(&'a self, kind: CommandKind, list: &mut Vec<&'a Command>) {
+        Self::extract_opt(kind, list, &self.filter);
+        Self::extract_opt(kind, list, &self.of_objects);
         Self::extract_opt(kind, list, &self.patterns);
     }
 
     fn extract_mut<'a>(&'a mut self, kind: CommandKind, list: &mut Vec<&'a mut Command>) {
+        Self::extract_mut_opt(kind, list, &mut self.filter);
+        Self::extract_mut_opt(kind, list, &mut self.of_objects);
         Self::extract_mut_opt(kind, list, &mut self.patterns);
     }
 }
 
-fn get_clocks(args: Vec<Argument>, location: Location) -> Result<Command, SemanticError> {
+fn get_clocks(
+    args: Vec<Argument>,
+    location: Location,
+    dialect: Dialect,
+) -> Result<Command, SemanticError> {
     let mut regexp = false;
     let mut nocase = false;
+    let mut filter = None;
+    let mut of_objects = None;
+    let mut quiet = false;
     let mut patterns = None;
 
     static DICT: OnceLock<LazyDict> = OnceLock::new();
-    let dict = DICT.get_or_init(|| LazyDict::new(&["-regexp", "-nocase"]));
+    let dict = DICT.get_or_init(|| LazyDict::from_kind(CommandKind::GetClocks));
 
     let mut iter = args.into_iter();
     while let Some(arg) = iter.next() {
         match LazyMatcher::new(arg.as_str(), dict, &location)? {
             x if x.m("-regexp") => regexp = opt_flg(arg, regexp)?,
             x if x.m("-nocase") => nocase = opt_flg(arg, nocase)?,
+            x if x.m("-filter") && dialect == Dialect::Xdc => {
+                filter = opt_arg(arg, iter.next(), filter)?
+            }
+            x if x.m("-of_objects") && dialect == Dialect::Xdc => {
+                of_objects = opt_arg(arg, iter.next(), of_objects)?
+            }
+            x if x.m("-quiet") && dialect == Dialect::Xdc => quiet = opt_flg(arg, quiet)?,
             _ => patterns = pos_args1(Some(arg), patterns, &location)?,
         }
     }
@@ -1870,6 +2411,9 @@ fn get_clocks(args: Vec<Argument>, location: Location) -> Result<Command, Semant
     Ok(Command::GetClocks(GetClocks {
         regexp,
         nocase,
+        filter,
+        of_objects,
+        quiet,
         patterns,
         location,
     }))
@@ -1886,6 +2430,14 @@ pub struct GetLibCells {
     alias: bool,
 }
 
+builder!(GetLibCells, GetLibCellsBuilder {
+    mandatory: [patterns],
+    optional: [hsc],
+    flag: [regexp, nocase],
+    repeatable: [],
+    extra: [alias: bool],
+});
+
 impl fmt::Display for GetLibCells {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut text = "get_lib_cells".to_string();
@@ -1910,21 +2462,6 @@ impl CommandExt for GetLibCells {
 impl Validate for GetLibCells {
     fn validate(&self, version: SdcVersion) -> Vec<ValidateError> {
         let mut ret = vec![];
-        self.cmd_supported_version(&mut ret, version.within(SDC1_1, SDC2_1));
-        self.alias_supported_version(&mut ret, version.within(SDC1_5, SDC2_1), self.alias);
-        self.arg_supported_version(&mut ret, version.within(SDC1_2, SDC2_1), &self.hsc, "hsc");
-        self.arg_supported_version(
-            &mut ret,
-            version.within(SDC1_5, SDC2_1),
-            &self.regexp,
-            "regexp",
-        );
-        self.arg_supported_version(
-            &mut ret,
-            version.within(SDC1_5, SDC2_1),
-            &self.nocase,
-            "nocase",
-        );
         validate_opt(&mut ret, version, &self.hsc);
         validate_arg(&mut ret, version, &self.patterns);
         ret
@@ -1954,7 +2491,7 @@ fn get_lib_cells(
     let mut patterns = None;
 
     static DICT: OnceLock<LazyDict> = OnceLock::new();
-    let dict = DICT.get_or_init(|| LazyDict::new(&["-regexp", "-hsc", "-nocase"]));
+    let dict = DICT.get_or_init(|| LazyDict::from_kind(CommandKind::GetLibCells));
 
     let mut iter = args.into_iter();
     while let Some(arg) = iter.next() {
@@ -1989,6 +2526,14 @@ pub struct GetLibPins {
     alias: bool,
 }
 
+builder!(GetLibPins, GetLibPinsBuilder {
+    mandatory: [patterns],
+    optional: [],
+    flag: [regexp, hsc, nocase],
+    repeatable: [],
+    extra: [alias: bool],
+});
+
 impl fmt::Display for GetLibPins {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut text = "get_lib_pins".to_string();
@@ -2013,21 +2558,6 @@ impl CommandExt for GetLibPins {
 impl Validate for GetLibPins {
     fn validate(&self, version: SdcVersion) -> Vec<ValidateError> {
         let mut ret = vec![];
-        self.cmd_supported_version(&mut ret, version.within(SDC1_1, SDC2_1));
-        self.alias_supported_version(&mut ret, version.within(SDC1_5, SDC2_1), self.alias);
-        self.arg_supported_version(&mut ret, version.within(SDC1_2, SDC2_1), &self.hsc, "hsc");
-        self.arg_supported_version(
-            &mut ret,
-            version.within(SDC1_5, SDC2_1),
-            &self.regexp,
-            "regexp",
-        );
-        self.arg_supported_version(
-            &mut ret,
-            version.within(SDC1_5, SDC2_1),
-            &self.nocase,
-            "nocase",
-        );
         validate_arg(&mut ret, version, &self.patterns);
         ret
     }
@@ -2054,7 +2584,7 @@ fn get_lib_pins(
     let mut patterns = None;
 
     static DICT: OnceLock<LazyDict> = OnceLock::new();
-    let dict = DICT.get_or_init(|| LazyDict::new(&["-regexp", "-hsc", "-nocase"]));
+    let dict = DICT.get_or_init(|| LazyDict::from_kind(CommandKind::GetLibPins));
 
     let mut iter = args.into_iter();
     while let Some(arg) = iter.next() {
@@ -2087,6 +2617,17 @@ pub struct GetLibs {
     location: Location,
 }
 
+builder!(
+    GetLibs,
+    GetLibsBuilder {
+        mandatory: [],
+        optional: [patterns],
+        flag: [regexp, nocase],
+        repeatable: [],
+        extra: [],
+    }
+);
+
 impl fmt::Display for GetLibs {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut text = "get_libs".to_string();
@@ -2110,19 +2651,6 @@ impl CommandExt for GetLibs {
 impl Validate for GetLibs {
     fn validate(&self, version: SdcVersion) -> Vec<ValidateError> {
         let mut ret = vec![];
-        self.cmd_supported_version(&mut ret, version.within(SDC1_1, SDC2_1));
-        self.arg_supported_version(
-            &mut ret,
-            version.within(SDC1_5, SDC2_1),
-            &self.regexp,
-            "regexp",
-        );
-        self.arg_supported_version(
-            &mut ret,
-            version.within(SDC1_5, SDC2_1),
-            &self.nocase,
-            "nocase",
-        );
         self.arg_comb1(
             &mut ret,
             version.within(SDC1_1, SDC1_4),
@@ -2150,7 +2678,7 @@ fn get_libs(args: Vec<Argument>, location: Location) -> Result<Command, Semantic
     let mut patterns = None;
 
     static DICT: OnceLock<LazyDict> = OnceLock::new();
-    let dict = DICT.get_or_init(|| LazyDict::new(&["-regexp", "-nocase"]));
+    let dict = DICT.get_or_init(|| LazyDict::from_kind(CommandKind::GetLibs));
 
     let mut iter = args.into_iter();
     while let Some(arg) = iter.next() {
@@ -2177,11 +2705,21 @@ pub struct GetNets {
     pub regexp: bool,
     pub nocase: bool,
     pub of_objects: Option<Argument>,
+    pub filter: Option<Argument>,
+    pub quiet: bool,
     pub patterns: Option<Argument>,
     location: Location,
     alias: bool,
 }
 
+builder!(GetNets, GetNetsBuilder {
+    mandatory: [],
+    optional: [hsc, of_objects, filter, patterns],
+    flag: [hierarchical, regexp, nocase, quiet],
+    repeatable: [],
+    extra: [alias: bool],
+});
+
 impl fmt::Display for GetNets {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut text = "get_nets".to_string();
@@ -2190,6 +2728,8 @@ impl fmt::Display for GetNets {
         text.push_str(&fmt_named_flg(self.regexp, "regexp"));
         text.push_str(&fmt_named_flg(self.nocase, "nocase"));
         text.push_str(&fmt_named_opt_arg(&self.of_objects, "of_objects"));
+        text.push_str(&fmt_named_opt_arg(&self.filter, "filter"));
+        text.push_str(&fmt_named_flg(self.quiet, "quiet"));
         text.push_str(&fmt_opt_arg(&self.patterns));
         text.fmt(f)
     }
@@ -2208,27 +2748,6 @@ impl CommandExt for GetNets {
 impl Validate for GetNets {
     fn validate(&self, version: SdcVersion) -> Vec<ValidateError> {
         let mut ret = vec![];
-        self.cmd_supported_version(&mut ret, version.within(SDC1_1, SDC2_1));
-        self.alias_supported_version(&mut ret, version.within(SDC1_5, SDC2_1), self.alias);
-        self.arg_supported_version(&mut ret, version.within(SDC1_2, SDC2_1), &self.hsc, "hsc");
-        self.arg_supported_version(
-            &mut ret,
-            version.within(SDC1_5, SDC2_1),
-            &self.of_objects,
-            "of_objects",
-        );
-        self.arg_supported_version(
-            &mut ret,
-            version.within(SDC1_5, SDC2_1),
-            &self.regexp,
-            "regexp",
-        );
-        self.arg_supported_version(
-            &mut ret,
-            version.within(SDC1_5, SDC2_1),
-            &self.nocase,
-            "nocase",
-        );
         self.arg_comb1(
             &mut ret,
             version.within(SDC1_1, SDC1_4),
@@ -2244,6 +2763,7 @@ impl Validate for GetNets {
         );
         validate_opt(&mut ret, version, &self.hsc);
         validate_opt(&mut ret, version, &self.of_objects);
+        validate_opt(&mut ret, version, &self.filter);
         validate_opt(&mut ret, version, &self.patterns);
         ret
     }
@@ -2253,12 +2773,14 @@ impl Extract for GetNets {
     fn extract<'a>(&'a self, kind: CommandKind, list: &mut Vec<&'a Command>) {
         Self::extract_opt(kind, list, &self.hsc);
         Self::extract_opt(kind, list, &self.of_objects);
+        Self::extract_opt(kind, list, &self.filter);
         Self::extract_opt(kind, list, &self.patterns);
     }
 
     fn extract_mut<'a>(&'a mut self, kind: CommandKind, list: &mut Vec<&'a mut Command>) {
         Self::extract_mut_opt(kind, list, &mut self.hsc);
         Self::extract_mut_opt(kind, list, &mut self.of_objects);
+        Self::extract_mut_opt(kind, list, &mut self.filter);
         Self::extract_mut_opt(kind, list, &mut self.patterns);
     }
 }
@@ -2267,18 +2789,19 @@ fn get_nets(
     args: Vec<Argument>,
     location: Location,
     alias: bool,
+    dialect: Dialect,
 ) -> Result<Command, SemanticError> {
     let mut hierarchical = false;
     let mut hsc = None;
     let mut regexp = false;
     let mut nocase = false;
     let mut of_objects = None;
+    let mut filter = None;
+    let mut quiet = false;
     let mut patterns = None;
 
     static DICT: OnceLock<LazyDict> = OnceLock::new();
-    let dict = DICT.get_or_init(|| {
-        LazyDict::new(&["-hierarchical", "-hsc", "-regexp", "-nocase", "-of_objects"])
-    });
+    let dict = DICT.get_or_init(|| LazyDict::from_kind(CommandKind::GetNets));
 
     let mut iter = args.into_iter();
     while let Some(arg) = iter.next() {
@@ -2288,6 +2811,10 @@ fn get_nets(
             x if x.m("-regexp") => regexp = opt_flg(arg, regexp)?,
             x if x.m("-nocase") => nocase = opt_flg(arg, nocase)?,
             x if x.m("-of_objects") => of_objects = opt_arg(arg, iter.next(), of_objects)?,
+            x if x.m("-filter") && dialect == Dialect::Xdc => {
+                filter = opt_arg(arg, iter.next(), filter)?
+            }
+            x if x.m("-quiet") && dialect == Dialect::Xdc => quiet = opt_flg(arg, quiet)?,
             _ => patterns = pos_args1(Some(arg), patterns, &location)?,
         }
     }
@@ -2298,6 +2825,8 @@ fn get_nets(
         regexp,
         nocase,
         of_objects,
+        filter,
+        quiet,
         patterns,
         location,
         alias,
@@ -2311,11 +2840,22 @@ pub struct GetPins {
     pub hsc: Option<Argument>,
     pub regexp: bool,
     pub nocase: bool,
+    pub filter: Option<Argument>,
+    pub of_objects: Option<Argument>,
+    pub quiet: bool,
     pub patterns: Option<Argument>,
     location: Location,
     alias: bool,
 }
 
+builder!(GetPins, GetPinsBuilder {
+    mandatory: [],
+    optional: [hsc, filter, of_objects, patterns],
+    flag: [hierarchical, regexp, nocase, quiet],
+    repeatable: [],
+    extra: [alias: bool],
+});
+
 impl fmt::Display for GetPins {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut text = "get_pins".to_string();
@@ -2323,6 +2863,9 @@ impl fmt::Display for GetPins {
         text.push_str(&fmt_named_opt_arg(&self.hsc, "hsc"));
         text.push_str(&fmt_named_flg(self.regexp, "regexp"));
         text.push_str(&fmt_named_flg(self.nocase, "nocase"));
+        text.push_str(&fmt_named_opt_arg(&self.filter, "filter"));
+        text.push_str(&fmt_named_opt_arg(&self.of_objects, "of_objects"));
+        text.push_str(&fmt_named_flg(self.quiet, "quiet"));
         text.push_str(&fmt_opt_arg(&self.patterns));
         text.fmt(f)
     }
@@ -2341,21 +2884,6 @@ impl CommandExt for GetPins {
 impl Validate for GetPins {
     fn validate(&self, version: SdcVersion) -> Vec<ValidateError> {
         let mut ret = vec![];
-        self.cmd_supported_version(&mut ret, version.within(SDC1_1, SDC2_1));
-        self.alias_supported_version(&mut ret, version.within(SDC1_5, SDC2_1), self.alias);
-        self.arg_supported_version(&mut ret, version.within(SDC1_2, SDC2_1), &self.hsc, "hsc");
-        self.arg_supported_version(
-            &mut ret,
-            version.within(SDC1_5, SDC2_1),
-            &self.regexp,
-            "regexp",
-        );
-        self.arg_supported_version(
-            &mut ret,
-            version.within(SDC1_5, SDC2_1),
-            &self.nocase,
-            "nocase",
-        );
         self.arg_comb1(
             &mut ret,
             version.within(SDC1_1, SDC1_4),
@@ -2363,6 +2891,8 @@ impl Validate for GetPins {
             |a| a,
         );
         validate_opt(&mut ret, version, &self.hsc);
+        validate_opt(&mut ret, version, &self.filter);
+        validate_opt(&mut ret, version, &self.of_objects);
         validate_opt(&mut ret, version, &self.patterns);
         ret
     }
@@ -2371,11 +2901,15 @@ impl Validate for GetPins {
 impl Extract for GetPins {
     fn extract<'a>(&'a self, kind: CommandKind, list: &mut Vec<&'a Command>) {
         Self::extract_opt(kind, list, &self.hsc);
+        Self::extract_opt(kind, list, &self.filter);
+        Self::extract_opt(kind, list, &self.of_objects);
         Self::extract_opt(kind, list, &self.patterns);
     }
 
     fn extract_mut<'a>(&'a mut self, kind: CommandKind, list: &mut Vec<&'a mut Command>) {
         Self::extract_mut_opt(kind, list, &mut self.hsc);
+        Self::extract_mut_opt(kind, list, &mut self.filter);
+        Self::extract_mut_opt(kind, list, &mut self.of_objects);
         Self::extract_mut_opt(kind, list, &mut self.patterns);
     }
 }
@@ -2384,15 +2918,19 @@ fn get_pins(
     args: Vec<Argument>,
     location: Location,
     alias: bool,
+    dialect: Dialect,
 ) -> Result<Command, SemanticError> {
     let mut hierarchical = false;
     let mut hsc = None;
     let mut regexp = false;
     let mut nocase = false;
+    let mut filter = None;
+    let mut of_objects = None;
+    let mut quiet = false;
     let mut patterns = None;
 
     static DICT: OnceLock<LazyDict> = OnceLock::new();
-    let dict = DICT.get_or_init(|| LazyDict::new(&["-hierarchical", "-hsc", "-regexp", "-nocase"]));
+    let dict = DICT.get_or_init(|| LazyDict::from_kind(CommandKind::GetPins));
 
     let mut iter = args.into_iter();
     while let Some(arg) = iter.next() {
@@ -2401,6 +2939,13 @@ fn get_pins(
             x if x.m("-hsc") => hsc = opt_arg(arg, iter.next(), hsc)?,
             x if x.m("-regexp") => regexp = opt_flg(arg, regexp)?,
             x if x.m("-nocase") => nocase = opt_flg(arg, nocase)?,
+            x if x.m("-filter") && dialect == Dialect::Xdc => {
+                filter = opt_arg(arg, iter.next(), filter)?
+            }
+            x if x.m("-of_objects") && dialect == Dialect::Xdc => {
+                of_objects = opt_arg(arg, iter.next(), of_objects)?
+            }
+            x if x.m("-quiet") && dialect == Dialect::Xdc => quiet = opt_flg(arg, quiet)?,
             _ => patterns = pos_args1(Some(arg), patterns, &location)?,
         }
     }
@@ -2410,6 +2955,9 @@ fn get_pins(
         hsc,
         regexp,
         nocase,
+        filter,
+        of_objects,
+        quiet,
         patterns,
         location,
         alias,
@@ -2421,16 +2969,32 @@ fn get_pins(
 pub struct GetPorts {
     pub hierarchical: bool,
     pub regexp: bool,
+    pub nocase: bool,
+    pub filter: Option<Argument>,
+    pub of_objects: Option<Argument>,
+    pub quiet: bool,
     pub patterns: Option<Argument>,
     location: Location,
     alias: bool,
 }
 
+builder!(GetPorts, GetPortsBuilder {
+    mandatory: [],
+    optional: [filter, of_objects, patterns],
+    flag: [hierarchical, regexp, nocase, quiet],
+    repeatable: [],
+    extra: [alias: bool],
+});
+
 impl fmt::Display for GetPorts {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut text = "get_ports".to_string();
         text.push_str(&fmt_named_flg(self.hierarchical, "hierarchical"));
         text.push_str(&fmt_named_flg(self.regexp, "regexp"));
+        text.push_str(&fmt_named_flg(self.nocase, "nocase"));
+        text.push_str(&fmt_named_opt_arg(&self.filter, "filter"));
+        text.push_str(&fmt_named_opt_arg(&self.of_objects, "of_objects"));
+        text.push_str(&fmt_named_flg(self.quiet, "quiet"));
         text.push_str(&fmt_opt_arg(&self.patterns));
         text.fmt(f)
     }
@@ -2449,26 +3013,14 @@ impl CommandExt for GetPorts {
 impl Validate for GetPorts {
     fn validate(&self, version: SdcVersion) -> Vec<ValidateError> {
         let mut ret = vec![];
-        self.cmd_supported_version(&mut ret, version.within(SDC1_1, SDC2_1));
-        self.alias_supported_version(&mut ret, version.within(SDC1_5, SDC2_1), self.alias);
-        self.arg_supported_version(
-            &mut ret,
-            version.within(SDC1_5, SDC2_1),
-            &self.hierarchical,
-            "hierarchical",
-        );
-        self.arg_supported_version(
-            &mut ret,
-            version.within(SDC1_5, SDC2_1),
-            &self.regexp,
-            "regexp",
-        );
         self.arg_comb1(
             &mut ret,
             version.within(SDC1_1, SDC1_4),
             &self.patterns,
             |a| a,
         );
+        validate_opt(&mut ret, version, &self.filter);
+        validate_opt(&mut ret, version, &self.of_objects);
         validate_opt(&mut ret, version, &self.patterns);
         ret
     }
@@ -2476,10 +3028,14 @@ impl Validate for GetPorts {
 
 impl Extract for GetPorts {
     fn extract<'a>(&'a self, kind: CommandKind, list: &mut Vec<&'a Command>) {
+        Self::extract_opt(kind, list, &self.filter);
+        Self::extract_opt(kind, list, &self.of_objects);
         Self::extract_opt(kind, list, &self.patterns);
     }
 
     fn extract_mut<'a>(&'a mut self, kind: CommandKind, list: &mut Vec<&'a mut Command>) {
+        Self::extract_mut_opt(kind, list, &mut self.filter);
+        Self::extract_mut_opt(kind, list, &mut self.of_objects);
         Self::extract_mut_opt(kind, list, &mut self.patterns);
     }
 }
@@ -2488,19 +3044,32 @@ fn get_ports(
     args: Vec<Argument>,
     location: Location,
     alias: bool,
+    dialect: Dialect,
 ) -> Result<Command, SemanticError> {
     let mut hierarchical = false;
     let mut regexp = false;
+    let mut nocase = false;
+    let mut filter = None;
+    let mut of_objects = None;
+    let mut quiet = false;
     let mut patterns = None;
 
     static DICT: OnceLock<LazyDict> = OnceLock::new();
-    let dict = DICT.get_or_init(|| LazyDict::new(&["-hierarchical", "-regexp"]));
+    let dict = DICT.get_or_init(|| LazyDict::from_kind(CommandKind::GetPorts));
 
     let mut iter = args.into_iter();
     while let Some(arg) = iter.next() {
         match LazyMatcher::new(arg.as_str(), dict, &location)? {
             x if x.m("-hierarchical") => hierarchical = opt_flg(arg, hierarchical)?,
             x if x.m("-regexp") => regexp = opt_flg(arg, regexp)?,
+            x if x.m("-nocase") => nocase = opt_flg(arg, nocase)?,
+            x if x.m("-filter") && dialect == Dialect::Xdc => {
+                filter = opt_arg(arg, iter.next(), filter)?
+            }
+            x if x.m("-of_objects") && dialect == Dialect::Xdc => {
+                of_objects = opt_arg(arg, iter.next(), of_objects)?
+            }
+            x if x.m("-quiet") && dialect == Dialect::Xdc => quiet = opt_flg(arg, quiet)?,
             _ => patterns = pos_args1(Some(arg), patterns, &location)?,
         }
     }
@@ -2508,6 +3077,10 @@ fn get_ports(
     Ok(Command::GetPorts(GetPorts {
         hierarchical,
         regexp,
+        nocase,
+        filter,
+        of_objects,
+        quiet,
         patterns,
         location,
         alias,
@@ -2533,6 +3106,17 @@ pub struct GroupPath {
     location: Location,
 }
 
+builder!(
+    GroupPath,
+    GroupPathBuilder {
+        mandatory: [],
+        optional: [name, weight, from, rise_from, fall_from, to, rise_to, fall_to, comment],
+        flag: [default],
+        repeatable: [through, rise_through, fall_through],
+        extra: [],
+    }
+);
+
 impl fmt::Display for GroupPath {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut text = "group_path".to_string();
@@ -2566,13 +3150,6 @@ impl CommandExt for GroupPath {
 impl Validate for GroupPath {
     fn validate(&self, version: SdcVersion) -> Vec<ValidateError> {
         let mut ret = vec![];
-        self.cmd_supported_version(&mut ret, version.within(SDC1_7, SDC2_1));
-        self.arg_supported_version(
-            &mut ret,
-            version.within(SDC1_9, SDC2_1),
-            &self.comment,
-            "comment",
-        );
         self.arg_comb5(
             &mut ret,
             version.within(SDC1_7, SDC2_1),
@@ -2647,23 +3224,7 @@ fn group_path(args: Vec<Argument>, location: Location) -> Result<Command, Semant
     let mut comment = None;
 
     static DICT: OnceLock<LazyDict> = OnceLock::new();
-    let dict = DICT.get_or_init(|| {
-        LazyDict::new(&[
-            "-name",
-            "-default",
-            "-weight",
-            "-from",
-            "-rise_from",
-            "-fall_from",
-            "-to",
-            "-rise_to",
-            "-fall_to",
-            "-through",
-            "-rise_through",
-            "-fall_through",
-            "-comment",
-        ])
-    });
+    let dict = DICT.get_or_init(|| LazyDict::from_kind(CommandKind::GroupPath));
 
     let mut iter = args.into_iter();
     while let Some(arg) = iter.next() {
@@ -2710,6 +3271,17 @@ pub struct List {
     location: Location,
 }
 
+builder!(
+    List,
+    ListBuilder {
+        mandatory: [],
+        optional: [],
+        flag: [],
+        repeatable: [args],
+        extra: [],
+    }
+);
+
 impl fmt::Display for List {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut text = "list".to_string();
@@ -2733,7 +3305,6 @@ impl CommandExt for List {
 impl Validate for List {
     fn validate(&self, version: SdcVersion) -> Vec<ValidateError> {
         let mut ret = vec![];
-        self.cmd_supported_version(&mut ret, version.within(SDC1_1, SDC2_1));
         validate_vec(&mut ret, version, &self.args);
         ret
     }
@@ -2771,6 +3342,17 @@ pub struct Set {
     location: Location,
 }
 
+builder!(
+    Set,
+    SetBuilder {
+        mandatory: [variable_name, value],
+        optional: [],
+        flag: [],
+        repeatable: [],
+        extra: [],
+    }
+);
+
 impl fmt::Display for Set {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut text = "set".to_string();
@@ -2793,7 +3375,6 @@ impl CommandExt for Set {
 impl Validate for Set {
     fn validate(&self, version: SdcVersion) -> Vec<ValidateError> {
         let mut ret = vec![];
-        self.cmd_supported_version(&mut ret, version.within(SDC1_1, SDC2_1));
         validate_arg(&mut ret, version, &self.variable_name);
         validate_arg(&mut ret, version, &self.value);
         ret
@@ -2839,6 +3420,17 @@ pub struct SetCaseAnalysis {
     location: Location,
 }
 
+builder!(
+    SetCaseAnalysis,
+    SetCaseAnalysisBuilder {
+        mandatory: [port_or_pin_list],
+        optional: [value],
+        flag: [],
+        repeatable: [],
+        extra: [],
+    }
+);
+
 impl fmt::Display for SetCaseAnalysis {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut text = "set_case_analysis".to_string();
@@ -2861,7 +3453,6 @@ impl CommandExt for SetCaseAnalysis {
 impl Validate for SetCaseAnalysis {
     fn validate(&self, version: SdcVersion) -> Vec<ValidateError> {
         let mut ret = vec![];
-        self.cmd_supported_version(&mut ret, version.within(SDC1_1, SDC2_1));
         self.arg_comb1(&mut ret, version.within(SDC1_2, SDC2_1), &self.value, |a| a);
         validate_opt(&mut ret, version, &self.value);
         validate_arg(&mut ret, version, &self.port_or_pin_list);
@@ -2912,6 +3503,17 @@ pub struct SetClockGatingCheck {
     location: Location,
 }
 
+builder!(
+    SetClockGatingCheck,
+    SetClockGatingCheckBuilder {
+        mandatory: [],
+        optional: [setup, hold, object_list],
+        flag: [rise, fall, high, low],
+        repeatable: [],
+        extra: [],
+    }
+);
+
 impl fmt::Display for SetClockGatingCheck {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut text = "set_clock_gating_check".to_string();
@@ -2939,7 +3541,6 @@ impl CommandExt for SetClockGatingCheck {
 impl Validate for SetClockGatingCheck {
     fn validate(&self, version: SdcVersion) -> Vec<ValidateError> {
         let mut ret = vec![];
-        self.cmd_supported_version(&mut ret, version.within(SDC1_2, SDC2_1));
         self.arg_comb4(
             &mut ret,
             version.within(SDC1_2, SDC2_1),
@@ -2983,8 +3584,7 @@ fn set_clock_gating_check(
     let mut object_list = None;
 
     static DICT: OnceLock<LazyDict> = OnceLock::new();
-    let dict =
-        DICT.get_or_init(|| LazyDict::new(&["-setup", "-hold", "-rise", "-fall", "-high", "-low"]));
+    let dict = DICT.get_or_init(|| LazyDict::from_kind(CommandKind::SetClockGatingCheck));
 
     let mut iter = args.into_iter();
     while let Some(arg) = iter.next() {
@@ -3018,13 +3618,26 @@ pub struct SetClockGroups {
     pub logically_exclusive: bool,
     pub physically_exclusive: bool,
     pub asynchronous: bool,
+    pub exclusive: bool,
     pub allow_paths: bool,
+    pub include_generated_clocks: bool,
     pub name: Option<Argument>,
     pub comment: Option<Argument>,
     location: Location,
     alias: bool,
 }
 
+builder!(SetClockGroups, SetClockGroupsBuilder {
+    mandatory: [],
+    optional: [name, comment],
+    flag: [
+        logically_exclusive, physically_exclusive, asynchronous, exclusive, allow_paths,
+        include_generated_clocks,
+    ],
+    repeatable: [group],
+    extra: [alias: bool],
+});
+
 impl fmt::Display for SetClockGroups {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut text = "set_clock_groups".to_string();
@@ -3038,7 +3651,12 @@ impl fmt::Display for SetClockGroups {
             "physically_exclusive",
         ));
         text.push_str(&fmt_named_flg(self.asynchronous, "asynchronous"));
+        text.push_str(&fmt_named_flg(self.exclusive, "exclusive"));
         text.push_str(&fmt_named_flg(self.allow_paths, "low"));
+        text.push_str(&fmt_named_flg(
+            self.include_generated_clocks,
+            "include_generated_clocks",
+        ));
         text.push_str(&fmt_named_opt_arg(&self.name, "name"));
         text.push_str(&fmt_named_opt_arg(&self.comment, "comment"));
         text.fmt(f)
@@ -3058,22 +3676,15 @@ impl CommandExt for SetClockGroups {
 impl Validate for SetClockGroups {
     fn validate(&self, version: SdcVersion) -> Vec<ValidateError> {
         let mut ret = vec![];
-        self.cmd_supported_version(&mut ret, version.within(SDC1_7, SDC2_1));
-        self.alias_supported_version(&mut ret, version.within(SDC1_7, SDC2_1), self.alias);
         // TODO group to dup at 1.8
-        self.arg_supported_version(
-            &mut ret,
-            version.within(SDC1_9, SDC2_1),
-            &self.comment,
-            "comment",
-        );
-        self.arg_comb3(
+        self.arg_comb4(
             &mut ret,
             version.within(SDC1_7, SDC2_1),
             &self.physically_exclusive,
             &self.logically_exclusive,
             &self.asynchronous,
-            |a, b, c| a ^ b ^ c,
+            &self.exclusive,
+            |a, b, c, d| a ^ b ^ c ^ d,
         );
         validate_vec(&mut ret, version, &self.group);
         validate_opt(&mut ret, version, &self.name);
@@ -3100,27 +3711,20 @@ fn set_clock_groups(
     args: Vec<Argument>,
     location: Location,
     alias: bool,
+    dialect: Dialect,
 ) -> Result<Command, SemanticError> {
     let mut group = vec![];
     let mut logically_exclusive = false;
     let mut physically_exclusive = false;
     let mut asynchronous = false;
+    let mut exclusive = false;
     let mut allow_paths = false;
+    let mut include_generated_clocks = false;
     let mut name = None;
     let mut comment = None;
 
     static DICT: OnceLock<LazyDict> = OnceLock::new();
-    let dict = DICT.get_or_init(|| {
-        LazyDict::new(&[
-            "-group",
-            "-logically_exclusive",
-            "-physically_exclusive",
-            "-asynchronous",
-            "-allow_paths",
-            "-name",
-            "-comment",
-        ])
-    });
+    let dict = DICT.get_or_init(|| LazyDict::from_kind(CommandKind::SetClockGroups));
 
     let mut iter = args.into_iter();
     while let Some(arg) = iter.next() {
@@ -3133,7 +3737,13 @@ fn set_clock_groups(
                 physically_exclusive = opt_flg(arg, physically_exclusive)?
             }
             x if x.m("-asynchronous") => asynchronous = opt_flg(arg, asynchronous)?,
+            x if x.m("-exclusive") && dialect == Dialect::Quartus => {
+                exclusive = opt_flg(arg, exclusive)?
+            }
             x if x.m("-allow_paths") => allow_paths = opt_flg(arg, allow_paths)?,
+            x if x.m("-include_generated_clocks") && dialect == Dialect::Xdc => {
+                include_generated_clocks = opt_flg(arg, include_generated_clocks)?
+            }
             x if x.m("-name") => name = opt_arg(arg, iter.next(), name)?,
             x if x.m("-comment") => comment = opt_arg(arg, iter.next(), comment)?,
             _ => return Err(SemanticError::WrongArgument(arg)),
@@ -3145,7 +3755,9 @@ fn set_clock_groups(
         logically_exclusive,
         physically_exclusive,
         asynchronous,
+        exclusive,
         allow_paths,
+        include_generated_clocks,
         name,
         comment,
         location,
@@ -3170,6 +3782,17 @@ pub struct SetClockLatency {
     location: Location,
 }
 
+builder!(
+    SetClockLatency,
+    SetClockLatencyBuilder {
+        mandatory: [delay, object_list],
+        optional: [clock],
+        flag: [rise, fall, min, max, source, dynamic, late, early],
+        repeatable: [],
+        extra: [],
+    }
+);
+
 impl fmt::Display for SetClockLatency {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut text = "set_clock_latency".to_string();
@@ -3201,26 +3824,6 @@ impl CommandExt for SetClockLatency {
 impl Validate for SetClockLatency {
     fn validate(&self, version: SdcVersion) -> Vec<ValidateError> {
         let mut ret = vec![];
-        self.cmd_supported_version(&mut ret, version.within(SDC1_1, SDC2_1));
-        self.arg_supported_version(
-            &mut ret,
-            version.within(SDC1_2, SDC2_1),
-            &self.early,
-            "early",
-        );
-        self.arg_supported_version(&mut ret, version.within(SDC1_2, SDC2_1), &self.late, "late");
-        self.arg_supported_version(
-            &mut ret,
-            version.within(SDC1_5, SDC2_1),
-            &self.clock,
-            "clock",
-        );
-        self.arg_supported_version(
-            &mut ret,
-            version.within(SDC2_1, SDC2_1),
-            &self.dynamic,
-            "dynamic",
-        );
         validate_opt(&mut ret, version, &self.clock);
         validate_arg(&mut ret, version, &self.delay);
         validate_arg(&mut ret, version, &self.object_list);
@@ -3256,11 +3859,7 @@ fn set_clock_latency(args: Vec<Argument>, location: Location) -> Result<Command,
     let mut object_list = None;
 
     static DICT: OnceLock<LazyDict> = OnceLock::new();
-    let dict = DICT.get_or_init(|| {
-        LazyDict::new(&[
-            "-rise", "-fall", "-min", "-max", "-source", "-dynamic", "-late", "-early", "-clock",
-        ])
-    });
+    let dict = DICT.get_or_init(|| LazyDict::from_kind(CommandKind::SetClockLatency));
 
     let mut iter = args.into_iter();
     while let Some(arg) = iter.next() {
@@ -3307,8 +3906,17 @@ pub struct SetClockSense {
     pub pulse: Option<Argument>,
     pub pins: Option<Argument>,
     location: Location,
+    dialect: Dialect,
 }
 
+builder!(SetClockSense, SetClockSenseBuilder {
+    mandatory: [],
+    optional: [clocks, pulse, pins],
+    flag: [positive, negative, stop_propagation],
+    repeatable: [],
+    extra: [dialect: Dialect],
+});
+
 impl fmt::Display for SetClockSense {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut text = "set_clock_sense".to_string();
@@ -3335,10 +3943,15 @@ impl CommandExt for SetClockSense {
 impl Validate for SetClockSense {
     fn validate(&self, version: SdcVersion) -> Vec<ValidateError> {
         let mut ret = vec![];
-        self.cmd_supported_version(&mut ret, version.within(SDC1_7, SDC2_0));
+        // OpenSTA keeps set_clock_sense after SDC 2.1
+        let supported = if self.dialect == Dialect::OpenSta {
+            version.within(SDC1_7, SDC2_1)
+        } else {
+            version.within(SDC1_7, SDC2_0)
+        };
         self.arg_comb4(
             &mut ret,
-            version.within(SDC1_7, SDC2_0),
+            supported,
             &self.positive,
             &self.negative,
             &self.stop_propagation,
@@ -3366,7 +3979,11 @@ impl Extract for SetClockSense {
     }
 }
 
-fn set_clock_sense(args: Vec<Argument>, location: Location) -> Result<Command, SemanticError> {
+fn set_clock_sense(
+    args: Vec<Argument>,
+    location: Location,
+    dialect: Dialect,
+) -> Result<Command, SemanticError> {
     let mut clocks = None;
     let mut positive = false;
     let mut negative = false;
@@ -3375,15 +3992,7 @@ fn set_clock_sense(args: Vec<Argument>, location: Location) -> Result<Command, S
     let mut pins = None;
 
     static DICT: OnceLock<LazyDict> = OnceLock::new();
-    let dict = DICT.get_or_init(|| {
-        LazyDict::new(&[
-            "-clocks",
-            "-positive",
-            "-negative",
-            "-stop_propagation",
-            "-pulse",
-        ])
-    });
+    let dict = DICT.get_or_init(|| LazyDict::from_kind(CommandKind::SetClockSense));
 
     let mut iter = args.into_iter();
     while let Some(arg) = iter.next() {
@@ -3405,6 +4014,7 @@ fn set_clock_sense(args: Vec<Argument>, location: Location) -> Result<Command, S
         pulse,
         pins,
         location,
+        dialect,
     }))
 }
 
@@ -3420,6 +4030,17 @@ pub struct SetClockTransition {
     location: Location,
 }
 
+builder!(
+    SetClockTransition,
+    SetClockTransitionBuilder {
+        mandatory: [transition, clock_list],
+        optional: [],
+        flag: [rise, fall, min, max],
+        repeatable: [],
+        extra: [],
+    }
+);
+
 impl fmt::Display for SetClockTransition {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut text = "set_clock_transition".to_string();
@@ -3446,7 +4067,6 @@ impl CommandExt for SetClockTransition {
 impl Validate for SetClockTransition {
     fn validate(&self, version: SdcVersion) -> Vec<ValidateError> {
         let mut ret = vec![];
-        self.cmd_supported_version(&mut ret, version.within(SDC1_1, SDC2_1));
         self.arg_comb2(
             &mut ret,
             version.within(SDC1_2, SDC2_1),
@@ -3481,7 +4101,7 @@ fn set_clock_transition(args: Vec<Argument>, location: Location) -> Result<Comma
     let mut clock_list = None;
 
     static DICT: OnceLock<LazyDict> = OnceLock::new();
-    let dict = DICT.get_or_init(|| LazyDict::new(&["-rise", "-fall", "-min", "-max"]));
+    let dict = DICT.get_or_init(|| LazyDict::from_kind(CommandKind::SetClockTransition));
 
     let mut iter = args.into_iter();
     while let Some(arg) = iter.next() {
@@ -3529,6 +4149,25 @@ pub struct SetClockUncertainty {
     location: Location,
 }
 
+builder!(
+    SetClockUncertainty,
+    SetClockUncertaintyBuilder {
+        mandatory: [uncertainty],
+        optional: [
+            from,
+            rise_from,
+            fall_from,
+            to,
+            rise_to,
+            fall_to,
+            object_list
+        ],
+        flag: [rise, fall, setup, hold],
+        repeatable: [],
+        extra: [],
+    }
+);
+
 impl fmt::Display for SetClockUncertainty {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut text = "set_clock_uncertainty".to_string();
@@ -3561,31 +4200,6 @@ impl CommandExt for SetClockUncertainty {
 impl Validate for SetClockUncertainty {
     fn validate(&self, version: SdcVersion) -> Vec<ValidateError> {
         let mut ret = vec![];
-        self.cmd_supported_version(&mut ret, version.within(SDC1_1, SDC2_1));
-        self.arg_supported_version(
-            &mut ret,
-            version.within(SDC1_5, SDC2_1),
-            &self.rise_from,
-            "rise_from",
-        );
-        self.arg_supported_version(
-            &mut ret,
-            version.within(SDC1_5, SDC2_1),
-            &self.fall_from,
-            "fall_from",
-        );
-        self.arg_supported_version(
-            &mut ret,
-            version.within(SDC1_5, SDC2_1),
-            &self.rise_to,
-            "rise_to",
-        );
-        self.arg_supported_version(
-            &mut ret,
-            version.within(SDC1_5, SDC2_1),
-            &self.fall_to,
-            "fall_to",
-        );
         self.arg_comb3(
             &mut ret,
             version.within(SDC1_1, SDC1_2),
@@ -3668,20 +4282,7 @@ fn set_clock_uncertainty(
     let mut object_list = None;
 
     static DICT: OnceLock<LazyDict> = OnceLock::new();
-    let dict = DICT.get_or_init(|| {
-        LazyDict::new(&[
-            "-from",
-            "-rise_from",
-            "-fall_from",
-            "-to",
-            "-rise_to",
-            "-fall_to",
-            "-rise",
-            "-fall",
-            "-setup",
-            "-hold",
-        ])
-    });
+    let dict = DICT.get_or_init(|| LazyDict::from_kind(CommandKind::SetClockUncertainty));
 
     let mut iter = args.into_iter();
     while let Some(arg) = iter.next() {
@@ -3738,6 +4339,17 @@ pub struct SetDataCheck {
     location: Location,
 }
 
+builder!(
+    SetDataCheck,
+    SetDataCheckBuilder {
+        mandatory: [value],
+        optional: [from, to, rise_from, fall_from, rise_to, fall_to, clock],
+        flag: [setup, hold],
+        repeatable: [],
+        extra: [],
+    }
+);
+
 impl fmt::Display for SetDataCheck {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut text = "set_data_check".to_string();
@@ -3768,7 +4380,6 @@ impl CommandExt for SetDataCheck {
 impl Validate for SetDataCheck {
     fn validate(&self, version: SdcVersion) -> Vec<ValidateError> {
         let mut ret = vec![];
-        self.cmd_supported_version(&mut ret, version.within(SDC1_4, SDC2_1));
         self.arg_comb6(
             &mut ret,
             version.within(SDC1_2, SDC2_1),
@@ -3829,19 +4440,7 @@ fn set_data_check(args: Vec<Argument>, location: Location) -> Result<Command, Se
     let mut value = None;
 
     static DICT: OnceLock<LazyDict> = OnceLock::new();
-    let dict = DICT.get_or_init(|| {
-        LazyDict::new(&[
-            "-from",
-            "-to",
-            "-rise_from",
-            "-fall_from",
-            "-rise_to",
-            "-fall_to",
-            "-setup",
-            "-hold",
-            "-clock",
-        ])
-    });
+    let dict = DICT.get_or_init(|| LazyDict::from_kind(CommandKind::SetDataCheck));
 
     let mut iter = args.into_iter();
     while let Some(arg) = iter.next() {
@@ -3885,6 +4484,17 @@ pub struct SetDisableTiming {
     location: Location,
 }
 
+builder!(
+    SetDisableTiming,
+    SetDisableTimingBuilder {
+        mandatory: [cell_pin_list],
+        optional: [from, to],
+        flag: [],
+        repeatable: [],
+        extra: [],
+    }
+);
+
 impl fmt::Display for SetDisableTiming {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut text = "set_disable_timing".to_string();
@@ -3908,7 +4518,6 @@ impl CommandExt for SetDisableTiming {
 impl Validate for SetDisableTiming {
     fn validate(&self, version: SdcVersion) -> Vec<ValidateError> {
         let mut ret = vec![];
-        self.cmd_supported_version(&mut ret, version.within(SDC1_1, SDC2_1));
         self.arg_comb2(
             &mut ret,
             version.within(SDC1_2, SDC2_1),
@@ -3943,7 +4552,7 @@ fn set_disable_timing(args: Vec<Argument>, location: Location) -> Result<Command
     let mut cell_pin_list = None;
 
     static DICT: OnceLock<LazyDict> = OnceLock::new();
-    let dict = DICT.get_or_init(|| LazyDict::new(&["-from", "-to"]));
+    let dict = DICT.get_or_init(|| LazyDict::from_kind(CommandKind::SetDisableTiming));
 
     let mut iter = args.into_iter();
     while let Some(arg) = iter.next() {
@@ -3976,6 +4585,17 @@ pub struct SetDrive {
     location: Location,
 }
 
+builder!(
+    SetDrive,
+    SetDriveBuilder {
+        mandatory: [resistance, port_list],
+        optional: [],
+        flag: [rise, fall, min, max],
+        repeatable: [],
+        extra: [],
+    }
+);
+
 impl fmt::Display for SetDrive {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut text = "set_drive".to_string();
@@ -4002,7 +4622,6 @@ impl CommandExt for SetDrive {
 impl Validate for SetDrive {
     fn validate(&self, version: SdcVersion) -> Vec<ValidateError> {
         let mut ret = vec![];
-        self.cmd_supported_version(&mut ret, version.within(SDC1_1, SDC2_1));
         validate_arg(&mut ret, version, &self.resistance);
         validate_arg(&mut ret, version, &self.port_list);
         ret
@@ -4030,7 +4649,7 @@ fn set_drive(args: Vec<Argument>, location: Location) -> Result<Command, Semanti
     let mut port_list = None;
 
     static DICT: OnceLock<LazyDict> = OnceLock::new();
-    let dict = DICT.get_or_init(|| LazyDict::new(&["-rise", "-fall", "-min", "-max"]));
+    let dict = DICT.get_or_init(|| LazyDict::from_kind(CommandKind::SetDrive));
 
     let mut iter = args.into_iter();
     while let Some(arg) = iter.next() {
@@ -4081,6 +4700,25 @@ pub struct SetDrivingCell {
     location: Location,
 }
 
+builder!(
+    SetDrivingCell,
+    SetDrivingCellBuilder {
+        mandatory: [lib_cell, port_list],
+        optional: [
+            library,
+            pin,
+            from_pin,
+            multiply_by,
+            clock,
+            input_transition_rise,
+            input_transition_fall,
+        ],
+        flag: [rise, fall, min, max, dont_scale, no_design_rule, clock_fall],
+        repeatable: [],
+        extra: [],
+    }
+);
+
 impl fmt::Display for SetDrivingCell {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut text = "set_driving_cell".to_string();
@@ -4123,27 +4761,6 @@ impl CommandExt for SetDrivingCell {
 impl Validate for SetDrivingCell {
     fn validate(&self, version: SdcVersion) -> Vec<ValidateError> {
         let mut ret = vec![];
-        self.cmd_supported_version(&mut ret, version.within(SDC1_1, SDC2_1));
-        self.arg_supported_version(&mut ret, version.within(SDC1_4, SDC2_1), &self.min, "min");
-        self.arg_supported_version(&mut ret, version.within(SDC1_4, SDC2_1), &self.max, "max");
-        self.arg_supported_version(
-            &mut ret,
-            version.within(SDC1_4, SDC2_1),
-            &self.clock,
-            "clock",
-        );
-        self.arg_supported_version(
-            &mut ret,
-            version.within(SDC1_4, SDC2_1),
-            &self.clock_fall,
-            "clock_fall",
-        );
-        self.arg_supported_version(
-            &mut ret,
-            version.within(SDC1_1, SDC2_0),
-            &self.multiply_by,
-            "multiply_by",
-        );
         validate_arg(&mut ret, version, &self.lib_cell);
         validate_opt(&mut ret, version, &self.library);
         validate_opt(&mut ret, version, &self.pin);
@@ -4202,25 +4819,7 @@ fn set_driving_cell(args: Vec<Argument>, location: Location) -> Result<Command,
     let mut port_list = None;
 
     static DICT: OnceLock<LazyDict> = OnceLock::new();
-    let dict = DICT.get_or_init(|| {
-        LazyDict::new(&[
-            "-lib_cell",
-            "-rise",
-            "-fall",
-            "-min",
-            "-max",
-            "-library",
-            "-pin",
-            "-from_pin",
-            "-multiply_by",
-            "-dont_scale",
-            "-no_design_rule",
-            "-clock",
-            "-clock_fall",
-            "-input_transition_rise",
-            "-input_transition_fall",
-        ])
-    });
+    let dict = DICT.get_or_init(|| LazyDict::from_kind(CommandKind::SetDrivingCell));
 
     let mut iter = args.into_iter();
     while let Some(arg) = iter.next() {
@@ -4292,6 +4891,17 @@ pub struct SetFalsePath {
     location: Location,
 }
 
+builder!(
+    SetFalsePath,
+    SetFalsePathBuilder {
+        mandatory: [],
+        optional: [from, to, rise_from, rise_to, fall_from, fall_to, comment],
+        flag: [setup, hold, rise, fall],
+        repeatable: [through, rise_through, fall_through],
+        extra: [],
+    }
+);
+
 impl fmt::Display for SetFalsePath {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut text = "set_false_path".to_string();
@@ -4320,55 +4930,12 @@ impl CommandExt for SetFalsePath {
 
     fn kind(&self) -> CommandKind {
         CommandKind::SetFalsePath
-    }
-}
-
-impl Validate for SetFalsePath {
-    fn validate(&self, version: SdcVersion) -> Vec<ValidateError> {
-        let mut ret = vec![];
-        self.cmd_supported_version(&mut ret, version.within(SDC1_1, SDC2_1));
-        self.arg_supported_version(
-            &mut ret,
-            version.within(SDC1_7, SDC2_1),
-            &self.rise_from,
-            "rise_from",
-        );
-        self.arg_supported_version(
-            &mut ret,
-            version.within(SDC1_7, SDC2_1),
-            &self.fall_from,
-            "fall_from",
-        );
-        self.arg_supported_version(
-            &mut ret,
-            version.within(SDC1_7, SDC2_1),
-            &self.rise_to,
-            "rise_to",
-        );
-        self.arg_supported_version(
-            &mut ret,
-            version.within(SDC1_7, SDC2_1),
-            &self.fall_to,
-            "fall_to",
-        );
-        self.arg_supported_version(
-            &mut ret,
-            version.within(SDC1_7, SDC2_1),
-            &self.rise_through,
-            "rise_through",
-        );
-        self.arg_supported_version(
-            &mut ret,
-            version.within(SDC1_7, SDC2_1),
-            &self.fall_through,
-            "fall_through",
-        );
-        self.arg_supported_version(
-            &mut ret,
-            version.within(SDC1_9, SDC2_1),
-            &self.comment,
-            "comment",
-        );
+    }
+}
+
+impl Validate for SetFalsePath {
+    fn validate(&self, version: SdcVersion) -> Vec<ValidateError> {
+        let mut ret = vec![];
         self.arg_comb3(
             &mut ret,
             version.within(SDC1_1, SDC1_1),
@@ -4448,24 +5015,7 @@ fn set_false_path(args: Vec<Argument>, location: Location) -> Result<Command, Se
     let mut comment = None;
 
     static DICT: OnceLock<LazyDict> = OnceLock::new();
-    let dict = DICT.get_or_init(|| {
-        LazyDict::new(&[
-            "-setup",
-            "-hold",
-            "-rise",
-            "-fall",
-            "-from",
-            "-to",
-            "-through",
-            "-rise_from",
-            "-rise_to",
-            "-rise_through",
-            "-fall_from",
-            "-fall_to",
-            "-fall_through",
-            "-comment",
-        ])
-    });
+    let dict = DICT.get_or_init(|| LazyDict::from_kind(CommandKind::SetFalsePath));
 
     let mut iter = args.into_iter();
     while let Some(arg) = iter.next() {
@@ -4515,6 +5065,17 @@ pub struct SetFanoutLoad {
     location: Location,
 }
 
+builder!(
+    SetFanoutLoad,
+    SetFanoutLoadBuilder {
+        mandatory: [value, port_list],
+        optional: [],
+        flag: [],
+        repeatable: [],
+        extra: [],
+    }
+);
+
 impl fmt::Display for SetFanoutLoad {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut text = "set_fanout_load".to_string();
@@ -4537,7 +5098,6 @@ impl CommandExt for SetFanoutLoad {
 impl Validate for SetFanoutLoad {
     fn validate(&self, version: SdcVersion) -> Vec<ValidateError> {
         let mut ret = vec![];
-        self.cmd_supported_version(&mut ret, version.within(SDC1_1, SDC2_1));
         validate_arg(&mut ret, version, &self.value);
         validate_arg(&mut ret, version, &self.port_list);
         ret
@@ -4582,6 +5142,17 @@ pub struct SetHierarchySeparator {
     location: Location,
 }
 
+builder!(
+    SetHierarchySeparator,
+    SetHierarchySeparatorBuilder {
+        mandatory: [separator],
+        optional: [],
+        flag: [],
+        repeatable: [],
+        extra: [],
+    }
+);
+
 impl fmt::Display for SetHierarchySeparator {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut text = "set_hierarchy_separator".to_string();
@@ -4603,7 +5174,6 @@ impl CommandExt for SetHierarchySeparator {
 impl Validate for SetHierarchySeparator {
     fn validate(&self, version: SdcVersion) -> Vec<ValidateError> {
         let mut ret = vec![];
-        self.cmd_supported_version(&mut ret, version.within(SDC1_2, SDC2_1));
         validate_arg(&mut ret, version, &self.separator);
         ret
     }
@@ -4650,6 +5220,17 @@ pub struct SetIdealLatency {
     location: Location,
 }
 
+builder!(
+    SetIdealLatency,
+    SetIdealLatencyBuilder {
+        mandatory: [delay, object_list],
+        optional: [],
+        flag: [rise, fall, min, max],
+        repeatable: [],
+        extra: [],
+    }
+);
+
 impl fmt::Display for SetIdealLatency {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut text = "set_ideal_latency".to_string();
@@ -4676,7 +5257,6 @@ impl CommandExt for SetIdealLatency {
 impl Validate for SetIdealLatency {
     fn validate(&self, version: SdcVersion) -> Vec<ValidateError> {
         let mut ret = vec![];
-        self.cmd_supported_version(&mut ret, version.within(SDC1_7, SDC2_1));
         validate_arg(&mut ret, version, &self.delay);
         validate_arg(&mut ret, version, &self.object_list);
         ret
@@ -4704,7 +5284,7 @@ fn set_ideal_latency(args: Vec<Argument>, location: Location) -> Result<Command,
     let mut object_list = None;
 
     static DICT: OnceLock<LazyDict> = OnceLock::new();
-    let dict = DICT.get_or_init(|| LazyDict::new(&["-rise", "-fall", "-min", "-max"]));
+    let dict = DICT.get_or_init(|| LazyDict::from_kind(CommandKind::SetIdealLatency));
 
     let mut iter = args.into_iter();
     while let Some(arg) = iter.next() {
@@ -4739,6 +5319,17 @@ pub struct SetIdealNetwork {
     location: Location,
 }
 
+builder!(
+    SetIdealNetwork,
+    SetIdealNetworkBuilder {
+        mandatory: [object_list],
+        optional: [],
+        flag: [no_propagate],
+        repeatable: [],
+        extra: [],
+    }
+);
+
 impl fmt::Display for SetIdealNetwork {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut text = "set_ideal_network".to_string();
@@ -4761,7 +5352,6 @@ impl CommandExt for SetIdealNetwork {
 impl Validate for SetIdealNetwork {
     fn validate(&self, version: SdcVersion) -> Vec<ValidateError> {
         let mut ret = vec![];
-        self.cmd_supported_version(&mut ret, version.within(SDC1_7, SDC2_1));
         validate_arg(&mut ret, version, &self.object_list);
         ret
     }
@@ -4782,7 +5372,7 @@ fn set_ideal_network(args: Vec<Argument>, location: Location) -> Result<Command,
     let mut object_list = None;
 
     static DICT: OnceLock<LazyDict> = OnceLock::new();
-    let dict = DICT.get_or_init(|| LazyDict::new(&["-no_propagate"]));
+    let dict = DICT.get_or_init(|| LazyDict::from_kind(CommandKind::SetIdealNetwork));
 
     let mut iter = args.into_iter();
     while let Some(arg) = iter.next() {
@@ -4813,6 +5403,17 @@ pub struct SetIdealTransition {
     location: Location,
 }
 
+builder!(
+    SetIdealTransition,
+    SetIdealTransitionBuilder {
+        mandatory: [transition_time, object_list],
+        optional: [],
+        flag: [rise, fall, min, max],
+        repeatable: [],
+        extra: [],
+    }
+);
+
 impl fmt::Display for SetIdealTransition {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut text = "set_ideal_transition".to_string();
@@ -4839,7 +5440,6 @@ impl CommandExt for SetIdealTransition {
 impl Validate for SetIdealTransition {
     fn validate(&self, version: SdcVersion) -> Vec<ValidateError> {
         let mut ret = vec![];
-        self.cmd_supported_version(&mut ret, version.within(SDC1_7, SDC2_1));
         validate_arg(&mut ret, version, &self.transition_time);
         validate_arg(&mut ret, version, &self.object_list);
         ret
@@ -4867,7 +5467,7 @@ fn set_ideal_transition(args: Vec<Argument>, location: Location) -> Result<Comma
     let mut object_list = None;
 
     static DICT: OnceLock<LazyDict> = OnceLock::new();
-    let dict = DICT.get_or_init(|| LazyDict::new(&["-rise", "-fall", "-min", "-max"]));
+    let dict = DICT.get_or_init(|| LazyDict::from_kind(CommandKind::SetIdealTransition));
 
     let mut iter = args.into_iter();
     while let Some(arg) = iter.next() {
@@ -4914,8 +5514,20 @@ pub struct SetInputDelay {
     pub delay_value: Argument,
     pub port_pin_list: Argument,
     location: Location,
+    dialect: Dialect,
 }
 
+builder!(SetInputDelay, SetInputDelayBuilder {
+    mandatory: [delay_value, port_pin_list],
+    optional: [clock, reference_pin],
+    flag: [
+        clock_fall, level_sensitive, rise, fall, max, min, add_delay, network_latency_included,
+        source_latency_included,
+    ],
+    repeatable: [],
+    extra: [dialect: Dialect],
+});
+
 impl fmt::Display for SetInputDelay {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut text = "set_input_delay".to_string();
@@ -4955,25 +5567,6 @@ impl CommandExt for SetInputDelay {
 impl Validate for SetInputDelay {
     fn validate(&self, version: SdcVersion) -> Vec<ValidateError> {
         let mut ret = vec![];
-        self.cmd_supported_version(&mut ret, version.within(SDC1_1, SDC2_1));
-        self.arg_supported_version(
-            &mut ret,
-            version.within(SDC1_4, SDC2_1),
-            &self.network_latency_included,
-            "network_latency_included",
-        );
-        self.arg_supported_version(
-            &mut ret,
-            version.within(SDC1_4, SDC2_1),
-            &self.source_latency_included,
-            "source_latency_included",
-        );
-        self.arg_supported_version(
-            &mut ret,
-            version.within(SDC2_0, SDC2_1),
-            &self.reference_pin,
-            "reference_pin",
-        );
         self.arg_comb3(
             &mut ret,
             version.within(SDC1_2, SDC2_1),
@@ -5006,7 +5599,11 @@ impl Extract for SetInputDelay {
     }
 }
 
-fn set_input_delay(args: Vec<Argument>, location: Location) -> Result<Command, SemanticError> {
+fn set_input_delay(
+    args: Vec<Argument>,
+    location: Location,
+    dialect: Dialect,
+) -> Result<Command, SemanticError> {
     let mut clock = None;
     let mut reference_pin = None;
     let mut clock_fall = false;
@@ -5022,21 +5619,7 @@ fn set_input_delay(args: Vec<Argument>, location: Location) -> Result<Command, S
     let mut port_pin_list = None;
 
     static DICT: OnceLock<LazyDict> = OnceLock::new();
-    let dict = DICT.get_or_init(|| {
-        LazyDict::new(&[
-            "-clock",
-            "-reference_pin",
-            "-clock_fall",
-            "-level_sensitive",
-            "-rise",
-            "-fall",
-            "-max",
-            "-min",
-            "-add_delay",
-            "-network_latency_included",
-            "-source_latency_included",
-        ])
-    });
+    let dict = DICT.get_or_init(|| LazyDict::from_kind(CommandKind::SetInputDelay));
 
     let mut iter = args.into_iter();
     while let Some(arg) = iter.next() {
@@ -5081,6 +5664,7 @@ fn set_input_delay(args: Vec<Argument>, location: Location) -> Result<Command, S
         delay_value,
         port_pin_list,
         location,
+        dialect,
     }))
 }
 
@@ -5098,6 +5682,17 @@ pub struct SetInputTransition {
     location: Location,
 }
 
+builder!(
+    SetInputTransition,
+    SetInputTransitionBuilder {
+        mandatory: [transition, port_list],
+        optional: [clock],
+        flag: [rise, fall, min, max, clock_fall],
+        repeatable: [],
+        extra: [],
+    }
+);
+
 impl fmt::Display for SetInputTransition {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut text = "set_input_transition".to_string();
@@ -5126,19 +5721,6 @@ impl CommandExt for SetInputTransition {
 impl Validate for SetInputTransition {
     fn validate(&self, version: SdcVersion) -> Vec<ValidateError> {
         let mut ret = vec![];
-        self.cmd_supported_version(&mut ret, version.within(SDC1_1, SDC2_1));
-        self.arg_supported_version(
-            &mut ret,
-            version.within(SDC1_4, SDC2_1),
-            &self.clock,
-            "clock",
-        );
-        self.arg_supported_version(
-            &mut ret,
-            version.within(SDC1_4, SDC2_1),
-            &self.clock_fall,
-            "clock_fall",
-        );
         validate_opt(&mut ret, version, &self.clock);
         validate_arg(&mut ret, version, &self.transition);
         validate_arg(&mut ret, version, &self.port_list);
@@ -5171,9 +5753,7 @@ fn set_input_transition(args: Vec<Argument>, location: Location) -> Result<Comma
     let mut port_list = None;
 
     static DICT: OnceLock<LazyDict> = OnceLock::new();
-    let dict = DICT.get_or_init(|| {
-        LazyDict::new(&["-rise", "-fall", "-min", "-max", "-clock", "-clock_fall"])
-    });
+    let dict = DICT.get_or_init(|| LazyDict::from_kind(CommandKind::SetInputTransition));
 
     let mut iter = args.into_iter();
     while let Some(arg) = iter.next() {
@@ -5213,6 +5793,17 @@ pub struct SetLevelShifterStrategy {
     location: Location,
 }
 
+builder!(
+    SetLevelShifterStrategy,
+    SetLevelShifterStrategyBuilder {
+        mandatory: [rule],
+        optional: [],
+        flag: [],
+        repeatable: [],
+        extra: [],
+    }
+);
+
 impl fmt::Display for SetLevelShifterStrategy {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut text = "set_level_shifter_strategy".to_string();
@@ -5234,7 +5825,6 @@ impl CommandExt for SetLevelShifterStrategy {
 impl Validate for SetLevelShifterStrategy {
     fn validate(&self, version: SdcVersion) -> Vec<ValidateError> {
         let mut ret = vec![];
-        self.cmd_supported_version(&mut ret, version.within(SDC1_6, SDC2_1));
         validate_arg(&mut ret, version, &self.rule);
         ret
     }
@@ -5257,7 +5847,7 @@ fn set_level_shifter_strategy(
     let mut rule = None;
 
     static DICT: OnceLock<LazyDict> = OnceLock::new();
-    let dict = DICT.get_or_init(|| LazyDict::new(&["-rule"]));
+    let dict = DICT.get_or_init(|| LazyDict::from_kind(CommandKind::SetLevelShifterStrategy));
 
     let mut iter = args.into_iter();
     while let Some(arg) = iter.next() {
@@ -5283,6 +5873,17 @@ pub struct SetLevelShifterThreshold {
     location: Location,
 }
 
+builder!(
+    SetLevelShifterThreshold,
+    SetLevelShifterThresholdBuilder {
+        mandatory: [voltage],
+        optional: [percent],
+        flag: [],
+        repeatable: [],
+        extra: [],
+    }
+);
+
 impl fmt::Display for SetLevelShifterThreshold {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut text = "set_level_shifter_threshold".to_string();
@@ -5305,7 +5906,6 @@ impl CommandExt for SetLevelShifterThreshold {
 impl Validate for SetLevelShifterThreshold {
     fn validate(&self, version: SdcVersion) -> Vec<ValidateError> {
         let mut ret = vec![];
-        self.cmd_supported_version(&mut ret, version.within(SDC1_6, SDC2_1));
         validate_arg(&mut ret, version, &self.voltage);
         validate_opt(&mut ret, version, &self.percent);
         ret
@@ -5332,7 +5932,7 @@ fn set_level_shifter_threshold(
     let mut percent = None;
 
     static DICT: OnceLock<LazyDict> = OnceLock::new();
-    let dict = DICT.get_or_init(|| LazyDict::new(&["-voltage", "-percent"]));
+    let dict = DICT.get_or_init(|| LazyDict::from_kind(CommandKind::SetLevelShifterThreshold));
 
     let mut iter = args.into_iter();
     while let Some(arg) = iter.next() {
@@ -5367,6 +5967,17 @@ pub struct SetLoad {
     location: Location,
 }
 
+builder!(
+    SetLoad,
+    SetLoadBuilder {
+        mandatory: [value, objects],
+        optional: [],
+        flag: [min, max, subtract_pin_load, pin_load, wire_load],
+        repeatable: [],
+        extra: [],
+    }
+);
+
 impl fmt::Display for SetLoad {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut text = "set_load".to_string();
@@ -5394,7 +6005,6 @@ impl CommandExt for SetLoad {
 impl Validate for SetLoad {
     fn validate(&self, version: SdcVersion) -> Vec<ValidateError> {
         let mut ret = vec![];
-        self.cmd_supported_version(&mut ret, version.within(SDC1_1, SDC2_1));
         validate_arg(&mut ret, version, &self.value);
         validate_arg(&mut ret, version, &self.objects);
         ret
@@ -5423,15 +6033,7 @@ fn set_load(args: Vec<Argument>, location: Location) -> Result<Command, Semantic
     let mut objects = None;
 
     static DICT: OnceLock<LazyDict> = OnceLock::new();
-    let dict = DICT.get_or_init(|| {
-        LazyDict::new(&[
-            "-min",
-            "-max",
-            "-subtract_pin_load",
-            "-pin_load",
-            "-wire_load",
-        ])
-    });
+    let dict = DICT.get_or_init(|| LazyDict::from_kind(CommandKind::SetLoad));
 
     let mut iter = args.into_iter();
     while let Some(arg) = iter.next() {
@@ -5467,6 +6069,17 @@ pub struct SetLogicDc {
     location: Location,
 }
 
+builder!(
+    SetLogicDc,
+    SetLogicDcBuilder {
+        mandatory: [port_list],
+        optional: [],
+        flag: [],
+        repeatable: [],
+        extra: [],
+    }
+);
+
 impl fmt::Display for SetLogicDc {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut text = "set_logic_dc".to_string();
@@ -5488,7 +6101,6 @@ impl CommandExt for SetLogicDc {
 impl Validate for SetLogicDc {
     fn validate(&self, version: SdcVersion) -> Vec<ValidateError> {
         let mut ret = vec![];
-        self.cmd_supported_version(&mut ret, version.within(SDC1_1, SDC2_1));
         validate_arg(&mut ret, version, &self.port_list);
         ret
     }
@@ -5527,6 +6139,17 @@ pub struct SetLogicOne {
     location: Location,
 }
 
+builder!(
+    SetLogicOne,
+    SetLogicOneBuilder {
+        mandatory: [port_list],
+        optional: [],
+        flag: [],
+        repeatable: [],
+        extra: [],
+    }
+);
+
 impl fmt::Display for SetLogicOne {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut text = "set_logic_one".to_string();
@@ -5548,7 +6171,6 @@ impl CommandExt for SetLogicOne {
 impl Validate for SetLogicOne {
     fn validate(&self, version: SdcVersion) -> Vec<ValidateError> {
         let mut ret = vec![];
-        self.cmd_supported_version(&mut ret, version.within(SDC1_1, SDC2_1));
         validate_arg(&mut ret, version, &self.port_list);
         ret
     }
@@ -5587,6 +6209,17 @@ pub struct SetLogicZero {
     location: Location,
 }
 
+builder!(
+    SetLogicZero,
+    SetLogicZeroBuilder {
+        mandatory: [port_list],
+        optional: [],
+        flag: [],
+        repeatable: [],
+        extra: [],
+    }
+);
+
 impl fmt::Display for SetLogicZero {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut text = "set_logic_zero".to_string();
@@ -5608,7 +6241,6 @@ impl CommandExt for SetLogicZero {
 impl Validate for SetLogicZero {
     fn validate(&self, version: SdcVersion) -> Vec<ValidateError> {
         let mut ret = vec![];
-        self.cmd_supported_version(&mut ret, version.within(SDC1_1, SDC2_1));
         validate_arg(&mut ret, version, &self.port_list);
         ret
     }
@@ -5647,6 +6279,17 @@ pub struct SetMaxArea {
     location: Location,
 }
 
+builder!(
+    SetMaxArea,
+    SetMaxAreaBuilder {
+        mandatory: [area_value],
+        optional: [],
+        flag: [],
+        repeatable: [],
+        extra: [],
+    }
+);
+
 impl fmt::Display for SetMaxArea {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut text = "set_max_area".to_string();
@@ -5668,7 +6311,6 @@ impl CommandExt for SetMaxArea {
 impl Validate for SetMaxArea {
     fn validate(&self, version: SdcVersion) -> Vec<ValidateError> {
         let mut ret = vec![];
-        self.cmd_supported_version(&mut ret, version.within(SDC1_1, SDC2_1));
         validate_arg(&mut ret, version, &self.area_value);
         ret
     }
@@ -5708,6 +6350,17 @@ pub struct SetMaxCapacitance {
     location: Location,
 }
 
+builder!(
+    SetMaxCapacitance,
+    SetMaxCapacitanceBuilder {
+        mandatory: [value, object_list],
+        optional: [],
+        flag: [],
+        repeatable: [],
+        extra: [],
+    }
+);
+
 impl fmt::Display for SetMaxCapacitance {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut text = "set_max_capacitance".to_string();
@@ -5730,7 +6383,6 @@ impl CommandExt for SetMaxCapacitance {
 impl Validate for SetMaxCapacitance {
     fn validate(&self, version: SdcVersion) -> Vec<ValidateError> {
         let mut ret = vec![];
-        self.cmd_supported_version(&mut ret, version.within(SDC1_1, SDC2_1));
         validate_arg(&mut ret, version, &self.value);
         validate_arg(&mut ret, version, &self.object_list);
         ret
@@ -5783,11 +6435,23 @@ pub struct SetMaxDelay {
     pub fall_to: Option<Argument>,
     pub fall_through: Vec<Argument>,
     pub ignore_clock_latency: bool,
+    pub datapath_only: bool,
     pub comment: Option<Argument>,
     pub delay_value: Argument,
     location: Location,
 }
 
+builder!(
+    SetMaxDelay,
+    SetMaxDelayBuilder {
+        mandatory: [delay_value],
+        optional: [from, to, rise_from, rise_to, fall_from, fall_to, comment],
+        flag: [rise, fall, ignore_clock_latency, datapath_only],
+        repeatable: [through, rise_through, fall_through],
+        extra: [],
+    }
+);
+
 impl fmt::Display for SetMaxDelay {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut text = "set_max_delay".to_string();
@@ -5806,6 +6470,7 @@ impl fmt::Display for SetMaxDelay {
             self.ignore_clock_latency,
             "ignore_clock_latency",
         ));
+        text.push_str(&fmt_named_flg(self.datapath_only, "datapath_only"));
         text.push_str(&fmt_named_opt_arg(&self.comment, "comment"));
         text.push_str(&fmt_arg(&self.delay_value));
         text.fmt(f)
@@ -5825,55 +6490,6 @@ impl CommandExt for SetMaxDelay {
 impl Validate for SetMaxDelay {
     fn validate(&self, version: SdcVersion) -> Vec<ValidateError> {
         let mut ret = vec![];
-        self.cmd_supported_version(&mut ret, version.within(SDC1_1, SDC2_1));
-        self.arg_supported_version(
-            &mut ret,
-            version.within(SDC1_7, SDC2_1),
-            &self.rise_from,
-            "rise_from",
-        );
-        self.arg_supported_version(
-            &mut ret,
-            version.within(SDC1_7, SDC2_1),
-            &self.fall_from,
-            "fall_from",
-        );
-        self.arg_supported_version(
-            &mut ret,
-            version.within(SDC1_7, SDC2_1),
-            &self.rise_to,
-            "rise_to",
-        );
-        self.arg_supported_version(
-            &mut ret,
-            version.within(SDC1_7, SDC2_1),
-            &self.fall_to,
-            "fall_to",
-        );
-        self.arg_supported_version(
-            &mut ret,
-            version.within(SDC1_7, SDC2_1),
-            &self.rise_through,
-            "rise_through",
-        );
-        self.arg_supported_version(
-            &mut ret,
-            version.within(SDC1_7, SDC2_1),
-            &self.fall_through,
-            "fall_through",
-        );
-        self.arg_supported_version(
-            &mut ret,
-            version.within(SDC1_9, SDC2_1),
-            &self.comment,
-            "comment",
-        );
-        self.arg_supported_version(
-            &mut ret,
-            version.within(SDC2_1, SDC2_1),
-            &self.ignore_clock_latency,
-            "ignore_clock_latency",
-        );
         self.arg_comb2(
             &mut ret,
             version.within(SDC1_2, SDC2_1),
@@ -5926,7 +6542,11 @@ impl Extract for SetMaxDelay {
     }
 }
 
-fn set_max_delay(args: Vec<Argument>, location: Location) -> Result<Command, SemanticError> {
+fn set_max_delay(
+    args: Vec<Argument>,
+    location: Location,
+    dialect: Dialect,
+) -> Result<Command, SemanticError> {
     let mut rise = false;
     let mut fall = false;
     let mut from = None;
@@ -5939,27 +6559,12 @@ fn set_max_delay(args: Vec<Argument>, location: Location) -> Result<Command, Sem
     let mut fall_to = None;
     let mut fall_through = vec![];
     let mut ignore_clock_latency = false;
+    let mut datapath_only = false;
     let mut comment = None;
     let mut delay_value = None;
 
     static DICT: OnceLock<LazyDict> = OnceLock::new();
-    let dict = DICT.get_or_init(|| {
-        LazyDict::new(&[
-            "-rise",
-            "-fall",
-            "-from",
-            "-to",
-            "-through",
-            "-rise_from",
-            "-rise_to",
-            "-rise_through",
-            "-fall_from",
-            "-fall_to",
-            "-fall_through",
-            "-ignore_clock_latency",
-            "-comment",
-        ])
-    });
+    let dict = DICT.get_or_init(|| LazyDict::from_kind(CommandKind::SetMaxDelay));
 
     let mut iter = args.into_iter();
     while let Some(arg) = iter.next() {
@@ -5978,6 +6583,9 @@ fn set_max_delay(args: Vec<Argument>, location: Location) -> Result<Command, Sem
             x if x.m("-ignore_clock_latency") => {
                 ignore_clock_latency = opt_flg(arg, ignore_clock_latency)?
             }
+            x if x.m("-datapath_only") && dialect == Dialect::Xdc => {
+                datapath_only = opt_flg(arg, datapath_only)?
+            }
             x if x.m("-comment") => comment = opt_arg(arg, iter.next(), comment)?,
             _ => delay_value = pos_args1(Some(arg), delay_value, &location)?,
         }
@@ -5998,6 +6606,7 @@ fn set_max_delay(args: Vec<Argument>, location: Location) -> Result<Command, Sem
         fall_to,
         fall_through,
         ignore_clock_latency,
+        datapath_only,
         comment,
         delay_value,
         location,
@@ -6012,6 +6621,17 @@ pub struct SetMaxDynamicPower {
     location: Location,
 }
 
+builder!(
+    SetMaxDynamicPower,
+    SetMaxDynamicPowerBuilder {
+        mandatory: [power],
+        optional: [unit],
+        flag: [],
+        repeatable: [],
+        extra: [],
+    }
+);
+
 impl fmt::Display for SetMaxDynamicPower {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut text = "set_max_dynamic_power".to_string();
@@ -6034,7 +6654,6 @@ impl CommandExt for SetMaxDynamicPower {
 impl Validate for SetMaxDynamicPower {
     fn validate(&self, version: SdcVersion) -> Vec<ValidateError> {
         let mut ret = vec![];
-        self.cmd_supported_version(&mut ret, version.within(SDC1_4, SDC2_1));
         validate_arg(&mut ret, version, &self.power);
         validate_opt(&mut ret, version, &self.unit);
         ret
@@ -6082,6 +6701,17 @@ pub struct SetMaxFanout {
     location: Location,
 }
 
+builder!(
+    SetMaxFanout,
+    SetMaxFanoutBuilder {
+        mandatory: [value, object_list],
+        optional: [],
+        flag: [],
+        repeatable: [],
+        extra: [],
+    }
+);
+
 impl fmt::Display for SetMaxFanout {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut text = "set_max_fanout".to_string();
@@ -6104,7 +6734,6 @@ impl CommandExt for SetMaxFanout {
 impl Validate for SetMaxFanout {
     fn validate(&self, version: SdcVersion) -> Vec<ValidateError> {
         let mut ret = vec![];
-        self.cmd_supported_version(&mut ret, version.within(SDC1_1, SDC2_1));
         validate_arg(&mut ret, version, &self.value);
         validate_arg(&mut ret, version, &self.object_list);
         ret
@@ -6150,6 +6779,17 @@ pub struct SetMaxLeakagePower {
     location: Location,
 }
 
+builder!(
+    SetMaxLeakagePower,
+    SetMaxLeakagePowerBuilder {
+        mandatory: [power],
+        optional: [unit],
+        flag: [],
+        repeatable: [],
+        extra: [],
+    }
+);
+
 impl fmt::Display for SetMaxLeakagePower {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut text = "set_max_leakage_power".to_string();
@@ -6172,7 +6812,6 @@ impl CommandExt for SetMaxLeakagePower {
 impl Validate for SetMaxLeakagePower {
     fn validate(&self, version: SdcVersion) -> Vec<ValidateError> {
         let mut ret = vec![];
-        self.cmd_supported_version(&mut ret, version.within(SDC1_4, SDC2_1));
         validate_arg(&mut ret, version, &self.power);
         validate_opt(&mut ret, version, &self.unit);
         ret
@@ -6220,6 +6859,17 @@ pub struct SetMaxTimeBorrow {
     location: Location,
 }
 
+builder!(
+    SetMaxTimeBorrow,
+    SetMaxTimeBorrowBuilder {
+        mandatory: [delay_value, object_list],
+        optional: [],
+        flag: [],
+        repeatable: [],
+        extra: [],
+    }
+);
+
 impl fmt::Display for SetMaxTimeBorrow {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut text = "set_max_time_borrow".to_string();
@@ -6242,7 +6892,6 @@ impl CommandExt for SetMaxTimeBorrow {
 impl Validate for SetMaxTimeBorrow {
     fn validate(&self, version: SdcVersion) -> Vec<ValidateError> {
         let mut ret = vec![];
-        self.cmd_supported_version(&mut ret, version.within(SDC1_1, SDC2_1));
         validate_arg(&mut ret, version, &self.delay_value);
         validate_arg(&mut ret, version, &self.object_list);
         ret
@@ -6291,6 +6940,17 @@ pub struct SetMaxTransition {
     location: Location,
 }
 
+builder!(
+    SetMaxTransition,
+    SetMaxTransitionBuilder {
+        mandatory: [value, object_list],
+        optional: [],
+        flag: [clock_path, rise, fall],
+        repeatable: [],
+        extra: [],
+    }
+);
+
 impl fmt::Display for SetMaxTransition {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut text = "set_max_transition".to_string();
@@ -6316,15 +6976,6 @@ impl CommandExt for SetMaxTransition {
 impl Validate for SetMaxTransition {
     fn validate(&self, version: SdcVersion) -> Vec<ValidateError> {
         let mut ret = vec![];
-        self.cmd_supported_version(&mut ret, version.within(SDC1_1, SDC2_1));
-        self.arg_supported_version(
-            &mut ret,
-            version.within(SDC1_5, SDC2_1),
-            &self.clock_path,
-            "clock_path",
-        );
-        self.arg_supported_version(&mut ret, version.within(SDC1_5, SDC2_1), &self.rise, "rise");
-        self.arg_supported_version(&mut ret, version.within(SDC1_5, SDC2_1), &self.fall, "fall");
         validate_arg(&mut ret, version, &self.value);
         validate_arg(&mut ret, version, &self.object_list);
         ret
@@ -6351,7 +7002,7 @@ fn set_max_transition(args: Vec<Argument>, location: Location) -> Result<Command
     let mut object_list = None;
 
     static DICT: OnceLock<LazyDict> = OnceLock::new();
-    let dict = DICT.get_or_init(|| LazyDict::new(&["-clock_path", "-rise", "-fall"]));
+    let dict = DICT.get_or_init(|| LazyDict::from_kind(CommandKind::SetMaxTransition));
 
     let mut iter = args.into_iter();
     while let Some(arg) = iter.next() {
@@ -6384,6 +7035,17 @@ pub struct SetMinCapacitance {
     location: Location,
 }
 
+builder!(
+    SetMinCapacitance,
+    SetMinCapacitanceBuilder {
+        mandatory: [value, object_list],
+        optional: [],
+        flag: [],
+        repeatable: [],
+        extra: [],
+    }
+);
+
 impl fmt::Display for SetMinCapacitance {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut text = "set_min_capacitance".to_string();
@@ -6406,7 +7068,6 @@ impl CommandExt for SetMinCapacitance {
 impl Validate for SetMinCapacitance {
     fn validate(&self, version: SdcVersion) -> Vec<ValidateError> {
         let mut ret = vec![];
-        self.cmd_supported_version(&mut ret, version.within(SDC1_1, SDC2_1));
         validate_arg(&mut ret, version, &self.value);
         validate_arg(&mut ret, version, &self.object_list);
         ret
@@ -6464,6 +7125,17 @@ pub struct SetMinDelay {
     location: Location,
 }
 
+builder!(
+    SetMinDelay,
+    SetMinDelayBuilder {
+        mandatory: [delay_value],
+        optional: [from, to, rise_from, rise_to, fall_from, fall_to, comment],
+        flag: [rise, fall, ignore_clock_latency],
+        repeatable: [through, rise_through, fall_through],
+        extra: [],
+    }
+);
+
 impl fmt::Display for SetMinDelay {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut text = "set_min_delay".to_string();
@@ -6501,55 +7173,6 @@ impl CommandExt for SetMinDelay {
 impl Validate for SetMinDelay {
     fn validate(&self, version: SdcVersion) -> Vec<ValidateError> {
         let mut ret = vec![];
-        self.cmd_supported_version(&mut ret, version.within(SDC1_1, SDC2_1));
-        self.arg_supported_version(
-            &mut ret,
-            version.within(SDC1_7, SDC2_1),
-            &self.rise_from,
-            "rise_from",
-        );
-        self.arg_supported_version(
-            &mut ret,
-            version.within(SDC1_7, SDC2_1),
-            &self.fall_from,
-            "fall_from",
-        );
-        self.arg_supported_version(
-            &mut ret,
-            version.within(SDC1_7, SDC2_1),
-            &self.rise_to,
-            "rise_to",
-        );
-        self.arg_supported_version(
-            &mut ret,
-            version.within(SDC1_7, SDC2_1),
-            &self.fall_to,
-            "fall_to",
-        );
-        self.arg_supported_version(
-            &mut ret,
-            version.within(SDC1_7, SDC2_1),
-            &self.rise_through,
-            "rise_through",
-        );
-        self.arg_supported_version(
-            &mut ret,
-            version.within(SDC1_7, SDC2_1),
-            &self.fall_through,
-            "fall_through",
-        );
-        self.arg_supported_version(
-            &mut ret,
-            version.within(SDC1_9, SDC2_1),
-            &self.comment,
-            "comment",
-        );
-        self.arg_supported_version(
-            &mut ret,
-            version.within(SDC2_1, SDC2_1),
-            &self.ignore_clock_latency,
-            "ignore_clock_latency",
-        );
         self.arg_comb2(
             &mut ret,
             version.within(SDC1_2, SDC2_1),
@@ -6619,23 +7242,7 @@ fn set_min_delay(args: Vec<Argument>, location: Location) -> Result<Command, Sem
     let mut delay_value = None;
 
     static DICT: OnceLock<LazyDict> = OnceLock::new();
-    let dict = DICT.get_or_init(|| {
-        LazyDict::new(&[
-            "-rise",
-            "-fall",
-            "-from",
-            "-to",
-            "-through",
-            "-rise_from",
-            "-rise_to",
-            "-rise_through",
-            "-fall_from",
-            "-fall_to",
-            "-fall_through",
-            "-ignore_clock_latency",
-            "-comment",
-        ])
-    });
+    let dict = DICT.get_or_init(|| LazyDict::from_kind(CommandKind::SetMinDelay));
 
     let mut iter = args.into_iter();
     while let Some(arg) = iter.next() {
@@ -6688,6 +7295,17 @@ pub struct SetMinPorosity {
     location: Location,
 }
 
+builder!(
+    SetMinPorosity,
+    SetMinPorosityBuilder {
+        mandatory: [porosity_value, object_list],
+        optional: [],
+        flag: [],
+        repeatable: [],
+        extra: [],
+    }
+);
+
 impl fmt::Display for SetMinPorosity {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut text = "set_min_porosity".to_string();
@@ -6710,7 +7328,6 @@ impl CommandExt for SetMinPorosity {
 impl Validate for SetMinPorosity {
     fn validate(&self, version: SdcVersion) -> Vec<ValidateError> {
         let mut ret = vec![];
-        self.cmd_supported_version(&mut ret, version.within(SDC1_4, SDC2_1));
         validate_arg(&mut ret, version, &self.porosity_value);
         validate_arg(&mut ret, version, &self.object_list);
         ret
@@ -6759,6 +7376,17 @@ pub struct SetMinPulseWidth {
     location: Location,
 }
 
+builder!(
+    SetMinPulseWidth,
+    SetMinPulseWidthBuilder {
+        mandatory: [value],
+        optional: [object_list],
+        flag: [low, high],
+        repeatable: [],
+        extra: [],
+    }
+);
+
 impl fmt::Display for SetMinPulseWidth {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut text = "set_min_pulse_width".to_string();
@@ -6783,7 +7411,6 @@ impl CommandExt for SetMinPulseWidth {
 impl Validate for SetMinPulseWidth {
     fn validate(&self, version: SdcVersion) -> Vec<ValidateError> {
         let mut ret = vec![];
-        self.cmd_supported_version(&mut ret, version.within(SDC2_0, SDC2_1));
         validate_arg(&mut ret, version, &self.value);
         validate_opt(&mut ret, version, &self.object_list);
         ret
@@ -6809,7 +7436,7 @@ fn set_min_pulse_width(args: Vec<Argument>, location: Location) -> Result<Comman
     let mut object_list = None;
 
     static DICT: OnceLock<LazyDict> = OnceLock::new();
-    let dict = DICT.get_or_init(|| LazyDict::new(&["-low", "-high"]));
+    let dict = DICT.get_or_init(|| LazyDict::from_kind(CommandKind::SetMinPulseWidth));
 
     let mut iter = args.into_iter();
     while let Some(arg) = iter.next() {
@@ -6854,6 +7481,17 @@ pub struct SetMulticyclePath {
     location: Location,
 }
 
+builder!(
+    SetMulticyclePath,
+    SetMulticyclePathBuilder {
+        mandatory: [path_multiplier],
+        optional: [from, to, rise_from, rise_to, fall_from, fall_to, comment],
+        flag: [setup, hold, rise, fall, start, end],
+        repeatable: [through, rise_through, fall_through],
+        extra: [],
+    }
+);
+
 impl fmt::Display for SetMulticyclePath {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut text = "set_multicycle_path".to_string();
@@ -6891,49 +7529,6 @@ impl CommandExt for SetMulticyclePath {
 impl Validate for SetMulticyclePath {
     fn validate(&self, version: SdcVersion) -> Vec<ValidateError> {
         let mut ret = vec![];
-        self.cmd_supported_version(&mut ret, version.within(SDC1_1, SDC2_1));
-        self.arg_supported_version(
-            &mut ret,
-            version.within(SDC1_7, SDC2_1),
-            &self.rise_from,
-            "rise_from",
-        );
-        self.arg_supported_version(
-            &mut ret,
-            version.within(SDC1_7, SDC2_1),
-            &self.fall_from,
-            "fall_from",
-        );
-        self.arg_supported_version(
-            &mut ret,
-            version.within(SDC1_7, SDC2_1),
-            &self.rise_to,
-            "rise_to",
-        );
-        self.arg_supported_version(
-            &mut ret,
-            version.within(SDC1_7, SDC2_1),
-            &self.fall_to,
-            "fall_to",
-        );
-        self.arg_supported_version(
-            &mut ret,
-            version.within(SDC1_7, SDC2_1),
-            &self.rise_through,
-            "rise_through",
-        );
-        self.arg_supported_version(
-            &mut ret,
-            version.within(SDC1_7, SDC2_1),
-            &self.fall_through,
-            "fall_through",
-        );
-        self.arg_supported_version(
-            &mut ret,
-            version.within(SDC1_9, SDC2_1),
-            &self.comment,
-            "comment",
-        );
         validate_opt(&mut ret, version, &self.from);
         validate_opt(&mut ret, version, &self.to);
         validate_vec(&mut ret, version, &self.through);
@@ -6999,26 +7594,7 @@ fn set_multicycle_path(args: Vec<Argument>, location: Location) -> Result<Comman
     let mut path_multiplier = None;
 
     static DICT: OnceLock<LazyDict> = OnceLock::new();
-    let dict = DICT.get_or_init(|| {
-        LazyDict::new(&[
-            "-setup",
-            "-hold",
-            "-rise",
-            "-fall",
-            "-start",
-            "-end",
-            "-from",
-            "-to",
-            "-through",
-            "-rise_from",
-            "-rise_to",
-            "-rise_through",
-            "-fall_from",
-            "-fall_to",
-            "-fall_through",
-            "-comment",
-        ])
-    });
+    let dict = DICT.get_or_init(|| LazyDict::from_kind(CommandKind::SetMulticyclePath));
 
     let mut iter = args.into_iter();
     while let Some(arg) = iter.next() {
@@ -7081,6 +7657,26 @@ pub struct SetOperatingConditions {
     location: Location,
 }
 
+builder!(
+    SetOperatingConditions,
+    SetOperatingConditionsBuilder {
+        mandatory: [],
+        optional: [
+            library,
+            analysis_type,
+            max,
+            min,
+            max_library,
+            min_library,
+            object_list,
+            condition
+        ],
+        flag: [],
+        repeatable: [],
+        extra: [],
+    }
+);
+
 impl fmt::Display for SetOperatingConditions {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut text = "set_operating_conditions".to_string();
@@ -7109,13 +7705,6 @@ impl CommandExt for SetOperatingConditions {
 impl Validate for SetOperatingConditions {
     fn validate(&self, version: SdcVersion) -> Vec<ValidateError> {
         let mut ret = vec![];
-        self.cmd_supported_version(&mut ret, version.within(SDC1_1, SDC2_1));
-        self.arg_supported_version(
-            &mut ret,
-            version.within(SDC1_5, SDC2_1),
-            &self.object_list,
-            "object_list",
-        );
         validate_opt(&mut ret, version, &self.library);
         validate_opt(&mut ret, version, &self.analysis_type);
         validate_opt(&mut ret, version, &self.max);
@@ -7166,17 +7755,7 @@ fn set_operating_conditions(
     let mut condition = None;
 
     static DICT: OnceLock<LazyDict> = OnceLock::new();
-    let dict = DICT.get_or_init(|| {
-        LazyDict::new(&[
-            "-library",
-            "-analysis_type",
-            "-max",
-            "-min",
-            "-max_library",
-            "-min_library",
-            "-object_list",
-        ])
-    });
+    let dict = DICT.get_or_init(|| LazyDict::from_kind(CommandKind::SetOperatingConditions));
 
     let mut iter = args.into_iter();
     while let Some(arg) = iter.next() {
@@ -7222,8 +7801,20 @@ pub struct SetOutputDelay {
     pub delay_value: Argument,
     pub port_pin_list: Argument,
     location: Location,
+    dialect: Dialect,
 }
 
+builder!(SetOutputDelay, SetOutputDelayBuilder {
+    mandatory: [delay_value, port_pin_list],
+    optional: [clock, reference_pin],
+    flag: [
+        clock_fall, level_sensitive, rise, fall, max, min, add_delay, network_latency_included,
+        source_latency_included,
+    ],
+    repeatable: [],
+    extra: [dialect: Dialect],
+});
+
 impl fmt::Display for SetOutputDelay {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut text = "set_output_delay".to_string();
@@ -7263,25 +7854,6 @@ impl CommandExt for SetOutputDelay {
 impl Validate for SetOutputDelay {
     fn validate(&self, version: SdcVersion) -> Vec<ValidateError> {
         let mut ret = vec![];
-        self.cmd_supported_version(&mut ret, version.within(SDC1_1, SDC2_1));
-        self.arg_supported_version(
-            &mut ret,
-            version.within(SDC1_4, SDC2_1),
-            &self.network_latency_included,
-            "network_latency_included",
-        );
-        self.arg_supported_version(
-            &mut ret,
-            version.within(SDC1_4, SDC2_1),
-            &self.source_latency_included,
-            "source_latency_included",
-        );
-        self.arg_supported_version(
-            &mut ret,
-            version.within(SDC2_0, SDC2_1),
-            &self.reference_pin,
-            "reference_pin",
-        );
         self.arg_comb3(
             &mut ret,
             version.within(SDC1_2, SDC2_1),
@@ -7314,7 +7886,11 @@ impl Extract for SetOutputDelay {
     }
 }
 
-fn set_output_delay(args: Vec<Argument>, location: Location) -> Result<Command, SemanticError> {
+fn set_output_delay(
+    args: Vec<Argument>,
+    location: Location,
+    dialect: Dialect,
+) -> Result<Command, SemanticError> {
     let mut clock = None;
     let mut reference_pin = None;
     let mut clock_fall = false;
@@ -7330,21 +7906,7 @@ fn set_output_delay(args: Vec<Argument>, location: Location) -> Result<Command,
     let mut port_pin_list = None;
 
     static DICT: OnceLock<LazyDict> = OnceLock::new();
-    let dict = DICT.get_or_init(|| {
-        LazyDict::new(&[
-            "-clock",
-            "-reference_pin",
-            "-clock_fall",
-            "-level_sensitive",
-            "-rise",
-            "-fall",
-            "-max",
-            "-min",
-            "-add_delay",
-            "-network_latency_included",
-            "-source_latency_included",
-        ])
-    });
+    let dict = DICT.get_or_init(|| LazyDict::from_kind(CommandKind::SetOutputDelay));
 
     let mut iter = args.into_iter();
     while let Some(arg) = iter.next() {
@@ -7389,6 +7951,7 @@ fn set_output_delay(args: Vec<Argument>, location: Location) -> Result<Command,
         delay_value,
         port_pin_list,
         location,
+        dialect,
     }))
 }
 
@@ -7400,6 +7963,17 @@ pub struct SetPortFanoutNumber {
     location: Location,
 }
 
+builder!(
+    SetPortFanoutNumber,
+    SetPortFanoutNumberBuilder {
+        mandatory: [value, port_list],
+        optional: [],
+        flag: [],
+        repeatable: [],
+        extra: [],
+    }
+);
+
 impl fmt::Display for SetPortFanoutNumber {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut text = "set_port_fanout_number".to_string();
@@ -7422,7 +7996,6 @@ impl CommandExt for SetPortFanoutNumber {
 impl Validate for SetPortFanoutNumber {
     fn validate(&self, version: SdcVersion) -> Vec<ValidateError> {
         let mut ret = vec![];
-        self.cmd_supported_version(&mut ret, version.within(SDC1_1, SDC2_1));
         validate_arg(&mut ret, version, &self.value);
         validate_arg(&mut ret, version, &self.port_list);
         ret
@@ -7470,6 +8043,17 @@ pub struct SetPropagatedClock {
     location: Location,
 }
 
+builder!(
+    SetPropagatedClock,
+    SetPropagatedClockBuilder {
+        mandatory: [object_list],
+        optional: [],
+        flag: [],
+        repeatable: [],
+        extra: [],
+    }
+);
+
 impl fmt::Display for SetPropagatedClock {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut text = "set_propagated_clock".to_string();
@@ -7491,7 +8075,6 @@ impl CommandExt for SetPropagatedClock {
 impl Validate for SetPropagatedClock {
     fn validate(&self, version: SdcVersion) -> Vec<ValidateError> {
         let mut ret = vec![];
-        self.cmd_supported_version(&mut ret, version.within(SDC1_1, SDC2_1));
         validate_arg(&mut ret, version, &self.object_list);
         ret
     }
@@ -7533,6 +8116,17 @@ pub struct SetResistance {
     location: Location,
 }
 
+builder!(
+    SetResistance,
+    SetResistanceBuilder {
+        mandatory: [value, net_list],
+        optional: [],
+        flag: [min, max],
+        repeatable: [],
+        extra: [],
+    }
+);
+
 impl fmt::Display for SetResistance {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut text = "set_resistance".to_string();
@@ -7557,7 +8151,6 @@ impl CommandExt for SetResistance {
 impl Validate for SetResistance {
     fn validate(&self, version: SdcVersion) -> Vec<ValidateError> {
         let mut ret = vec![];
-        self.cmd_supported_version(&mut ret, version.within(SDC1_1, SDC2_1));
         validate_arg(&mut ret, version, &self.value);
         validate_arg(&mut ret, version, &self.net_list);
         ret
@@ -7583,7 +8176,7 @@ fn set_resistance(args: Vec<Argument>, location: Location) -> Result<Command, Se
     let mut net_list = None;
 
     static DICT: OnceLock<LazyDict> = OnceLock::new();
-    let dict = DICT.get_or_init(|| LazyDict::new(&["-min", "-max"]));
+    let dict = DICT.get_or_init(|| LazyDict::from_kind(CommandKind::SetResistance));
 
     let mut iter = args.into_iter();
     while let Some(arg) = iter.next() {
@@ -7621,6 +8214,17 @@ pub struct SetSense {
     location: Location,
 }
 
+builder!(
+    SetSense,
+    SetSenseBuilder {
+        mandatory: [pin_list],
+        optional: [r#type, pulse, clocks],
+        flag: [non_unate, positive, negative, clock_leaf, stop_propagation],
+        repeatable: [],
+        extra: [],
+    }
+);
+
 impl fmt::Display for SetSense {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut text = "set_sense".to_string();
@@ -7650,7 +8254,6 @@ impl CommandExt for SetSense {
 impl Validate for SetSense {
     fn validate(&self, version: SdcVersion) -> Vec<ValidateError> {
         let mut ret = vec![];
-        self.cmd_supported_version(&mut ret, version.within(SDC2_1, SDC2_1));
         self.arg_comb6(
             &mut ret,
             version.within(SDC1_2, SDC2_1),
@@ -7698,18 +8301,7 @@ fn set_sense(args: Vec<Argument>, location: Location) -> Result<Command, Semanti
     let mut pin_list = None;
 
     static DICT: OnceLock<LazyDict> = OnceLock::new();
-    let dict = DICT.get_or_init(|| {
-        LazyDict::new(&[
-            "-type",
-            "-non_unate",
-            "-positive",
-            "-negative",
-            "-clock_leaf",
-            "-stop_propagation",
-            "-pulse",
-            "-clocks",
-        ])
-    });
+    let dict = DICT.get_or_init(|| LazyDict::from_kind(CommandKind::SetSense));
 
     let mut iter = args.into_iter();
     while let Some(arg) = iter.next() {
@@ -7762,6 +8354,20 @@ pub struct SetTimingDerate {
     location: Location,
 }
 
+builder!(
+    SetTimingDerate,
+    SetTimingDerateBuilder {
+        mandatory: [derate_value],
+        optional: [object_list],
+        flag: [
+            cell_delay, cell_check, net_delay, data, clock, early, late, rise, fall, r#static,
+            dynamic, increment,
+        ],
+        repeatable: [],
+        extra: [],
+    }
+);
+
 impl fmt::Display for SetTimingDerate {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut text = "set_timing_derate".to_string();
@@ -7796,27 +8402,6 @@ impl CommandExt for SetTimingDerate {
 impl Validate for SetTimingDerate {
     fn validate(&self, version: SdcVersion) -> Vec<ValidateError> {
         let mut ret = vec![];
-        self.cmd_supported_version(&mut ret, version.within(SDC1_5, SDC2_1));
-        self.arg_supported_version(&mut ret, version.within(SDC1_8, SDC2_1), &self.rise, "rise");
-        self.arg_supported_version(&mut ret, version.within(SDC1_8, SDC2_1), &self.fall, "fall");
-        self.arg_supported_version(
-            &mut ret,
-            version.within(SDC2_1, SDC2_1),
-            &self.r#static,
-            "static",
-        );
-        self.arg_supported_version(
-            &mut ret,
-            version.within(SDC2_1, SDC2_1),
-            &self.dynamic,
-            "dynamic",
-        );
-        self.arg_supported_version(
-            &mut ret,
-            version.within(SDC2_1, SDC2_1),
-            &self.increment,
-            "increment",
-        );
         self.arg_comb2(
             &mut ret,
             version.within(SDC1_5, SDC2_1),
@@ -7859,22 +8444,7 @@ fn set_timing_derate(args: Vec<Argument>, location: Location) -> Result<Command,
     let mut object_list = None;
 
     static DICT: OnceLock<LazyDict> = OnceLock::new();
-    let dict = DICT.get_or_init(|| {
-        LazyDict::new(&[
-            "-cell_delay",
-            "-cell_check",
-            "-net_delay",
-            "-data",
-            "-clock",
-            "-early",
-            "-late",
-            "-rise",
-            "-fall",
-            "-static",
-            "-dynamic",
-            "-increment",
-        ])
-    });
+    let dict = DICT.get_or_init(|| LazyDict::from_kind(CommandKind::SetTimingDerate));
 
     let mut iter = args.into_iter();
     while let Some(arg) = iter.next() {
@@ -7932,6 +8502,14 @@ pub struct SetUnits {
     alias: bool,
 }
 
+builder!(SetUnits, SetUnitsBuilder {
+    mandatory: [],
+    optional: [capacitance, resistance, time, voltage, current, power],
+    flag: [],
+    repeatable: [],
+    extra: [alias: bool],
+});
+
 impl fmt::Display for SetUnits {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut text = "set_units".to_string();
@@ -7958,8 +8536,6 @@ impl CommandExt for SetUnits {
 impl Validate for SetUnits {
     fn validate(&self, version: SdcVersion) -> Vec<ValidateError> {
         let mut ret = vec![];
-        self.cmd_supported_version(&mut ret, version.within(SDC1_7, SDC2_1));
-        self.alias_supported_version(&mut ret, version.within(SDC1_7, SDC2_1), self.alias);
         validate_opt(&mut ret, version, &self.capacitance);
         validate_opt(&mut ret, version, &self.resistance);
         validate_opt(&mut ret, version, &self.time);
@@ -8003,16 +8579,7 @@ fn set_units(
     let mut power = None;
 
     static DICT: OnceLock<LazyDict> = OnceLock::new();
-    let dict = DICT.get_or_init(|| {
-        LazyDict::new(&[
-            "-capacitance",
-            "-resistance",
-            "-time",
-            "-voltage",
-            "-current",
-            "-power",
-        ])
-    });
+    let dict = DICT.get_or_init(|| LazyDict::from_kind(CommandKind::SetUnits));
 
     let mut iter = args.into_iter();
     while let Some(arg) = iter.next() {
@@ -8048,6 +8615,17 @@ pub struct SetVoltage {
     location: Location,
 }
 
+builder!(
+    SetVoltage,
+    SetVoltageBuilder {
+        mandatory: [max_case_voltage],
+        optional: [min, object_list],
+        flag: [],
+        repeatable: [],
+        extra: [],
+    }
+);
+
 impl fmt::Display for SetVoltage {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut text = "set_voltage".to_string();
@@ -8071,7 +8649,6 @@ impl CommandExt for SetVoltage {
 impl Validate for SetVoltage {
     fn validate(&self, version: SdcVersion) -> Vec<ValidateError> {
         let mut ret = vec![];
-        self.cmd_supported_version(&mut ret, version.within(SDC1_8, SDC2_1));
         validate_opt(&mut ret, version, &self.min);
         validate_opt(&mut ret, version, &self.object_list);
         validate_arg(&mut ret, version, &self.max_case_voltage);
@@ -8099,7 +8676,7 @@ fn set_voltage(args: Vec<Argument>, location: Location) -> Result<Command, Seman
     let mut max_case_voltage = None;
 
     static DICT: OnceLock<LazyDict> = OnceLock::new();
-    let dict = DICT.get_or_init(|| LazyDict::new(&["-min", "-object_list"]));
+    let dict = DICT.get_or_init(|| LazyDict::from_kind(CommandKind::SetVoltage));
 
     let mut iter = args.into_iter();
     while let Some(arg) = iter.next() {
@@ -8127,6 +8704,17 @@ pub struct SetWireLoadMinBlockSize {
     location: Location,
 }
 
+builder!(
+    SetWireLoadMinBlockSize,
+    SetWireLoadMinBlockSizeBuilder {
+        mandatory: [size],
+        optional: [],
+        flag: [],
+        repeatable: [],
+        extra: [],
+    }
+);
+
 impl fmt::Display for SetWireLoadMinBlockSize {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut text = "set_wire_load_min_block_size".to_string();
@@ -8148,7 +8736,6 @@ impl CommandExt for SetWireLoadMinBlockSize {
 impl Validate for SetWireLoadMinBlockSize {
     fn validate(&self, version: SdcVersion) -> Vec<ValidateError> {
         let mut ret = vec![];
-        self.cmd_supported_version(&mut ret, version.within(SDC1_1, SDC2_1));
         validate_arg(&mut ret, version, &self.size);
         ret
     }
@@ -8190,6 +8777,17 @@ pub struct SetWireLoadMode {
     location: Location,
 }
 
+builder!(
+    SetWireLoadMode,
+    SetWireLoadModeBuilder {
+        mandatory: [mode_name],
+        optional: [],
+        flag: [],
+        repeatable: [],
+        extra: [],
+    }
+);
+
 impl fmt::Display for SetWireLoadMode {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut text = "set_wire_load_mode".to_string();
@@ -8211,7 +8809,6 @@ impl CommandExt for SetWireLoadMode {
 impl Validate for SetWireLoadMode {
     fn validate(&self, version: SdcVersion) -> Vec<ValidateError> {
         let mut ret = vec![];
-        self.cmd_supported_version(&mut ret, version.within(SDC1_1, SDC2_1));
         validate_arg(&mut ret, version, &self.mode_name);
         ret
     }
@@ -8254,6 +8851,17 @@ pub struct SetWireLoadModel {
     location: Location,
 }
 
+builder!(
+    SetWireLoadModel,
+    SetWireLoadModelBuilder {
+        mandatory: [name],
+        optional: [library, object_list],
+        flag: [min, max],
+        repeatable: [],
+        extra: [],
+    }
+);
+
 impl fmt::Display for SetWireLoadModel {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut text = "set_wire_load_model".to_string();
@@ -8279,7 +8887,6 @@ impl CommandExt for SetWireLoadModel {
 impl Validate for SetWireLoadModel {
     fn validate(&self, version: SdcVersion) -> Vec<ValidateError> {
         let mut ret = vec![];
-        self.cmd_supported_version(&mut ret, version.within(SDC1_1, SDC2_1));
         validate_arg(&mut ret, version, &self.name);
         validate_opt(&mut ret, version, &self.library);
         validate_opt(&mut ret, version, &self.object_list);
@@ -8309,7 +8916,7 @@ fn set_wire_load_model(args: Vec<Argument>, location: Location) -> Result<Comman
     let mut object_list = None;
 
     static DICT: OnceLock<LazyDict> = OnceLock::new();
-    let dict = DICT.get_or_init(|| LazyDict::new(&["-name", "-library", "-min", "-max"]));
+    let dict = DICT.get_or_init(|| LazyDict::from_kind(CommandKind::SetWireLoadModel));
 
     let mut iter = args.into_iter();
     while let Some(arg) = iter.next() {
@@ -8345,6 +8952,17 @@ pub struct SetWireLoadSelectionGroup {
     location: Location,
 }
 
+builder!(
+    SetWireLoadSelectionGroup,
+    SetWireLoadSelectionGroupBuilder {
+        mandatory: [group_name],
+        optional: [library, object_list],
+        flag: [min, max],
+        repeatable: [],
+        extra: [],
+    }
+);
+
 impl fmt::Display for SetWireLoadSelectionGroup {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut text = "set_wire_load_selection_group".to_string();
@@ -8370,7 +8988,6 @@ impl CommandExt for SetWireLoadSelectionGroup {
 impl Validate for SetWireLoadSelectionGroup {
     fn validate(&self, version: SdcVersion) -> Vec<ValidateError> {
         let mut ret = vec![];
-        self.cmd_supported_version(&mut ret, version.within(SDC1_1, SDC2_1));
         // TODO group_name change from opt_arg to pos_arg at SDC1.3
         validate_opt(&mut ret, version, &self.library);
         validate_arg(&mut ret, version, &self.group_name);
@@ -8404,7 +9021,7 @@ fn set_wire_load_selection_group(
     let mut object_list = None;
 
     static DICT: OnceLock<LazyDict> = OnceLock::new();
-    let dict = DICT.get_or_init(|| LazyDict::new(&["-library", "-min", "-max"]));
+    let dict = DICT.get_or_init(|| LazyDict::from_kind(CommandKind::SetWireLoadSelectionGroup));
 
     let mut iter = args.into_iter();
     while let Some(arg) = iter.next() {
@@ -8441,6 +9058,33 @@ pub struct Unknown {
     location: Location,
 }
 
+impl Unknown {
+    /// Arguments of the command
+    pub fn arguments(&self) -> Vec<&Argument> {
+        self.args.iter().collect()
+    }
+
+    /// Mutable arguments of the command
+    pub fn arguments_mut(&mut self) -> Vec<&mut Argument> {
+        self.args.iter_mut().collect()
+    }
+
+    /// Values of option or positional argument `name`
+    pub fn values(&self, _name: &str) -> Vec<&Argument> {
+        vec![]
+    }
+
+    /// Mutable values of option or positional argument `name`
+    pub fn values_mut(&mut self, _name: &str) -> Vec<&mut Argument> {
+        vec![]
+    }
+
+    /// Whether flag `name` is specified
+    pub fn flag(&self, _name: &str) -> bool {
+        false
+    }
+}
+
 impl fmt::Display for Unknown {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut text = self.name.clone();
@@ -0,0 +1,256 @@
+#![allow(clippy::while_let_on_iterator)]
+
+use crate::errors::SemanticError;
+use crate::errors::ValidateError;
+use crate::file_db::Location;
+use crate::sdc::util::*;
+use crate::sdc::{Argument, Command, CommandKind, Dialect, Sdc, SdcVersion};
+use std::fmt;
+use std::sync::OnceLock;
+
+/// SDC commands which OpenSTA `read_sdc` does not support
+const UNSUPPORTED: &[CommandKind] = &[
+    CommandKind::CreateVoltageArea,
+    CommandKind::SetIdealLatency,
+    CommandKind::SetIdealNetwork,
+    CommandKind::SetIdealTransition,
+    CommandKind::SetLevelShifterStrategy,
+    CommandKind::SetLevelShifterThreshold,
+    CommandKind::SetMaxDynamicPower,
+    CommandKind::SetMaxLeakagePower,
+    CommandKind::SetMinPorosity,
+];
+
+/// Report commands which OpenSTA can't load.
+///
+/// Unknown commands and nested command substitutions are reported too.
+pub(crate) fn compatibility(sdc: &Sdc, ret: &mut Vec<ValidateError>) {
+    for command in &sdc.commands {
+        let mut list = vec![];
+        for kind in UNSUPPORTED.iter().chain([&CommandKind::Unknown]) {
+            command.extract(*kind, &mut list);
+        }
+        list.sort_by_key(|x| x.location());
+        for x in list {
+            let name = match x {
+                Command::Unknown(x) => x.name.clone(),
+                x => {
+                    let name = x.to_string();
+                    name.split_whitespace()
+                        .next()
+                        .unwrap_or_default()
+                        .to_string()
+                }
+            };
+            ret.push(ValidateError::Incompatible(
+                name,
+                Dialect::OpenSta,
+                x.location(),
+            ));
+        }
+    }
+}
+
+/// set_assigned_delay
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct SetAssignedDelay {
+    pub cell: bool,
+    pub net: bool,
+    pub rise: bool,
+    pub fall: bool,
+    pub corner: Option<Argument>,
+    pub min: bool,
+    pub max: bool,
+    pub from: Option<Argument>,
+    pub to: Option<Argument>,
+    pub delay: Argument,
+    location: Location,
+}
+
+builder!(
+    SetAssignedDelay,
+    SetAssignedDelayBuilder {
+        mandatory: [delay],
+        optional: [corner, from, to],
+        flag: [cell, net, rise, fall, min, max],
+        repeatable: [],
+        extra: [],
+    }
+);
+
+impl fmt::Display for SetAssignedDelay {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut text = "set_assigned_delay".to_string();
+        text.push_str(&fmt_named_flg(self.cell, "cell"));
+        text.push_str(&fmt_named_flg(self.net, "net"));
+        text.push_str(&fmt_named_flg(self.rise, "rise"));
+        text.push_str(&fmt_named_flg(self.fall, "fall"));
+        text.push_str(&fmt_named_opt_arg(&self.corner, "corner"));
+        text.push_str(&fmt_named_flg(self.min, "min"));
+        text.push_str(&fmt_named_flg(self.max, "max"));
+        text.push_str(&fmt_named_opt_arg(&self.from, "from"));
+        text.push_str(&fmt_named_opt_arg(&self.to, "to"));
+        text.push_str(&fmt_arg(&self.delay));
+        text.fmt(f)
+    }
+}
+
+impl CommandExt for SetAssignedDelay {
+    fn location(&self) -> Location {
+        self.location.clone()
+    }
+
+    fn kind(&self) -> CommandKind {
+        CommandKind::SetAssignedDelay
+    }
+}
+
+impl Validate for SetAssignedDelay {
+    fn validate(&self, version: SdcVersion) -> Vec<ValidateError> {
+        let mut ret = vec![];
+        self.arg_comb2(&mut ret, version.any(), &self.cell, &self.net, |a, b| a ^ b);
+        self.arg_comb2(&mut ret, version.any(), &self.from, &self.to, |a, b| a & b);
+        validate_opt(&mut ret, version, &self.corner);
+        validate_opt(&mut ret, version, &self.from);
+        validate_opt(&mut ret, version, &self.to);
+        validate_arg(&mut ret, version, &self.delay);
+        ret
+    }
+}
+
+impl Extract for SetAssignedDelay {
+    fn extract<'a>(&'a self, kind: CommandKind, list: &mut Vec<&'a Command>) {
+        Self::extract_opt(kind, list, &self.from);
+        Self::extract_opt(kind, list, &self.to);
+        Self::extract_arg(kind, list, &self.delay);
+    }
+
+    fn extract_mut<'a>(&'a mut self, kind: CommandKind, list: &mut Vec<&'a mut Command>) {
+        Self::extract_mut_opt(kind, list, &mut self.from);
+        Self::extract_mut_opt(kind, list, &mut self.to);
+        Self::extract_mut_arg(kind, list, &mut self.delay);
+    }
+}
+
+pub(crate) fn set_assigned_delay(
+    args: Vec<Argument>,
+    location: Location,
+) -> Result<Command, SemanticError> {
+    let mut cell = false;
+    let mut net = false;
+    let mut rise = false;
+    let mut fall = false;
+    let mut corner = None;
+    let mut min = false;
+    let mut max = false;
+    let mut from = None;
+    let mut to = None;
+    let mut delay = None;
+
+    static DICT: OnceLock<LazyDict> = OnceLock::new();
+    let dict = DICT.get_or_init(|| LazyDict::from_kind(CommandKind::SetAssignedDelay));
+
+    let mut iter = args.into_iter();
+    while let Some(arg) = iter.next() {
+        match LazyMatcher::new(arg.as_str(), dict, &location)? {
+            x if x.m("-cell") => cell = opt_flg(arg, cell)?,
+            x if x.m("-net") => net = opt_flg(arg, net)?,
+            x if x.m("-rise") => rise = opt_flg(arg, rise)?,
+            x if x.m("-fall") => fall = opt_flg(arg, fall)?,
+            x if x.m("-corner") => corner = opt_arg(arg, iter.next(), corner)?,
+            x if x.m("-min") => min = opt_flg(arg, min)?,
+            x if x.m("-max") => max = opt_flg(arg, max)?,
+            x if x.m("-from") => from = opt_arg(arg, iter.next(), from)?,
+            x if x.m("-to") => to = opt_arg(arg, iter.next(), to)?,
+            _ => delay = pos_args1(Some(arg), delay, &location)?,
+        }
+    }
+
+    let delay = mandatory(delay, "delay", &location)?;
+
+    Ok(Command::SetAssignedDelay(SetAssignedDelay {
+        cell,
+        net,
+        rise,
+        fall,
+        corner,
+        min,
+        max,
+        from,
+        to,
+        delay,
+        location,
+    }))
+}
+
+/// set_disable_inferred_clock_gating
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct SetDisableInferredClockGating {
+    pub objects: Argument,
+    location: Location,
+}
+
+builder!(
+    SetDisableInferredClockGating,
+    SetDisableInferredClockGatingBuilder {
+        mandatory: [objects],
+        optional: [],
+        flag: [],
+        repeatable: [],
+        extra: [],
+    }
+);
+
+impl fmt::Display for SetDisableInferredClockGating {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut text = "set_disable_inferred_clock_gating".to_string();
+        text.push_str(&fmt_arg(&self.objects));
+        text.fmt(f)
+    }
+}
+
+impl CommandExt for SetDisableInferredClockGating {
+    fn location(&self) -> Location {
+        self.location.clone()
+    }
+
+    fn kind(&self) -> CommandKind {
+        CommandKind::SetDisableInferredClockGating
+    }
+}
+
+impl Validate for SetDisableInferredClockGating {
+    fn validate(&self, version: SdcVersion) -> Vec<ValidateError> {
+        let mut ret = vec![];
+        validate_arg(&mut ret, version, &self.objects);
+        ret
+    }
+}
+
+impl Extract for SetDisableInferredClockGating {
+    fn extract<'a>(&'a self, kind: CommandKind, list: &mut Vec<&'a Command>) {
+        Self::extract_arg(kind, list, &self.objects);
+    }
+
+    fn extract_mut<'a>(&'a mut self, kind: CommandKind, list: &mut Vec<&'a mut Command>) {
+        Self::extract_mut_arg(kind, list, &mut self.objects);
+    }
+}
+
+pub(crate) fn set_disable_inferred_clock_gating(
+    args: Vec<Argument>,
+    location: Location,
+) -> Result<Command, SemanticError> {
+    let mut objects = None;
+
+    let mut iter = args.into_iter();
+    while let Some(arg) = iter.next() {
+        objects = pos_args1(Some(arg), objects, &location)?;
+    }
+
+    let objects = mandatory(objects, "objects", &location)?;
+
+    Ok(Command::SetDisableInferredClockGating(
+        SetDisableInferredClockGating { objects, location },
+    ))
+}
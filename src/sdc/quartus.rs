@@ -0,0 +1,541 @@
+#![allow(clippy::while_let_on_iterator)]
+
+use crate::errors::SemanticError;
+use crate::errors::ValidateError;
+use crate::file_db::Location;
+use crate::sdc::util::*;
+use crate::sdc::{Argument, Command, CommandKind, SdcVersion};
+use std::fmt;
+use std::sync::OnceLock;
+
+/// derive_clock_uncertainty
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct DeriveClockUncertainty {
+    pub add: bool,
+    pub overwrite: bool,
+    pub dtcc: bool,
+    location: Location,
+}
+
+builder!(
+    DeriveClockUncertainty,
+    DeriveClockUncertaintyBuilder {
+        mandatory: [],
+        optional: [],
+        flag: [add, overwrite, dtcc],
+        repeatable: [],
+        extra: [],
+    }
+);
+
+impl fmt::Display for DeriveClockUncertainty {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut text = "derive_clock_uncertainty".to_string();
+        text.push_str(&fmt_named_flg(self.add, "add"));
+        text.push_str(&fmt_named_flg(self.overwrite, "overwrite"));
+        text.push_str(&fmt_named_flg(self.dtcc, "dtcc"));
+        text.fmt(f)
+    }
+}
+
+impl CommandExt for DeriveClockUncertainty {
+    fn location(&self) -> Location {
+        self.location.clone()
+    }
+
+    fn kind(&self) -> CommandKind {
+        CommandKind::DeriveClockUncertainty
+    }
+}
+
+impl Validate for DeriveClockUncertainty {
+    fn validate(&self, version: SdcVersion) -> Vec<ValidateError> {
+        let mut ret = vec![];
+        self.arg_comb2(
+            &mut ret,
+            version.any(),
+            &self.add,
+            &self.overwrite,
+            |a, b| !(a & b),
+        );
+        ret
+    }
+}
+
+impl Extract for DeriveClockUncertainty {}
+
+pub(crate) fn derive_clock_uncertainty(
+    args: Vec<Argument>,
+    location: Location,
+) -> Result<Command, SemanticError> {
+    let mut add = false;
+    let mut overwrite = false;
+    let mut dtcc = false;
+
+    static DICT: OnceLock<LazyDict> = OnceLock::new();
+    let dict = DICT.get_or_init(|| LazyDict::from_kind(CommandKind::DeriveClockUncertainty));
+
+    let mut iter = args.into_iter();
+    while let Some(arg) = iter.next() {
+        match LazyMatcher::new(arg.as_str(), dict, &location)? {
+            x if x.m("-add") => add = opt_flg(arg, add)?,
+            x if x.m("-overwrite") => overwrite = opt_flg(arg, overwrite)?,
+            x if x.m("-dtcc") => dtcc = opt_flg(arg, dtcc)?,
+            _ => return Err(SemanticError::WrongArgument(arg)),
+        }
+    }
+
+    Ok(Command::DeriveClockUncertainty(DeriveClockUncertainty {
+        add,
+        overwrite,
+        dtcc,
+        location,
+    }))
+}
+
+/// derive_pll_clocks
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct DerivePllClocks {
+    pub create_base_clocks: bool,
+    pub use_net_name: bool,
+    pub use_tan_name: bool,
+    location: Location,
+}
+
+builder!(
+    DerivePllClocks,
+    DerivePllClocksBuilder {
+        mandatory: [],
+        optional: [],
+        flag: [create_base_clocks, use_net_name, use_tan_name],
+        repeatable: [],
+        extra: [],
+    }
+);
+
+impl fmt::Display for DerivePllClocks {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut text = "derive_pll_clocks".to_string();
+        text.push_str(&fmt_named_flg(
+            self.create_base_clocks,
+            "create_base_clocks",
+        ));
+        text.push_str(&fmt_named_flg(self.use_net_name, "use_net_name"));
+        text.push_str(&fmt_named_flg(self.use_tan_name, "use_tan_name"));
+        text.fmt(f)
+    }
+}
+
+impl CommandExt for DerivePllClocks {
+    fn location(&self) -> Location {
+        self.location.clone()
+    }
+
+    fn kind(&self) -> CommandKind {
+        CommandKind::DerivePllClocks
+    }
+}
+
+impl Validate for DerivePllClocks {
+    fn validate(&self, _version: SdcVersion) -> Vec<ValidateError> {
+        vec![]
+    }
+}
+
+impl Extract for DerivePllClocks {}
+
+pub(crate) fn derive_pll_clocks(
+    args: Vec<Argument>,
+    location: Location,
+) -> Result<Command, SemanticError> {
+    let mut create_base_clocks = false;
+    let mut use_net_name = false;
+    let mut use_tan_name = false;
+
+    static DICT: OnceLock<LazyDict> = OnceLock::new();
+    let dict = DICT.get_or_init(|| LazyDict::from_kind(CommandKind::DerivePllClocks));
+
+    let mut iter = args.into_iter();
+    while let Some(arg) = iter.next() {
+        match LazyMatcher::new(arg.as_str(), dict, &location)? {
+            x if x.m("-create_base_clocks") => {
+                create_base_clocks = opt_flg(arg, create_base_clocks)?
+            }
+            x if x.m("-use_net_name") => use_net_name = opt_flg(arg, use_net_name)?,
+            x if x.m("-use_tan_name") => use_tan_name = opt_flg(arg, use_tan_name)?,
+            _ => return Err(SemanticError::WrongArgument(arg)),
+        }
+    }
+
+    Ok(Command::DerivePllClocks(DerivePllClocks {
+        create_base_clocks,
+        use_net_name,
+        use_tan_name,
+        location,
+    }))
+}
+
+/// get_fanouts
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct GetFanouts {
+    pub no_logic: bool,
+    pub stop_at_clocks: bool,
+    pub through: Option<Argument>,
+    pub filter: Argument,
+    location: Location,
+}
+
+builder!(
+    GetFanouts,
+    GetFanoutsBuilder {
+        mandatory: [filter],
+        optional: [through],
+        flag: [no_logic, stop_at_clocks],
+        repeatable: [],
+        extra: [],
+    }
+);
+
+impl fmt::Display for GetFanouts {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut text = "get_fanouts".to_string();
+        text.push_str(&fmt_named_flg(self.no_logic, "no_logic"));
+        text.push_str(&fmt_named_flg(self.stop_at_clocks, "stop_at_clocks"));
+        text.push_str(&fmt_named_opt_arg(&self.through, "through"));
+        text.push_str(&fmt_arg(&self.filter));
+        text.fmt(f)
+    }
+}
+
+impl CommandExt for GetFanouts {
+    fn location(&self) -> Location {
+        self.location.clone()
+    }
+
+    fn kind(&self) -> CommandKind {
+        CommandKind::GetFanouts
+    }
+}
+
+impl Validate for GetFanouts {
+    fn validate(&self, version: SdcVersion) -> Vec<ValidateError> {
+        let mut ret = vec![];
+        validate_opt(&mut ret, version, &self.through);
+        validate_arg(&mut ret, version, &self.filter);
+        ret
+    }
+}
+
+impl Extract for GetFanouts {
+    fn extract<'a>(&'a self, kind: CommandKind, list: &mut Vec<&'a Command>) {
+        Self::extract_opt(kind, list, &self.through);
+        Self::extract_arg(kind, list, &self.filter);
+    }
+
+    fn extract_mut<'a>(&'a mut self, kind: CommandKind, list: &mut Vec<&'a mut Command>) {
+        Self::extract_mut_opt(kind, list, &mut self.through);
+        Self::extract_mut_arg(kind, list, &mut self.filter);
+    }
+}
+
+pub(crate) fn get_fanouts(
+    args: Vec<Argument>,
+    location: Location,
+) -> Result<Command, SemanticError> {
+    let mut no_logic = false;
+    let mut stop_at_clocks = false;
+    let mut through = None;
+    let mut filter = None;
+
+    static DICT: OnceLock<LazyDict> = OnceLock::new();
+    let dict = DICT.get_or_init(|| LazyDict::from_kind(CommandKind::GetFanouts));
+
+    let mut iter = args.into_iter();
+    while let Some(arg) = iter.next() {
+        match LazyMatcher::new(arg.as_str(), dict, &location)? {
+            x if x.m("-no_logic") => no_logic = opt_flg(arg, no_logic)?,
+            x if x.m("-stop_at_clocks") => stop_at_clocks = opt_flg(arg, stop_at_clocks)?,
+            x if x.m("-through") => through = opt_arg(arg, iter.next(), through)?,
+            _ => filter = pos_args1(Some(arg), filter, &location)?,
+        }
+    }
+
+    let filter = mandatory(filter, "filter", &location)?;
+
+    Ok(Command::GetFanouts(GetFanouts {
+        no_logic,
+        stop_at_clocks,
+        through,
+        filter,
+        location,
+    }))
+}
+
+/// get_keepers
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct GetKeepers {
+    pub no_duplicates: bool,
+    pub nocase: bool,
+    pub nowarn: bool,
+    pub filter: Option<Argument>,
+    location: Location,
+}
+
+builder!(
+    GetKeepers,
+    GetKeepersBuilder {
+        mandatory: [],
+        optional: [filter],
+        flag: [no_duplicates, nocase, nowarn],
+        repeatable: [],
+        extra: [],
+    }
+);
+
+impl fmt::Display for GetKeepers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut text = "get_keepers".to_string();
+        text.push_str(&fmt_named_flg(self.no_duplicates, "no_duplicates"));
+        text.push_str(&fmt_named_flg(self.nocase, "nocase"));
+        text.push_str(&fmt_named_flg(self.nowarn, "nowarn"));
+        text.push_str(&fmt_opt_arg(&self.filter));
+        text.fmt(f)
+    }
+}
+
+impl CommandExt for GetKeepers {
+    fn location(&self) -> Location {
+        self.location.clone()
+    }
+
+    fn kind(&self) -> CommandKind {
+        CommandKind::GetKeepers
+    }
+}
+
+impl Validate for GetKeepers {
+    fn validate(&self, version: SdcVersion) -> Vec<ValidateError> {
+        let mut ret = vec![];
+        validate_opt(&mut ret, version, &self.filter);
+        ret
+    }
+}
+
+impl Extract for GetKeepers {
+    fn extract<'a>(&'a self, kind: CommandKind, list: &mut Vec<&'a Command>) {
+        Self::extract_opt(kind, list, &self.filter);
+    }
+
+    fn extract_mut<'a>(&'a mut self, kind: CommandKind, list: &mut Vec<&'a mut Command>) {
+        Self::extract_mut_opt(kind, list, &mut self.filter);
+    }
+}
+
+pub(crate) fn get_keepers(
+    args: Vec<Argument>,
+    location: Location,
+) -> Result<Command, SemanticError> {
+    let mut no_duplicates = false;
+    let mut nocase = false;
+    let mut nowarn = false;
+    let mut filter = None;
+
+    static DICT: OnceLock<LazyDict> = OnceLock::new();
+    let dict = DICT.get_or_init(|| LazyDict::from_kind(CommandKind::GetKeepers));
+
+    let mut iter = args.into_iter();
+    while let Some(arg) = iter.next() {
+        match LazyMatcher::new(arg.as_str(), dict, &location)? {
+            x if x.m("-no_duplicates") => no_duplicates = opt_flg(arg, no_duplicates)?,
+            x if x.m("-nocase") => nocase = opt_flg(arg, nocase)?,
+            x if x.m("-nowarn") => nowarn = opt_flg(arg, nowarn)?,
+            _ => filter = pos_args1(Some(arg), filter, &location)?,
+        }
+    }
+
+    Ok(Command::GetKeepers(GetKeepers {
+        no_duplicates,
+        nocase,
+        nowarn,
+        filter,
+        location,
+    }))
+}
+
+/// get_registers
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct GetRegisters {
+    pub no_duplicates: bool,
+    pub nocase: bool,
+    pub nowarn: bool,
+    pub filter: Option<Argument>,
+    location: Location,
+}
+
+builder!(
+    GetRegisters,
+    GetRegistersBuilder {
+        mandatory: [],
+        optional: [filter],
+        flag: [no_duplicates, nocase, nowarn],
+        repeatable: [],
+        extra: [],
+    }
+);
+
+impl fmt::Display for GetRegisters {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut text = "get_registers".to_string();
+        text.push_str(&fmt_named_flg(self.no_duplicates, "no_duplicates"));
+        text.push_str(&fmt_named_flg(self.nocase, "nocase"));
+        text.push_str(&fmt_named_flg(self.nowarn, "nowarn"));
+        text.push_str(&fmt_opt_arg(&self.filter));
+        text.fmt(f)
+    }
+}
+
+impl CommandExt for GetRegisters {
+    fn location(&self) -> Location {
+        self.location.clone()
+    }
+
+    fn kind(&self) -> CommandKind {
+        CommandKind::GetRegisters
+    }
+}
+
+impl Validate for GetRegisters {
+    fn validate(&self, version: SdcVersion) -> Vec<ValidateError> {
+        let mut ret = vec![];
+        validate_opt(&mut ret, version, &self.filter);
+        ret
+    }
+}
+
+impl Extract for GetRegisters {
+    fn extract<'a>(&'a self, kind: CommandKind, list: &mut Vec<&'a Command>) {
+        Self::extract_opt(kind, list, &self.filter);
+    }
+
+    fn extract_mut<'a>(&'a mut self, kind: CommandKind, list: &mut Vec<&'a mut Command>) {
+        Self::extract_mut_opt(kind, list, &mut self.filter);
+    }
+}
+
+pub(crate) fn get_registers(
+    args: Vec<Argument>,
+    location: Location,
+) -> Result<Command, SemanticError> {
+    let mut no_duplicates = false;
+    let mut nocase = false;
+    let mut nowarn = false;
+    let mut filter = None;
+
+    static DICT: OnceLock<LazyDict> = OnceLock::new();
+    let dict = DICT.get_or_init(|| LazyDict::from_kind(CommandKind::GetRegisters));
+
+    let mut iter = args.into_iter();
+    while let Some(arg) = iter.next() {
+        match LazyMatcher::new(arg.as_str(), dict, &location)? {
+            x if x.m("-no_duplicates") => no_duplicates = opt_flg(arg, no_duplicates)?,
+            x if x.m("-nocase") => nocase = opt_flg(arg, nocase)?,
+            x if x.m("-nowarn") => nowarn = opt_flg(arg, nowarn)?,
+            _ => filter = pos_args1(Some(arg), filter, &location)?,
+        }
+    }
+
+    Ok(Command::GetRegisters(GetRegisters {
+        no_duplicates,
+        nocase,
+        nowarn,
+        filter,
+        location,
+    }))
+}
+
+/// set_time_format
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct SetTimeFormat {
+    pub unit: Option<Argument>,
+    pub decimal_places: Option<Argument>,
+    location: Location,
+}
+
+builder!(
+    SetTimeFormat,
+    SetTimeFormatBuilder {
+        mandatory: [],
+        optional: [unit, decimal_places],
+        flag: [],
+        repeatable: [],
+        extra: [],
+    }
+);
+
+impl fmt::Display for SetTimeFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut text = "set_time_format".to_string();
+        text.push_str(&fmt_named_opt_arg(&self.unit, "unit"));
+        text.push_str(&fmt_named_opt_arg(&self.decimal_places, "decimal_places"));
+        text.fmt(f)
+    }
+}
+
+impl CommandExt for SetTimeFormat {
+    fn location(&self) -> Location {
+        self.location.clone()
+    }
+
+    fn kind(&self) -> CommandKind {
+        CommandKind::SetTimeFormat
+    }
+}
+
+impl Validate for SetTimeFormat {
+    fn validate(&self, version: SdcVersion) -> Vec<ValidateError> {
+        let mut ret = vec![];
+        validate_opt(&mut ret, version, &self.unit);
+        validate_opt(&mut ret, version, &self.decimal_places);
+        ret
+    }
+}
+
+impl Extract for SetTimeFormat {
+    fn extract<'a>(&'a self, kind: CommandKind, list: &mut Vec<&'a Command>) {
+        Self::extract_opt(kind, list, &self.unit);
+        Self::extract_opt(kind, list, &self.decimal_places);
+    }
+
+    fn extract_mut<'a>(&'a mut self, kind: CommandKind, list: &mut Vec<&'a mut Command>) {
+        Self::extract_mut_opt(kind, list, &mut self.unit);
+        Self::extract_mut_opt(kind, list, &mut self.decimal_places);
+    }
+}
+
+pub(crate) fn set_time_format(
+    args: Vec<Argument>,
+    location: Location,
+) -> Result<Command, SemanticError> {
+    let mut unit = None;
+    let mut decimal_places = None;
+
+    static DICT: OnceLock<LazyDict> = OnceLock::new();
+    let dict = DICT.get_or_init(|| LazyDict::from_kind(CommandKind::SetTimeFormat));
+
+    let mut iter = args.into_iter();
+    while let Some(arg) = iter.next() {
+        match LazyMatcher::new(arg.as_str(), dict, &location)? {
+            x if x.m("-unit") => unit = opt_arg(arg, iter.next(), unit)?,
+            x if x.m("-decimal_places") => {
+                decimal_places = opt_arg(arg, iter.next(), decimal_places)?
+            }
+            _ => return Err(SemanticError::WrongArgument(arg)),
+        }
+    }
+
+    Ok(Command::SetTimeFormat(SetTimeFormat {
+        unit,
+        decimal_places,
+        location,
+    }))
+}
@@ -0,0 +1,2747 @@
+use crate::sdc::SdcVersion::*;
+use crate::sdc::{CommandKind, Dialect, SdcVersion};
+use std::fmt;
+
+/// Range of SDC versions
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct VersionRange {
+    pub from: SdcVersion,
+    pub to: SdcVersion,
+}
+
+impl VersionRange {
+    pub const fn new(from: SdcVersion, to: SdcVersion) -> Self {
+        Self { from, to }
+    }
+
+    pub fn contains(&self, version: SdcVersion) -> bool {
+        self.from <= version && version <= self.to
+    }
+}
+
+impl fmt::Display for VersionRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.from == self.to {
+            write!(f, "SDC {}", self.from.version_string())
+        } else {
+            write!(
+                f,
+                "SDC {}-{}",
+                self.from.version_string(),
+                self.to.version_string()
+            )
+        }
+    }
+}
+
+/// Type of option value or positional argument
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ValueType {
+    /// Numeric value
+    Number,
+    /// String like name or expression
+    String,
+    /// Tcl list of values
+    List,
+    /// Design objects like ports, pins and clocks
+    Objects,
+    /// Any value
+    Any,
+}
+
+impl fmt::Display for ValueType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ValueType::Number => "number".fmt(f),
+            ValueType::String => "string".fmt(f),
+            ValueType::List => "list".fmt(f),
+            ValueType::Objects => "objects".fmt(f),
+            ValueType::Any => "any".fmt(f),
+        }
+    }
+}
+
+/// Specification of command option
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OptionSpec {
+    pub name: &'static str,
+    /// Type of the value, `None` if the option is a flag
+    pub value: Option<ValueType>,
+    /// Whether the option can be specified multiple times
+    pub repeatable: bool,
+    pub mandatory: bool,
+    /// Supported SDC versions, `None` if they are the same as the command
+    pub versions: Option<VersionRange>,
+    /// Dialect supporting the option, `None` if it is supported by all dialects
+    pub dialect: Option<Dialect>,
+    /// Value used when the option is omitted
+    pub default: Option<&'static str>,
+    pub help: &'static str,
+}
+
+impl OptionSpec {
+    pub const fn flag(name: &'static str, help: &'static str) -> Self {
+        Self {
+            name,
+            value: None,
+            repeatable: false,
+            mandatory: false,
+            versions: None,
+            dialect: None,
+            default: None,
+            help,
+        }
+    }
+
+    pub const fn value(name: &'static str, value: ValueType, help: &'static str) -> Self {
+        Self {
+            value: Some(value),
+            ..Self::flag(name, help)
+        }
+    }
+
+    const fn repeatable(self) -> Self {
+        Self {
+            repeatable: true,
+            ..self
+        }
+    }
+
+    const fn mandatory(self) -> Self {
+        Self {
+            mandatory: true,
+            ..self
+        }
+    }
+
+    const fn versions(self, from: SdcVersion, to: SdcVersion) -> Self {
+        Self {
+            versions: Some(VersionRange::new(from, to)),
+            ..self
+        }
+    }
+
+    const fn dialect(self, dialect: Dialect) -> Self {
+        Self {
+            dialect: Some(dialect),
+            ..self
+        }
+    }
+
+    const fn default(self, value: &'static str) -> Self {
+        Self {
+            default: Some(value),
+            ..self
+        }
+    }
+
+    pub fn is_flag(&self) -> bool {
+        self.value.is_none()
+    }
+}
+
+/// Specification of positional argument
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ArgumentSpec {
+    pub name: &'static str,
+    pub value: ValueType,
+    /// Whether the argument takes all remaining arguments
+    pub repeatable: bool,
+    pub mandatory: bool,
+    pub help: &'static str,
+}
+
+impl ArgumentSpec {
+    pub const fn new(name: &'static str, value: ValueType, help: &'static str) -> Self {
+        Self {
+            name,
+            value,
+            repeatable: false,
+            mandatory: false,
+            help,
+        }
+    }
+
+    const fn repeatable(self) -> Self {
+        Self {
+            repeatable: true,
+            ..self
+        }
+    }
+
+    const fn mandatory(self) -> Self {
+        Self {
+            mandatory: true,
+            ..self
+        }
+    }
+}
+
+/// Specification of SDC command
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CommandSpec {
+    pub name: &'static str,
+    pub kind: CommandKind,
+    pub aliases: &'static [&'static str],
+    /// Supported SDC versions, `None` if the command is a dialect extension
+    pub versions: Option<VersionRange>,
+    /// Supported SDC versions of aliases
+    pub alias_versions: Option<VersionRange>,
+    pub help: &'static str,
+    pub options: &'static [OptionSpec],
+    pub arguments: &'static [ArgumentSpec],
+}
+
+impl CommandSpec {
+    /// Specification of `kind`
+    pub fn of(kind: CommandKind) -> Option<&'static CommandSpec> {
+        COMMAND_SPECS.iter().find(|x| x.kind == kind)
+    }
+
+    /// Specification of command `name` or its alias in `dialect`
+    pub fn find(name: &str, dialect: Dialect) -> Option<&'static CommandSpec> {
+        COMMAND_SPECS
+            .iter()
+            .find(|x| (x.name == name || x.aliases.contains(&name)) && x.is_supported(dialect))
+    }
+
+    /// Dialect defining the command
+    pub fn dialect(&self) -> Dialect {
+        self.kind.dialect()
+    }
+
+    pub fn is_supported(&self, dialect: Dialect) -> bool {
+        self.dialect() == Dialect::Sdc || self.dialect() == dialect
+    }
+
+    /// Option matched by `text` including unambiguous abbreviation
+    pub fn option(&self, text: &str) -> Option<&'static OptionSpec> {
+        if let Some(x) = self.options.iter().find(|x| x.name == text) {
+            return Some(x);
+        }
+        let mut found = self.options.iter().filter(|x| x.name.starts_with(text));
+        match (found.next(), found.next()) {
+            (Some(x), None) => Some(x),
+            _ => None,
+        }
+    }
+
+    /// Synopsis like `create_clock -period <number> [-name <string>] [source_objects]`
+    pub fn usage(&self) -> String {
+        self.synopsis(self.options.iter())
+    }
+
+    /// Synopsis including only options supported at `version`
+    pub fn usage_at(&self, version: SdcVersion) -> String {
+        self.synopsis(self.options_at(version))
+    }
+
+    fn synopsis<'a>(&self, options: impl Iterator<Item = &'a OptionSpec>) -> String {
+        let mut ret = self.name.to_string();
+        for x in options {
+            let text = match x.value {
+                Some(value) => format!("{} <{value}>", x.name),
+                None => x.name.to_string(),
+            };
+            if x.mandatory {
+                ret.push_str(&format!(" {text}"));
+            } else {
+                ret.push_str(&format!(" [{text}]"));
+            }
+        }
+        for x in self.arguments {
+            let text = if x.repeatable {
+                format!("{}...", x.name)
+            } else {
+                x.name.to_string()
+            };
+            if x.mandatory {
+                ret.push_str(&format!(" {text}"));
+            } else {
+                ret.push_str(&format!(" [{text}]"));
+            }
+        }
+        ret
+    }
+
+    /// Supported SDC versions of `option`
+    pub fn option_versions(&self, option: &OptionSpec) -> Option<VersionRange> {
+        option.versions.or(self.versions)
+    }
+
+    /// Standard options supported at `version`
+    pub fn options_at(&self, version: SdcVersion) -> impl Iterator<Item = &'static OptionSpec> {
+        let versions = self.versions;
+        self.options.iter().filter(move |x| {
+            x.dialect.is_none() && x.versions.or(versions).is_some_and(|x| x.contains(version))
+        })
+    }
+
+    /// Synopsis at `version` followed by supported versions of each option
+    pub fn usage_with_versions(&self, version: SdcVersion) -> String {
+        let mut ret = format!("{}\n", self.usage_at(version));
+        let options: Vec<_> = self.options_at(version).collect();
+        let width = options.iter().map(|x| x.name.len()).max().unwrap_or(0);
+        for x in options {
+            if let Some(versions) = self.option_versions(x) {
+                ret.push_str(&format!("  {:width$}  {versions}\n", x.name));
+            }
+        }
+        ret
+    }
+
+    /// Standard commands supported at `version`
+    pub fn available(version: SdcVersion) -> impl Iterator<Item = &'static CommandSpec> {
+        COMMAND_SPECS
+            .iter()
+            .filter(move |x| x.versions.is_some_and(|x| x.contains(version)))
+    }
+
+    /// Documentation including synopsis and description of each option
+    pub fn explain(&self) -> String {
+        let mut ret = format!(
+            "{} - {}\n\nUsage:\n  {}\n",
+            self.name,
+            self.help,
+            self.usage()
+        );
+
+        let support =
+            |versions: Option<VersionRange>, dialect: Option<Dialect>| match (dialect, versions) {
+                (Some(x), _) => format!("{} only", x.name()),
+                (None, Some(x)) => x.to_string(),
+                (None, None) => format!("{} only", self.dialect().name()),
+            };
+
+        let mut items = vec![];
+        for x in self.options {
+            let name = match x.value {
+                Some(value) => format!("{} <{value}>", x.name),
+                None => x.name.to_string(),
+            };
+            let mut help = x.help.to_string();
+            if x.mandatory {
+                help.push_str(" (mandatory)");
+            }
+            if x.repeatable {
+                help.push_str(" (repeatable)");
+            }
+            if let Some(default) = x.default {
+                help.push_str(&format!(" (default: {default})"));
+            }
+            let versions = support(self.option_versions(x), x.dialect);
+            items.push((true, name, versions, help));
+        }
+        for x in self.arguments {
+            let name = format!("{} <{}>", x.name, x.value);
+            let mut help = x.help.to_string();
+            if x.mandatory {
+                help.push_str(" (mandatory)");
+            }
+            if x.repeatable {
+                help.push_str(" (repeatable)");
+            }
+            items.push((false, name, String::new(), help));
+        }
+
+        let name_width = items.iter().map(|x| x.1.len()).max().unwrap_or(0);
+        let version_width = items.iter().map(|x| x.2.len()).max().unwrap_or(0);
+        for (option, heading) in [(true, "Options"), (false, "Arguments")] {
+            let items: Vec<_> = items.iter().filter(|x| x.0 == option).collect();
+            if items.is_empty() {
+                continue;
+            }
+            ret.push_str(&format!("\n{heading}:\n"));
+            for (_, name, versions, help) in items {
+                let line = if option {
+                    format!("  {name:name_width$}  {versions:version_width$}  {help}")
+                } else {
+                    format!("  {name:name_width$}  {help}")
+                };
+                ret.push_str(line.trim_end());
+                ret.push('\n');
+            }
+        }
+
+        ret.push_str(&format!("\nSupported: {}\n", support(self.versions, None)));
+        if !self.aliases.is_empty() {
+            let versions = support(self.alias_versions.or(self.versions), None);
+            ret.push_str(&format!(
+                "Aliases: {} ({versions})\n",
+                self.aliases.join(", ")
+            ));
+        }
+        ret
+    }
+}
+
+/// Specifications of all supported commands
+///
+/// Versions of commands and options are checked by `Sdc::validate` through this table.
+pub static COMMAND_SPECS: &[CommandSpec] = &[
+    CommandSpec {
+        name: "add_cells_to_pblock",
+        kind: CommandKind::AddCellsToPblock,
+        aliases: &[],
+        versions: None,
+        alias_versions: None,
+        help: "Add cells to a Pblock",
+        options: &[
+            OptionSpec::flag("-top", "Add to the top-level Pblock"),
+            OptionSpec::flag(
+                "-add_primitives",
+                "Add primitive cells of hierarchical cells",
+            ),
+            OptionSpec::flag("-clear_locs", "Clear location constraints of the cells"),
+            OptionSpec::flag("-quiet", "Suppress messages"),
+            OptionSpec::flag("-verbose", "Report details"),
+        ],
+        arguments: &[
+            ArgumentSpec::new("pblock", ValueType::Objects, "Pblock").mandatory(),
+            ArgumentSpec::new("cells", ValueType::Objects, "Cells to be added"),
+        ],
+    },
+    CommandSpec {
+        name: "all_clocks",
+        kind: CommandKind::AllClocks,
+        aliases: &[],
+        versions: Some(VersionRange::new(SDC1_1, SDC2_1)),
+        alias_versions: None,
+        help: "All clocks in the design",
+        options: &[],
+        arguments: &[],
+    },
+    CommandSpec {
+        name: "all_inputs",
+        kind: CommandKind::AllInputs,
+        aliases: &[],
+        versions: Some(VersionRange::new(SDC1_1, SDC2_1)),
+        alias_versions: None,
+        help: "All input ports in the design",
+        options: &[
+            OptionSpec::flag("-level_sensitive", "Level-sensitive objects"),
+            OptionSpec::flag("-edge_triggered", "Edge-triggered objects"),
+            OptionSpec::value(
+                "-clock",
+                ValueType::Objects,
+                "Ports with input delay relative to the clocks",
+            ),
+        ],
+        arguments: &[],
+    },
+    CommandSpec {
+        name: "all_outputs",
+        kind: CommandKind::AllOutputs,
+        aliases: &[],
+        versions: Some(VersionRange::new(SDC1_1, SDC2_1)),
+        alias_versions: None,
+        help: "All output ports in the design",
+        options: &[
+            OptionSpec::flag("-level_sensitive", "Level-sensitive objects"),
+            OptionSpec::flag("-edge_triggered", "Edge-triggered objects"),
+            OptionSpec::value(
+                "-clock",
+                ValueType::Objects,
+                "Ports with output delay relative to the clocks",
+            ),
+        ],
+        arguments: &[],
+    },
+    CommandSpec {
+        name: "all_registers",
+        kind: CommandKind::AllRegisters,
+        aliases: &[],
+        versions: Some(VersionRange::new(SDC1_7, SDC2_1)),
+        alias_versions: None,
+        help: "Sequential cells or pins in the design",
+        options: &[
+            OptionSpec::flag("-no_hierarchy", "Search only the current instance"),
+            OptionSpec::value(
+                "-clock",
+                ValueType::Objects,
+                "Registers clocked by the clocks",
+            ),
+            OptionSpec::value(
+                "-rise_clock",
+                ValueType::Objects,
+                "Triggered by the rising edge of the clock",
+            ),
+            OptionSpec::value(
+                "-fall_clock",
+                ValueType::Objects,
+                "Triggered by the falling edge of the clock",
+            ),
+            OptionSpec::flag("-cells", "Return cells"),
+            OptionSpec::flag("-data_pins", "Data pins"),
+            OptionSpec::flag("-clock_pins", "Clock pins"),
+            OptionSpec::flag("-slave_clock_pins", "Clock pins of slave latches"),
+            OptionSpec::flag("-async_pins", "Asynchronous preset or clear pins"),
+            OptionSpec::flag("-output_pins", "Output pins"),
+            OptionSpec::flag("-level_sensitive", "Level-sensitive objects"),
+            OptionSpec::flag("-edge_triggered", "Edge-triggered objects"),
+            OptionSpec::flag("-master_slave", "Master-slave objects"),
+        ],
+        arguments: &[],
+    },
+    CommandSpec {
+        name: "create_clock",
+        kind: CommandKind::CreateClock,
+        aliases: &[],
+        versions: Some(VersionRange::new(SDC1_1, SDC2_1)),
+        alias_versions: None,
+        help: "Create a clock",
+        options: &[
+            OptionSpec::value("-period", ValueType::Number, "Clock period").mandatory(),
+            OptionSpec::value("-name", ValueType::String, "Clock name"),
+            OptionSpec::value(
+                "-waveform",
+                ValueType::List,
+                "Rising and falling edge times",
+            )
+            .default("{0 period/2}"),
+            OptionSpec::flag("-add", "Add to existing definitions instead of overwriting")
+                .versions(SDC1_4, SDC2_1),
+            OptionSpec::value("-comment", ValueType::String, "Comment string")
+                .versions(SDC1_9, SDC2_1),
+        ],
+        arguments: &[ArgumentSpec::new(
+            "source_objects",
+            ValueType::Objects,
+            "Clock sources",
+        )],
+    },
+    CommandSpec {
+        name: "create_generated_clock",
+        kind: CommandKind::CreateGeneratedClock,
+        aliases: &[],
+        versions: Some(VersionRange::new(SDC1_3, SDC2_1)),
+        alias_versions: None,
+        help: "Create a clock derived from a master clock",
+        options: &[
+            OptionSpec::value("-name", ValueType::String, "Clock name"),
+            OptionSpec::value("-source", ValueType::Objects, "Master clock source pin").mandatory(),
+            OptionSpec::value(
+                "-edges",
+                ValueType::List,
+                "Edges of the master clock forming the generated clock",
+            ),
+            OptionSpec::value("-divide_by", ValueType::Number, "Frequency division factor"),
+            OptionSpec::value(
+                "-multiply_by",
+                ValueType::Number,
+                "Frequency multiplication factor",
+            ),
+            OptionSpec::value("-duty_cycle", ValueType::Number, "Duty cycle in percent"),
+            OptionSpec::flag("-invert", "Invert the generated clock"),
+            OptionSpec::value("-edge_shift", ValueType::List, "Shift amount of each edge"),
+            OptionSpec::flag("-add", "Add to existing definitions instead of overwriting")
+                .versions(SDC1_4, SDC2_1),
+            OptionSpec::value("-master_clock", ValueType::Objects, "Master clock")
+                .versions(SDC1_4, SDC2_1),
+            // spec shows -combinational was removed at SDC2.1, but
+            // actual implementations shows it is keeped.
+            OptionSpec::flag(
+                "-combinational",
+                "Clock propagates only through combinational logic",
+            )
+            .versions(SDC1_7, SDC2_1),
+            OptionSpec::value("-comment", ValueType::String, "Comment string")
+                .versions(SDC1_9, SDC2_1),
+        ],
+        arguments: &[
+            ArgumentSpec::new("source_objects", ValueType::Objects, "Clock sources").mandatory(),
+        ],
+    },
+    CommandSpec {
+        name: "create_pblock",
+        kind: CommandKind::CreatePblock,
+        aliases: &[],
+        versions: None,
+        alias_versions: None,
+        help: "Create a Pblock",
+        options: &[
+            OptionSpec::flag("-quiet", "Suppress messages"),
+            OptionSpec::flag("-verbose", "Report details"),
+        ],
+        arguments: &[ArgumentSpec::new("name", ValueType::String, "Pblock name").mandatory()],
+    },
+    CommandSpec {
+        name: "create_voltage_area",
+        kind: CommandKind::CreateVoltageArea,
+        aliases: &[],
+        versions: Some(VersionRange::new(SDC1_6, SDC2_1)),
+        alias_versions: None,
+        help: "Create a voltage area",
+        options: &[
+            OptionSpec::value("-name", ValueType::String, "Voltage area name").mandatory(),
+            OptionSpec::value("-coordinate", ValueType::List, "Coordinates of the area"),
+            OptionSpec::value(
+                "-guard_band_x",
+                ValueType::Number,
+                "Guard band width in x direction",
+            ),
+            OptionSpec::value(
+                "-guard_band_y",
+                ValueType::Number,
+                "Guard band width in y direction",
+            ),
+        ],
+        arguments: &[ArgumentSpec::new("cell_list", ValueType::Objects, "Cells").mandatory()],
+    },
+    CommandSpec {
+        name: "current_design",
+        kind: CommandKind::CurrentDesign,
+        aliases: &[],
+        versions: Some(VersionRange::new(SDC1_1, SDC2_1)),
+        alias_versions: None,
+        help: "Set the current design",
+        options: &[],
+        arguments: &[],
+    },
+    CommandSpec {
+        name: "current_instance",
+        kind: CommandKind::CurrentInstance,
+        aliases: &[],
+        versions: Some(VersionRange::new(SDC1_1, SDC2_1)),
+        alias_versions: None,
+        help: "Set the current instance in the design hierarchy",
+        options: &[],
+        arguments: &[ArgumentSpec::new(
+            "instance",
+            ValueType::Objects,
+            "Instance",
+        )],
+    },
+    CommandSpec {
+        name: "derive_clock_uncertainty",
+        kind: CommandKind::DeriveClockUncertainty,
+        aliases: &[],
+        versions: None,
+        alias_versions: None,
+        help: "Apply clock uncertainty derived from device characteristics",
+        options: &[
+            OptionSpec::flag("-add", "Add to user-defined uncertainty"),
+            OptionSpec::flag("-overwrite", "Overwrite existing uncertainty"),
+            OptionSpec::flag("-dtcc", "Use detailed clock uncertainty calculation"),
+        ],
+        arguments: &[],
+    },
+    CommandSpec {
+        name: "derive_pll_clocks",
+        kind: CommandKind::DerivePllClocks,
+        aliases: &[],
+        versions: None,
+        alias_versions: None,
+        help: "Create generated clocks on PLL outputs",
+        options: &[
+            OptionSpec::flag("-create_base_clocks", "Create base clocks on PLL inputs"),
+            OptionSpec::flag("-use_net_name", "Use net names as clock names"),
+            OptionSpec::flag(
+                "-use_tan_name",
+                "Use clock names of Classic Timing Analyzer",
+            ),
+        ],
+        arguments: &[],
+    },
+    CommandSpec {
+        name: "expr",
+        kind: CommandKind::Expr,
+        aliases: &[],
+        versions: Some(VersionRange::new(SDC1_1, SDC2_1)),
+        alias_versions: None,
+        help: "Evaluate a Tcl expression",
+        options: &[],
+        arguments: &[ArgumentSpec::new("args", ValueType::Any, "Arguments").repeatable()],
+    },
+    CommandSpec {
+        name: "get_cells",
+        kind: CommandKind::GetCells,
+        aliases: &["get_cell"],
+        versions: Some(VersionRange::new(SDC1_1, SDC2_1)),
+        alias_versions: Some(VersionRange::new(SDC1_5, SDC2_1)),
+        help: "Cells matching the patterns",
+        options: &[
+            OptionSpec::flag("-hierarchical", "Search in all hierarchy levels"),
+            OptionSpec::flag("-regexp", "Patterns are regular expressions")
+                .versions(SDC1_5, SDC2_1),
+            OptionSpec::flag("-nocase", "Case-insensitive match").versions(SDC1_5, SDC2_1),
+            OptionSpec::value("-hsc", ValueType::String, "Hierarchy separator in patterns")
+                .versions(SDC1_2, SDC2_1)
+                .default("/"),
+            OptionSpec::value(
+                "-of_objects",
+                ValueType::Objects,
+                "Objects related to the objects",
+            ),
+            OptionSpec::value("-filter", ValueType::String, "Filter expression")
+                .dialect(Dialect::Xdc),
+            OptionSpec::flag("-quiet", "Suppress messages").dialect(Dialect::Xdc),
+        ],
+        arguments: &[ArgumentSpec::new(
+            "patterns",
+            ValueType::List,
+            "Name patterns",
+        )],
+    },
+    CommandSpec {
+        name: "get_clocks",
+        kind: CommandKind::GetClocks,
+        aliases: &[],
+        versions: Some(VersionRange::new(SDC1_1, SDC2_1)),
+        alias_versions: None,
+        help: "Clocks matching the patterns",
+        options: &[
+            OptionSpec::flag("-regexp", "Patterns are regular expressions")
+                .versions(SDC1_5, SDC2_1),
+            OptionSpec::flag("-nocase", "Case-insensitive match").versions(SDC1_5, SDC2_1),
+            OptionSpec::value("-filter", ValueType::String, "Filter expression")
+                .dialect(Dialect::Xdc),
+            OptionSpec::value(
+                "-of_objects",
+                ValueType::Objects,
+                "Objects related to the objects",
+            )
+            .dialect(Dialect::Xdc),
+            OptionSpec::flag("-quiet", "Suppress messages").dialect(Dialect::Xdc),
+        ],
+        arguments: &[ArgumentSpec::new(
+            "patterns",
+            ValueType::List,
+            "Name patterns",
+        )],
+    },
+    CommandSpec {
+        name: "get_fanouts",
+        kind: CommandKind::GetFanouts,
+        aliases: &[],
+        versions: None,
+        alias_versions: None,
+        help: "Fan-out nodes of the filter",
+        options: &[
+            OptionSpec::flag("-no_logic", "Exclude logic between nodes"),
+            OptionSpec::flag("-stop_at_clocks", "Stop at clock pins"),
+            OptionSpec::value(
+                "-through",
+                ValueType::Objects,
+                "Nodes through which fan-outs are traced",
+            ),
+        ],
+        arguments: &[
+            ArgumentSpec::new("filter", ValueType::String, "Filter expression").mandatory(),
+        ],
+    },
+    CommandSpec {
+        name: "get_iobanks",
+        kind: CommandKind::GetIobanks,
+        aliases: &[],
+        versions: None,
+        alias_versions: None,
+        help: "I/O banks matching the patterns",
+        options: &[
+            OptionSpec::flag("-regexp", "Patterns are regular expressions"),
+            OptionSpec::flag("-nocase", "Case-insensitive match"),
+            OptionSpec::value("-filter", ValueType::String, "Filter expression"),
+            OptionSpec::value(
+                "-of_objects",
+                ValueType::Objects,
+                "Objects related to the objects",
+            ),
+            OptionSpec::flag("-quiet", "Suppress messages"),
+            OptionSpec::flag("-verbose", "Report details"),
+        ],
+        arguments: &[ArgumentSpec::new(
+            "patterns",
+            ValueType::List,
+            "Name patterns",
+        )],
+    },
+    CommandSpec {
+        name: "get_keepers",
+        kind: CommandKind::GetKeepers,
+        aliases: &[],
+        versions: None,
+        alias_versions: None,
+        help: "Keepers matching the filter",
+        options: &[
+            OptionSpec::flag("-no_duplicates", "Exclude duplicated registers"),
+            OptionSpec::flag("-nocase", "Case-insensitive match"),
+            OptionSpec::flag("-nowarn", "Suppress warnings"),
+        ],
+        arguments: &[ArgumentSpec::new(
+            "filter",
+            ValueType::String,
+            "Filter expression",
+        )],
+    },
+    CommandSpec {
+        name: "get_lib_cells",
+        kind: CommandKind::GetLibCells,
+        aliases: &["get_lib_cell"],
+        versions: Some(VersionRange::new(SDC1_1, SDC2_1)),
+        alias_versions: Some(VersionRange::new(SDC1_5, SDC2_1)),
+        help: "Library cells matching the patterns",
+        options: &[
+            OptionSpec::flag("-regexp", "Patterns are regular expressions")
+                .versions(SDC1_5, SDC2_1),
+            OptionSpec::value("-hsc", ValueType::String, "Hierarchy separator in patterns")
+                .versions(SDC1_2, SDC2_1)
+                .default("/"),
+            OptionSpec::flag("-nocase", "Case-insensitive match").versions(SDC1_5, SDC2_1),
+        ],
+        arguments: &[ArgumentSpec::new("patterns", ValueType::List, "Name patterns").mandatory()],
+    },
+    CommandSpec {
+        name: "get_lib_pins",
+        kind: CommandKind::GetLibPins,
+        aliases: &["get_lib_pin"],
+        versions: Some(VersionRange::new(SDC1_1, SDC2_1)),
+        alias_versions: Some(VersionRange::new(SDC1_5, SDC2_1)),
+        help: "Library cell pins matching the patterns",
+        options: &[
+            OptionSpec::flag("-regexp", "Patterns are regular expressions")
+                .versions(SDC1_5, SDC2_1),
+            OptionSpec::flag("-hsc", "Hierarchy separator in patterns").versions(SDC1_2, SDC2_1),
+            OptionSpec::flag("-nocase", "Case-insensitive match").versions(SDC1_5, SDC2_1),
+        ],
+        arguments: &[ArgumentSpec::new("patterns", ValueType::List, "Name patterns").mandatory()],
+    },
+    CommandSpec {
+        name: "get_libs",
+        kind: CommandKind::GetLibs,
+        aliases: &[],
+        versions: Some(VersionRange::new(SDC1_1, SDC2_1)),
+        alias_versions: None,
+        help: "Libraries matching the patterns",
+        options: &[
+            OptionSpec::flag("-regexp", "Patterns are regular expressions")
+                .versions(SDC1_5, SDC2_1),
+            OptionSpec::flag("-nocase", "Case-insensitive match").versions(SDC1_5, SDC2_1),
+        ],
+        arguments: &[ArgumentSpec::new(
+            "patterns",
+            ValueType::List,
+            "Name patterns",
+        )],
+    },
+    CommandSpec {
+        name: "get_nets",
+        kind: CommandKind::GetNets,
+        aliases: &["get_net"],
+        versions: Some(VersionRange::new(SDC1_1, SDC2_1)),
+        alias_versions: Some(VersionRange::new(SDC1_5, SDC2_1)),
+        help: "Nets matching the patterns",
+        options: &[
+            OptionSpec::flag("-hierarchical", "Search in all hierarchy levels"),
+            OptionSpec::value("-hsc", ValueType::String, "Hierarchy separator in patterns")
+                .versions(SDC1_2, SDC2_1)
+                .default("/"),
+            OptionSpec::flag("-regexp", "Patterns are regular expressions")
+                .versions(SDC1_5, SDC2_1),
+            OptionSpec::flag("-nocase", "Case-insensitive match").versions(SDC1_5, SDC2_1),
+            OptionSpec::value(
+                "-of_objects",
+                ValueType::Objects,
+                "Objects related to the objects",
+            )
+            .versions(SDC1_5, SDC2_1),
+            OptionSpec::value("-filter", ValueType::String, "Filter expression")
+                .dialect(Dialect::Xdc),
+            OptionSpec::flag("-quiet", "Suppress messages").dialect(Dialect::Xdc),
+        ],
+        arguments: &[ArgumentSpec::new(
+            "patterns",
+            ValueType::List,
+            "Name patterns",
+        )],
+    },
+    CommandSpec {
+        name: "get_pblocks",
+        kind: CommandKind::GetPblocks,
+        aliases: &[],
+        versions: None,
+        alias_versions: None,
+        help: "Pblocks matching the patterns",
+        options: &[
+            OptionSpec::flag("-regexp", "Patterns are regular expressions"),
+            OptionSpec::flag("-nocase", "Case-insensitive match"),
+            OptionSpec::value("-filter", ValueType::String, "Filter expression"),
+            OptionSpec::value(
+                "-of_objects",
+                ValueType::Objects,
+                "Objects related to the objects",
+            ),
+            OptionSpec::flag("-include_nested_pblocks", "Include nested Pblocks"),
+            OptionSpec::flag("-quiet", "Suppress messages"),
+            OptionSpec::flag("-verbose", "Report details"),
+        ],
+        arguments: &[ArgumentSpec::new(
+            "patterns",
+            ValueType::List,
+            "Name patterns",
+        )],
+    },
+    CommandSpec {
+        name: "get_pins",
+        kind: CommandKind::GetPins,
+        aliases: &["get_pin"],
+        versions: Some(VersionRange::new(SDC1_1, SDC2_1)),
+        alias_versions: Some(VersionRange::new(SDC1_5, SDC2_1)),
+        help: "Pins matching the patterns",
+        options: &[
+            OptionSpec::flag("-hierarchical", "Search in all hierarchy levels"),
+            OptionSpec::value("-hsc", ValueType::String, "Hierarchy separator in patterns")
+                .versions(SDC1_2, SDC2_1)
+                .default("/"),
+            OptionSpec::flag("-regexp", "Patterns are regular expressions")
+                .versions(SDC1_5, SDC2_1),
+            OptionSpec::flag("-nocase", "Case-insensitive match").versions(SDC1_5, SDC2_1),
+            OptionSpec::value("-filter", ValueType::String, "Filter expression")
+                .dialect(Dialect::Xdc),
+            OptionSpec::value(
+                "-of_objects",
+                ValueType::Objects,
+                "Objects related to the objects",
+            )
+            .dialect(Dialect::Xdc),
+            OptionSpec::flag("-quiet", "Suppress messages").dialect(Dialect::Xdc),
+        ],
+        arguments: &[ArgumentSpec::new(
+            "patterns",
+            ValueType::List,
+            "Name patterns",
+        )],
+    },
+    CommandSpec {
+        name: "get_ports",
+        kind: CommandKind::GetPorts,
+        aliases: &["get_port"],
+        versions: Some(VersionRange::new(SDC1_1, SDC2_1)),
+        alias_versions: Some(VersionRange::new(SDC1_5, SDC2_1)),
+        help: "Ports matching the patterns",
+        options: &[
+            OptionSpec::flag("-hierarchical", "Search in all hierarchy levels")
+                .versions(SDC1_5, SDC2_1),
+            OptionSpec::flag("-regexp", "Patterns are regular expressions")
+                .versions(SDC1_5, SDC2_1),
+            OptionSpec::flag("-nocase", "Case-insensitive match").versions(SDC1_5, SDC2_1),
+            OptionSpec::value("-filter", ValueType::String, "Filter expression")
+                .dialect(Dialect::Xdc),
+            OptionSpec::value(
+                "-of_objects",
+                ValueType::Objects,
+                "Objects related to the objects",
+            )
+            .dialect(Dialect::Xdc),
+            OptionSpec::flag("-quiet", "Suppress messages").dialect(Dialect::Xdc),
+        ],
+        arguments: &[ArgumentSpec::new(
+            "patterns",
+            ValueType::List,
+            "Name patterns",
+        )],
+    },
+    CommandSpec {
+        name: "get_property",
+        kind: CommandKind::GetProperty,
+        aliases: &[],
+        versions: None,
+        alias_versions: None,
+        help: "Value of a property of an object",
+        options: &[
+            OptionSpec::flag("-min", "Minimum condition"),
+            OptionSpec::flag("-max", "Maximum condition"),
+            OptionSpec::flag("-quiet", "Suppress messages"),
+            OptionSpec::flag("-verbose", "Report details"),
+        ],
+        arguments: &[
+            ArgumentSpec::new("name", ValueType::String, "Property name").mandatory(),
+            ArgumentSpec::new("object", ValueType::Objects, "Object").mandatory(),
+        ],
+    },
+    CommandSpec {
+        name: "get_registers",
+        kind: CommandKind::GetRegisters,
+        aliases: &[],
+        versions: None,
+        alias_versions: None,
+        help: "Registers matching the filter",
+        options: &[
+            OptionSpec::flag("-no_duplicates", "Exclude duplicated registers"),
+            OptionSpec::flag("-nocase", "Case-insensitive match"),
+            OptionSpec::flag("-nowarn", "Suppress warnings"),
+        ],
+        arguments: &[ArgumentSpec::new(
+            "filter",
+            ValueType::String,
+            "Filter expression",
+        )],
+    },
+    CommandSpec {
+        name: "get_timing_paths",
+        kind: CommandKind::GetTimingPaths,
+        aliases: &[],
+        versions: None,
+        alias_versions: None,
+        help: "Timing paths matching the conditions",
+        options: &[
+            OptionSpec::value("-from", ValueType::Objects, "Paths starting at objects"),
+            OptionSpec::value("-to", ValueType::Objects, "Paths ending at objects"),
+            OptionSpec::value("-through", ValueType::Objects, "Paths through objects").repeatable(),
+            OptionSpec::value(
+                "-delay_type",
+                ValueType::String,
+                "Type of path delay (max, min or min_max)",
+            ),
+            OptionSpec::value("-max_paths", ValueType::Number, "Maximum number of paths"),
+            OptionSpec::value("-nworst", ValueType::Number, "Number of paths per endpoint"),
+            OptionSpec::value("-group", ValueType::Objects, "Path groups"),
+            OptionSpec::value(
+                "-slack_lesser_than",
+                ValueType::Number,
+                "Paths with slack less than the value",
+            ),
+            OptionSpec::value(
+                "-slack_greater_than",
+                ValueType::Number,
+                "Paths with slack greater than the value",
+            ),
+        ],
+        arguments: &[],
+    },
+    CommandSpec {
+        name: "group_path",
+        kind: CommandKind::GroupPath,
+        aliases: &[],
+        versions: Some(VersionRange::new(SDC1_7, SDC2_1)),
+        alias_versions: None,
+        help: "Group timing paths for cost function calculation",
+        options: &[
+            OptionSpec::value("-name", ValueType::String, "Path group name"),
+            OptionSpec::flag("-default", "Default path group"),
+            OptionSpec::value("-weight", ValueType::Number, "Cost function weight"),
+            OptionSpec::value("-from", ValueType::Objects, "Paths starting at objects"),
+            OptionSpec::value(
+                "-rise_from",
+                ValueType::Objects,
+                "Paths starting at rising transitions of objects",
+            ),
+            OptionSpec::value(
+                "-fall_from",
+                ValueType::Objects,
+                "Paths starting at falling transitions of objects",
+            ),
+            OptionSpec::value("-to", ValueType::Objects, "Paths ending at objects"),
+            OptionSpec::value(
+                "-rise_to",
+                ValueType::Objects,
+                "Paths ending at rising transitions of objects",
+            ),
+            OptionSpec::value(
+                "-fall_to",
+                ValueType::Objects,
+                "Paths ending at falling transitions of objects",
+            ),
+            OptionSpec::value("-through", ValueType::Objects, "Paths through objects").repeatable(),
+            OptionSpec::value(
+                "-rise_through",
+                ValueType::Objects,
+                "Paths through rising transitions of objects",
+            )
+            .repeatable(),
+            OptionSpec::value(
+                "-fall_through",
+                ValueType::Objects,
+                "Paths through falling transitions of objects",
+            )
+            .repeatable(),
+            OptionSpec::value("-comment", ValueType::String, "Comment string")
+                .versions(SDC1_9, SDC2_1),
+        ],
+        arguments: &[],
+    },
+    CommandSpec {
+        name: "list",
+        kind: CommandKind::List,
+        aliases: &[],
+        versions: Some(VersionRange::new(SDC1_1, SDC2_1)),
+        alias_versions: None,
+        help: "Create a Tcl list",
+        options: &[],
+        arguments: &[ArgumentSpec::new("args", ValueType::Any, "Arguments").repeatable()],
+    },
+    CommandSpec {
+        name: "remove_case_analysis",
+        kind: CommandKind::RemoveCaseAnalysis,
+        aliases: &[],
+        versions: None,
+        alias_versions: None,
+        help: "Remove constant values set by set_case_analysis",
+        options: &[],
+        arguments: &[
+            ArgumentSpec::new("port_or_pin_list", ValueType::Objects, "Ports or pins").mandatory(),
+        ],
+    },
+    CommandSpec {
+        name: "remove_clock",
+        kind: CommandKind::RemoveClock,
+        aliases: &[],
+        versions: None,
+        alias_versions: None,
+        help: "Remove clocks",
+        options: &[OptionSpec::flag("-all", "Remove all clocks")],
+        arguments: &[ArgumentSpec::new(
+            "clock_list",
+            ValueType::Objects,
+            "Clocks",
+        )],
+    },
+    CommandSpec {
+        name: "remove_clock_groups",
+        kind: CommandKind::RemoveClockGroups,
+        aliases: &[],
+        versions: None,
+        alias_versions: None,
+        help: "Remove clock groups",
+        options: &[
+            OptionSpec::flag(
+                "-logically_exclusive",
+                "Groups are logically exclusive to each other",
+            ),
+            OptionSpec::flag(
+                "-physically_exclusive",
+                "Groups are physically exclusive to each other",
+            ),
+            OptionSpec::flag("-asynchronous", "Groups are asynchronous to each other"),
+            OptionSpec::flag("-all", "Remove all clock groups"),
+        ],
+        arguments: &[ArgumentSpec::new(
+            "name_list",
+            ValueType::List,
+            "Clock group names",
+        )],
+    },
+    CommandSpec {
+        name: "remove_disable_timing",
+        kind: CommandKind::RemoveDisableTiming,
+        aliases: &[],
+        versions: None,
+        alias_versions: None,
+        help: "Remove timing arcs disabled by set_disable_timing",
+        options: &[
+            OptionSpec::value("-from", ValueType::Objects, "Source pin of timing arcs"),
+            OptionSpec::value("-to", ValueType::Objects, "Sink pin of timing arcs"),
+        ],
+        arguments: &[ArgumentSpec::new("object_list", ValueType::Objects, "Objects").mandatory()],
+    },
+    CommandSpec {
+        name: "remove_generated_clock",
+        kind: CommandKind::RemoveGeneratedClock,
+        aliases: &[],
+        versions: None,
+        alias_versions: None,
+        help: "Remove generated clocks",
+        options: &[OptionSpec::flag("-all", "Remove all generated clocks")],
+        arguments: &[ArgumentSpec::new(
+            "clock_list",
+            ValueType::Objects,
+            "Clocks",
+        )],
+    },
+    CommandSpec {
+        name: "remove_input_delay",
+        kind: CommandKind::RemoveInputDelay,
+        aliases: &[],
+        versions: None,
+        alias_versions: None,
+        help: "Remove input delays",
+        options: &[
+            OptionSpec::value(
+                "-clock",
+                ValueType::Objects,
+                "Relative clock of delays to be removed",
+            ),
+            OptionSpec::flag("-clock_fall", "Relative to the falling edge of the clock"),
+            OptionSpec::flag("-level_sensitive", "Level-sensitive objects"),
+            OptionSpec::flag("-rise", "Rising transitions"),
+            OptionSpec::flag("-fall", "Falling transitions"),
+            OptionSpec::flag("-max", "Maximum condition"),
+            OptionSpec::flag("-min", "Minimum condition"),
+        ],
+        arguments: &[
+            ArgumentSpec::new("port_pin_list", ValueType::Objects, "Ports or pins").mandatory(),
+        ],
+    },
+    CommandSpec {
+        name: "remove_output_delay",
+        kind: CommandKind::RemoveOutputDelay,
+        aliases: &[],
+        versions: None,
+        alias_versions: None,
+        help: "Remove output delays",
+        options: &[
+            OptionSpec::value(
+                "-clock",
+                ValueType::Objects,
+                "Relative clock of delays to be removed",
+            ),
+            OptionSpec::flag("-clock_fall", "Relative to the falling edge of the clock"),
+            OptionSpec::flag("-level_sensitive", "Level-sensitive objects"),
+            OptionSpec::flag("-rise", "Rising transitions"),
+            OptionSpec::flag("-fall", "Falling transitions"),
+            OptionSpec::flag("-max", "Maximum condition"),
+            OptionSpec::flag("-min", "Minimum condition"),
+        ],
+        arguments: &[
+            ArgumentSpec::new("port_pin_list", ValueType::Objects, "Ports or pins").mandatory(),
+        ],
+    },
+    CommandSpec {
+        name: "resize_pblock",
+        kind: CommandKind::ResizePblock,
+        aliases: &[],
+        versions: None,
+        alias_versions: None,
+        help: "Change the ranges of a Pblock",
+        options: &[
+            OptionSpec::value("-add", ValueType::List, "Ranges to be added").repeatable(),
+            OptionSpec::value("-remove", ValueType::List, "Ranges to be removed").repeatable(),
+            OptionSpec::value("-from", ValueType::List, "Ranges to be moved from"),
+            OptionSpec::value("-to", ValueType::List, "Ranges to be moved to"),
+            OptionSpec::flag("-replace", "Replace all ranges"),
+            OptionSpec::value("-locs", ValueType::List, "Handling of location constraints"),
+            OptionSpec::flag("-quiet", "Suppress messages"),
+            OptionSpec::flag("-verbose", "Report details"),
+        ],
+        arguments: &[ArgumentSpec::new("pblock", ValueType::Objects, "Pblock").mandatory()],
+    },
+    CommandSpec {
+        name: "set",
+        kind: CommandKind::Set,
+        aliases: &[],
+        versions: Some(VersionRange::new(SDC1_1, SDC2_1)),
+        alias_versions: None,
+        help: "Set a Tcl variable",
+        options: &[],
+        arguments: &[
+            ArgumentSpec::new("variable_name", ValueType::String, "Variable name").mandatory(),
+            ArgumentSpec::new("value", ValueType::Any, "Variable value").mandatory(),
+        ],
+    },
+    CommandSpec {
+        name: "set_annotated_delay",
+        kind: CommandKind::SetAnnotatedDelay,
+        aliases: &[],
+        versions: None,
+        alias_versions: None,
+        help: "Annotate cell or net delays",
+        options: &[
+            OptionSpec::flag("-cell", "Cell delay"),
+            OptionSpec::flag("-net", "Net delay"),
+            OptionSpec::flag("-rise", "Rising transitions"),
+            OptionSpec::flag("-fall", "Falling transitions"),
+            OptionSpec::flag("-min", "Minimum condition"),
+            OptionSpec::flag("-max", "Maximum condition"),
+            OptionSpec::value(
+                "-load_delay",
+                ValueType::String,
+                "Load delay is included in cell or net delay",
+            ),
+            OptionSpec::value("-from", ValueType::Objects, "Driver pin"),
+            OptionSpec::value("-to", ValueType::Objects, "Load pin"),
+        ],
+        arguments: &[
+            ArgumentSpec::new("delay_value", ValueType::Number, "Delay value").mandatory(),
+        ],
+    },
+    CommandSpec {
+        name: "set_app_var",
+        kind: CommandKind::SetAppVar,
+        aliases: &[],
+        versions: None,
+        alias_versions: None,
+        help: "Set an application variable",
+        options: &[],
+        arguments: &[
+            ArgumentSpec::new("variable", ValueType::String, "Variable name").mandatory(),
+            ArgumentSpec::new("value", ValueType::Any, "Variable value").mandatory(),
+        ],
+    },
+    CommandSpec {
+        name: "set_assigned_delay",
+        kind: CommandKind::SetAssignedDelay,
+        aliases: &[],
+        versions: None,
+        alias_versions: None,
+        help: "Assign cell or net delays",
+        options: &[
+            OptionSpec::flag("-cell", "Cell delay"),
+            OptionSpec::flag("-net", "Net delay"),
+            OptionSpec::flag("-rise", "Rising transitions"),
+            OptionSpec::flag("-fall", "Falling transitions"),
+            OptionSpec::value("-corner", ValueType::String, "Analysis corner"),
+            OptionSpec::flag("-min", "Minimum condition"),
+            OptionSpec::flag("-max", "Maximum condition"),
+            OptionSpec::value("-from", ValueType::Objects, "Driver pin"),
+            OptionSpec::value("-to", ValueType::Objects, "Load pin"),
+        ],
+        arguments: &[ArgumentSpec::new("delay", ValueType::Number, "Delay value").mandatory()],
+    },
+    CommandSpec {
+        name: "set_case_analysis",
+        kind: CommandKind::SetCaseAnalysis,
+        aliases: &[],
+        versions: Some(VersionRange::new(SDC1_1, SDC2_1)),
+        alias_versions: None,
+        help: "Set constant values or transitions on ports or pins",
+        options: &[],
+        arguments: &[
+            ArgumentSpec::new(
+                "value",
+                ValueType::String,
+                "Constant value (0, 1, rising or falling)",
+            )
+            .mandatory(),
+            ArgumentSpec::new("port_or_pin_list", ValueType::Objects, "Ports or pins").mandatory(),
+        ],
+    },
+    CommandSpec {
+        name: "set_clock_gating_check",
+        kind: CommandKind::SetClockGatingCheck,
+        aliases: &[],
+        versions: Some(VersionRange::new(SDC1_2, SDC2_1)),
+        alias_versions: None,
+        help: "Set setup and hold checks of clock gating",
+        options: &[
+            OptionSpec::value("-setup", ValueType::Number, "Setup time of clock gating")
+                .default("0"),
+            OptionSpec::value("-hold", ValueType::Number, "Hold time of clock gating").default("0"),
+            OptionSpec::flag("-rise", "Rising transitions"),
+            OptionSpec::flag("-fall", "Falling transitions"),
+            OptionSpec::flag("-high", "High level of the clock"),
+            OptionSpec::flag("-low", "Low level of the clock"),
+        ],
+        arguments: &[ArgumentSpec::new(
+            "object_list",
+            ValueType::Objects,
+            "Objects",
+        )],
+    },
+    CommandSpec {
+        name: "set_clock_groups",
+        kind: CommandKind::SetClockGroups,
+        aliases: &["set_clock_group"],
+        versions: Some(VersionRange::new(SDC1_7, SDC2_1)),
+        alias_versions: Some(VersionRange::new(SDC1_7, SDC2_1)),
+        help: "Set relationships between groups of clocks",
+        options: &[
+            OptionSpec::value("-group", ValueType::Objects, "Clocks in a group").repeatable(),
+            OptionSpec::flag(
+                "-logically_exclusive",
+                "Groups are logically exclusive to each other",
+            ),
+            OptionSpec::flag(
+                "-physically_exclusive",
+                "Groups are physically exclusive to each other",
+            ),
+            OptionSpec::flag("-asynchronous", "Groups are asynchronous to each other"),
+            OptionSpec::flag("-exclusive", "Groups are exclusive to each other")
+                .dialect(Dialect::Quartus),
+            OptionSpec::flag("-allow_paths", "Allow timing paths between the groups"),
+            OptionSpec::flag(
+                "-include_generated_clocks",
+                "Include clocks generated from the group",
+            )
+            .dialect(Dialect::Xdc),
+            OptionSpec::value("-name", ValueType::String, "Clock group name"),
+            OptionSpec::value("-comment", ValueType::String, "Comment string")
+                .versions(SDC1_9, SDC2_1),
+        ],
+        arguments: &[],
+    },
+    CommandSpec {
+        name: "set_clock_gating_style",
+        kind: CommandKind::SetClockGatingStyle,
+        aliases: &[],
+        versions: None,
+        alias_versions: None,
+        help: "Set the style of clock gating",
+        options: &[
+            OptionSpec::value(
+                "-sequential_cell",
+                ValueType::String,
+                "Sequential cell used for gating",
+            ),
+            OptionSpec::value(
+                "-minimum_bitwidth",
+                ValueType::Number,
+                "Minimum bit width of registers to be gated",
+            ),
+            OptionSpec::value(
+                "-positive_edge_logic",
+                ValueType::List,
+                "Gating logic for positive edge triggered registers",
+            ),
+            OptionSpec::value(
+                "-negative_edge_logic",
+                ValueType::List,
+                "Gating logic for negative edge triggered registers",
+            ),
+            OptionSpec::value(
+                "-control_point",
+                ValueType::String,
+                "Location of the control point (none, before or after)",
+            ),
+            OptionSpec::value(
+                "-control_signal",
+                ValueType::String,
+                "Type of the control signal (scan_enable or test_mode)",
+            ),
+            OptionSpec::value(
+                "-max_fanout",
+                ValueType::Number,
+                "Maximum fanout of gating logic",
+            ),
+            OptionSpec::value("-setup", ValueType::Number, "Setup time of gating logic"),
+            OptionSpec::value("-hold", ValueType::Number, "Hold time of gating logic"),
+        ],
+        arguments: &[],
+    },
+    CommandSpec {
+        name: "set_clock_latency",
+        kind: CommandKind::SetClockLatency,
+        aliases: &[],
+        versions: Some(VersionRange::new(SDC1_1, SDC2_1)),
+        alias_versions: None,
+        help: "Set clock latency",
+        options: &[
+            OptionSpec::flag("-rise", "Rising transitions"),
+            OptionSpec::flag("-fall", "Falling transitions"),
+            OptionSpec::flag("-min", "Minimum condition"),
+            OptionSpec::flag("-max", "Maximum condition"),
+            OptionSpec::flag("-source", "Source latency instead of network latency"),
+            OptionSpec::flag("-dynamic", "Dynamic source latency").versions(SDC2_1, SDC2_1),
+            OptionSpec::flag("-late", "Late (maximum) path").versions(SDC1_2, SDC2_1),
+            OptionSpec::flag("-early", "Early (minimum) path").versions(SDC1_2, SDC2_1),
+            OptionSpec::value(
+                "-clock",
+                ValueType::Objects,
+                "Clocks whose latency is set on the objects",
+            )
+            .versions(SDC1_5, SDC2_1),
+        ],
+        arguments: &[
+            ArgumentSpec::new("delay", ValueType::Number, "Delay value").mandatory(),
+            ArgumentSpec::new("object_list", ValueType::Objects, "Objects").mandatory(),
+        ],
+    },
+    CommandSpec {
+        name: "set_clock_sense",
+        kind: CommandKind::SetClockSense,
+        aliases: &[],
+        versions: Some(VersionRange::new(SDC1_7, SDC2_0)),
+        alias_versions: None,
+        help: "Set unateness of clocks propagated through pins",
+        options: &[
+            OptionSpec::value(
+                "-clocks",
+                ValueType::Objects,
+                "Clocks to which the sense applies",
+            ),
+            OptionSpec::flag("-positive", "Positive unate"),
+            OptionSpec::flag("-negative", "Negative unate"),
+            OptionSpec::flag("-stop_propagation", "Stop propagation of the clock"),
+            OptionSpec::value(
+                "-pulse",
+                ValueType::String,
+                "Pulse type (rise_triggered_high_pulse and so on)",
+            ),
+        ],
+        arguments: &[ArgumentSpec::new("pins", ValueType::Objects, "Pins")],
+    },
+    CommandSpec {
+        name: "set_clock_transition",
+        kind: CommandKind::SetClockTransition,
+        aliases: &[],
+        versions: Some(VersionRange::new(SDC1_1, SDC2_1)),
+        alias_versions: None,
+        help: "Set transition time of ideal clocks",
+        options: &[
+            OptionSpec::flag("-rise", "Rising transitions"),
+            OptionSpec::flag("-fall", "Falling transitions"),
+            OptionSpec::flag("-min", "Minimum condition"),
+            OptionSpec::flag("-max", "Maximum condition"),
+        ],
+        arguments: &[
+            ArgumentSpec::new("transition", ValueType::Number, "Transition time").mandatory(),
+            ArgumentSpec::new("clock_list", ValueType::Objects, "Clocks").mandatory(),
+        ],
+    },
+    CommandSpec {
+        name: "set_clock_uncertainty",
+        kind: CommandKind::SetClockUncertainty,
+        aliases: &[],
+        versions: Some(VersionRange::new(SDC1_1, SDC2_1)),
+        alias_versions: None,
+        help: "Set clock uncertainty",
+        options: &[
+            OptionSpec::value(
+                "-from",
+                ValueType::Objects,
+                "Launch clocks of inter-clock uncertainty",
+            ),
+            OptionSpec::value(
+                "-rise_from",
+                ValueType::Objects,
+                "Rising edges of launch clocks",
+            )
+            .versions(SDC1_5, SDC2_1),
+            OptionSpec::value(
+                "-fall_from",
+                ValueType::Objects,
+                "Falling edges of launch clocks",
+            )
+            .versions(SDC1_5, SDC2_1),
+            OptionSpec::value(
+                "-to",
+                ValueType::Objects,
+                "Capture clocks of inter-clock uncertainty",
+            ),
+            OptionSpec::value(
+                "-rise_to",
+                ValueType::Objects,
+                "Rising edges of capture clocks",
+            )
+            .versions(SDC1_5, SDC2_1),
+            OptionSpec::value(
+                "-fall_to",
+                ValueType::Objects,
+                "Falling edges of capture clocks",
+            )
+            .versions(SDC1_5, SDC2_1),
+            OptionSpec::flag("-rise", "Rising transitions"),
+            OptionSpec::flag("-fall", "Falling transitions"),
+            OptionSpec::flag("-setup", "Setup (maximum) checks"),
+            OptionSpec::flag("-hold", "Hold (minimum) checks"),
+        ],
+        arguments: &[
+            ArgumentSpec::new("uncertainty", ValueType::Number, "Uncertainty value").mandatory(),
+            ArgumentSpec::new("object_list", ValueType::Objects, "Objects"),
+        ],
+    },
+    CommandSpec {
+        name: "set_data_check",
+        kind: CommandKind::SetDataCheck,
+        aliases: &[],
+        versions: Some(VersionRange::new(SDC1_4, SDC2_1)),
+        alias_versions: None,
+        help: "Set data-to-data checks",
+        options: &[
+            OptionSpec::value("-from", ValueType::Objects, "Related pin"),
+            OptionSpec::value("-to", ValueType::Objects, "Constrained pin"),
+            OptionSpec::value(
+                "-rise_from",
+                ValueType::Objects,
+                "Rising transition of the related pin",
+            ),
+            OptionSpec::value(
+                "-fall_from",
+                ValueType::Objects,
+                "Falling transition of the related pin",
+            ),
+            OptionSpec::value(
+                "-rise_to",
+                ValueType::Objects,
+                "Rising transition of the constrained pin",
+            ),
+            OptionSpec::value(
+                "-fall_to",
+                ValueType::Objects,
+                "Falling transition of the constrained pin",
+            ),
+            OptionSpec::flag("-setup", "Setup (maximum) checks"),
+            OptionSpec::flag("-hold", "Hold (minimum) checks"),
+            OptionSpec::value("-clock", ValueType::Objects, "Relative clock"),
+        ],
+        arguments: &[ArgumentSpec::new("value", ValueType::Number, "Check value").mandatory()],
+    },
+    CommandSpec {
+        name: "set_disable_inferred_clock_gating",
+        kind: CommandKind::SetDisableInferredClockGating,
+        aliases: &[],
+        versions: None,
+        alias_versions: None,
+        help: "Disable clock gating checks inferred on objects",
+        options: &[],
+        arguments: &[ArgumentSpec::new("objects", ValueType::Objects, "Objects").mandatory()],
+    },
+    CommandSpec {
+        name: "set_disable_timing",
+        kind: CommandKind::SetDisableTiming,
+        aliases: &[],
+        versions: Some(VersionRange::new(SDC1_1, SDC2_1)),
+        alias_versions: None,
+        help: "Disable timing arcs",
+        options: &[
+            OptionSpec::value("-from", ValueType::Objects, "Source pin of timing arcs"),
+            OptionSpec::value("-to", ValueType::Objects, "Sink pin of timing arcs"),
+        ],
+        arguments: &[
+            ArgumentSpec::new("cell_pin_list", ValueType::Objects, "Cells or pins").mandatory(),
+        ],
+    },
+    CommandSpec {
+        name: "set_drive",
+        kind: CommandKind::SetDrive,
+        aliases: &[],
+        versions: Some(VersionRange::new(SDC1_1, SDC2_1)),
+        alias_versions: None,
+        help: "Set drive resistance of input or inout ports",
+        options: &[
+            OptionSpec::flag("-rise", "Rising transitions"),
+            OptionSpec::flag("-fall", "Falling transitions"),
+            OptionSpec::flag("-min", "Minimum condition"),
+            OptionSpec::flag("-max", "Maximum condition"),
+        ],
+        arguments: &[
+            ArgumentSpec::new("resistance", ValueType::Number, "Resistance value").mandatory(),
+            ArgumentSpec::new("port_list", ValueType::Objects, "Ports").mandatory(),
+        ],
+    },
+    CommandSpec {
+        name: "set_driving_cell",
+        kind: CommandKind::SetDrivingCell,
+        aliases: &[],
+        versions: Some(VersionRange::new(SDC1_1, SDC2_1)),
+        alias_versions: None,
+        help: "Set a library cell driving input or inout ports",
+        options: &[
+            OptionSpec::value("-lib_cell", ValueType::String, "Library cell").mandatory(),
+            OptionSpec::flag("-rise", "Rising transitions"),
+            OptionSpec::flag("-fall", "Falling transitions"),
+            OptionSpec::flag("-min", "Minimum condition").versions(SDC1_4, SDC2_1),
+            OptionSpec::flag("-max", "Maximum condition").versions(SDC1_4, SDC2_1),
+            OptionSpec::value("-library", ValueType::String, "Library"),
+            OptionSpec::value("-pin", ValueType::String, "Output pin of the library cell"),
+            OptionSpec::value(
+                "-from_pin",
+                ValueType::String,
+                "Input pin of the library cell",
+            ),
+            OptionSpec::value(
+                "-multiply_by",
+                ValueType::Number,
+                "Factor to scale drive capability",
+            )
+            .versions(SDC1_1, SDC2_0),
+            OptionSpec::flag(
+                "-dont_scale",
+                "Don't scale drive capability by operating conditions",
+            ),
+            OptionSpec::flag("-no_design_rule", "Don't apply design rules of the cell"),
+            OptionSpec::value("-clock", ValueType::Objects, "Clock for input transition")
+                .versions(SDC1_4, SDC2_1),
+            OptionSpec::flag("-clock_fall", "Relative to the falling edge of the clock")
+                .versions(SDC1_4, SDC2_1),
+            OptionSpec::value(
+                "-input_transition_rise",
+                ValueType::Number,
+                "Rising transition time at the input pin of the cell",
+            ),
+            OptionSpec::value(
+                "-input_transition_fall",
+                ValueType::Number,
+                "Falling transition time at the input pin of the cell",
+            ),
+        ],
+        arguments: &[ArgumentSpec::new("port_list", ValueType::Objects, "Ports").mandatory()],
+    },
+    CommandSpec {
+        name: "set_false_path",
+        kind: CommandKind::SetFalsePath,
+        aliases: &[],
+        versions: Some(VersionRange::new(SDC1_1, SDC2_1)),
+        alias_versions: None,
+        help: "Set false paths",
+        options: &[
+            OptionSpec::flag("-setup", "Setup (maximum) checks"),
+            OptionSpec::flag("-hold", "Hold (minimum) checks"),
+            OptionSpec::flag("-rise", "Rising transitions"),
+            OptionSpec::flag("-fall", "Falling transitions"),
+            OptionSpec::value("-from", ValueType::Objects, "Paths starting at objects"),
+            OptionSpec::value("-to", ValueType::Objects, "Paths ending at objects"),
+            OptionSpec::value("-through", ValueType::Objects, "Paths through objects").repeatable(),
+            OptionSpec::value(
+                "-rise_from",
+                ValueType::Objects,
+                "Paths starting at rising transitions of objects",
+            )
+            .versions(SDC1_7, SDC2_1),
+            OptionSpec::value(
+                "-rise_to",
+                ValueType::Objects,
+                "Paths ending at rising transitions of objects",
+            )
+            .versions(SDC1_7, SDC2_1),
+            OptionSpec::value(
+                "-rise_through",
+                ValueType::Objects,
+                "Paths through rising transitions of objects",
+            )
+            .repeatable()
+            .versions(SDC1_7, SDC2_1),
+            OptionSpec::value(
+                "-fall_from",
+                ValueType::Objects,
+                "Paths starting at falling transitions of objects",
+            )
+            .versions(SDC1_7, SDC2_1),
+            OptionSpec::value(
+                "-fall_to",
+                ValueType::Objects,
+                "Paths ending at falling transitions of objects",
+            )
+            .versions(SDC1_7, SDC2_1),
+            OptionSpec::value(
+                "-fall_through",
+                ValueType::Objects,
+                "Paths through falling transitions of objects",
+            )
+            .repeatable()
+            .versions(SDC1_7, SDC2_1),
+            OptionSpec::value("-comment", ValueType::String, "Comment string")
+                .versions(SDC1_9, SDC2_1),
+        ],
+        arguments: &[],
+    },
+    CommandSpec {
+        name: "set_fanout_load",
+        kind: CommandKind::SetFanoutLoad,
+        aliases: &[],
+        versions: Some(VersionRange::new(SDC1_1, SDC2_1)),
+        alias_versions: None,
+        help: "Set fanout load of output ports",
+        options: &[],
+        arguments: &[
+            ArgumentSpec::new("value", ValueType::Number, "Fanout load").mandatory(),
+            ArgumentSpec::new("port_list", ValueType::Objects, "Ports").mandatory(),
+        ],
+    },
+    CommandSpec {
+        name: "set_hierarchy_separator",
+        kind: CommandKind::SetHierarchySeparator,
+        aliases: &[],
+        versions: Some(VersionRange::new(SDC1_2, SDC2_1)),
+        alias_versions: None,
+        help: "Set the hierarchy separator in names",
+        options: &[],
+        arguments: &[
+            ArgumentSpec::new("separator", ValueType::String, "Hierarchy separator").mandatory(),
+        ],
+    },
+    CommandSpec {
+        name: "set_ideal_latency",
+        kind: CommandKind::SetIdealLatency,
+        aliases: &[],
+        versions: Some(VersionRange::new(SDC1_7, SDC2_1)),
+        alias_versions: None,
+        help: "Set latency of ideal networks",
+        options: &[
+            OptionSpec::flag("-rise", "Rising transitions"),
+            OptionSpec::flag("-fall", "Falling transitions"),
+            OptionSpec::flag("-min", "Minimum condition"),
+            OptionSpec::flag("-max", "Maximum condition"),
+        ],
+        arguments: &[
+            ArgumentSpec::new("delay", ValueType::Number, "Delay value").mandatory(),
+            ArgumentSpec::new("object_list", ValueType::Objects, "Objects").mandatory(),
+        ],
+    },
+    CommandSpec {
+        name: "set_ideal_network",
+        kind: CommandKind::SetIdealNetwork,
+        aliases: &[],
+        versions: Some(VersionRange::new(SDC1_7, SDC2_1)),
+        alias_versions: None,
+        help: "Set ideal networks",
+        options: &[OptionSpec::flag(
+            "-no_propagate",
+            "Don't propagate through logic",
+        )],
+        arguments: &[ArgumentSpec::new("object_list", ValueType::Objects, "Objects").mandatory()],
+    },
+    CommandSpec {
+        name: "set_ideal_transition",
+        kind: CommandKind::SetIdealTransition,
+        aliases: &[],
+        versions: Some(VersionRange::new(SDC1_7, SDC2_1)),
+        alias_versions: None,
+        help: "Set transition time of ideal networks",
+        options: &[
+            OptionSpec::flag("-rise", "Rising transitions"),
+            OptionSpec::flag("-fall", "Falling transitions"),
+            OptionSpec::flag("-min", "Minimum condition"),
+            OptionSpec::flag("-max", "Maximum condition"),
+        ],
+        arguments: &[
+            ArgumentSpec::new("transition_time", ValueType::Number, "Transition time").mandatory(),
+            ArgumentSpec::new("object_list", ValueType::Objects, "Objects").mandatory(),
+        ],
+    },
+    CommandSpec {
+        name: "set_input_delay",
+        kind: CommandKind::SetInputDelay,
+        aliases: &[],
+        versions: Some(VersionRange::new(SDC1_1, SDC2_1)),
+        alias_versions: None,
+        help: "Set input delays on ports or pins",
+        options: &[
+            OptionSpec::value("-clock", ValueType::Objects, "Relative clock"),
+            OptionSpec::value(
+                "-reference_pin",
+                ValueType::Objects,
+                "Delay is relative to the pin",
+            )
+            .versions(SDC2_0, SDC2_1),
+            OptionSpec::flag("-clock_fall", "Relative to the falling edge of the clock"),
+            OptionSpec::flag("-level_sensitive", "Level-sensitive objects"),
+            OptionSpec::flag("-rise", "Rising transitions"),
+            OptionSpec::flag("-fall", "Falling transitions"),
+            OptionSpec::flag("-max", "Maximum condition"),
+            OptionSpec::flag("-min", "Minimum condition"),
+            OptionSpec::flag(
+                "-add_delay",
+                "Add to existing delays instead of overwriting",
+            ),
+            OptionSpec::flag(
+                "-network_latency_included",
+                "Delay includes clock network latency",
+            )
+            .versions(SDC1_4, SDC2_1),
+            OptionSpec::flag(
+                "-source_latency_included",
+                "Delay includes clock source latency",
+            )
+            .versions(SDC1_4, SDC2_1),
+        ],
+        arguments: &[
+            ArgumentSpec::new("delay_value", ValueType::Number, "Delay value").mandatory(),
+            ArgumentSpec::new("port_pin_list", ValueType::Objects, "Ports or pins").mandatory(),
+        ],
+    },
+    CommandSpec {
+        name: "set_input_transition",
+        kind: CommandKind::SetInputTransition,
+        aliases: &[],
+        versions: Some(VersionRange::new(SDC1_1, SDC2_1)),
+        alias_versions: None,
+        help: "Set transition time of input or inout ports",
+        options: &[
+            OptionSpec::flag("-rise", "Rising transitions"),
+            OptionSpec::flag("-fall", "Falling transitions"),
+            OptionSpec::flag("-min", "Minimum condition"),
+            OptionSpec::flag("-max", "Maximum condition"),
+            OptionSpec::value("-clock", ValueType::Objects, "Clock for input transition")
+                .versions(SDC1_4, SDC2_1),
+            OptionSpec::flag("-clock_fall", "Relative to the falling edge of the clock")
+                .versions(SDC1_4, SDC2_1),
+        ],
+        arguments: &[
+            ArgumentSpec::new("transition", ValueType::Number, "Transition time").mandatory(),
+            ArgumentSpec::new("port_list", ValueType::Objects, "Ports").mandatory(),
+        ],
+    },
+    CommandSpec {
+        name: "set_latch_loop_breaker",
+        kind: CommandKind::SetLatchLoopBreaker,
+        aliases: &[],
+        versions: None,
+        alias_versions: None,
+        help: "Break latch loops",
+        options: &[],
+        arguments: &[ArgumentSpec::new("object_list", ValueType::Objects, "Objects").mandatory()],
+    },
+    CommandSpec {
+        name: "set_level_shifter_strategy",
+        kind: CommandKind::SetLevelShifterStrategy,
+        aliases: &[],
+        versions: Some(VersionRange::new(SDC1_6, SDC2_1)),
+        alias_versions: None,
+        help: "Set the strategy of level shifter insertion",
+        options: &[OptionSpec::value(
+            "-rule",
+            ValueType::String,
+            "Level shifter rule (all, low_to_high or high_to_low)",
+        )
+        .mandatory()],
+        arguments: &[],
+    },
+    CommandSpec {
+        name: "set_level_shifter_threshold",
+        kind: CommandKind::SetLevelShifterThreshold,
+        aliases: &[],
+        versions: Some(VersionRange::new(SDC1_6, SDC2_1)),
+        alias_versions: None,
+        help: "Set the threshold of voltage difference for level shifters",
+        options: &[
+            OptionSpec::value(
+                "-voltage",
+                ValueType::Number,
+                "Threshold voltage difference",
+            )
+            .mandatory(),
+            OptionSpec::value("-percent", ValueType::Number, "Threshold as percentage"),
+        ],
+        arguments: &[],
+    },
+    CommandSpec {
+        name: "set_load",
+        kind: CommandKind::SetLoad,
+        aliases: &[],
+        versions: Some(VersionRange::new(SDC1_1, SDC2_1)),
+        alias_versions: None,
+        help: "Set capacitance load on ports or nets",
+        options: &[
+            OptionSpec::flag("-min", "Minimum condition"),
+            OptionSpec::flag("-max", "Maximum condition"),
+            OptionSpec::flag("-subtract_pin_load", "Subtract pin load from the value"),
+            OptionSpec::flag("-pin_load", "Value is pin load"),
+            OptionSpec::flag("-wire_load", "Value is wire load"),
+        ],
+        arguments: &[
+            ArgumentSpec::new("value", ValueType::Number, "Capacitance").mandatory(),
+            ArgumentSpec::new("objects", ValueType::Objects, "Objects").mandatory(),
+        ],
+    },
+    CommandSpec {
+        name: "set_logic_dc",
+        kind: CommandKind::SetLogicDc,
+        aliases: &[],
+        versions: Some(VersionRange::new(SDC1_1, SDC2_1)),
+        alias_versions: None,
+        help: "Set ports or pins as don't care",
+        options: &[],
+        arguments: &[ArgumentSpec::new("port_list", ValueType::Objects, "Ports").mandatory()],
+    },
+    CommandSpec {
+        name: "set_logic_one",
+        kind: CommandKind::SetLogicOne,
+        aliases: &[],
+        versions: Some(VersionRange::new(SDC1_1, SDC2_1)),
+        alias_versions: None,
+        help: "Set ports or pins as logic one",
+        options: &[],
+        arguments: &[ArgumentSpec::new("port_list", ValueType::Objects, "Ports").mandatory()],
+    },
+    CommandSpec {
+        name: "set_logic_zero",
+        kind: CommandKind::SetLogicZero,
+        aliases: &[],
+        versions: Some(VersionRange::new(SDC1_1, SDC2_1)),
+        alias_versions: None,
+        help: "Set ports or pins as logic zero",
+        options: &[],
+        arguments: &[ArgumentSpec::new("port_list", ValueType::Objects, "Ports").mandatory()],
+    },
+    CommandSpec {
+        name: "set_max_area",
+        kind: CommandKind::SetMaxArea,
+        aliases: &[],
+        versions: Some(VersionRange::new(SDC1_1, SDC2_1)),
+        alias_versions: None,
+        help: "Set the maximum area of the design",
+        options: &[],
+        arguments: &[
+            ArgumentSpec::new("area_value", ValueType::Number, "Maximum area").mandatory(),
+        ],
+    },
+    CommandSpec {
+        name: "set_max_capacitance",
+        kind: CommandKind::SetMaxCapacitance,
+        aliases: &[],
+        versions: Some(VersionRange::new(SDC1_1, SDC2_1)),
+        alias_versions: None,
+        help: "Set the maximum capacitance",
+        options: &[],
+        arguments: &[
+            ArgumentSpec::new("value", ValueType::Number, "Maximum capacitance").mandatory(),
+            ArgumentSpec::new("object_list", ValueType::Objects, "Objects").mandatory(),
+        ],
+    },
+    CommandSpec {
+        name: "set_max_delay",
+        kind: CommandKind::SetMaxDelay,
+        aliases: &[],
+        versions: Some(VersionRange::new(SDC1_1, SDC2_1)),
+        alias_versions: None,
+        help: "Set the maximum delay of paths",
+        options: &[
+            OptionSpec::flag("-rise", "Rising transitions"),
+            OptionSpec::flag("-fall", "Falling transitions"),
+            OptionSpec::value("-from", ValueType::Objects, "Paths starting at objects"),
+            OptionSpec::value("-to", ValueType::Objects, "Paths ending at objects"),
+            OptionSpec::value("-through", ValueType::Objects, "Paths through objects").repeatable(),
+            OptionSpec::value(
+                "-rise_from",
+                ValueType::Objects,
+                "Paths starting at rising transitions of objects",
+            )
+            .versions(SDC1_7, SDC2_1),
+            OptionSpec::value(
+                "-rise_to",
+                ValueType::Objects,
+                "Paths ending at rising transitions of objects",
+            )
+            .versions(SDC1_7, SDC2_1),
+            OptionSpec::value(
+                "-rise_through",
+                ValueType::Objects,
+                "Paths through rising transitions of objects",
+            )
+            .repeatable()
+            .versions(SDC1_7, SDC2_1),
+            OptionSpec::value(
+                "-fall_from",
+                ValueType::Objects,
+                "Paths starting at falling transitions of objects",
+            )
+            .versions(SDC1_7, SDC2_1),
+            OptionSpec::value(
+                "-fall_to",
+                ValueType::Objects,
+                "Paths ending at falling transitions of objects",
+            )
+            .versions(SDC1_7, SDC2_1),
+            OptionSpec::value(
+                "-fall_through",
+                ValueType::Objects,
+                "Paths through falling transitions of objects",
+            )
+            .repeatable()
+            .versions(SDC1_7, SDC2_1),
+            OptionSpec::flag("-ignore_clock_latency", "Ignore clock latency")
+                .versions(SDC2_1, SDC2_1),
+            OptionSpec::flag("-datapath_only", "Ignore clock skew").dialect(Dialect::Xdc),
+            OptionSpec::value("-comment", ValueType::String, "Comment string")
+                .versions(SDC1_9, SDC2_1),
+        ],
+        arguments: &[
+            ArgumentSpec::new("delay_value", ValueType::Number, "Delay value").mandatory(),
+        ],
+    },
+    CommandSpec {
+        name: "set_max_dynamic_power",
+        kind: CommandKind::SetMaxDynamicPower,
+        aliases: &[],
+        versions: Some(VersionRange::new(SDC1_4, SDC2_1)),
+        alias_versions: None,
+        help: "Set the maximum dynamic power",
+        options: &[],
+        arguments: &[
+            ArgumentSpec::new("power", ValueType::Number, "Power value").mandatory(),
+            ArgumentSpec::new("unit", ValueType::String, "Power unit"),
+        ],
+    },
+    CommandSpec {
+        name: "set_max_fanout",
+        kind: CommandKind::SetMaxFanout,
+        aliases: &[],
+        versions: Some(VersionRange::new(SDC1_1, SDC2_1)),
+        alias_versions: None,
+        help: "Set the maximum fanout",
+        options: &[],
+        arguments: &[
+            ArgumentSpec::new("value", ValueType::Number, "Maximum fanout").mandatory(),
+            ArgumentSpec::new("object_list", ValueType::Objects, "Objects").mandatory(),
+        ],
+    },
+    CommandSpec {
+        name: "set_max_leakage_power",
+        kind: CommandKind::SetMaxLeakagePower,
+        aliases: &[],
+        versions: Some(VersionRange::new(SDC1_4, SDC2_1)),
+        alias_versions: None,
+        help: "Set the maximum leakage power",
+        options: &[],
+        arguments: &[
+            ArgumentSpec::new("power", ValueType::Number, "Power value").mandatory(),
+            ArgumentSpec::new("unit", ValueType::String, "Power unit"),
+        ],
+    },
+    CommandSpec {
+        name: "set_max_time_borrow",
+        kind: CommandKind::SetMaxTimeBorrow,
+        aliases: &[],
+        versions: Some(VersionRange::new(SDC1_1, SDC2_1)),
+        alias_versions: None,
+        help: "Set the maximum time borrowing of latches",
+        options: &[],
+        arguments: &[
+            ArgumentSpec::new("delay_value", ValueType::Number, "Delay value").mandatory(),
+            ArgumentSpec::new("object_list", ValueType::Objects, "Objects").mandatory(),
+        ],
+    },
+    CommandSpec {
+        name: "set_max_transition",
+        kind: CommandKind::SetMaxTransition,
+        aliases: &[],
+        versions: Some(VersionRange::new(SDC1_1, SDC2_1)),
+        alias_versions: None,
+        help: "Set the maximum transition time",
+        options: &[
+            OptionSpec::flag("-clock_path", "Apply to clock paths").versions(SDC1_5, SDC2_1),
+            OptionSpec::flag("-rise", "Rising transitions").versions(SDC1_5, SDC2_1),
+            OptionSpec::flag("-fall", "Falling transitions").versions(SDC1_5, SDC2_1),
+        ],
+        arguments: &[
+            ArgumentSpec::new("value", ValueType::Number, "Maximum transition time").mandatory(),
+            ArgumentSpec::new("object_list", ValueType::Objects, "Objects").mandatory(),
+        ],
+    },
+    CommandSpec {
+        name: "set_min_capacitance",
+        kind: CommandKind::SetMinCapacitance,
+        aliases: &[],
+        versions: Some(VersionRange::new(SDC1_1, SDC2_1)),
+        alias_versions: None,
+        help: "Set the minimum capacitance",
+        options: &[],
+        arguments: &[
+            ArgumentSpec::new("value", ValueType::Number, "Minimum capacitance").mandatory(),
+            ArgumentSpec::new("object_list", ValueType::Objects, "Objects").mandatory(),
+        ],
+    },
+    CommandSpec {
+        name: "set_min_delay",
+        kind: CommandKind::SetMinDelay,
+        aliases: &[],
+        versions: Some(VersionRange::new(SDC1_1, SDC2_1)),
+        alias_versions: None,
+        help: "Set the minimum delay of paths",
+        options: &[
+            OptionSpec::flag("-rise", "Rising transitions"),
+            OptionSpec::flag("-fall", "Falling transitions"),
+            OptionSpec::value("-from", ValueType::Objects, "Paths starting at objects"),
+            OptionSpec::value("-to", ValueType::Objects, "Paths ending at objects"),
+            OptionSpec::value("-through", ValueType::Objects, "Paths through objects").repeatable(),
+            OptionSpec::value(
+                "-rise_from",
+                ValueType::Objects,
+                "Paths starting at rising transitions of objects",
+            )
+            .versions(SDC1_7, SDC2_1),
+            OptionSpec::value(
+                "-rise_to",
+                ValueType::Objects,
+                "Paths ending at rising transitions of objects",
+            )
+            .versions(SDC1_7, SDC2_1),
+            OptionSpec::value(
+                "-rise_through",
+                ValueType::Objects,
+                "Paths through rising transitions of objects",
+            )
+            .repeatable()
+            .versions(SDC1_7, SDC2_1),
+            OptionSpec::value(
+                "-fall_from",
+                ValueType::Objects,
+                "Paths starting at falling transitions of objects",
+            )
+            .versions(SDC1_7, SDC2_1),
+            OptionSpec::value(
+                "-fall_to",
+                ValueType::Objects,
+                "Paths ending at falling transitions of objects",
+            )
+            .versions(SDC1_7, SDC2_1),
+            OptionSpec::value(
+                "-fall_through",
+                ValueType::Objects,
+                "Paths through falling transitions of objects",
+            )
+            .repeatable()
+            .versions(SDC1_7, SDC2_1),
+            OptionSpec::flag("-ignore_clock_latency", "Ignore clock latency")
+                .versions(SDC2_1, SDC2_1),
+            OptionSpec::value("-comment", ValueType::String, "Comment string")
+                .versions(SDC1_9, SDC2_1),
+        ],
+        arguments: &[
+            ArgumentSpec::new("delay_value", ValueType::Number, "Delay value").mandatory(),
+        ],
+    },
+    CommandSpec {
+        name: "set_min_porosity",
+        kind: CommandKind::SetMinPorosity,
+        aliases: &[],
+        versions: Some(VersionRange::new(SDC1_4, SDC2_1)),
+        alias_versions: None,
+        help: "Set the minimum porosity of voltage areas",
+        options: &[],
+        arguments: &[
+            ArgumentSpec::new("porosity_value", ValueType::Number, "Minimum porosity").mandatory(),
+            ArgumentSpec::new("object_list", ValueType::Objects, "Objects").mandatory(),
+        ],
+    },
+    CommandSpec {
+        name: "set_min_pulse_width",
+        kind: CommandKind::SetMinPulseWidth,
+        aliases: &[],
+        versions: Some(VersionRange::new(SDC2_0, SDC2_1)),
+        alias_versions: None,
+        help: "Set the minimum pulse width",
+        options: &[
+            OptionSpec::flag("-low", "Low level of the clock"),
+            OptionSpec::flag("-high", "High level of the clock"),
+        ],
+        arguments: &[
+            ArgumentSpec::new("value", ValueType::Number, "Minimum pulse width").mandatory(),
+            ArgumentSpec::new("object_list", ValueType::Objects, "Objects"),
+        ],
+    },
+    CommandSpec {
+        name: "set_multicycle_path",
+        kind: CommandKind::SetMulticyclePath,
+        aliases: &[],
+        versions: Some(VersionRange::new(SDC1_1, SDC2_1)),
+        alias_versions: None,
+        help: "Set multicycle paths",
+        options: &[
+            OptionSpec::flag("-setup", "Setup (maximum) checks"),
+            OptionSpec::flag("-hold", "Hold (minimum) checks"),
+            OptionSpec::flag("-rise", "Rising transitions"),
+            OptionSpec::flag("-fall", "Falling transitions"),
+            OptionSpec::flag("-start", "Multiplier is relative to the start clock"),
+            OptionSpec::flag("-end", "Multiplier is relative to the end clock"),
+            OptionSpec::value("-from", ValueType::Objects, "Paths starting at objects"),
+            OptionSpec::value("-to", ValueType::Objects, "Paths ending at objects"),
+            OptionSpec::value("-through", ValueType::Objects, "Paths through objects").repeatable(),
+            OptionSpec::value(
+                "-rise_from",
+                ValueType::Objects,
+                "Paths starting at rising transitions of objects",
+            )
+            .versions(SDC1_7, SDC2_1),
+            OptionSpec::value(
+                "-rise_to",
+                ValueType::Objects,
+                "Paths ending at rising transitions of objects",
+            )
+            .versions(SDC1_7, SDC2_1),
+            OptionSpec::value(
+                "-rise_through",
+                ValueType::Objects,
+                "Paths through rising transitions of objects",
+            )
+            .repeatable()
+            .versions(SDC1_7, SDC2_1),
+            OptionSpec::value(
+                "-fall_from",
+                ValueType::Objects,
+                "Paths starting at falling transitions of objects",
+            )
+            .versions(SDC1_7, SDC2_1),
+            OptionSpec::value(
+                "-fall_to",
+                ValueType::Objects,
+                "Paths ending at falling transitions of objects",
+            )
+            .versions(SDC1_7, SDC2_1),
+            OptionSpec::value(
+                "-fall_through",
+                ValueType::Objects,
+                "Paths through falling transitions of objects",
+            )
+            .repeatable()
+            .versions(SDC1_7, SDC2_1),
+            OptionSpec::value("-comment", ValueType::String, "Comment string")
+                .versions(SDC1_9, SDC2_1),
+        ],
+        arguments: &[
+            ArgumentSpec::new("path_multiplier", ValueType::Number, "Number of cycles").mandatory(),
+        ],
+    },
+    CommandSpec {
+        name: "set_operating_conditions",
+        kind: CommandKind::SetOperatingConditions,
+        aliases: &[],
+        versions: Some(VersionRange::new(SDC1_1, SDC2_1)),
+        alias_versions: None,
+        help: "Set operating conditions",
+        options: &[
+            OptionSpec::value("-library", ValueType::String, "Library"),
+            OptionSpec::value(
+                "-analysis_type",
+                ValueType::String,
+                "Analysis type (single, bc_wc or on_chip_variation)",
+            ),
+            OptionSpec::value("-max", ValueType::String, "Maximum operating condition"),
+            OptionSpec::value("-min", ValueType::String, "Minimum operating condition"),
+            OptionSpec::value(
+                "-max_library",
+                ValueType::String,
+                "Library of the maximum operating condition",
+            ),
+            OptionSpec::value(
+                "-min_library",
+                ValueType::String,
+                "Library of the minimum operating condition",
+            ),
+            OptionSpec::value(
+                "-object_list",
+                ValueType::Objects,
+                "Objects to which the setting applies",
+            )
+            .versions(SDC1_5, SDC2_1),
+        ],
+        arguments: &[ArgumentSpec::new(
+            "condition",
+            ValueType::String,
+            "Operating condition",
+        )],
+    },
+    CommandSpec {
+        name: "set_output_delay",
+        kind: CommandKind::SetOutputDelay,
+        aliases: &[],
+        versions: Some(VersionRange::new(SDC1_1, SDC2_1)),
+        alias_versions: None,
+        help: "Set output delays on ports or pins",
+        options: &[
+            OptionSpec::value("-clock", ValueType::Objects, "Relative clock"),
+            OptionSpec::value(
+                "-reference_pin",
+                ValueType::Objects,
+                "Delay is relative to the pin",
+            )
+            .versions(SDC2_0, SDC2_1),
+            OptionSpec::flag("-clock_fall", "Relative to the falling edge of the clock"),
+            OptionSpec::flag("-level_sensitive", "Level-sensitive objects"),
+            OptionSpec::flag("-rise", "Rising transitions"),
+            OptionSpec::flag("-fall", "Falling transitions"),
+            OptionSpec::flag("-max", "Maximum condition"),
+            OptionSpec::flag("-min", "Minimum condition"),
+            OptionSpec::flag(
+                "-add_delay",
+                "Add to existing delays instead of overwriting",
+            ),
+            OptionSpec::flag(
+                "-network_latency_included",
+                "Delay includes clock network latency",
+            )
+            .versions(SDC1_4, SDC2_1),
+            OptionSpec::flag(
+                "-source_latency_included",
+                "Delay includes clock source latency",
+            )
+            .versions(SDC1_4, SDC2_1),
+        ],
+        arguments: &[
+            ArgumentSpec::new("delay_value", ValueType::Number, "Delay value").mandatory(),
+            ArgumentSpec::new("port_pin_list", ValueType::Objects, "Ports or pins").mandatory(),
+        ],
+    },
+    CommandSpec {
+        name: "set_path_margin",
+        kind: CommandKind::SetPathMargin,
+        aliases: &[],
+        versions: None,
+        alias_versions: None,
+        help: "Set margin of path timing",
+        options: &[
+            OptionSpec::flag("-setup", "Setup (maximum) checks"),
+            OptionSpec::flag("-hold", "Hold (minimum) checks"),
+            OptionSpec::flag("-rise", "Rising transitions"),
+            OptionSpec::flag("-fall", "Falling transitions"),
+            OptionSpec::value("-from", ValueType::Objects, "Paths starting at objects"),
+            OptionSpec::value("-to", ValueType::Objects, "Paths ending at objects"),
+            OptionSpec::value("-through", ValueType::Objects, "Paths through objects").repeatable(),
+            OptionSpec::value("-comment", ValueType::String, "Comment string"),
+        ],
+        arguments: &[
+            ArgumentSpec::new("margin_value", ValueType::Number, "Margin value").mandatory(),
+        ],
+    },
+    CommandSpec {
+        name: "set_port_fanout_number",
+        kind: CommandKind::SetPortFanoutNumber,
+        aliases: &[],
+        versions: Some(VersionRange::new(SDC1_1, SDC2_1)),
+        alias_versions: None,
+        help: "Set the number of external fanouts of ports",
+        options: &[],
+        arguments: &[
+            ArgumentSpec::new("value", ValueType::Number, "Number of fanouts").mandatory(),
+            ArgumentSpec::new("port_list", ValueType::Objects, "Ports").mandatory(),
+        ],
+    },
+    CommandSpec {
+        name: "set_propagated_clock",
+        kind: CommandKind::SetPropagatedClock,
+        aliases: &[],
+        versions: Some(VersionRange::new(SDC1_1, SDC2_1)),
+        alias_versions: None,
+        help: "Use propagated latency for clocks",
+        options: &[],
+        arguments: &[ArgumentSpec::new("object_list", ValueType::Objects, "Objects").mandatory()],
+    },
+    CommandSpec {
+        name: "set_property",
+        kind: CommandKind::SetProperty,
+        aliases: &[],
+        versions: None,
+        alias_versions: None,
+        help: "Set a property of objects",
+        options: &[
+            OptionSpec::value(
+                "-dict",
+                ValueType::List,
+                "List of property name and value pairs",
+            ),
+            OptionSpec::flag("-quiet", "Suppress messages"),
+            OptionSpec::flag("-verbose", "Report details"),
+        ],
+        arguments: &[],
+    },
+    CommandSpec {
+        name: "set_resistance",
+        kind: CommandKind::SetResistance,
+        aliases: &[],
+        versions: Some(VersionRange::new(SDC1_1, SDC2_1)),
+        alias_versions: None,
+        help: "Set resistance of nets",
+        options: &[
+            OptionSpec::flag("-min", "Minimum condition"),
+            OptionSpec::flag("-max", "Maximum condition"),
+        ],
+        arguments: &[
+            ArgumentSpec::new("value", ValueType::Number, "Resistance").mandatory(),
+            ArgumentSpec::new("net_list", ValueType::Objects, "Nets").mandatory(),
+        ],
+    },
+    CommandSpec {
+        name: "set_sense",
+        kind: CommandKind::SetSense,
+        aliases: &[],
+        versions: Some(VersionRange::new(SDC2_1, SDC2_1)),
+        alias_versions: None,
+        help: "Set unateness of clocks or data propagated through pins",
+        options: &[
+            OptionSpec::value(
+                "-type",
+                ValueType::String,
+                "Type of propagated signal (clock or data)",
+            ),
+            OptionSpec::flag("-non_unate", "Non-unate"),
+            OptionSpec::flag("-positive", "Positive unate"),
+            OptionSpec::flag("-negative", "Negative unate"),
+            OptionSpec::flag("-clock_leaf", "Pins are clock leaves"),
+            OptionSpec::flag("-stop_propagation", "Stop propagation of the clock"),
+            OptionSpec::value(
+                "-pulse",
+                ValueType::String,
+                "Pulse type (rise_triggered_high_pulse and so on)",
+            ),
+            OptionSpec::value(
+                "-clocks",
+                ValueType::Objects,
+                "Clocks to which the sense applies",
+            ),
+        ],
+        arguments: &[ArgumentSpec::new("pin_list", ValueType::Objects, "Pins").mandatory()],
+    },
+    CommandSpec {
+        name: "set_time_format",
+        kind: CommandKind::SetTimeFormat,
+        aliases: &[],
+        versions: None,
+        alias_versions: None,
+        help: "Set the format of time values in reports",
+        options: &[
+            OptionSpec::value("-unit", ValueType::String, "Time unit"),
+            OptionSpec::value(
+                "-decimal_places",
+                ValueType::Number,
+                "Number of decimal places",
+            ),
+        ],
+        arguments: &[],
+    },
+    CommandSpec {
+        name: "set_timing_derate",
+        kind: CommandKind::SetTimingDerate,
+        aliases: &[],
+        versions: Some(VersionRange::new(SDC1_5, SDC2_1)),
+        alias_versions: None,
+        help: "Set derating factors of timing",
+        options: &[
+            OptionSpec::flag("-cell_delay", "Derate delays of cells"),
+            OptionSpec::flag("-cell_check", "Derate timing checks of cells"),
+            OptionSpec::flag("-net_delay", "Derate delays of nets"),
+            OptionSpec::flag("-data", "Derate data paths"),
+            OptionSpec::flag("-clock", "Derate clock paths"),
+            OptionSpec::flag("-early", "Early (minimum) path"),
+            OptionSpec::flag("-late", "Late (maximum) path"),
+            OptionSpec::flag("-rise", "Rising transitions").versions(SDC1_8, SDC2_1),
+            OptionSpec::flag("-fall", "Falling transitions").versions(SDC1_8, SDC2_1),
+            OptionSpec::flag("-static", "Static derating factor").versions(SDC2_1, SDC2_1),
+            OptionSpec::flag("-dynamic", "Dynamic derating factor").versions(SDC2_1, SDC2_1),
+            OptionSpec::flag("-increment", "Add to existing derating factors")
+                .versions(SDC2_1, SDC2_1),
+        ],
+        arguments: &[
+            ArgumentSpec::new("derate_value", ValueType::Number, "Derating factor").mandatory(),
+            ArgumentSpec::new("object_list", ValueType::Objects, "Objects"),
+        ],
+    },
+    CommandSpec {
+        name: "set_units",
+        kind: CommandKind::SetUnits,
+        aliases: &["set_unit"],
+        versions: Some(VersionRange::new(SDC1_7, SDC2_1)),
+        alias_versions: Some(VersionRange::new(SDC1_7, SDC2_1)),
+        help: "Set units of the design",
+        options: &[
+            OptionSpec::value("-capacitance", ValueType::String, "Capacitance unit"),
+            OptionSpec::value("-resistance", ValueType::String, "Resistance unit"),
+            OptionSpec::value("-time", ValueType::String, "Time unit"),
+            OptionSpec::value("-voltage", ValueType::String, "Voltage unit"),
+            OptionSpec::value("-current", ValueType::String, "Current unit"),
+            OptionSpec::value("-power", ValueType::String, "Power unit"),
+        ],
+        arguments: &[],
+    },
+    CommandSpec {
+        name: "set_voltage",
+        kind: CommandKind::SetVoltage,
+        aliases: &[],
+        versions: Some(VersionRange::new(SDC1_8, SDC2_1)),
+        alias_versions: None,
+        help: "Set operating voltage",
+        options: &[
+            OptionSpec::value("-min", ValueType::Number, "Minimum case voltage"),
+            OptionSpec::value(
+                "-object_list",
+                ValueType::Objects,
+                "Objects to which the setting applies",
+            ),
+        ],
+        arguments: &[ArgumentSpec::new(
+            "max_case_voltage",
+            ValueType::Number,
+            "Maximum case voltage",
+        )
+        .mandatory()],
+    },
+    CommandSpec {
+        name: "set_wire_load_min_block_size",
+        kind: CommandKind::SetWireLoadMinBlockSize,
+        aliases: &[],
+        versions: Some(VersionRange::new(SDC1_1, SDC2_1)),
+        alias_versions: None,
+        help: "Set the minimum block size for wire load model selection",
+        options: &[],
+        arguments: &[
+            ArgumentSpec::new("size", ValueType::Number, "Minimum block size").mandatory(),
+        ],
+    },
+    CommandSpec {
+        name: "set_wire_load_mode",
+        kind: CommandKind::SetWireLoadMode,
+        aliases: &[],
+        versions: Some(VersionRange::new(SDC1_1, SDC2_1)),
+        alias_versions: None,
+        help: "Set the mode of wire load models across hierarchy",
+        options: &[],
+        arguments: &[ArgumentSpec::new(
+            "mode_name",
+            ValueType::String,
+            "Wire load mode (top, enclosed or segmented)",
+        )
+        .mandatory()],
+    },
+    CommandSpec {
+        name: "set_wire_load_model",
+        kind: CommandKind::SetWireLoadModel,
+        aliases: &[],
+        versions: Some(VersionRange::new(SDC1_1, SDC2_1)),
+        alias_versions: None,
+        help: "Set the wire load model",
+        options: &[
+            OptionSpec::value("-name", ValueType::String, "Wire load model name").mandatory(),
+            OptionSpec::value("-library", ValueType::String, "Library"),
+            OptionSpec::flag("-min", "Minimum condition"),
+            OptionSpec::flag("-max", "Maximum condition"),
+        ],
+        arguments: &[ArgumentSpec::new(
+            "object_list",
+            ValueType::Objects,
+            "Objects",
+        )],
+    },
+    CommandSpec {
+        name: "set_wire_load_selection_group",
+        kind: CommandKind::SetWireLoadSelectionGroup,
+        aliases: &[],
+        versions: Some(VersionRange::new(SDC1_1, SDC2_1)),
+        alias_versions: None,
+        help: "Set the wire load selection group",
+        options: &[
+            OptionSpec::value("-library", ValueType::String, "Library"),
+            OptionSpec::flag("-min", "Minimum condition"),
+            OptionSpec::flag("-max", "Maximum condition"),
+        ],
+        arguments: &[
+            ArgumentSpec::new(
+                "group_name",
+                ValueType::String,
+                "Wire load selection group name",
+            )
+            .mandatory(),
+            ArgumentSpec::new("object_list", ValueType::Objects, "Objects"),
+        ],
+    },
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::ValidateError;
+    use crate::sdc::util::CommandExt;
+    use crate::Parser;
+
+    const VERSIONS: [SdcVersion; 11] = [
+        SDC1_1, SDC1_2, SDC1_3, SDC1_4, SDC1_5, SDC1_6, SDC1_7, SDC1_8, SDC1_9, SDC2_0, SDC2_1,
+    ];
+
+    #[test]
+    fn find() {
+        let spec = CommandSpec::find("create_clock", Dialect::Sdc).unwrap();
+        assert_eq!(spec.option("-period").unwrap().name, "-period");
+        assert_eq!(spec.option("-p").unwrap().name, "-period");
+        assert_eq!(spec.option("-a").unwrap().name, "-add");
+        assert!(spec.option("-").is_none());
+        assert!(spec.option("-unknown").is_none());
+        assert_eq!(
+            spec.usage(),
+            "create_clock -period <number> [-name <string>] [-waveform <list>] [-add] [-comment <string>] [source_objects]"
+        );
+
+        let spec = CommandSpec::find("get_cell", Dialect::Sdc).unwrap();
+        assert_eq!(spec.kind, CommandKind::GetCells);
+        assert_eq!(CommandSpec::of(CommandKind::GetCells), Some(spec));
+
+        assert!(CommandSpec::find("create_pblock", Dialect::Sdc).is_none());
+        assert!(CommandSpec::find("create_pblock", Dialect::Xdc).is_some());
+    }
+
+    #[test]
+    fn explain() {
+        let spec = CommandSpec::find("create_clock", Dialect::Sdc).unwrap();
+        assert_eq!(
+            spec.explain(),
+            r#"create_clock - Create a clock
+
+Usage:
+  create_clock -period <number> [-name <string>] [-waveform <list>] [-add] [-comment <string>] [source_objects]
+
+Options:
+  -period <number>          SDC 1.1-2.1  Clock period (mandatory)
+  -name <string>            SDC 1.1-2.1  Clock name
+  -waveform <list>          SDC 1.1-2.1  Rising and falling edge times (default: {0 period/2})
+  -add                      SDC 1.4-2.1  Add to existing definitions instead of overwriting
+  -comment <string>         SDC 1.9-2.1  Comment string
+
+Arguments:
+  source_objects <objects>  Clock sources
+
+Supported: SDC 1.1-2.1
+"#
+        );
+        assert_eq!(
+            spec.usage_at(SDC1_3),
+            "create_clock -period <number> [-name <string>] [-waveform <list>] [source_objects]"
+        );
+        assert_eq!(
+            spec.usage_with_versions(SDC1_4),
+            r#"create_clock -period <number> [-name <string>] [-waveform <list>] [-add] [source_objects]
+  -period    SDC 1.1-2.1
+  -name      SDC 1.1-2.1
+  -waveform  SDC 1.1-2.1
+  -add       SDC 1.4-2.1
+"#
+        );
+
+        let spec = CommandSpec::find("get_cell", Dialect::Sdc).unwrap();
+        assert!(spec
+            .explain()
+            .contains("  -filter <string>       xdc only     Filter expression\n"));
+        assert!(spec
+            .explain()
+            .ends_with("Aliases: get_cell (SDC 1.5-2.1)\n"));
+
+        let names: Vec<_> = CommandSpec::available(SDC1_3).map(|x| x.name).collect();
+        assert!(names.contains(&"create_generated_clock"));
+        assert!(!names.contains(&"set_timing_derate"));
+        assert!(!names.contains(&"create_pblock"));
+    }
+
+    /// Mandatory arguments and the option with dummy values
+    fn command(spec: &CommandSpec, option: Option<&OptionSpec>) -> String {
+        let value = |x: ValueType| match x {
+            ValueType::Number => "1",
+            _ => "a",
+        };
+        let mut ret = spec.name.to_string();
+        let option = option.filter(|x| !x.mandatory);
+        let options = spec.options.iter().filter(|x| x.mandatory);
+        for x in options.chain(option).filter(|x| x.dialect.is_none()) {
+            ret.push_str(&format!(" {}", x.name));
+            if let Some(x) = x.value {
+                ret.push_str(&format!(" {}", value(x)));
+            }
+        }
+        for x in spec.arguments.iter().filter(|x| x.mandatory) {
+            ret.push_str(&format!(" {}", value(x.value)));
+        }
+        ret.push('\n');
+        ret
+    }
+
+    /// Option names of the table resolve to fields of the parsed commands
+    #[test]
+    fn consistency() {
+        for spec in COMMAND_SPECS.iter().filter(|x| x.versions.is_some()) {
+            let code = command(spec, None);
+            let sdc = Parser::parse(&code, &"").unwrap();
+            assert_eq!(sdc.commands[0].kind(), spec.kind, "{code}");
+            for version in VERSIONS {
+                let unsupported = sdc
+                    .validate(Some(version))
+                    .iter()
+                    .any(|x| matches!(x, ValidateError::CmdUnsupportedVersion(_, _)));
+                assert_eq!(
+                    unsupported,
+                    !spec.versions.unwrap().contains(version),
+                    "{code}{version}"
+                );
+            }
+
+            for option in spec.options.iter().filter(|x| x.dialect.is_none()) {
+                let code = command(spec, Some(option));
+                let sdc = Parser::parse(&code, &"").unwrap();
+                let versions = spec.option_versions(option).unwrap();
+                let name = option.name.trim_start_matches('-');
+                for version in VERSIONS
+                    .into_iter()
+                    .filter(|x| spec.versions.unwrap().contains(*x))
+                {
+                    let unsupported = sdc.validate(Some(version)).iter().any(
+                        |x| matches!(x, ValidateError::ArgUnsupportedVersion(_, _, x) if x == name),
+                    );
+                    assert_eq!(unsupported, !versions.contains(version), "{code}{version}");
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,1440 @@
+#![allow(clippy::while_let_on_iterator)]
+
+use crate::errors::SemanticError;
+use crate::errors::ValidateError;
+use crate::file_db::Location;
+use crate::sdc::util::*;
+use crate::sdc::{Argument, Command, CommandKind, SdcVersion};
+use std::fmt;
+use std::sync::OnceLock;
+
+/// get_timing_paths
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct GetTimingPaths {
+    pub from: Option<Argument>,
+    pub to: Option<Argument>,
+    pub through: Vec<Argument>,
+    pub delay_type: Option<Argument>,
+    pub max_paths: Option<Argument>,
+    pub nworst: Option<Argument>,
+    pub group: Option<Argument>,
+    pub slack_lesser_than: Option<Argument>,
+    pub slack_greater_than: Option<Argument>,
+    location: Location,
+}
+
+builder!(
+    GetTimingPaths,
+    GetTimingPathsBuilder {
+        mandatory: [],
+        optional: [
+            from,
+            to,
+            delay_type,
+            max_paths,
+            nworst,
+            group,
+            slack_lesser_than,
+            slack_greater_than,
+        ],
+        flag: [],
+        repeatable: [through],
+        extra: [],
+    }
+);
+
+impl fmt::Display for GetTimingPaths {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut text = "get_timing_paths".to_string();
+        text.push_str(&fmt_named_opt_arg(&self.from, "from"));
+        text.push_str(&fmt_named_opt_arg(&self.to, "to"));
+        text.push_str(&fmt_named_vec_arg(&self.through, "through"));
+        text.push_str(&fmt_named_opt_arg(&self.delay_type, "delay_type"));
+        text.push_str(&fmt_named_opt_arg(&self.max_paths, "max_paths"));
+        text.push_str(&fmt_named_opt_arg(&self.nworst, "nworst"));
+        text.push_str(&fmt_named_opt_arg(&self.group, "group"));
+        text.push_str(&fmt_named_opt_arg(
+            &self.slack_lesser_than,
+            "slack_lesser_than",
+        ));
+        text.push_str(&fmt_named_opt_arg(
+            &self.slack_greater_than,
+            "slack_greater_than",
+        ));
+        text.fmt(f)
+    }
+}
+
+impl CommandExt for GetTimingPaths {
+    fn location(&self) -> Location {
+        self.location.clone()
+    }
+
+    fn kind(&self) -> CommandKind {
+        CommandKind::GetTimingPaths
+    }
+}
+
+impl Validate for GetTimingPaths {
+    fn validate(&self, version: SdcVersion) -> Vec<ValidateError> {
+        let mut ret = vec![];
+        validate_opt(&mut ret, version, &self.from);
+        validate_opt(&mut ret, version, &self.to);
+        validate_vec(&mut ret, version, &self.through);
+        validate_opt(&mut ret, version, &self.delay_type);
+        validate_opt(&mut ret, version, &self.max_paths);
+        validate_opt(&mut ret, version, &self.nworst);
+        validate_opt(&mut ret, version, &self.group);
+        validate_opt(&mut ret, version, &self.slack_lesser_than);
+        validate_opt(&mut ret, version, &self.slack_greater_than);
+        ret
+    }
+}
+
+impl Extract for GetTimingPaths {
+    fn extract<'a>(&'a self, kind: CommandKind, list: &mut Vec<&'a Command>) {
+        Self::extract_opt(kind, list, &self.from);
+        Self::extract_opt(kind, list, &self.to);
+        Self::extract_vec(kind, list, &self.through);
+        Self::extract_opt(kind, list, &self.group);
+    }
+
+    fn extract_mut<'a>(&'a mut self, kind: CommandKind, list: &mut Vec<&'a mut Command>) {
+        Self::extract_mut_opt(kind, list, &mut self.from);
+        Self::extract_mut_opt(kind, list, &mut self.to);
+        Self::extract_mut_vec(kind, list, &mut self.through);
+        Self::extract_mut_opt(kind, list, &mut self.group);
+    }
+}
+
+pub(crate) fn get_timing_paths(
+    args: Vec<Argument>,
+    location: Location,
+) -> Result<Command, SemanticError> {
+    let mut from = None;
+    let mut to = None;
+    let mut through = vec![];
+    let mut delay_type = None;
+    let mut max_paths = None;
+    let mut nworst = None;
+    let mut group = None;
+    let mut slack_lesser_than = None;
+    let mut slack_greater_than = None;
+
+    static DICT: OnceLock<LazyDict> = OnceLock::new();
+    let dict = DICT.get_or_init(|| LazyDict::from_kind(CommandKind::GetTimingPaths));
+
+    let mut iter = args.into_iter();
+    while let Some(arg) = iter.next() {
+        match LazyMatcher::new(arg.as_str(), dict, &location)? {
+            x if x.m("-from") => from = opt_arg(arg, iter.next(), from)?,
+            x if x.m("-to") => to = opt_arg(arg, iter.next(), to)?,
+            x if x.m("-through") => through = vec_arg(arg, iter.next(), through)?,
+            x if x.m("-delay_type") => delay_type = opt_arg(arg, iter.next(), delay_type)?,
+            x if x.m("-max_paths") => max_paths = opt_arg(arg, iter.next(), max_paths)?,
+            x if x.m("-nworst") => nworst = opt_arg(arg, iter.next(), nworst)?,
+            x if x.m("-group") => group = opt_arg(arg, iter.next(), group)?,
+            x if x.m("-slack_lesser_than") => {
+                slack_lesser_than = opt_arg(arg, iter.next(), slack_lesser_than)?
+            }
+            x if x.m("-slack_greater_than") => {
+                slack_greater_than = opt_arg(arg, iter.next(), slack_greater_than)?
+            }
+            _ => return Err(SemanticError::WrongArgument(arg)),
+        }
+    }
+
+    Ok(Command::GetTimingPaths(GetTimingPaths {
+        from,
+        to,
+        through,
+        delay_type,
+        max_paths,
+        nworst,
+        group,
+        slack_lesser_than,
+        slack_greater_than,
+        location,
+    }))
+}
+
+/// remove_case_analysis
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct RemoveCaseAnalysis {
+    pub port_or_pin_list: Argument,
+    location: Location,
+}
+
+builder!(
+    RemoveCaseAnalysis,
+    RemoveCaseAnalysisBuilder {
+        mandatory: [port_or_pin_list],
+        optional: [],
+        flag: [],
+        repeatable: [],
+        extra: [],
+    }
+);
+
+impl fmt::Display for RemoveCaseAnalysis {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut text = "remove_case_analysis".to_string();
+        text.push_str(&fmt_arg(&self.port_or_pin_list));
+        text.fmt(f)
+    }
+}
+
+impl CommandExt for RemoveCaseAnalysis {
+    fn location(&self) -> Location {
+        self.location.clone()
+    }
+
+    fn kind(&self) -> CommandKind {
+        CommandKind::RemoveCaseAnalysis
+    }
+}
+
+impl Validate for RemoveCaseAnalysis {
+    fn validate(&self, version: SdcVersion) -> Vec<ValidateError> {
+        let mut ret = vec![];
+        validate_arg(&mut ret, version, &self.port_or_pin_list);
+        ret
+    }
+}
+
+impl Extract for RemoveCaseAnalysis {
+    fn extract<'a>(&'a self, kind: CommandKind, list: &mut Vec<&'a Command>) {
+        Self::extract_arg(kind, list, &self.port_or_pin_list);
+    }
+
+    fn extract_mut<'a>(&'a mut self, kind: CommandKind, list: &mut Vec<&'a mut Command>) {
+        Self::extract_mut_arg(kind, list, &mut self.port_or_pin_list);
+    }
+}
+
+pub(crate) fn remove_case_analysis(
+    args: Vec<Argument>,
+    location: Location,
+) -> Result<Command, SemanticError> {
+    let mut port_or_pin_list = None;
+
+    let mut iter = args.into_iter();
+    while let Some(arg) = iter.next() {
+        port_or_pin_list = pos_args1(Some(arg), port_or_pin_list, &location)?;
+    }
+
+    let port_or_pin_list = mandatory(port_or_pin_list, "port_or_pin_list", &location)?;
+
+    Ok(Command::RemoveCaseAnalysis(RemoveCaseAnalysis {
+        port_or_pin_list,
+        location,
+    }))
+}
+
+/// remove_clock
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct RemoveClock {
+    pub all: bool,
+    pub clock_list: Option<Argument>,
+    location: Location,
+}
+
+builder!(
+    RemoveClock,
+    RemoveClockBuilder {
+        mandatory: [],
+        optional: [clock_list],
+        flag: [all],
+        repeatable: [],
+        extra: [],
+    }
+);
+
+impl fmt::Display for RemoveClock {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut text = "remove_clock".to_string();
+        text.push_str(&fmt_named_flg(self.all, "all"));
+        text.push_str(&fmt_opt_arg(&self.clock_list));
+        text.fmt(f)
+    }
+}
+
+impl CommandExt for RemoveClock {
+    fn location(&self) -> Location {
+        self.location.clone()
+    }
+
+    fn kind(&self) -> CommandKind {
+        CommandKind::RemoveClock
+    }
+}
+
+impl Validate for RemoveClock {
+    fn validate(&self, version: SdcVersion) -> Vec<ValidateError> {
+        let mut ret = vec![];
+        self.arg_comb2(
+            &mut ret,
+            version.any(),
+            &self.all,
+            &self.clock_list,
+            |a, b| a ^ b,
+        );
+        validate_opt(&mut ret, version, &self.clock_list);
+        ret
+    }
+}
+
+impl Extract for RemoveClock {
+    fn extract<'a>(&'a self, kind: CommandKind, list: &mut Vec<&'a Command>) {
+        Self::extract_opt(kind, list, &self.clock_list);
+    }
+
+    fn extract_mut<'a>(&'a mut self, kind: CommandKind, list: &mut Vec<&'a mut Command>) {
+        Self::extract_mut_opt(kind, list, &mut self.clock_list);
+    }
+}
+
+pub(crate) fn remove_clock(
+    args: Vec<Argument>,
+    location: Location,
+) -> Result<Command, SemanticError> {
+    let mut all = false;
+    let mut clock_list = None;
+
+    static DICT: OnceLock<LazyDict> = OnceLock::new();
+    let dict = DICT.get_or_init(|| LazyDict::from_kind(CommandKind::RemoveClock));
+
+    let mut iter = args.into_iter();
+    while let Some(arg) = iter.next() {
+        match LazyMatcher::new(arg.as_str(), dict, &location)? {
+            x if x.m("-all") => all = opt_flg(arg, all)?,
+            _ => clock_list = pos_args1(Some(arg), clock_list, &location)?,
+        }
+    }
+
+    Ok(Command::RemoveClock(RemoveClock {
+        all,
+        clock_list,
+        location,
+    }))
+}
+
+/// remove_clock_groups
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct RemoveClockGroups {
+    pub logically_exclusive: bool,
+    pub physically_exclusive: bool,
+    pub asynchronous: bool,
+    pub all: bool,
+    pub name_list: Option<Argument>,
+    location: Location,
+}
+
+builder!(
+    RemoveClockGroups,
+    RemoveClockGroupsBuilder {
+        mandatory: [],
+        optional: [name_list],
+        flag: [logically_exclusive, physically_exclusive, asynchronous, all],
+        repeatable: [],
+        extra: [],
+    }
+);
+
+impl fmt::Display for RemoveClockGroups {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut text = "remove_clock_groups".to_string();
+        text.push_str(&fmt_named_flg(
+            self.logically_exclusive,
+            "logically_exclusive",
+        ));
+        text.push_str(&fmt_named_flg(
+            self.physically_exclusive,
+            "physically_exclusive",
+        ));
+        text.push_str(&fmt_named_flg(self.asynchronous, "asynchronous"));
+        text.push_str(&fmt_named_flg(self.all, "all"));
+        text.push_str(&fmt_opt_arg(&self.name_list));
+        text.fmt(f)
+    }
+}
+
+impl CommandExt for RemoveClockGroups {
+    fn location(&self) -> Location {
+        self.location.clone()
+    }
+
+    fn kind(&self) -> CommandKind {
+        CommandKind::RemoveClockGroups
+    }
+}
+
+impl Validate for RemoveClockGroups {
+    fn validate(&self, version: SdcVersion) -> Vec<ValidateError> {
+        let mut ret = vec![];
+        self.arg_comb3(
+            &mut ret,
+            version.any(),
+            &self.physically_exclusive,
+            &self.logically_exclusive,
+            &self.asynchronous,
+            |a, b, c| a ^ b ^ c,
+        );
+        self.arg_comb2(
+            &mut ret,
+            version.any(),
+            &self.all,
+            &self.name_list,
+            |a, b| a ^ b,
+        );
+        validate_opt(&mut ret, version, &self.name_list);
+        ret
+    }
+}
+
+impl Extract for RemoveClockGroups {
+    fn extract<'a>(&'a self, kind: CommandKind, list: &mut Vec<&'a Command>) {
+        Self::extract_opt(kind, list, &self.name_list);
+    }
+
+    fn extract_mut<'a>(&'a mut self, kind: CommandKind, list: &mut Vec<&'a mut Command>) {
+        Self::extract_mut_opt(kind, list, &mut self.name_list);
+    }
+}
+
+pub(crate) fn remove_clock_groups(
+    args: Vec<Argument>,
+    location: Location,
+) -> Result<Command, SemanticError> {
+    let mut logically_exclusive = false;
+    let mut physically_exclusive = false;
+    let mut asynchronous = false;
+    let mut all = false;
+    let mut name_list = None;
+
+    static DICT: OnceLock<LazyDict> = OnceLock::new();
+    let dict = DICT.get_or_init(|| LazyDict::from_kind(CommandKind::RemoveClockGroups));
+
+    let mut iter = args.into_iter();
+    while let Some(arg) = iter.next() {
+        match LazyMatcher::new(arg.as_str(), dict, &location)? {
+            x if x.m("-logically_exclusive") => {
+                logically_exclusive = opt_flg(arg, logically_exclusive)?
+            }
+            x if x.m("-physically_exclusive") => {
+                physically_exclusive = opt_flg(arg, physically_exclusive)?
+            }
+            x if x.m("-asynchronous") => asynchronous = opt_flg(arg, asynchronous)?,
+            x if x.m("-all") => all = opt_flg(arg, all)?,
+            _ => name_list = pos_args1(Some(arg), name_list, &location)?,
+        }
+    }
+
+    Ok(Command::RemoveClockGroups(RemoveClockGroups {
+        logically_exclusive,
+        physically_exclusive,
+        asynchronous,
+        all,
+        name_list,
+        location,
+    }))
+}
+
+/// remove_disable_timing
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct RemoveDisableTiming {
+    pub from: Option<Argument>,
+    pub to: Option<Argument>,
+    pub object_list: Argument,
+    location: Location,
+}
+
+builder!(
+    RemoveDisableTiming,
+    RemoveDisableTimingBuilder {
+        mandatory: [object_list],
+        optional: [from, to],
+        flag: [],
+        repeatable: [],
+        extra: [],
+    }
+);
+
+impl fmt::Display for RemoveDisableTiming {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut text = "remove_disable_timing".to_string();
+        text.push_str(&fmt_named_opt_arg(&self.from, "from"));
+        text.push_str(&fmt_named_opt_arg(&self.to, "to"));
+        text.push_str(&fmt_arg(&self.object_list));
+        text.fmt(f)
+    }
+}
+
+impl CommandExt for RemoveDisableTiming {
+    fn location(&self) -> Location {
+        self.location.clone()
+    }
+
+    fn kind(&self) -> CommandKind {
+        CommandKind::RemoveDisableTiming
+    }
+}
+
+impl Validate for RemoveDisableTiming {
+    fn validate(&self, version: SdcVersion) -> Vec<ValidateError> {
+        let mut ret = vec![];
+        self.arg_comb2(&mut ret, version.any(), &self.from, &self.to, |a, b| {
+            !(a ^ b)
+        });
+        validate_opt(&mut ret, version, &self.from);
+        validate_opt(&mut ret, version, &self.to);
+        validate_arg(&mut ret, version, &self.object_list);
+        ret
+    }
+}
+
+impl Extract for RemoveDisableTiming {
+    fn extract<'a>(&'a self, kind: CommandKind, list: &mut Vec<&'a Command>) {
+        Self::extract_opt(kind, list, &self.from);
+        Self::extract_opt(kind, list, &self.to);
+        Self::extract_arg(kind, list, &self.object_list);
+    }
+
+    fn extract_mut<'a>(&'a mut self, kind: CommandKind, list: &mut Vec<&'a mut Command>) {
+        Self::extract_mut_opt(kind, list, &mut self.from);
+        Self::extract_mut_opt(kind, list, &mut self.to);
+        Self::extract_mut_arg(kind, list, &mut self.object_list);
+    }
+}
+
+pub(crate) fn remove_disable_timing(
+    args: Vec<Argument>,
+    location: Location,
+) -> Result<Command, SemanticError> {
+    let mut from = None;
+    let mut to = None;
+    let mut object_list = None;
+
+    static DICT: OnceLock<LazyDict> = OnceLock::new();
+    let dict = DICT.get_or_init(|| LazyDict::from_kind(CommandKind::RemoveDisableTiming));
+
+    let mut iter = args.into_iter();
+    while let Some(arg) = iter.next() {
+        match LazyMatcher::new(arg.as_str(), dict, &location)? {
+            x if x.m("-from") => from = opt_arg(arg, iter.next(), from)?,
+            x if x.m("-to") => to = opt_arg(arg, iter.next(), to)?,
+            _ => object_list = pos_args1(Some(arg), object_list, &location)?,
+        }
+    }
+
+    let object_list = mandatory(object_list, "object_list", &location)?;
+
+    Ok(Command::RemoveDisableTiming(RemoveDisableTiming {
+        from,
+        to,
+        object_list,
+        location,
+    }))
+}
+
+/// remove_generated_clock
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct RemoveGeneratedClock {
+    pub all: bool,
+    pub clock_list: Option<Argument>,
+    location: Location,
+}
+
+builder!(
+    RemoveGeneratedClock,
+    RemoveGeneratedClockBuilder {
+        mandatory: [],
+        optional: [clock_list],
+        flag: [all],
+        repeatable: [],
+        extra: [],
+    }
+);
+
+impl fmt::Display for RemoveGeneratedClock {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut text = "remove_generated_clock".to_string();
+        text.push_str(&fmt_named_flg(self.all, "all"));
+        text.push_str(&fmt_opt_arg(&self.clock_list));
+        text.fmt(f)
+    }
+}
+
+impl CommandExt for RemoveGeneratedClock {
+    fn location(&self) -> Location {
+        self.location.clone()
+    }
+
+    fn kind(&self) -> CommandKind {
+        CommandKind::RemoveGeneratedClock
+    }
+}
+
+impl Validate for RemoveGeneratedClock {
+    fn validate(&self, version: SdcVersion) -> Vec<ValidateError> {
+        let mut ret = vec![];
+        self.arg_comb2(
+            &mut ret,
+            version.any(),
+            &self.all,
+            &self.clock_list,
+            |a, b| a ^ b,
+        );
+        validate_opt(&mut ret, version, &self.clock_list);
+        ret
+    }
+}
+
+impl Extract for RemoveGeneratedClock {
+    fn extract<'a>(&'a self, kind: CommandKind, list: &mut Vec<&'a Command>) {
+        Self::extract_opt(kind, list, &self.clock_list);
+    }
+
+    fn extract_mut<'a>(&'a mut self, kind: CommandKind, list: &mut Vec<&'a mut Command>) {
+        Self::extract_mut_opt(kind, list, &mut self.clock_list);
+    }
+}
+
+pub(crate) fn remove_generated_clock(
+    args: Vec<Argument>,
+    location: Location,
+) -> Result<Command, SemanticError> {
+    let mut all = false;
+    let mut clock_list = None;
+
+    static DICT: OnceLock<LazyDict> = OnceLock::new();
+    let dict = DICT.get_or_init(|| LazyDict::from_kind(CommandKind::RemoveGeneratedClock));
+
+    let mut iter = args.into_iter();
+    while let Some(arg) = iter.next() {
+        match LazyMatcher::new(arg.as_str(), dict, &location)? {
+            x if x.m("-all") => all = opt_flg(arg, all)?,
+            _ => clock_list = pos_args1(Some(arg), clock_list, &location)?,
+        }
+    }
+
+    Ok(Command::RemoveGeneratedClock(RemoveGeneratedClock {
+        all,
+        clock_list,
+        location,
+    }))
+}
+
+/// remove_input_delay
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct RemoveInputDelay {
+    pub clock: Option<Argument>,
+    pub clock_fall: bool,
+    pub level_sensitive: bool,
+    pub rise: bool,
+    pub fall: bool,
+    pub max: bool,
+    pub min: bool,
+    pub port_pin_list: Argument,
+    location: Location,
+}
+
+builder!(
+    RemoveInputDelay,
+    RemoveInputDelayBuilder {
+        mandatory: [port_pin_list],
+        optional: [clock],
+        flag: [clock_fall, level_sensitive, rise, fall, max, min],
+        repeatable: [],
+        extra: [],
+    }
+);
+
+impl fmt::Display for RemoveInputDelay {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut text = "remove_input_delay".to_string();
+        text.push_str(&fmt_named_opt_arg(&self.clock, "clock"));
+        text.push_str(&fmt_named_flg(self.clock_fall, "clock_fall"));
+        text.push_str(&fmt_named_flg(self.level_sensitive, "level_sensitive"));
+        text.push_str(&fmt_named_flg(self.rise, "rise"));
+        text.push_str(&fmt_named_flg(self.fall, "fall"));
+        text.push_str(&fmt_named_flg(self.max, "max"));
+        text.push_str(&fmt_named_flg(self.min, "min"));
+        text.push_str(&fmt_arg(&self.port_pin_list));
+        text.fmt(f)
+    }
+}
+
+impl CommandExt for RemoveInputDelay {
+    fn location(&self) -> Location {
+        self.location.clone()
+    }
+
+    fn kind(&self) -> CommandKind {
+        CommandKind::RemoveInputDelay
+    }
+}
+
+impl Validate for RemoveInputDelay {
+    fn validate(&self, version: SdcVersion) -> Vec<ValidateError> {
+        let mut ret = vec![];
+        self.arg_comb3(
+            &mut ret,
+            version.any(),
+            &self.clock_fall,
+            &self.level_sensitive,
+            &self.clock,
+            |a, b, c| !((a | b) & !c),
+        );
+        validate_opt(&mut ret, version, &self.clock);
+        validate_arg(&mut ret, version, &self.port_pin_list);
+        ret
+    }
+}
+
+impl Extract for RemoveInputDelay {
+    fn extract<'a>(&'a self, kind: CommandKind, list: &mut Vec<&'a Command>) {
+        Self::extract_opt(kind, list, &self.clock);
+        Self::extract_arg(kind, list, &self.port_pin_list);
+    }
+
+    fn extract_mut<'a>(&'a mut self, kind: CommandKind, list: &mut Vec<&'a mut Command>) {
+        Self::extract_mut_opt(kind, list, &mut self.clock);
+        Self::extract_mut_arg(kind, list, &mut self.port_pin_list);
+    }
+}
+
+pub(crate) fn remove_input_delay(
+    args: Vec<Argument>,
+    location: Location,
+) -> Result<Command, SemanticError> {
+    let mut clock = None;
+    let mut clock_fall = false;
+    let mut level_sensitive = false;
+    let mut rise = false;
+    let mut fall = false;
+    let mut max = false;
+    let mut min = false;
+    let mut port_pin_list = None;
+
+    static DICT: OnceLock<LazyDict> = OnceLock::new();
+    let dict = DICT.get_or_init(|| LazyDict::from_kind(CommandKind::RemoveInputDelay));
+
+    let mut iter = args.into_iter();
+    while let Some(arg) = iter.next() {
+        match LazyMatcher::new(arg.as_str(), dict, &location)? {
+            x if x.m("-clock") => clock = opt_arg(arg, iter.next(), clock)?,
+            x if x.m("-clock_fall") => clock_fall = opt_flg(arg, clock_fall)?,
+            x if x.m("-level_sensitive") => level_sensitive = opt_flg(arg, level_sensitive)?,
+            x if x.m("-rise") => rise = opt_flg(arg, rise)?,
+            x if x.m("-fall") => fall = opt_flg(arg, fall)?,
+            x if x.m("-max") => max = opt_flg(arg, max)?,
+            x if x.m("-min") => min = opt_flg(arg, min)?,
+            _ => port_pin_list = pos_args1(Some(arg), port_pin_list, &location)?,
+        }
+    }
+
+    let port_pin_list = mandatory(port_pin_list, "port_pin_list", &location)?;
+
+    Ok(Command::RemoveInputDelay(RemoveInputDelay {
+        clock,
+        clock_fall,
+        level_sensitive,
+        rise,
+        fall,
+        max,
+        min,
+        port_pin_list,
+        location,
+    }))
+}
+
+/// remove_output_delay
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct RemoveOutputDelay {
+    pub clock: Option<Argument>,
+    pub clock_fall: bool,
+    pub level_sensitive: bool,
+    pub rise: bool,
+    pub fall: bool,
+    pub max: bool,
+    pub min: bool,
+    pub port_pin_list: Argument,
+    location: Location,
+}
+
+builder!(
+    RemoveOutputDelay,
+    RemoveOutputDelayBuilder {
+        mandatory: [port_pin_list],
+        optional: [clock],
+        flag: [clock_fall, level_sensitive, rise, fall, max, min],
+        repeatable: [],
+        extra: [],
+    }
+);
+
+impl fmt::Display for RemoveOutputDelay {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut text = "remove_output_delay".to_string();
+        text.push_str(&fmt_named_opt_arg(&self.clock, "clock"));
+        text.push_str(&fmt_named_flg(self.clock_fall, "clock_fall"));
+        text.push_str(&fmt_named_flg(self.level_sensitive, "level_sensitive"));
+        text.push_str(&fmt_named_flg(self.rise, "rise"));
+        text.push_str(&fmt_named_flg(self.fall, "fall"));
+        text.push_str(&fmt_named_flg(self.max, "max"));
+        text.push_str(&fmt_named_flg(self.min, "min"));
+        text.push_str(&fmt_arg(&self.port_pin_list));
+        text.fmt(f)
+    }
+}
+
+impl CommandExt for RemoveOutputDelay {
+    fn location(&self) -> Location {
+        self.location.clone()
+    }
+
+    fn kind(&self) -> CommandKind {
+        CommandKind::RemoveOutputDelay
+    }
+}
+
+impl Validate for RemoveOutputDelay {
+    fn validate(&self, version: SdcVersion) -> Vec<ValidateError> {
+        let mut ret = vec![];
+        self.arg_comb3(
+            &mut ret,
+            version.any(),
+            &self.clock_fall,
+            &self.level_sensitive,
+            &self.clock,
+            |a, b, c| !((a | b) & !c),
+        );
+        validate_opt(&mut ret, version, &self.clock);
+        validate_arg(&mut ret, version, &self.port_pin_list);
+        ret
+    }
+}
+
+impl Extract for RemoveOutputDelay {
+    fn extract<'a>(&'a self, kind: CommandKind, list: &mut Vec<&'a Command>) {
+        Self::extract_opt(kind, list, &self.clock);
+        Self::extract_arg(kind, list, &self.port_pin_list);
+    }
+
+    fn extract_mut<'a>(&'a mut self, kind: CommandKind, list: &mut Vec<&'a mut Command>) {
+        Self::extract_mut_opt(kind, list, &mut self.clock);
+        Self::extract_mut_arg(kind, list, &mut self.port_pin_list);
+    }
+}
+
+pub(crate) fn remove_output_delay(
+    args: Vec<Argument>,
+    location: Location,
+) -> Result<Command, SemanticError> {
+    let mut clock = None;
+    let mut clock_fall = false;
+    let mut level_sensitive = false;
+    let mut rise = false;
+    let mut fall = false;
+    let mut max = false;
+    let mut min = false;
+    let mut port_pin_list = None;
+
+    static DICT: OnceLock<LazyDict> = OnceLock::new();
+    let dict = DICT.get_or_init(|| LazyDict::from_kind(CommandKind::RemoveOutputDelay));
+
+    let mut iter = args.into_iter();
+    while let Some(arg) = iter.next() {
+        match LazyMatcher::new(arg.as_str(), dict, &location)? {
+            x if x.m("-clock") => clock = opt_arg(arg, iter.next(), clock)?,
+            x if x.m("-clock_fall") => clock_fall = opt_flg(arg, clock_fall)?,
+            x if x.m("-level_sensitive") => level_sensitive = opt_flg(arg, level_sensitive)?,
+            x if x.m("-rise") => rise = opt_flg(arg, rise)?,
+            x if x.m("-fall") => fall = opt_flg(arg, fall)?,
+            x if x.m("-max") => max = opt_flg(arg, max)?,
+            x if x.m("-min") => min = opt_flg(arg, min)?,
+            _ => port_pin_list = pos_args1(Some(arg), port_pin_list, &location)?,
+        }
+    }
+
+    let port_pin_list = mandatory(port_pin_list, "port_pin_list", &location)?;
+
+    Ok(Command::RemoveOutputDelay(RemoveOutputDelay {
+        clock,
+        clock_fall,
+        level_sensitive,
+        rise,
+        fall,
+        max,
+        min,
+        port_pin_list,
+        location,
+    }))
+}
+
+/// set_annotated_delay
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct SetAnnotatedDelay {
+    pub cell: bool,
+    pub net: bool,
+    pub rise: bool,
+    pub fall: bool,
+    pub min: bool,
+    pub max: bool,
+    pub load_delay: Option<Argument>,
+    pub from: Option<Argument>,
+    pub to: Option<Argument>,
+    pub delay_value: Argument,
+    location: Location,
+}
+
+builder!(
+    SetAnnotatedDelay,
+    SetAnnotatedDelayBuilder {
+        mandatory: [delay_value],
+        optional: [load_delay, from, to],
+        flag: [cell, net, rise, fall, min, max],
+        repeatable: [],
+        extra: [],
+    }
+);
+
+impl fmt::Display for SetAnnotatedDelay {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut text = "set_annotated_delay".to_string();
+        text.push_str(&fmt_named_flg(self.cell, "cell"));
+        text.push_str(&fmt_named_flg(self.net, "net"));
+        text.push_str(&fmt_named_flg(self.rise, "rise"));
+        text.push_str(&fmt_named_flg(self.fall, "fall"));
+        text.push_str(&fmt_named_flg(self.min, "min"));
+        text.push_str(&fmt_named_flg(self.max, "max"));
+        text.push_str(&fmt_named_opt_arg(&self.load_delay, "load_delay"));
+        text.push_str(&fmt_named_opt_arg(&self.from, "from"));
+        text.push_str(&fmt_named_opt_arg(&self.to, "to"));
+        text.push_str(&fmt_arg(&self.delay_value));
+        text.fmt(f)
+    }
+}
+
+impl CommandExt for SetAnnotatedDelay {
+    fn location(&self) -> Location {
+        self.location.clone()
+    }
+
+    fn kind(&self) -> CommandKind {
+        CommandKind::SetAnnotatedDelay
+    }
+}
+
+impl Validate for SetAnnotatedDelay {
+    fn validate(&self, version: SdcVersion) -> Vec<ValidateError> {
+        let mut ret = vec![];
+        self.arg_comb2(&mut ret, version.any(), &self.cell, &self.net, |a, b| a ^ b);
+        self.arg_comb2(&mut ret, version.any(), &self.from, &self.to, |a, b| a & b);
+        validate_opt(&mut ret, version, &self.load_delay);
+        validate_opt(&mut ret, version, &self.from);
+        validate_opt(&mut ret, version, &self.to);
+        validate_arg(&mut ret, version, &self.delay_value);
+        ret
+    }
+}
+
+impl Extract for SetAnnotatedDelay {
+    fn extract<'a>(&'a self, kind: CommandKind, list: &mut Vec<&'a Command>) {
+        Self::extract_opt(kind, list, &self.from);
+        Self::extract_opt(kind, list, &self.to);
+        Self::extract_arg(kind, list, &self.delay_value);
+    }
+
+    fn extract_mut<'a>(&'a mut self, kind: CommandKind, list: &mut Vec<&'a mut Command>) {
+        Self::extract_mut_opt(kind, list, &mut self.from);
+        Self::extract_mut_opt(kind, list, &mut self.to);
+        Self::extract_mut_arg(kind, list, &mut self.delay_value);
+    }
+}
+
+pub(crate) fn set_annotated_delay(
+    args: Vec<Argument>,
+    location: Location,
+) -> Result<Command, SemanticError> {
+    let mut cell = false;
+    let mut net = false;
+    let mut rise = false;
+    let mut fall = false;
+    let mut min = false;
+    let mut max = false;
+    let mut load_delay = None;
+    let mut from = None;
+    let mut to = None;
+    let mut delay_value = None;
+
+    static DICT: OnceLock<LazyDict> = OnceLock::new();
+    let dict = DICT.get_or_init(|| LazyDict::from_kind(CommandKind::SetAnnotatedDelay));
+
+    let mut iter = args.into_iter();
+    while let Some(arg) = iter.next() {
+        match LazyMatcher::new(arg.as_str(), dict, &location)? {
+            x if x.m("-cell") => cell = opt_flg(arg, cell)?,
+            x if x.m("-net") => net = opt_flg(arg, net)?,
+            x if x.m("-rise") => rise = opt_flg(arg, rise)?,
+            x if x.m("-fall") => fall = opt_flg(arg, fall)?,
+            x if x.m("-min") => min = opt_flg(arg, min)?,
+            x if x.m("-max") => max = opt_flg(arg, max)?,
+            x if x.m("-load_delay") => load_delay = opt_arg(arg, iter.next(), load_delay)?,
+            x if x.m("-from") => from = opt_arg(arg, iter.next(), from)?,
+            x if x.m("-to") => to = opt_arg(arg, iter.next(), to)?,
+            _ => delay_value = pos_args1(Some(arg), delay_value, &location)?,
+        }
+    }
+
+    let delay_value = mandatory(delay_value, "delay_value", &location)?;
+
+    Ok(Command::SetAnnotatedDelay(SetAnnotatedDelay {
+        cell,
+        net,
+        rise,
+        fall,
+        min,
+        max,
+        load_delay,
+        from,
+        to,
+        delay_value,
+        location,
+    }))
+}
+
+/// set_app_var
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct SetAppVar {
+    pub variable: Argument,
+    pub value: Argument,
+    location: Location,
+}
+
+builder!(
+    SetAppVar,
+    SetAppVarBuilder {
+        mandatory: [variable, value],
+        optional: [],
+        flag: [],
+        repeatable: [],
+        extra: [],
+    }
+);
+
+impl fmt::Display for SetAppVar {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut text = "set_app_var".to_string();
+        text.push_str(&fmt_arg(&self.variable));
+        text.push_str(&fmt_arg(&self.value));
+        text.fmt(f)
+    }
+}
+
+impl CommandExt for SetAppVar {
+    fn location(&self) -> Location {
+        self.location.clone()
+    }
+
+    fn kind(&self) -> CommandKind {
+        CommandKind::SetAppVar
+    }
+}
+
+impl Validate for SetAppVar {
+    fn validate(&self, version: SdcVersion) -> Vec<ValidateError> {
+        let mut ret = vec![];
+        validate_arg(&mut ret, version, &self.variable);
+        validate_arg(&mut ret, version, &self.value);
+        ret
+    }
+}
+
+impl Extract for SetAppVar {
+    fn extract<'a>(&'a self, kind: CommandKind, list: &mut Vec<&'a Command>) {
+        Self::extract_arg(kind, list, &self.value);
+    }
+
+    fn extract_mut<'a>(&'a mut self, kind: CommandKind, list: &mut Vec<&'a mut Command>) {
+        Self::extract_mut_arg(kind, list, &mut self.value);
+    }
+}
+
+pub(crate) fn set_app_var(
+    args: Vec<Argument>,
+    location: Location,
+) -> Result<Command, SemanticError> {
+    let mut variable = None;
+    let mut value = None;
+
+    let mut iter = args.into_iter();
+    while let Some(arg) = iter.next() {
+        (variable, value) = pos_args2(Some(arg), (variable, value), &location)?;
+    }
+
+    let variable = mandatory(variable, "variable", &location)?;
+    let value = mandatory(value, "value", &location)?;
+
+    Ok(Command::SetAppVar(SetAppVar {
+        variable,
+        value,
+        location,
+    }))
+}
+
+/// set_clock_gating_style
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct SetClockGatingStyle {
+    pub sequential_cell: Option<Argument>,
+    pub minimum_bitwidth: Option<Argument>,
+    pub positive_edge_logic: Option<Argument>,
+    pub negative_edge_logic: Option<Argument>,
+    pub control_point: Option<Argument>,
+    pub control_signal: Option<Argument>,
+    pub max_fanout: Option<Argument>,
+    pub setup: Option<Argument>,
+    pub hold: Option<Argument>,
+    location: Location,
+}
+
+builder!(
+    SetClockGatingStyle,
+    SetClockGatingStyleBuilder {
+        mandatory: [],
+        optional: [
+            sequential_cell,
+            minimum_bitwidth,
+            positive_edge_logic,
+            negative_edge_logic,
+            control_point,
+            control_signal,
+            max_fanout,
+            setup,
+            hold,
+        ],
+        flag: [],
+        repeatable: [],
+        extra: [],
+    }
+);
+
+impl fmt::Display for SetClockGatingStyle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut text = "set_clock_gating_style".to_string();
+        text.push_str(&fmt_named_opt_arg(&self.sequential_cell, "sequential_cell"));
+        text.push_str(&fmt_named_opt_arg(
+            &self.minimum_bitwidth,
+            "minimum_bitwidth",
+        ));
+        text.push_str(&fmt_named_opt_arg(
+            &self.positive_edge_logic,
+            "positive_edge_logic",
+        ));
+        text.push_str(&fmt_named_opt_arg(
+            &self.negative_edge_logic,
+            "negative_edge_logic",
+        ));
+        text.push_str(&fmt_named_opt_arg(&self.control_point, "control_point"));
+        text.push_str(&fmt_named_opt_arg(&self.control_signal, "control_signal"));
+        text.push_str(&fmt_named_opt_arg(&self.max_fanout, "max_fanout"));
+        text.push_str(&fmt_named_opt_arg(&self.setup, "setup"));
+        text.push_str(&fmt_named_opt_arg(&self.hold, "hold"));
+        text.fmt(f)
+    }
+}
+
+impl CommandExt for SetClockGatingStyle {
+    fn location(&self) -> Location {
+        self.location.clone()
+    }
+
+    fn kind(&self) -> CommandKind {
+        CommandKind::SetClockGatingStyle
+    }
+}
+
+impl Validate for SetClockGatingStyle {
+    fn validate(&self, version: SdcVersion) -> Vec<ValidateError> {
+        let mut ret = vec![];
+        validate_opt(&mut ret, version, &self.sequential_cell);
+        validate_opt(&mut ret, version, &self.minimum_bitwidth);
+        validate_opt(&mut ret, version, &self.positive_edge_logic);
+        validate_opt(&mut ret, version, &self.negative_edge_logic);
+        validate_opt(&mut ret, version, &self.control_point);
+        validate_opt(&mut ret, version, &self.control_signal);
+        validate_opt(&mut ret, version, &self.max_fanout);
+        validate_opt(&mut ret, version, &self.setup);
+        validate_opt(&mut ret, version, &self.hold);
+        ret
+    }
+}
+
+impl Extract for SetClockGatingStyle {}
+
+pub(crate) fn set_clock_gating_style(
+    args: Vec<Argument>,
+    location: Location,
+) -> Result<Command, SemanticError> {
+    let mut sequential_cell = None;
+    let mut minimum_bitwidth = None;
+    let mut positive_edge_logic = None;
+    let mut negative_edge_logic = None;
+    let mut control_point = None;
+    let mut control_signal = None;
+    let mut max_fanout = None;
+    let mut setup = None;
+    let mut hold = None;
+
+    static DICT: OnceLock<LazyDict> = OnceLock::new();
+    let dict = DICT.get_or_init(|| LazyDict::from_kind(CommandKind::SetClockGatingStyle));
+
+    let mut iter = args.into_iter();
+    while let Some(arg) = iter.next() {
+        match LazyMatcher::new(arg.as_str(), dict, &location)? {
+            x if x.m("-sequential_cell") => {
+                sequential_cell = opt_arg(arg, iter.next(), sequential_cell)?
+            }
+            x if x.m("-minimum_bitwidth") => {
+                minimum_bitwidth = opt_arg(arg, iter.next(), minimum_bitwidth)?
+            }
+            x if x.m("-positive_edge_logic") => {
+                positive_edge_logic = opt_arg(arg, iter.next(), positive_edge_logic)?
+            }
+            x if x.m("-negative_edge_logic") => {
+                negative_edge_logic = opt_arg(arg, iter.next(), negative_edge_logic)?
+            }
+            x if x.m("-control_point") => control_point = opt_arg(arg, iter.next(), control_point)?,
+            x if x.m("-control_signal") => {
+                control_signal = opt_arg(arg, iter.next(), control_signal)?
+            }
+            x if x.m("-max_fanout") => max_fanout = opt_arg(arg, iter.next(), max_fanout)?,
+            x if x.m("-setup") => setup = opt_arg(arg, iter.next(), setup)?,
+            x if x.m("-hold") => hold = opt_arg(arg, iter.next(), hold)?,
+            _ => return Err(SemanticError::WrongArgument(arg)),
+        }
+    }
+
+    Ok(Command::SetClockGatingStyle(SetClockGatingStyle {
+        sequential_cell,
+        minimum_bitwidth,
+        positive_edge_logic,
+        negative_edge_logic,
+        control_point,
+        control_signal,
+        max_fanout,
+        setup,
+        hold,
+        location,
+    }))
+}
+
+/// set_latch_loop_breaker
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct SetLatchLoopBreaker {
+    pub object_list: Argument,
+    location: Location,
+}
+
+builder!(
+    SetLatchLoopBreaker,
+    SetLatchLoopBreakerBuilder {
+        mandatory: [object_list],
+        optional: [],
+        flag: [],
+        repeatable: [],
+        extra: [],
+    }
+);
+
+impl fmt::Display for SetLatchLoopBreaker {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut text = "set_latch_loop_breaker".to_string();
+        text.push_str(&fmt_arg(&self.object_list));
+        text.fmt(f)
+    }
+}
+
+impl CommandExt for SetLatchLoopBreaker {
+    fn location(&self) -> Location {
+        self.location.clone()
+    }
+
+    fn kind(&self) -> CommandKind {
+        CommandKind::SetLatchLoopBreaker
+    }
+}
+
+impl Validate for SetLatchLoopBreaker {
+    fn validate(&self, version: SdcVersion) -> Vec<ValidateError> {
+        let mut ret = vec![];
+        validate_arg(&mut ret, version, &self.object_list);
+        ret
+    }
+}
+
+impl Extract for SetLatchLoopBreaker {
+    fn extract<'a>(&'a self, kind: CommandKind, list: &mut Vec<&'a Command>) {
+        Self::extract_arg(kind, list, &self.object_list);
+    }
+
+    fn extract_mut<'a>(&'a mut self, kind: CommandKind, list: &mut Vec<&'a mut Command>) {
+        Self::extract_mut_arg(kind, list, &mut self.object_list);
+    }
+}
+
+pub(crate) fn set_latch_loop_breaker(
+    args: Vec<Argument>,
+    location: Location,
+) -> Result<Command, SemanticError> {
+    let mut object_list = None;
+
+    let mut iter = args.into_iter();
+    while let Some(arg) = iter.next() {
+        object_list = pos_args1(Some(arg), object_list, &location)?;
+    }
+
+    let object_list = mandatory(object_list, "object_list", &location)?;
+
+    Ok(Command::SetLatchLoopBreaker(SetLatchLoopBreaker {
+        object_list,
+        location,
+    }))
+}
+
+/// set_path_margin
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct SetPathMargin {
+    pub setup: bool,
+    pub hold: bool,
+    pub rise: bool,
+    pub fall: bool,
+    pub from: Option<Argument>,
+    pub to: Option<Argument>,
+    pub through: Vec<Argument>,
+    pub comment: Option<Argument>,
+    pub margin_value: Argument,
+    location: Location,
+}
+
+builder!(
+    SetPathMargin,
+    SetPathMarginBuilder {
+        mandatory: [margin_value],
+        optional: [from, to, comment],
+        flag: [setup, hold, rise, fall],
+        repeatable: [through],
+        extra: [],
+    }
+);
+
+impl fmt::Display for SetPathMargin {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut text = "set_path_margin".to_string();
+        text.push_str(&fmt_named_flg(self.setup, "setup"));
+        text.push_str(&fmt_named_flg(self.hold, "hold"));
+        text.push_str(&fmt_named_flg(self.rise, "rise"));
+        text.push_str(&fmt_named_flg(self.fall, "fall"));
+        text.push_str(&fmt_named_opt_arg(&self.from, "from"));
+        text.push_str(&fmt_named_opt_arg(&self.to, "to"));
+        text.push_str(&fmt_named_vec_arg(&self.through, "through"));
+        text.push_str(&fmt_named_opt_arg(&self.comment, "comment"));
+        text.push_str(&fmt_arg(&self.margin_value));
+        text.fmt(f)
+    }
+}
+
+impl CommandExt for SetPathMargin {
+    fn location(&self) -> Location {
+        self.location.clone()
+    }
+
+    fn kind(&self) -> CommandKind {
+        CommandKind::SetPathMargin
+    }
+}
+
+impl Validate for SetPathMargin {
+    fn validate(&self, version: SdcVersion) -> Vec<ValidateError> {
+        let mut ret = vec![];
+        self.arg_comb2(&mut ret, version.any(), &self.setup, &self.hold, |a, b| {
+            !(a & b)
+        });
+        self.arg_comb2(&mut ret, version.any(), &self.rise, &self.fall, |a, b| {
+            !(a & b)
+        });
+        validate_opt(&mut ret, version, &self.from);
+        validate_opt(&mut ret, version, &self.to);
+        validate_vec(&mut ret, version, &self.through);
+        validate_opt(&mut ret, version, &self.comment);
+        validate_arg(&mut ret, version, &self.margin_value);
+        ret
+    }
+}
+
+impl Extract for SetPathMargin {
+    fn extract<'a>(&'a self, kind: CommandKind, list: &mut Vec<&'a Command>) {
+        Self::extract_opt(kind, list, &self.from);
+        Self::extract_opt(kind, list, &self.to);
+        Self::extract_vec(kind, list, &self.through);
+        Self::extract_opt(kind, list, &self.comment);
+        Self::extract_arg(kind, list, &self.margin_value);
+    }
+
+    fn extract_mut<'a>(&'a mut self, kind: CommandKind, list: &mut Vec<&'a mut Command>) {
+        Self::extract_mut_opt(kind, list, &mut self.from);
+        Self::extract_mut_opt(kind, list, &mut self.to);
+        Self::extract_mut_vec(kind, list, &mut self.through);
+        Self::extract_mut_opt(kind, list, &mut self.comment);
+        Self::extract_mut_arg(kind, list, &mut self.margin_value);
+    }
+}
+
+pub(crate) fn set_path_margin(
+    args: Vec<Argument>,
+    location: Location,
+) -> Result<Command, SemanticError> {
+    let mut setup = false;
+    let mut hold = false;
+    let mut rise = false;
+    let mut fall = false;
+    let mut from = None;
+    let mut to = None;
+    let mut through = vec![];
+    let mut comment = None;
+    let mut margin_value = None;
+
+    static DICT: OnceLock<LazyDict> = OnceLock::new();
+    let dict = DICT.get_or_init(|| LazyDict::from_kind(CommandKind::SetPathMargin));
+
+    let mut iter = args.into_iter();
+    while let Some(arg) = iter.next() {
+        match LazyMatcher::new(arg.as_str(), dict, &location)? {
+            x if x.m("-setup") => setup = opt_flg(arg, setup)?,
+            x if x.m("-hold") => hold = opt_flg(arg, hold)?,
+            x if x.m("-rise") => rise = opt_flg(arg, rise)?,
+            x if x.m("-fall") => fall = opt_flg(arg, fall)?,
+            x if x.m("-from") => from = opt_arg(arg, iter.next(), from)?,
+            x if x.m("-to") => to = opt_arg(arg, iter.next(), to)?,
+            x if x.m("-through") => through = vec_arg(arg, iter.next(), through)?,
+            x if x.m("-comment") => comment = opt_arg(arg, iter.next(), comment)?,
+            _ => margin_value = pos_args1(Some(arg), margin_value, &location)?,
+        }
+    }
+
+    let margin_value = mandatory(margin_value, "margin_value", &location)?;
+
+    Ok(Command::SetPathMargin(SetPathMargin {
+        setup,
+        hold,
+        rise,
+        fall,
+        from,
+        to,
+        through,
+        comment,
+        margin_value,
+        location,
+    }))
+}
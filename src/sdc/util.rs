@@ -1,6 +1,6 @@
-use crate::errors::{SemanticError, ValidateError};
+use crate::errors::{BuildError, SemanticError, ValidateError};
 use crate::file_db::Location;
-use crate::sdc::{Argument, Command, CommandKind, SdcVersion};
+use crate::sdc::{Argument, Command, CommandKind, CommandSpec, SdcVersion};
 use std::collections::HashMap;
 
 pub(crate) fn opt_arg(
@@ -117,7 +117,8 @@ pub(crate) fn fmt_named_flg(x: bool, name: &str) -> String {
     }
 }
 
-pub(crate) trait CommandExt {
+/// Location and kind of command
+pub trait CommandExt {
     fn location(&self) -> Location;
 
     fn kind(&self) -> CommandKind;
@@ -166,45 +167,6 @@ pub(crate) fn validate_vec(ret: &mut Vec<ValidateError>, version: SdcVersion, x:
 }
 
 pub(crate) trait Validate: CommandExt {
-    fn cmd_supported_version(&self, ret: &mut Vec<ValidateError>, cond: (bool, SdcVersion)) {
-        if !cond.0 {
-            ret.push(ValidateError::CmdUnsupportedVersion(
-                cond.1,
-                self.location(),
-            ));
-        }
-    }
-
-    fn alias_supported_version(
-        &self,
-        ret: &mut Vec<ValidateError>,
-        cond: (bool, SdcVersion),
-        is_alias: bool,
-    ) {
-        if is_alias && !cond.0 {
-            ret.push(ValidateError::CmdUnsupportedVersion(
-                cond.1,
-                self.location(),
-            ));
-        }
-    }
-
-    fn arg_supported_version<T: Exist>(
-        &self,
-        ret: &mut Vec<ValidateError>,
-        cond: (bool, SdcVersion),
-        arg: &T,
-        name: &str,
-    ) {
-        if arg.exist() && !cond.0 {
-            ret.push(ValidateError::ArgUnsupportedVersion(
-                cond.1,
-                self.location(),
-                name.into(),
-            ));
-        }
-    }
-
     fn arg_comb1<A: Exist, T: Fn(bool) -> bool>(
         &self,
         ret: &mut Vec<ValidateError>,
@@ -405,6 +367,13 @@ impl LazyDict {
 
         LazyDict { dict }
     }
+
+    /// Dictionary of options defined by [`CommandSpec`] of `kind`
+    pub(crate) fn from_kind(kind: CommandKind) -> Self {
+        let spec = CommandSpec::of(kind).unwrap();
+        let opts: Vec<_> = spec.options.iter().map(|x| x.name).collect();
+        Self::new(&opts)
+    }
 }
 
 pub(crate) struct LazyMatcher<'a, 'b> {
@@ -496,6 +465,182 @@ pub(crate) trait Extract {
     fn extract_mut<'a>(&'a mut self, _kind: CommandKind, _list: &mut Vec<&'a mut Command>) {}
 }
 
+/// Define builder and argument accessors of command
+///
+/// `Argument` fields are mandatory, `Option<Argument>` fields are optional,
+/// `bool` fields are flags and `Vec<Argument>` fields are repeatable.
+/// Private fields except `location` are listed in `extra`.
+macro_rules! builder {
+    (
+        $command:ident, $builder:ident {
+            mandatory: [$($mandatory:ident),* $(,)?],
+            optional: [$($optional:ident),* $(,)?],
+            flag: [$($flag:ident),* $(,)?],
+            repeatable: [$($repeatable:ident),* $(,)?],
+            extra: [$($extra:ident: $extra_type:ty),* $(,)?] $(,)?
+        }
+    ) => {
+        impl $command {
+            pub fn builder() -> $builder {
+                <$builder as Default>::default()
+            }
+
+            /// Arguments of the command
+            pub fn arguments(&self) -> Vec<&$crate::sdc::Argument> {
+                #[allow(unused_mut)]
+                let mut ret = vec![$(&self.$mandatory),*];
+                $(ret.extend(self.$optional.iter());)*
+                $(ret.extend(self.$repeatable.iter());)*
+                ret
+            }
+
+            /// Values of option or positional argument `name`
+            #[allow(unused_variables)]
+            pub fn values(&self, name: &str) -> Vec<&$crate::sdc::Argument> {
+                $(
+                    if stringify!($mandatory).trim_start_matches("r#") == name.trim_start_matches('-') {
+                        return vec![&self.$mandatory];
+                    }
+                )*
+                $(
+                    if stringify!($optional).trim_start_matches("r#") == name.trim_start_matches('-') {
+                        return self.$optional.iter().collect();
+                    }
+                )*
+                $(
+                    if stringify!($repeatable).trim_start_matches("r#") == name.trim_start_matches('-') {
+                        return self.$repeatable.iter().collect();
+                    }
+                )*
+                vec![]
+            }
+
+            /// Mutable values of option or positional argument `name`
+            #[allow(unused_variables)]
+            pub fn values_mut(&mut self, name: &str) -> Vec<&mut $crate::sdc::Argument> {
+                $(
+                    if stringify!($mandatory).trim_start_matches("r#") == name.trim_start_matches('-') {
+                        return vec![&mut self.$mandatory];
+                    }
+                )*
+                $(
+                    if stringify!($optional).trim_start_matches("r#") == name.trim_start_matches('-') {
+                        return self.$optional.iter_mut().collect();
+                    }
+                )*
+                $(
+                    if stringify!($repeatable).trim_start_matches("r#") == name.trim_start_matches('-') {
+                        return self.$repeatable.iter_mut().collect();
+                    }
+                )*
+                vec![]
+            }
+
+            /// Whether flag `name` is specified
+            #[allow(unused_variables)]
+            pub fn flag(&self, name: &str) -> bool {
+                $(
+                    if stringify!($flag).trim_start_matches("r#") == name.trim_start_matches('-') {
+                        return self.$flag;
+                    }
+                )*
+                false
+            }
+
+            /// Mutable arguments of the command
+            pub fn arguments_mut(&mut self) -> Vec<&mut $crate::sdc::Argument> {
+                #[allow(unused_mut)]
+                let mut ret = vec![$(&mut self.$mandatory),*];
+                $(ret.extend(self.$optional.iter_mut());)*
+                $(ret.extend(self.$repeatable.iter_mut());)*
+                ret
+            }
+        }
+
+        #[doc = concat!("Builder of [`", stringify!($command), "`]")]
+        #[derive(Clone, Debug, Default)]
+        pub struct $builder {
+            $($mandatory: Option<$crate::sdc::Argument>,)*
+            $($optional: Option<$crate::sdc::Argument>,)*
+            $($flag: bool,)*
+            $($repeatable: Vec<$crate::sdc::Argument>,)*
+            $($extra: $extra_type,)*
+        }
+
+        impl $builder {
+            $(
+                pub fn $mandatory(&mut self, value: impl Into<$crate::sdc::Argument>) -> &mut Self {
+                    self.$mandatory = Some(value.into());
+                    self
+                }
+            )*
+
+            $(
+                pub fn $optional(&mut self, value: impl Into<$crate::sdc::Argument>) -> &mut Self {
+                    self.$optional = Some(value.into());
+                    self
+                }
+            )*
+
+            $(
+                pub fn $flag(&mut self, value: bool) -> &mut Self {
+                    self.$flag = value;
+                    self
+                }
+            )*
+
+            $(
+                /// Append a value
+                pub fn $repeatable(&mut self, value: impl Into<$crate::sdc::Argument>) -> &mut Self {
+                    self.$repeatable.push(value.into());
+                    self
+                }
+            )*
+
+            $(
+                pub fn $extra(&mut self, value: $extra_type) -> &mut Self {
+                    self.$extra = value;
+                    self
+                }
+            )*
+
+            /// Build and validate the command
+            pub fn build(&self) -> Result<$command, $crate::errors::BuildError> {
+                let command = $command {
+                    $(
+                        $mandatory: self.$mandatory.clone().ok_or_else(|| {
+                            $crate::errors::BuildError::MissingArgument(
+                                stringify!($mandatory).trim_start_matches("r#").into(),
+                            )
+                        })?,
+                    )*
+                    $($optional: self.$optional.clone(),)*
+                    $($flag: self.$flag,)*
+                    $($repeatable: self.$repeatable.clone(),)*
+                    $($extra: self.$extra.clone(),)*
+                    location: $crate::file_db::Location::default(),
+                };
+                $crate::sdc::util::validate_build(command)
+            }
+        }
+
+        impl From<$command> for $crate::sdc::Command {
+            fn from(value: $command) -> Self {
+                $crate::sdc::Command::$command(value)
+            }
+        }
+    };
+}
+pub(crate) use builder;
+
+/// Validate a command built by builder at the latest SDC version
+pub(crate) fn validate_build<T: Validate>(command: T) -> Result<T, BuildError> {
+    match command.validate(SdcVersion::SDC2_1).into_iter().next() {
+        Some(x) => Err(BuildError::Invalid(x)),
+        None => Ok(command),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
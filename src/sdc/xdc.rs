@@ -0,0 +1,830 @@
+#![allow(clippy::while_let_on_iterator)]
+
+use crate::errors::SemanticError;
+use crate::errors::ValidateError;
+use crate::file_db::Location;
+use crate::sdc::util::*;
+use crate::sdc::{Argument, Command, CommandKind, SdcVersion};
+use std::fmt;
+use std::sync::OnceLock;
+
+/// add_cells_to_pblock
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct AddCellsToPblock {
+    pub top: bool,
+    pub add_primitives: bool,
+    pub clear_locs: bool,
+    pub quiet: bool,
+    pub verbose: bool,
+    pub pblock: Argument,
+    pub cells: Option<Argument>,
+    location: Location,
+}
+
+builder!(
+    AddCellsToPblock,
+    AddCellsToPblockBuilder {
+        mandatory: [pblock],
+        optional: [cells],
+        flag: [top, add_primitives, clear_locs, quiet, verbose],
+        repeatable: [],
+        extra: [],
+    }
+);
+
+impl fmt::Display for AddCellsToPblock {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut text = "add_cells_to_pblock".to_string();
+        text.push_str(&fmt_named_flg(self.top, "top"));
+        text.push_str(&fmt_named_flg(self.add_primitives, "add_primitives"));
+        text.push_str(&fmt_named_flg(self.clear_locs, "clear_locs"));
+        text.push_str(&fmt_named_flg(self.quiet, "quiet"));
+        text.push_str(&fmt_named_flg(self.verbose, "verbose"));
+        text.push_str(&fmt_arg(&self.pblock));
+        text.push_str(&fmt_opt_arg(&self.cells));
+        text.fmt(f)
+    }
+}
+
+impl CommandExt for AddCellsToPblock {
+    fn location(&self) -> Location {
+        self.location.clone()
+    }
+
+    fn kind(&self) -> CommandKind {
+        CommandKind::AddCellsToPblock
+    }
+}
+
+impl Validate for AddCellsToPblock {
+    fn validate(&self, version: SdcVersion) -> Vec<ValidateError> {
+        let mut ret = vec![];
+        self.arg_comb2(&mut ret, version.any(), &self.top, &self.cells, |a, b| {
+            a ^ b
+        });
+        validate_arg(&mut ret, version, &self.pblock);
+        validate_opt(&mut ret, version, &self.cells);
+        ret
+    }
+}
+
+impl Extract for AddCellsToPblock {
+    fn extract<'a>(&'a self, kind: CommandKind, list: &mut Vec<&'a Command>) {
+        Self::extract_arg(kind, list, &self.pblock);
+        Self::extract_opt(kind, list, &self.cells);
+    }
+
+    fn extract_mut<'a>(&'a mut self, kind: CommandKind, list: &mut Vec<&'a mut Command>) {
+        Self::extract_mut_arg(kind, list, &mut self.pblock);
+        Self::extract_mut_opt(kind, list, &mut self.cells);
+    }
+}
+
+pub(crate) fn add_cells_to_pblock(
+    args: Vec<Argument>,
+    location: Location,
+) -> Result<Command, SemanticError> {
+    let mut top = false;
+    let mut add_primitives = false;
+    let mut clear_locs = false;
+    let mut quiet = false;
+    let mut verbose = false;
+    let mut pblock = None;
+    let mut cells = None;
+
+    static DICT: OnceLock<LazyDict> = OnceLock::new();
+    let dict = DICT.get_or_init(|| LazyDict::from_kind(CommandKind::AddCellsToPblock));
+
+    let mut iter = args.into_iter();
+    while let Some(arg) = iter.next() {
+        match LazyMatcher::new(arg.as_str(), dict, &location)? {
+            x if x.m("-top") => top = opt_flg(arg, top)?,
+            x if x.m("-add_primitives") => add_primitives = opt_flg(arg, add_primitives)?,
+            x if x.m("-clear_locs") => clear_locs = opt_flg(arg, clear_locs)?,
+            x if x.m("-quiet") => quiet = opt_flg(arg, quiet)?,
+            x if x.m("-verbose") => verbose = opt_flg(arg, verbose)?,
+            _ => (pblock, cells) = pos_args2(Some(arg), (pblock, cells), &location)?,
+        }
+    }
+
+    let pblock = mandatory(pblock, "pblock", &location)?;
+
+    Ok(Command::AddCellsToPblock(AddCellsToPblock {
+        top,
+        add_primitives,
+        clear_locs,
+        quiet,
+        verbose,
+        pblock,
+        cells,
+        location,
+    }))
+}
+
+/// create_pblock
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct CreatePblock {
+    pub quiet: bool,
+    pub verbose: bool,
+    pub name: Argument,
+    location: Location,
+}
+
+builder!(
+    CreatePblock,
+    CreatePblockBuilder {
+        mandatory: [name],
+        optional: [],
+        flag: [quiet, verbose],
+        repeatable: [],
+        extra: [],
+    }
+);
+
+impl fmt::Display for CreatePblock {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut text = "create_pblock".to_string();
+        text.push_str(&fmt_named_flg(self.quiet, "quiet"));
+        text.push_str(&fmt_named_flg(self.verbose, "verbose"));
+        text.push_str(&fmt_arg(&self.name));
+        text.fmt(f)
+    }
+}
+
+impl CommandExt for CreatePblock {
+    fn location(&self) -> Location {
+        self.location.clone()
+    }
+
+    fn kind(&self) -> CommandKind {
+        CommandKind::CreatePblock
+    }
+}
+
+impl Validate for CreatePblock {
+    fn validate(&self, version: SdcVersion) -> Vec<ValidateError> {
+        let mut ret = vec![];
+        validate_arg(&mut ret, version, &self.name);
+        ret
+    }
+}
+
+impl Extract for CreatePblock {
+    fn extract<'a>(&'a self, kind: CommandKind, list: &mut Vec<&'a Command>) {
+        Self::extract_arg(kind, list, &self.name);
+    }
+
+    fn extract_mut<'a>(&'a mut self, kind: CommandKind, list: &mut Vec<&'a mut Command>) {
+        Self::extract_mut_arg(kind, list, &mut self.name);
+    }
+}
+
+pub(crate) fn create_pblock(
+    args: Vec<Argument>,
+    location: Location,
+) -> Result<Command, SemanticError> {
+    let mut quiet = false;
+    let mut verbose = false;
+    let mut name = None;
+
+    static DICT: OnceLock<LazyDict> = OnceLock::new();
+    let dict = DICT.get_or_init(|| LazyDict::from_kind(CommandKind::CreatePblock));
+
+    let mut iter = args.into_iter();
+    while let Some(arg) = iter.next() {
+        match LazyMatcher::new(arg.as_str(), dict, &location)? {
+            x if x.m("-quiet") => quiet = opt_flg(arg, quiet)?,
+            x if x.m("-verbose") => verbose = opt_flg(arg, verbose)?,
+            _ => name = pos_args1(Some(arg), name, &location)?,
+        }
+    }
+
+    let name = mandatory(name, "name", &location)?;
+
+    Ok(Command::CreatePblock(CreatePblock {
+        quiet,
+        verbose,
+        name,
+        location,
+    }))
+}
+
+/// get_iobanks
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct GetIobanks {
+    pub regexp: bool,
+    pub nocase: bool,
+    pub filter: Option<Argument>,
+    pub of_objects: Option<Argument>,
+    pub quiet: bool,
+    pub verbose: bool,
+    pub patterns: Option<Argument>,
+    location: Location,
+}
+
+builder!(
+    GetIobanks,
+    GetIobanksBuilder {
+        mandatory: [],
+        optional: [filter, of_objects, patterns],
+        flag: [regexp, nocase, quiet, verbose],
+        repeatable: [],
+        extra: [],
+    }
+);
+
+impl fmt::Display for GetIobanks {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut text = "get_iobanks".to_string();
+        text.push_str(&fmt_named_flg(self.regexp, "regexp"));
+        text.push_str(&fmt_named_flg(self.nocase, "nocase"));
+        text.push_str(&fmt_named_opt_arg(&self.filter, "filter"));
+        text.push_str(&fmt_named_opt_arg(&self.of_objects, "of_objects"));
+        text.push_str(&fmt_named_flg(self.quiet, "quiet"));
+        text.push_str(&fmt_named_flg(self.verbose, "verbose"));
+        text.push_str(&fmt_opt_arg(&self.patterns));
+        text.fmt(f)
+    }
+}
+
+impl CommandExt for GetIobanks {
+    fn location(&self) -> Location {
+        self.location.clone()
+    }
+
+    fn kind(&self) -> CommandKind {
+        CommandKind::GetIobanks
+    }
+}
+
+impl Validate for GetIobanks {
+    fn validate(&self, version: SdcVersion) -> Vec<ValidateError> {
+        let mut ret = vec![];
+        self.arg_comb2(
+            &mut ret,
+            version.any(),
+            &self.patterns,
+            &self.of_objects,
+            |a, b| !(a & b),
+        );
+        validate_opt(&mut ret, version, &self.filter);
+        validate_opt(&mut ret, version, &self.of_objects);
+        validate_opt(&mut ret, version, &self.patterns);
+        ret
+    }
+}
+
+impl Extract for GetIobanks {
+    fn extract<'a>(&'a self, kind: CommandKind, list: &mut Vec<&'a Command>) {
+        Self::extract_opt(kind, list, &self.filter);
+        Self::extract_opt(kind, list, &self.of_objects);
+        Self::extract_opt(kind, list, &self.patterns);
+    }
+
+    fn extract_mut<'a>(&'a mut self, kind: CommandKind, list: &mut Vec<&'a mut Command>) {
+        Self::extract_mut_opt(kind, list, &mut self.filter);
+        Self::extract_mut_opt(kind, list, &mut self.of_objects);
+        Self::extract_mut_opt(kind, list, &mut self.patterns);
+    }
+}
+
+pub(crate) fn get_iobanks(
+    args: Vec<Argument>,
+    location: Location,
+) -> Result<Command, SemanticError> {
+    let mut regexp = false;
+    let mut nocase = false;
+    let mut filter = None;
+    let mut of_objects = None;
+    let mut quiet = false;
+    let mut verbose = false;
+    let mut patterns = None;
+
+    static DICT: OnceLock<LazyDict> = OnceLock::new();
+    let dict = DICT.get_or_init(|| LazyDict::from_kind(CommandKind::GetIobanks));
+
+    let mut iter = args.into_iter();
+    while let Some(arg) = iter.next() {
+        match LazyMatcher::new(arg.as_str(), dict, &location)? {
+            x if x.m("-regexp") => regexp = opt_flg(arg, regexp)?,
+            x if x.m("-nocase") => nocase = opt_flg(arg, nocase)?,
+            x if x.m("-filter") => filter = opt_arg(arg, iter.next(), filter)?,
+            x if x.m("-of_objects") => of_objects = opt_arg(arg, iter.next(), of_objects)?,
+            x if x.m("-quiet") => quiet = opt_flg(arg, quiet)?,
+            x if x.m("-verbose") => verbose = opt_flg(arg, verbose)?,
+            _ => patterns = pos_args1(Some(arg), patterns, &location)?,
+        }
+    }
+
+    Ok(Command::GetIobanks(GetIobanks {
+        regexp,
+        nocase,
+        filter,
+        of_objects,
+        quiet,
+        verbose,
+        patterns,
+        location,
+    }))
+}
+
+/// get_pblocks
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct GetPblocks {
+    pub regexp: bool,
+    pub nocase: bool,
+    pub filter: Option<Argument>,
+    pub of_objects: Option<Argument>,
+    pub include_nested_pblocks: bool,
+    pub quiet: bool,
+    pub verbose: bool,
+    pub patterns: Option<Argument>,
+    location: Location,
+}
+
+builder!(
+    GetPblocks,
+    GetPblocksBuilder {
+        mandatory: [],
+        optional: [filter, of_objects, patterns],
+        flag: [regexp, nocase, include_nested_pblocks, quiet, verbose],
+        repeatable: [],
+        extra: [],
+    }
+);
+
+impl fmt::Display for GetPblocks {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut text = "get_pblocks".to_string();
+        text.push_str(&fmt_named_flg(self.regexp, "regexp"));
+        text.push_str(&fmt_named_flg(self.nocase, "nocase"));
+        text.push_str(&fmt_named_opt_arg(&self.filter, "filter"));
+        text.push_str(&fmt_named_opt_arg(&self.of_objects, "of_objects"));
+        text.push_str(&fmt_named_flg(
+            self.include_nested_pblocks,
+            "include_nested_pblocks",
+        ));
+        text.push_str(&fmt_named_flg(self.quiet, "quiet"));
+        text.push_str(&fmt_named_flg(self.verbose, "verbose"));
+        text.push_str(&fmt_opt_arg(&self.patterns));
+        text.fmt(f)
+    }
+}
+
+impl CommandExt for GetPblocks {
+    fn location(&self) -> Location {
+        self.location.clone()
+    }
+
+    fn kind(&self) -> CommandKind {
+        CommandKind::GetPblocks
+    }
+}
+
+impl Validate for GetPblocks {
+    fn validate(&self, version: SdcVersion) -> Vec<ValidateError> {
+        let mut ret = vec![];
+        self.arg_comb2(
+            &mut ret,
+            version.any(),
+            &self.patterns,
+            &self.of_objects,
+            |a, b| !(a & b),
+        );
+        validate_opt(&mut ret, version, &self.filter);
+        validate_opt(&mut ret, version, &self.of_objects);
+        validate_opt(&mut ret, version, &self.patterns);
+        ret
+    }
+}
+
+impl Extract for GetPblocks {
+    fn extract<'a>(&'a self, kind: CommandKind, list: &mut Vec<&'a Command>) {
+        Self::extract_opt(kind, list, &self.filter);
+        Self::extract_opt(kind, list, &self.of_objects);
+        Self::extract_opt(kind, list, &self.patterns);
+    }
+
+    fn extract_mut<'a>(&'a mut self, kind: CommandKind, list: &mut Vec<&'a mut Command>) {
+        Self::extract_mut_opt(kind, list, &mut self.filter);
+        Self::extract_mut_opt(kind, list, &mut self.of_objects);
+        Self::extract_mut_opt(kind, list, &mut self.patterns);
+    }
+}
+
+pub(crate) fn get_pblocks(
+    args: Vec<Argument>,
+    location: Location,
+) -> Result<Command, SemanticError> {
+    let mut regexp = false;
+    let mut nocase = false;
+    let mut filter = None;
+    let mut of_objects = None;
+    let mut include_nested_pblocks = false;
+    let mut quiet = false;
+    let mut verbose = false;
+    let mut patterns = None;
+
+    static DICT: OnceLock<LazyDict> = OnceLock::new();
+    let dict = DICT.get_or_init(|| LazyDict::from_kind(CommandKind::GetPblocks));
+
+    let mut iter = args.into_iter();
+    while let Some(arg) = iter.next() {
+        match LazyMatcher::new(arg.as_str(), dict, &location)? {
+            x if x.m("-regexp") => regexp = opt_flg(arg, regexp)?,
+            x if x.m("-nocase") => nocase = opt_flg(arg, nocase)?,
+            x if x.m("-filter") => filter = opt_arg(arg, iter.next(), filter)?,
+            x if x.m("-of_objects") => of_objects = opt_arg(arg, iter.next(), of_objects)?,
+            x if x.m("-include_nested_pblocks") => {
+                include_nested_pblocks = opt_flg(arg, include_nested_pblocks)?
+            }
+            x if x.m("-quiet") => quiet = opt_flg(arg, quiet)?,
+            x if x.m("-verbose") => verbose = opt_flg(arg, verbose)?,
+            _ => patterns = pos_args1(Some(arg), patterns, &location)?,
+        }
+    }
+
+    Ok(Command::GetPblocks(GetPblocks {
+        regexp,
+        nocase,
+        filter,
+        of_objects,
+        include_nested_pblocks,
+        quiet,
+        verbose,
+        patterns,
+        location,
+    }))
+}
+
+/// get_property
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct GetProperty {
+    pub min: bool,
+    pub max: bool,
+    pub quiet: bool,
+    pub verbose: bool,
+    pub name: Argument,
+    pub object: Argument,
+    location: Location,
+}
+
+builder!(
+    GetProperty,
+    GetPropertyBuilder {
+        mandatory: [name, object],
+        optional: [],
+        flag: [min, max, quiet, verbose],
+        repeatable: [],
+        extra: [],
+    }
+);
+
+impl fmt::Display for GetProperty {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut text = "get_property".to_string();
+        text.push_str(&fmt_named_flg(self.min, "min"));
+        text.push_str(&fmt_named_flg(self.max, "max"));
+        text.push_str(&fmt_named_flg(self.quiet, "quiet"));
+        text.push_str(&fmt_named_flg(self.verbose, "verbose"));
+        text.push_str(&fmt_arg(&self.name));
+        text.push_str(&fmt_arg(&self.object));
+        text.fmt(f)
+    }
+}
+
+impl CommandExt for GetProperty {
+    fn location(&self) -> Location {
+        self.location.clone()
+    }
+
+    fn kind(&self) -> CommandKind {
+        CommandKind::GetProperty
+    }
+}
+
+impl Validate for GetProperty {
+    fn validate(&self, version: SdcVersion) -> Vec<ValidateError> {
+        let mut ret = vec![];
+        self.arg_comb2(&mut ret, version.any(), &self.min, &self.max, |a, b| {
+            !(a & b)
+        });
+        validate_arg(&mut ret, version, &self.name);
+        validate_arg(&mut ret, version, &self.object);
+        ret
+    }
+}
+
+impl Extract for GetProperty {
+    fn extract<'a>(&'a self, kind: CommandKind, list: &mut Vec<&'a Command>) {
+        Self::extract_arg(kind, list, &self.name);
+        Self::extract_arg(kind, list, &self.object);
+    }
+
+    fn extract_mut<'a>(&'a mut self, kind: CommandKind, list: &mut Vec<&'a mut Command>) {
+        Self::extract_mut_arg(kind, list, &mut self.name);
+        Self::extract_mut_arg(kind, list, &mut self.object);
+    }
+}
+
+pub(crate) fn get_property(
+    args: Vec<Argument>,
+    location: Location,
+) -> Result<Command, SemanticError> {
+    let mut min = false;
+    let mut max = false;
+    let mut quiet = false;
+    let mut verbose = false;
+    let mut name = None;
+    let mut object = None;
+
+    static DICT: OnceLock<LazyDict> = OnceLock::new();
+    let dict = DICT.get_or_init(|| LazyDict::from_kind(CommandKind::GetProperty));
+
+    let mut iter = args.into_iter();
+    while let Some(arg) = iter.next() {
+        match LazyMatcher::new(arg.as_str(), dict, &location)? {
+            x if x.m("-min") => min = opt_flg(arg, min)?,
+            x if x.m("-max") => max = opt_flg(arg, max)?,
+            x if x.m("-quiet") => quiet = opt_flg(arg, quiet)?,
+            x if x.m("-verbose") => verbose = opt_flg(arg, verbose)?,
+            _ => (name, object) = pos_args2(Some(arg), (name, object), &location)?,
+        }
+    }
+
+    let name = mandatory(name, "name", &location)?;
+    let object = mandatory(object, "object", &location)?;
+
+    Ok(Command::GetProperty(GetProperty {
+        min,
+        max,
+        quiet,
+        verbose,
+        name,
+        object,
+        location,
+    }))
+}
+
+/// resize_pblock
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ResizePblock {
+    pub add: Vec<Argument>,
+    pub remove: Vec<Argument>,
+    pub from: Option<Argument>,
+    pub to: Option<Argument>,
+    pub replace: bool,
+    pub locs: Option<Argument>,
+    pub quiet: bool,
+    pub verbose: bool,
+    pub pblock: Argument,
+    location: Location,
+}
+
+builder!(
+    ResizePblock,
+    ResizePblockBuilder {
+        mandatory: [pblock],
+        optional: [from, to, locs],
+        flag: [replace, quiet, verbose],
+        repeatable: [add, remove],
+        extra: [],
+    }
+);
+
+impl fmt::Display for ResizePblock {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut text = "resize_pblock".to_string();
+        text.push_str(&fmt_named_vec_arg(&self.add, "add"));
+        text.push_str(&fmt_named_vec_arg(&self.remove, "remove"));
+        text.push_str(&fmt_named_opt_arg(&self.from, "from"));
+        text.push_str(&fmt_named_opt_arg(&self.to, "to"));
+        text.push_str(&fmt_named_flg(self.replace, "replace"));
+        text.push_str(&fmt_named_opt_arg(&self.locs, "locs"));
+        text.push_str(&fmt_named_flg(self.quiet, "quiet"));
+        text.push_str(&fmt_named_flg(self.verbose, "verbose"));
+        text.push_str(&fmt_arg(&self.pblock));
+        text.fmt(f)
+    }
+}
+
+impl CommandExt for ResizePblock {
+    fn location(&self) -> Location {
+        self.location.clone()
+    }
+
+    fn kind(&self) -> CommandKind {
+        CommandKind::ResizePblock
+    }
+}
+
+impl Validate for ResizePblock {
+    fn validate(&self, version: SdcVersion) -> Vec<ValidateError> {
+        let mut ret = vec![];
+        self.arg_comb2(&mut ret, version.any(), &self.from, &self.to, |a, b| {
+            !(a ^ b)
+        });
+        validate_vec(&mut ret, version, &self.add);
+        validate_vec(&mut ret, version, &self.remove);
+        validate_opt(&mut ret, version, &self.from);
+        validate_opt(&mut ret, version, &self.to);
+        validate_opt(&mut ret, version, &self.locs);
+        validate_arg(&mut ret, version, &self.pblock);
+        ret
+    }
+}
+
+impl Extract for ResizePblock {
+    fn extract<'a>(&'a self, kind: CommandKind, list: &mut Vec<&'a Command>) {
+        Self::extract_vec(kind, list, &self.add);
+        Self::extract_vec(kind, list, &self.remove);
+        Self::extract_opt(kind, list, &self.from);
+        Self::extract_opt(kind, list, &self.to);
+        Self::extract_opt(kind, list, &self.locs);
+        Self::extract_arg(kind, list, &self.pblock);
+    }
+
+    fn extract_mut<'a>(&'a mut self, kind: CommandKind, list: &mut Vec<&'a mut Command>) {
+        Self::extract_mut_vec(kind, list, &mut self.add);
+        Self::extract_mut_vec(kind, list, &mut self.remove);
+        Self::extract_mut_opt(kind, list, &mut self.from);
+        Self::extract_mut_opt(kind, list, &mut self.to);
+        Self::extract_mut_opt(kind, list, &mut self.locs);
+        Self::extract_mut_arg(kind, list, &mut self.pblock);
+    }
+}
+
+pub(crate) fn resize_pblock(
+    args: Vec<Argument>,
+    location: Location,
+) -> Result<Command, SemanticError> {
+    let mut add = vec![];
+    let mut remove = vec![];
+    let mut from = None;
+    let mut to = None;
+    let mut replace = false;
+    let mut locs = None;
+    let mut quiet = false;
+    let mut verbose = false;
+    let mut pblock = None;
+
+    static DICT: OnceLock<LazyDict> = OnceLock::new();
+    let dict = DICT.get_or_init(|| LazyDict::from_kind(CommandKind::ResizePblock));
+
+    let mut iter = args.into_iter();
+    while let Some(arg) = iter.next() {
+        match LazyMatcher::new(arg.as_str(), dict, &location)? {
+            x if x.m("-add") => add = vec_arg(arg, iter.next(), add)?,
+            x if x.m("-remove") => remove = vec_arg(arg, iter.next(), remove)?,
+            x if x.m("-from") => from = opt_arg(arg, iter.next(), from)?,
+            x if x.m("-to") => to = opt_arg(arg, iter.next(), to)?,
+            x if x.m("-replace") => replace = opt_flg(arg, replace)?,
+            x if x.m("-locs") => locs = opt_arg(arg, iter.next(), locs)?,
+            x if x.m("-quiet") => quiet = opt_flg(arg, quiet)?,
+            x if x.m("-verbose") => verbose = opt_flg(arg, verbose)?,
+            _ => pblock = pos_args1(Some(arg), pblock, &location)?,
+        }
+    }
+
+    let pblock = mandatory(pblock, "pblock", &location)?;
+
+    Ok(Command::ResizePblock(ResizePblock {
+        add,
+        remove,
+        from,
+        to,
+        replace,
+        locs,
+        quiet,
+        verbose,
+        pblock,
+        location,
+    }))
+}
+
+/// set_property
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct SetProperty {
+    pub dict: Option<Argument>,
+    pub quiet: bool,
+    pub verbose: bool,
+    pub name: Option<Argument>,
+    pub value: Option<Argument>,
+    pub objects: Argument,
+    location: Location,
+}
+
+builder!(
+    SetProperty,
+    SetPropertyBuilder {
+        mandatory: [objects],
+        optional: [dict, name, value],
+        flag: [quiet, verbose],
+        repeatable: [],
+        extra: [],
+    }
+);
+
+impl fmt::Display for SetProperty {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut text = "set_property".to_string();
+        text.push_str(&fmt_named_opt_arg(&self.dict, "dict"));
+        text.push_str(&fmt_named_flg(self.quiet, "quiet"));
+        text.push_str(&fmt_named_flg(self.verbose, "verbose"));
+        text.push_str(&fmt_opt_arg(&self.name));
+        text.push_str(&fmt_opt_arg(&self.value));
+        text.push_str(&fmt_arg(&self.objects));
+        text.fmt(f)
+    }
+}
+
+impl CommandExt for SetProperty {
+    fn location(&self) -> Location {
+        self.location.clone()
+    }
+
+    fn kind(&self) -> CommandKind {
+        CommandKind::SetProperty
+    }
+}
+
+impl Validate for SetProperty {
+    fn validate(&self, version: SdcVersion) -> Vec<ValidateError> {
+        let mut ret = vec![];
+        self.arg_comb3(
+            &mut ret,
+            version.any(),
+            &self.dict,
+            &self.name,
+            &self.value,
+            |a, b, c| (a & !b & !c) | (!a & b & c),
+        );
+        validate_opt(&mut ret, version, &self.dict);
+        validate_opt(&mut ret, version, &self.name);
+        validate_opt(&mut ret, version, &self.value);
+        validate_arg(&mut ret, version, &self.objects);
+        ret
+    }
+}
+
+impl Extract for SetProperty {
+    fn extract<'a>(&'a self, kind: CommandKind, list: &mut Vec<&'a Command>) {
+        Self::extract_opt(kind, list, &self.dict);
+        Self::extract_opt(kind, list, &self.name);
+        Self::extract_opt(kind, list, &self.value);
+        Self::extract_arg(kind, list, &self.objects);
+    }
+
+    fn extract_mut<'a>(&'a mut self, kind: CommandKind, list: &mut Vec<&'a mut Command>) {
+        Self::extract_mut_opt(kind, list, &mut self.dict);
+        Self::extract_mut_opt(kind, list, &mut self.name);
+        Self::extract_mut_opt(kind, list, &mut self.value);
+        Self::extract_mut_arg(kind, list, &mut self.objects);
+    }
+}
+
+pub(crate) fn set_property(
+    args: Vec<Argument>,
+    location: Location,
+) -> Result<Command, SemanticError> {
+    let mut dict_arg = None;
+    let mut quiet = false;
+    let mut verbose = false;
+    let mut positional = vec![];
+
+    static DICT: OnceLock<LazyDict> = OnceLock::new();
+    let dict = DICT.get_or_init(|| LazyDict::from_kind(CommandKind::SetProperty));
+
+    let mut iter = args.into_iter();
+    while let Some(arg) = iter.next() {
+        match LazyMatcher::new(arg.as_str(), dict, &location)? {
+            x if x.m("-dict") => dict_arg = opt_arg(arg, iter.next(), dict_arg)?,
+            x if x.m("-quiet") => quiet = opt_flg(arg, quiet)?,
+            x if x.m("-verbose") => verbose = opt_flg(arg, verbose)?,
+            _ => positional.push(arg),
+        }
+    }
+
+    // `set_property -dict {name value ...} objects` or `set_property name value objects`
+    let expected = if dict_arg.is_some() { 1 } else { 3 };
+    if positional.len() < expected {
+        return Err(SemanticError::MissingPosArgument(location));
+    }
+    if positional.len() > expected {
+        return Err(SemanticError::TooManyArgument(location));
+    }
+
+    let objects = positional.pop().unwrap();
+    let value = positional.pop();
+    let name = positional.pop();
+
+    Ok(Command::SetProperty(SetProperty {
+        dict: dict_arg,
+        quiet,
+        verbose,
+        name,
+        value,
+        objects,
+        location,
+    }))
+}
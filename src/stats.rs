@@ -0,0 +1,402 @@
+use crate::constraints::{list_items, Clock, Constraints, IoDelay, IoDelayKind, Quantity};
+use crate::sdc::util::CommandExt;
+use crate::sdc::{Command, CommandKind, CommandSpec, Sdc, SdcVersion};
+use crate::visit::{walk_command, Visit};
+#[cfg(feature = "json")]
+use serde_json::{json, Value};
+use std::fmt;
+
+/// Commands counted as timing exceptions
+const EXCEPTION_KINDS: [CommandKind; 4] = [
+    CommandKind::SetFalsePath,
+    CommandKind::SetMulticyclePath,
+    CommandKind::SetMaxDelay,
+    CommandKind::SetMinDelay,
+];
+
+/// Clock defined by `create_clock`
+#[derive(Clone, Debug, PartialEq)]
+pub struct ClockStats {
+    pub name: String,
+    pub period: Quantity,
+}
+
+impl ClockStats {
+    /// Frequency in Hz
+    pub fn frequency(&self) -> f64 {
+        1.0 / self.period.to_si()
+    }
+}
+
+/// Number of IO delays referring a clock
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct IoDelayStats {
+    /// `None` for IO delays without `-clock`
+    pub clock: Option<String>,
+    pub input: usize,
+    pub output: usize,
+}
+
+/// Constraint statistics report
+#[derive(Clone, Debug, PartialEq)]
+pub struct Stats {
+    /// Number of top-level commands per command name, ordered by count
+    pub commands: Vec<(String, usize)>,
+    pub total: usize,
+    pub unknown: usize,
+    pub clocks: Vec<ClockStats>,
+    /// Number of clocks whose period can't be interpreted
+    pub uninterpreted_clocks: usize,
+    pub io_delays: Vec<IoDelayStats>,
+    /// Number of IO delays which can't be interpreted
+    pub uninterpreted_io_delays: usize,
+    pub exceptions: Vec<(String, usize)>,
+    /// Object queries with glob or regexp patterns including nested ones
+    pub wildcard_queries: usize,
+    /// Version declared by `set sdc_version`
+    pub declared_version: Option<SdcVersion>,
+    /// Minimum version at which all commands are valid
+    pub required_version: Option<SdcVersion>,
+}
+
+impl Stats {
+    pub fn new(sdc: &Sdc) -> Self {
+        let mut commands: Vec<(String, usize)> = vec![];
+        let mut unknown = 0;
+        for command in &sdc.commands {
+            let name = match command {
+                Command::Unknown(_) => {
+                    unknown += 1;
+                    continue;
+                }
+                _ => command_name(command.kind()),
+            };
+            match commands.iter_mut().find(|x| x.0 == name) {
+                Some(x) => x.1 += 1,
+                None => commands.push((name, 1)),
+            }
+        }
+        commands.sort_by(|x, y| y.1.cmp(&x.1).then(x.0.cmp(&y.0)));
+
+        let exceptions = EXCEPTION_KINDS
+            .iter()
+            .map(|kind| {
+                let count = sdc.commands.iter().filter(|x| x.kind() == *kind).count();
+                (command_name(*kind), count)
+            })
+            .collect();
+
+        // Clocks and IO delays which can't be interpreted, e.g. a period by a variable,
+        // are counted as uninterpreted instead of failing the whole report.
+        let units = Constraints::from(sdc.clone()).units().ok();
+
+        let mut clocks = vec![];
+        let mut uninterpreted_clocks = 0;
+        for command in sdc.extract(CommandKind::CreateClock) {
+            if let Command::CreateClock(x) = command {
+                match (Clock::try_from(x), &units) {
+                    (Ok(mut clock), Some(units)) => {
+                        clock.set_units(units);
+                        clocks.push(ClockStats {
+                            name: clock.name().to_string(),
+                            period: clock.period_quantity(),
+                        });
+                    }
+                    _ => uninterpreted_clocks += 1,
+                }
+            }
+        }
+
+        let mut io_delays: Vec<IoDelayStats> = vec![];
+        let mut uninterpreted_io_delays = 0;
+        for command in &sdc.commands {
+            let delay = match command {
+                Command::SetInputDelay(x) => IoDelay::try_from(x),
+                Command::SetOutputDelay(x) => IoDelay::try_from(x),
+                _ => continue,
+            };
+            let delay = match (delay, &units) {
+                (Ok(mut delay), Some(units)) => {
+                    delay.set_units(units);
+                    delay
+                }
+                _ => {
+                    uninterpreted_io_delays += 1;
+                    continue;
+                }
+            };
+            let index = match io_delays.iter().position(|x| &x.clock == delay.clock()) {
+                Some(x) => x,
+                None => {
+                    io_delays.push(IoDelayStats {
+                        clock: delay.clock().clone(),
+                        ..Default::default()
+                    });
+                    io_delays.len() - 1
+                }
+            };
+            match delay.kind() {
+                IoDelayKind::Input => io_delays[index].input += 1,
+                IoDelayKind::Output => io_delays[index].output += 1,
+            }
+        }
+
+        let mut wildcard = WildcardCounter::default();
+        wildcard.visit_sdc(sdc);
+
+        Self {
+            commands,
+            total: sdc.commands.len(),
+            unknown,
+            clocks,
+            uninterpreted_clocks,
+            io_delays,
+            uninterpreted_io_delays,
+            exceptions,
+            wildcard_queries: wildcard.count,
+            declared_version: sdc.version,
+            required_version: sdc.required_version().map(|x| x.version),
+        }
+    }
+
+    /// Percentage of unknown commands
+    pub fn unknown_ratio(&self) -> f64 {
+        if self.total == 0 {
+            0.0
+        } else {
+            self.unknown as f64 * 100.0 / self.total as f64
+        }
+    }
+
+    #[cfg(feature = "json")]
+    pub fn to_json(&self) -> Value {
+        let counts = |x: &[(String, usize)]| {
+            x.iter()
+                .map(|(name, count)| (name.clone(), json!(count)))
+                .collect::<serde_json::Map<_, _>>()
+        };
+        let version = |x: Option<SdcVersion>| x.map(|x| x.version_string().to_string());
+        json!({
+            "commands": counts(&self.commands),
+            "total": self.total,
+            "unknown": self.unknown,
+            "unknown_ratio": self.unknown_ratio(),
+            "clocks": self.clocks.iter().map(|x| json!({
+                "name": x.name,
+                "period": x.period.to_si(),
+                "frequency": x.frequency(),
+            })).collect::<Vec<_>>(),
+            "uninterpreted_clocks": self.uninterpreted_clocks,
+            "io_delays": self.io_delays.iter().map(|x| json!({
+                "clock": x.clock,
+                "input": x.input,
+                "output": x.output,
+            })).collect::<Vec<_>>(),
+            "uninterpreted_io_delays": self.uninterpreted_io_delays,
+            "exceptions": counts(&self.exceptions),
+            "wildcard_queries": self.wildcard_queries,
+            "declared_version": version(self.declared_version),
+            "required_version": version(self.required_version),
+        })
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let width = self
+            .commands
+            .iter()
+            .chain(self.exceptions.iter())
+            .map(|x| x.0.len())
+            .chain(self.clocks.iter().map(|x| x.name.len()))
+            .max()
+            .unwrap_or(0)
+            .max("(no clock)".len());
+
+        writeln!(f, "Commands:")?;
+        for (name, count) in &self.commands {
+            writeln!(f, "  {name:width$}  {count}")?;
+        }
+        writeln!(
+            f,
+            "  {:width$}  {} ({:.1}%)",
+            "unknown",
+            self.unknown,
+            self.unknown_ratio()
+        )?;
+        writeln!(f, "  {:width$}  {}", "total", self.total)?;
+
+        writeln!(f, "Clocks:")?;
+        for x in &self.clocks {
+            writeln!(
+                f,
+                "  {:width$}  {}  {:.3}MHz",
+                x.name,
+                x.period,
+                x.frequency() / 1e6
+            )?;
+        }
+        if self.uninterpreted_clocks > 0 {
+            writeln!(
+                f,
+                "  {:width$}  {}",
+                "uninterpreted", self.uninterpreted_clocks
+            )?;
+        }
+
+        writeln!(f, "IO delays:")?;
+        for x in &self.io_delays {
+            let clock = x.clock.as_deref().unwrap_or("(no clock)");
+            writeln!(
+                f,
+                "  {clock:width$}  input {}  output {}",
+                x.input, x.output
+            )?;
+        }
+        if self.uninterpreted_io_delays > 0 {
+            writeln!(
+                f,
+                "  {:width$}  {}",
+                "uninterpreted", self.uninterpreted_io_delays
+            )?;
+        }
+
+        writeln!(f, "Exceptions:")?;
+        for (name, count) in &self.exceptions {
+            writeln!(f, "  {name:width$}  {count}")?;
+        }
+
+        writeln!(f, "Wildcard queries: {}", self.wildcard_queries)?;
+
+        let version = |x: Option<SdcVersion>| {
+            x.map(|x| x.version_string().to_string())
+                .unwrap_or("-".to_string())
+        };
+        writeln!(
+            f,
+            "SDC version: declared {}, required {}",
+            version(self.declared_version),
+            version(self.required_version)
+        )
+    }
+}
+
+fn command_name(kind: CommandKind) -> String {
+    CommandSpec::of(kind)
+        .map(|x| x.name.to_string())
+        .unwrap_or(format!("{kind:?}"))
+}
+
+#[derive(Default)]
+struct WildcardCounter {
+    count: usize,
+}
+
+impl Visit for WildcardCounter {
+    fn visit_command(&mut self, command: &Command) {
+        let patterns = command.values("patterns");
+        let wildcard = patterns
+            .into_iter()
+            .flat_map(list_items)
+            .any(|x| x.contains(['*', '?']));
+        if !matches!(command, Command::Unknown(_)) && (wildcard || command.flag("regexp")) {
+            self.count += 1;
+        }
+        walk_command(self, command);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Parser;
+
+    #[test]
+    fn stats() {
+        let code = r##"set sdc_version 2.1
+set_units -time ps
+create_clock -period 1000 -name CLK_A [get_ports clk_a]
+create_clock -period 4000 -name CLK_B [get_ports clk_b]
+set_input_delay 100 -clock CLK_A [get_ports din*]
+set_input_delay 200 -clock CLK_A [get_ports en]
+set_output_delay 100 -clock CLK_B [get_ports dout]
+set_false_path -from [get_clocks CLK_A] -to [get_clocks CLK_B]
+set_false_path -through [get_pins -regexp {u_core/.*}]
+set_max_delay 500 -from [all_registers -clock CLK_A]
+report_timing
+"##;
+        let sdc = Parser::parse(code, &"").unwrap();
+        let stats = Stats::new(&sdc);
+
+        assert_eq!(stats.total, 10);
+        assert_eq!(stats.unknown, 1);
+        assert_eq!(stats.unknown_ratio(), 10.0);
+        assert_eq!(stats.commands[0], ("create_clock".to_string(), 2));
+        assert_eq!(stats.clocks.len(), 2);
+        assert_eq!(stats.clocks[0].name, "CLK_A");
+        assert!((stats.clocks[0].frequency() - 1e9).abs() < 1.0);
+        assert_eq!(
+            stats.io_delays,
+            vec![
+                IoDelayStats {
+                    clock: Some("CLK_A".into()),
+                    input: 2,
+                    output: 0
+                },
+                IoDelayStats {
+                    clock: Some("CLK_B".into()),
+                    input: 0,
+                    output: 1
+                },
+            ]
+        );
+        assert_eq!(
+            stats.exceptions,
+            vec![
+                ("set_false_path".to_string(), 2),
+                ("set_multicycle_path".to_string(), 0),
+                ("set_max_delay".to_string(), 1),
+                ("set_min_delay".to_string(), 0),
+            ]
+        );
+        assert_eq!(stats.wildcard_queries, 2);
+        assert_eq!(stats.declared_version, Some(SdcVersion::SDC2_1));
+        assert_eq!(stats.required_version, Some(SdcVersion::SDC1_7));
+
+        #[cfg(feature = "json")]
+        {
+            let json = stats.to_json();
+            assert_eq!(json["commands"]["set_false_path"], 2);
+            assert_eq!(json["io_delays"][0]["clock"], "CLK_A");
+            assert_eq!(json["required_version"], "1.7");
+        }
+    }
+
+    #[test]
+    fn uninterpreted() {
+        let code = r##"set CLK_PERIOD 10
+create_clock -period $CLK_PERIOD -name CLK_A [get_ports clk_a]
+create_clock -period 5 -name CLK_B [get_ports clk_b]
+set_input_delay $IN_DELAY -clock CLK_A [get_ports din]
+set_output_delay 1 -clock CLK_B [get_ports dout]
+"##;
+        let sdc = Parser::parse(code, &"").unwrap();
+        let stats = Stats::new(&sdc);
+
+        assert_eq!(stats.clocks.len(), 1);
+        assert_eq!(stats.clocks[0].name, "CLK_B");
+        assert_eq!(stats.uninterpreted_clocks, 1);
+        assert_eq!(
+            stats.io_delays,
+            vec![IoDelayStats {
+                clock: Some("CLK_B".into()),
+                input: 0,
+                output: 1
+            }]
+        );
+        assert_eq!(stats.uninterpreted_io_delays, 1);
+        assert!(stats.to_string().contains("uninterpreted"));
+        #[cfg(feature = "json")]
+        assert_eq!(stats.to_json()["uninterpreted_clocks"], 1);
+    }
+}
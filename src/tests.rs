@@ -1,3 +1,4 @@
+use crate::sdc::util::CommandExt;
 use crate::sdc::*;
 use crate::Parser;
 use std::fs::File;
@@ -30,11 +31,21 @@ fn check_format(code: &str, format: &str) {
     assert_eq!(&format!("{}", sdc.commands[0]), format);
 }
 
+fn check_format_with_dialect(code: &str, format: &str, dialect: Dialect) {
+    let code = format!("{code}\n");
+    let sdc = Parser::parse_with_dialect(&code, &"", dialect).unwrap();
+    assert_eq!(&format!("{}", sdc.commands[0]), format);
+}
+
 fn check_testcase(path: &str, validatable: bool) {
+    check_testcase_with_dialect(path, validatable, Dialect::Sdc);
+}
+
+fn check_testcase_with_dialect(path: &str, validatable: bool, dialect: Dialect) {
     let mut f = File::open(&path).unwrap();
     let mut code = String::new();
     let _ = f.read_to_string(&mut code);
-    let sdc = Parser::parse(&code, &"");
+    let sdc = Parser::parse_with_dialect(&code, &"", dialect);
     //dbg!(&sdc);
     assert!(sdc.is_ok());
     if validatable {
@@ -109,21 +120,320 @@ fn format() {
     check_format("get_pins   a[3:2]", "get_pins a[3:2]");
 }
 
+#[test]
+fn xdc() {
+    check_format_with_dialect(
+        "set_property -dict {PACKAGE_PIN E3 IOSTANDARD LVCMOS33} [get_ports clk]",
+        "set_property -dict {PACKAGE_PIN E3 IOSTANDARD LVCMOS33} [get_ports clk]",
+        Dialect::Xdc,
+    );
+    check_format_with_dialect(
+        "set_property   IOSTANDARD LVCMOS33 [get_ports led*]",
+        "set_property IOSTANDARD LVCMOS33 [get_ports led*]",
+        Dialect::Xdc,
+    );
+    check_format_with_dialect(
+        "get_cells -hier -filter {REF_NAME == FDRE} -quiet",
+        "get_cells -hierarchical -filter {REF_NAME == FDRE} -quiet",
+        Dialect::Xdc,
+    );
+    check_format_with_dialect(
+        "set_clock_groups -include_generated_clocks -asynchronous -group A -group B",
+        "set_clock_groups -group A -group B -asynchronous -include_generated_clocks",
+        Dialect::Xdc,
+    );
+    check_format_with_dialect(
+        "set_max_delay -datapath_only -from A -to B 5",
+        "set_max_delay -from A -to B -datapath_only 5",
+        Dialect::Xdc,
+    );
+    check_format_with_dialect(
+        "resize_pblock pblock_cpu -add SLICE_X0Y0:SLICE_X10Y10",
+        "resize_pblock -add SLICE_X0Y0:SLICE_X10Y10 pblock_cpu",
+        Dialect::Xdc,
+    );
+
+    // XDC commands are unknown in standard SDC
+    let code = "set_property IOSTANDARD LVCMOS33 [get_ports led]\n";
+    let sdc = Parser::parse(code, &"").unwrap();
+    assert_eq!(sdc.commands[0].kind(), CommandKind::Unknown);
+    let code = "get_cells -filter {REF_NAME == FDRE}\n";
+    assert!(Parser::parse(code, &"").is_err());
+
+    // set_property requires name and value without -dict
+    let code = "set_property IOSTANDARD [get_ports led]\n";
+    assert!(Parser::parse_with_dialect(code, &"", Dialect::Xdc).is_err());
+}
+
+#[test]
+fn quartus() {
+    check_format_with_dialect(
+        "derive_pll_clocks   -create_base_clocks",
+        "derive_pll_clocks -create_base_clocks",
+        Dialect::Quartus,
+    );
+    check_format_with_dialect(
+        "set_time_format -decimal_places 3 -unit ns",
+        "set_time_format -unit ns -decimal_places 3",
+        Dialect::Quartus,
+    );
+    check_format_with_dialect(
+        "get_registers -nowarn {*|sync_reg[0]}",
+        "get_registers -nowarn {*|sync_reg[0]}",
+        Dialect::Quartus,
+    );
+    check_format_with_dialect(
+        "get_fanouts -through A -no_logic B",
+        "get_fanouts -no_logic -through A B",
+        Dialect::Quartus,
+    );
+    check_format_with_dialect(
+        "set_clock_groups -exclusive -group A -group B",
+        "set_clock_groups -group A -group B -exclusive",
+        Dialect::Quartus,
+    );
+
+    // Quartus commands are unknown in standard SDC
+    let code = "derive_clock_uncertainty\n";
+    let sdc = Parser::parse(code, &"").unwrap();
+    assert_eq!(sdc.commands[0].kind(), CommandKind::Unknown);
+    let code = "set_clock_groups -exclusive -group A -group B\n";
+    assert!(Parser::parse(code, &"").is_err());
+
+    // -multiply_by with -divide_by is valid only in Quartus
+    let code = "create_generated_clock -source A -multiply_by 2 -divide_by 3 B\n";
+    let sdc = Parser::parse(code, &"").unwrap();
+    assert!(!sdc.validate(None).is_empty());
+    let sdc = Parser::parse_with_dialect(code, &"", Dialect::Quartus).unwrap();
+    assert!(sdc.validate(None).is_empty());
+}
+
+#[test]
+fn synopsys() {
+    check_format_with_dialect(
+        "set_app_var   timing_report_unconstrained_paths true",
+        "set_app_var timing_report_unconstrained_paths true",
+        Dialect::Synopsys,
+    );
+    check_format_with_dialect(
+        "set_path_margin 0.1 -from A -hold",
+        "set_path_margin -hold -from A 0.1",
+        Dialect::Synopsys,
+    );
+    check_format_with_dialect(
+        "set_annotated_delay -to B -from A -cell 0.2",
+        "set_annotated_delay -cell -from A -to B 0.2",
+        Dialect::Synopsys,
+    );
+    check_format_with_dialect(
+        "set_clock_gating_style -hold 0.1 -sequential_cell latch",
+        "set_clock_gating_style -sequential_cell latch -hold 0.1",
+        Dialect::Synopsys,
+    );
+    check_format_with_dialect(
+        "get_timing_paths -through A -through B -max_paths 10",
+        "get_timing_paths -through A -through B -max_paths 10",
+        Dialect::Synopsys,
+    );
+    check_format_with_dialect("remove_clock -all", "remove_clock -all", Dialect::Synopsys);
+
+    // Synopsys commands are unknown in standard SDC
+    let code = "set_app_var timing_report_unconstrained_paths true\n";
+    let sdc = Parser::parse(code, &"").unwrap();
+    assert_eq!(sdc.commands[0].kind(), CommandKind::Unknown);
+
+    // set_annotated_delay requires either -cell or -net
+    let code = "set_annotated_delay -from A -to B 0.2\n";
+    let sdc = Parser::parse_with_dialect(code, &"", Dialect::Synopsys).unwrap();
+    assert!(!sdc.validate(None).is_empty());
+
+    // -reference_pin is accepted before SDC 2.0 only in Synopsys
+    let code = "set sdc_version 1.9\nset_input_delay 1 -clock C -reference_pin P [get_ports A]\n";
+    let sdc = Parser::parse(code, &"").unwrap();
+    assert!(!sdc.validate(None).is_empty());
+    let sdc = Parser::parse_with_dialect(code, &"", Dialect::Synopsys).unwrap();
+    assert!(sdc.validate(None).is_empty());
+}
+
+#[test]
+fn opensta() {
+    check_format_with_dialect(
+        "set_assigned_delay -from A -to B -cell -max 0.1",
+        "set_assigned_delay -cell -max -from A -to B 0.1",
+        Dialect::OpenSta,
+    );
+    check_format_with_dialect(
+        "set_disable_inferred_clock_gating   [get_cells icg]",
+        "set_disable_inferred_clock_gating [get_cells icg]",
+        Dialect::OpenSta,
+    );
+
+    // OpenSTA commands are unknown in standard SDC
+    let code = "set_disable_inferred_clock_gating [get_cells icg]\n";
+    let sdc = Parser::parse(code, &"").unwrap();
+    assert_eq!(sdc.commands[0].kind(), CommandKind::Unknown);
+
+    // set_clock_sense is kept by OpenSTA
+    let code = "set_clock_sense -stop_propagation -clocks C [get_pins A]\n";
+    let sdc = Parser::parse(code, &"").unwrap();
+    assert!(!sdc.validate(None).is_empty());
+    let sdc = Parser::parse_with_dialect(code, &"", Dialect::OpenSta).unwrap();
+    assert!(sdc.validate(None).is_empty());
+
+    // Commands outside of the OpenSTA subset are reported
+    let code = "set_load -pin_load 0.1 [get_ports A]\nset_ideal_network [get_ports rst]\n";
+    let sdc = Parser::parse_with_dialect(code, &"", Dialect::OpenSta).unwrap();
+    let errors = sdc.validate(None);
+    assert_eq!(errors.len(), 1);
+    assert!(
+        matches!(&errors[0], crate::errors::ValidateError::Incompatible(x, Dialect::OpenSta, _) if x == "set_ideal_network")
+    );
+    let sdc = Parser::parse(code, &"").unwrap();
+    assert!(sdc.validate(None).is_empty());
+
+    // Unknown commands are reported even if nested
+    let code = "set_false_path -to [find_pins u0/D]\nset_dont_use\n";
+    let sdc = Parser::parse_with_dialect(code, &"", Dialect::OpenSta).unwrap();
+    let names: Vec<_> = sdc
+        .validate(None)
+        .into_iter()
+        .filter_map(|x| match x {
+            crate::errors::ValidateError::Incompatible(x, Dialect::OpenSta, _) => Some(x),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(names, vec!["find_pins", "set_dont_use"]);
+}
+
 #[test]
 fn testcase() {
-    check_testcase("testcase/cdctl.sdc", false);
+    check_testcase_with_dialect("testcase/cdctl.sdc", true, Dialect::Quartus);
     check_testcase("testcase/ctu_can_fd.sdc", true);
-    check_testcase("testcase/logic_clock_domain_crossing.sdc", false);
-    check_testcase("testcase/peridot_cam.sdc", false);
-    check_testcase("testcase/peridot_hostbridge.sdc", false);
-    check_testcase("testcase/peridot_wsg.sdc", false);
+    check_testcase_with_dialect(
+        "testcase/logic_clock_domain_crossing.sdc",
+        true,
+        Dialect::Quartus,
+    );
+    check_testcase_with_dialect("testcase/peridot_cam.sdc", true, Dialect::Quartus);
+    check_testcase_with_dialect("testcase/peridot_hostbridge.sdc", true, Dialect::Quartus);
+    check_testcase_with_dialect("testcase/peridot_wsg.sdc", true, Dialect::Quartus);
+    check_testcase_with_dialect("testcase/primetime_signoff.sdc", true, Dialect::Synopsys);
     check_testcase("testcase/scan.sdc", true);
     // TODO linebreak of the end of file is missing
     //check_testcase("testcase/signoff.sdc", true);
     check_testcase("testcase/soc_system_hdmi_i2c_only.sdc", true);
-    check_testcase("testcase/soc_system_mandelbrot_timing.sdc", false);
-    check_testcase("testcase/spi_slave_mm.sdc", false);
+    check_testcase_with_dialect(
+        "testcase/soc_system_mandelbrot_timing.sdc",
+        true,
+        Dialect::Quartus,
+    );
+    check_testcase_with_dialect("testcase/spi_slave_mm.sdc", true, Dialect::Quartus);
     check_testcase("testcase/tiles_base.sdc", true);
     check_testcase("testcase/timing_constraints.sdc", true);
     check_testcase("testcase/tinyODIN.sdc", true);
+    check_testcase_with_dialect("testcase/arty_a7.xdc", true, Dialect::Xdc);
+}
+
+#[test]
+fn builder() {
+    use crate::constraints::object::Object;
+    use crate::errors::BuildError;
+
+    let clock = CreateClock::builder()
+        .period(10.0)
+        .name("CLK")
+        .waveform(Argument::list([0, 5]))
+        .source_objects(Object::Port("clk".into()))
+        .build()
+        .unwrap();
+    assert_eq!(
+        clock.to_string(),
+        "create_clock -period 10 -name CLK -waveform {0 5} [get_ports clk]"
+    );
+
+    let delay = SetInputDelay::builder()
+        .clock(GetClocks::builder().patterns("CLK").build().unwrap())
+        .max(true)
+        .delay_value(1.5)
+        .port_pin_list(Argument::list([
+            Object::Port("a[0]".into()),
+            Object::Port("b".into()),
+        ]))
+        .build()
+        .unwrap();
+    assert_eq!(
+        delay.to_string(),
+        "set_input_delay -clock [get_clocks CLK] -max 1.5 [list [get_ports {a[0]}] [get_ports b]]"
+    );
+
+    let path = SetFalsePath::builder()
+        .through("a")
+        .through("b c")
+        .build()
+        .unwrap();
+    assert_eq!(path.to_string(), "set_false_path -through a -through {b c}");
+
+    let path = SetFalsePath::builder()
+        .through("{a")
+        .through("{a} {b}")
+        .through("{a b}")
+        .through("a\\")
+        .build()
+        .unwrap();
+    assert_eq!(
+        path.to_string(),
+        r#"set_false_path -through "{a" -through "{a} {b}" -through {a b} -through "a\\""#
+    );
+    let parsed = Parser::parse(&format!("{path}\n"), &"").unwrap();
+    assert_eq!(parsed.to_string(), format!("{path}\n"));
+
+    assert!(matches!(
+        CreateClock::builder().name("CLK").build(),
+        Err(BuildError::MissingArgument(x)) if x == "period"
+    ));
+    assert!(matches!(
+        SetInputDelay::builder()
+            .delay_value(1)
+            .port_pin_list("a")
+            .level_sensitive(true)
+            .network_latency_included(true)
+            .build(),
+        Err(BuildError::Invalid(_))
+    ));
+
+    let mut sdc = Sdc::default();
+    sdc.push(clock).push(delay);
+    let code = sdc.to_string();
+    let parsed = Parser::parse(&code, &"").unwrap();
+    assert_eq!(parsed.to_string(), code);
+    assert!(parsed.validate(None).is_empty());
+}
+
+#[test]
+fn required_version() {
+    let code = r##"set sdc_version 2.1
+create_clock -period 10 -name CLK [get_ports clk]
+create_generated_clock -combinational -source [get_ports clk] [get_pins u_mux/Z]
+set_false_path -from [get_clocks CLK]
+"##;
+    let sdc = Parser::parse(code, &"").unwrap();
+    let required = sdc.required_version().unwrap();
+    assert_eq!(required.version, SdcVersion::SDC1_7);
+    assert_eq!(required.reasons.len(), 1);
+    assert_eq!(required.reasons[0].location.start_line, 3);
+    assert_eq!(
+        required.reasons[0].to_string(),
+        "create_generated_clock -combinational"
+    );
+
+    let sdc = Parser::parse("set_false_path -from [get_cells -regexp {u_.*}]\n", &"").unwrap();
+    let required = sdc.required_version().unwrap();
+    assert_eq!(required.version, SdcVersion::SDC1_5);
+    assert_eq!(required.reasons[0].kind, CommandKind::GetCells);
+    assert_eq!(required.reasons[0].to_string(), "get_cells -regexp");
+
+    let sdc = Parser::parse("set_false_path -from a\n", &"").unwrap();
+    let required = sdc.required_version().unwrap();
+    assert_eq!(required.version, SdcVersion::SDC1_1);
+    assert!(required.reasons.is_empty());
 }
@@ -0,0 +1,272 @@
+use crate::sdc::*;
+
+/// Define visitor traits and walk functions for pairs of `Command` variant and method names
+///
+/// `walk_command` and `walk_command_mut` match all variants,
+/// so a missing variant is reported at compile time.
+macro_rules! visitor {
+    ($($variant:ident: $visit:ident, $visit_mut:ident;)*) => {
+        /// Visitor of SDC
+        ///
+        /// Each method walks into the children by default.
+        /// Overriding methods can call `walk_*` functions to continue the traversal.
+        pub trait Visit {
+            fn visit_sdc(&mut self, sdc: &Sdc) {
+                walk_sdc(self, sdc);
+            }
+
+            fn visit_command(&mut self, command: &Command) {
+                walk_command(self, command);
+            }
+
+            fn visit_argument(&mut self, argument: &Argument) {
+                walk_argument(self, argument);
+            }
+
+            $(
+                fn $visit(&mut self, command: &$variant) {
+                    walk_arguments(self, command.arguments());
+                }
+            )*
+        }
+
+        /// Mutable visitor of SDC
+        ///
+        /// Each method walks into the children by default.
+        /// Overriding methods can call `walk_*_mut` functions to continue the traversal.
+        pub trait VisitMut {
+            fn visit_sdc_mut(&mut self, sdc: &mut Sdc) {
+                walk_sdc_mut(self, sdc);
+            }
+
+            fn visit_command_mut(&mut self, command: &mut Command) {
+                walk_command_mut(self, command);
+            }
+
+            fn visit_argument_mut(&mut self, argument: &mut Argument) {
+                walk_argument_mut(self, argument);
+            }
+
+            $(
+                fn $visit_mut(&mut self, command: &mut $variant) {
+                    walk_arguments_mut(self, command.arguments_mut());
+                }
+            )*
+        }
+
+        pub fn walk_command<V: Visit + ?Sized>(visitor: &mut V, command: &Command) {
+            match command {
+                $(Command::$variant(x) => visitor.$visit(x),)*
+            }
+        }
+
+        pub fn walk_command_mut<V: VisitMut + ?Sized>(visitor: &mut V, command: &mut Command) {
+            match command {
+                $(Command::$variant(x) => visitor.$visit_mut(x),)*
+            }
+        }
+    };
+}
+
+visitor! {
+    AddCellsToPblock: visit_add_cells_to_pblock, visit_add_cells_to_pblock_mut;
+    AllClocks: visit_all_clocks, visit_all_clocks_mut;
+    AllInputs: visit_all_inputs, visit_all_inputs_mut;
+    AllOutputs: visit_all_outputs, visit_all_outputs_mut;
+    AllRegisters: visit_all_registers, visit_all_registers_mut;
+    CreateClock: visit_create_clock, visit_create_clock_mut;
+    CreateGeneratedClock: visit_create_generated_clock, visit_create_generated_clock_mut;
+    CreatePblock: visit_create_pblock, visit_create_pblock_mut;
+    CreateVoltageArea: visit_create_voltage_area, visit_create_voltage_area_mut;
+    CurrentDesign: visit_current_design, visit_current_design_mut;
+    CurrentInstance: visit_current_instance, visit_current_instance_mut;
+    DeriveClockUncertainty: visit_derive_clock_uncertainty, visit_derive_clock_uncertainty_mut;
+    DerivePllClocks: visit_derive_pll_clocks, visit_derive_pll_clocks_mut;
+    Expr: visit_expr, visit_expr_mut;
+    GetCells: visit_get_cells, visit_get_cells_mut;
+    GetClocks: visit_get_clocks, visit_get_clocks_mut;
+    GetFanouts: visit_get_fanouts, visit_get_fanouts_mut;
+    GetIobanks: visit_get_iobanks, visit_get_iobanks_mut;
+    GetKeepers: visit_get_keepers, visit_get_keepers_mut;
+    GetLibCells: visit_get_lib_cells, visit_get_lib_cells_mut;
+    GetLibPins: visit_get_lib_pins, visit_get_lib_pins_mut;
+    GetLibs: visit_get_libs, visit_get_libs_mut;
+    GetNets: visit_get_nets, visit_get_nets_mut;
+    GetPblocks: visit_get_pblocks, visit_get_pblocks_mut;
+    GetPins: visit_get_pins, visit_get_pins_mut;
+    GetPorts: visit_get_ports, visit_get_ports_mut;
+    GetProperty: visit_get_property, visit_get_property_mut;
+    GetRegisters: visit_get_registers, visit_get_registers_mut;
+    GetTimingPaths: visit_get_timing_paths, visit_get_timing_paths_mut;
+    GroupPath: visit_group_path, visit_group_path_mut;
+    List: visit_list, visit_list_mut;
+    RemoveCaseAnalysis: visit_remove_case_analysis, visit_remove_case_analysis_mut;
+    RemoveClock: visit_remove_clock, visit_remove_clock_mut;
+    RemoveClockGroups: visit_remove_clock_groups, visit_remove_clock_groups_mut;
+    RemoveDisableTiming: visit_remove_disable_timing, visit_remove_disable_timing_mut;
+    RemoveGeneratedClock: visit_remove_generated_clock, visit_remove_generated_clock_mut;
+    RemoveInputDelay: visit_remove_input_delay, visit_remove_input_delay_mut;
+    RemoveOutputDelay: visit_remove_output_delay, visit_remove_output_delay_mut;
+    ResizePblock: visit_resize_pblock, visit_resize_pblock_mut;
+    Set: visit_set, visit_set_mut;
+    SetAnnotatedDelay: visit_set_annotated_delay, visit_set_annotated_delay_mut;
+    SetAppVar: visit_set_app_var, visit_set_app_var_mut;
+    SetAssignedDelay: visit_set_assigned_delay, visit_set_assigned_delay_mut;
+    SetCaseAnalysis: visit_set_case_analysis, visit_set_case_analysis_mut;
+    SetClockGatingCheck: visit_set_clock_gating_check, visit_set_clock_gating_check_mut;
+    SetClockGatingStyle: visit_set_clock_gating_style, visit_set_clock_gating_style_mut;
+    SetClockGroups: visit_set_clock_groups, visit_set_clock_groups_mut;
+    SetClockLatency: visit_set_clock_latency, visit_set_clock_latency_mut;
+    SetClockSense: visit_set_clock_sense, visit_set_clock_sense_mut;
+    SetClockTransition: visit_set_clock_transition, visit_set_clock_transition_mut;
+    SetClockUncertainty: visit_set_clock_uncertainty, visit_set_clock_uncertainty_mut;
+    SetDataCheck: visit_set_data_check, visit_set_data_check_mut;
+    SetDisableInferredClockGating: visit_set_disable_inferred_clock_gating, visit_set_disable_inferred_clock_gating_mut;
+    SetDisableTiming: visit_set_disable_timing, visit_set_disable_timing_mut;
+    SetDrive: visit_set_drive, visit_set_drive_mut;
+    SetDrivingCell: visit_set_driving_cell, visit_set_driving_cell_mut;
+    SetFalsePath: visit_set_false_path, visit_set_false_path_mut;
+    SetFanoutLoad: visit_set_fanout_load, visit_set_fanout_load_mut;
+    SetHierarchySeparator: visit_set_hierarchy_separator, visit_set_hierarchy_separator_mut;
+    SetIdealLatency: visit_set_ideal_latency, visit_set_ideal_latency_mut;
+    SetIdealNetwork: visit_set_ideal_network, visit_set_ideal_network_mut;
+    SetIdealTransition: visit_set_ideal_transition, visit_set_ideal_transition_mut;
+    SetInputDelay: visit_set_input_delay, visit_set_input_delay_mut;
+    SetInputTransition: visit_set_input_transition, visit_set_input_transition_mut;
+    SetLatchLoopBreaker: visit_set_latch_loop_breaker, visit_set_latch_loop_breaker_mut;
+    SetLevelShifterStrategy: visit_set_level_shifter_strategy, visit_set_level_shifter_strategy_mut;
+    SetLevelShifterThreshold: visit_set_level_shifter_threshold, visit_set_level_shifter_threshold_mut;
+    SetLoad: visit_set_load, visit_set_load_mut;
+    SetLogicDc: visit_set_logic_dc, visit_set_logic_dc_mut;
+    SetLogicOne: visit_set_logic_one, visit_set_logic_one_mut;
+    SetLogicZero: visit_set_logic_zero, visit_set_logic_zero_mut;
+    SetMaxArea: visit_set_max_area, visit_set_max_area_mut;
+    SetMaxCapacitance: visit_set_max_capacitance, visit_set_max_capacitance_mut;
+    SetMaxDelay: visit_set_max_delay, visit_set_max_delay_mut;
+    SetMaxDynamicPower: visit_set_max_dynamic_power, visit_set_max_dynamic_power_mut;
+    SetMaxFanout: visit_set_max_fanout, visit_set_max_fanout_mut;
+    SetMaxLeakagePower: visit_set_max_leakage_power, visit_set_max_leakage_power_mut;
+    SetMaxTimeBorrow: visit_set_max_time_borrow, visit_set_max_time_borrow_mut;
+    SetMaxTransition: visit_set_max_transition, visit_set_max_transition_mut;
+    SetMinCapacitance: visit_set_min_capacitance, visit_set_min_capacitance_mut;
+    SetMinDelay: visit_set_min_delay, visit_set_min_delay_mut;
+    SetMinPorosity: visit_set_min_porosity, visit_set_min_porosity_mut;
+    SetMinPulseWidth: visit_set_min_pulse_width, visit_set_min_pulse_width_mut;
+    SetMulticyclePath: visit_set_multicycle_path, visit_set_multicycle_path_mut;
+    SetOperatingConditions: visit_set_operating_conditions, visit_set_operating_conditions_mut;
+    SetOutputDelay: visit_set_output_delay, visit_set_output_delay_mut;
+    SetPathMargin: visit_set_path_margin, visit_set_path_margin_mut;
+    SetPortFanoutNumber: visit_set_port_fanout_number, visit_set_port_fanout_number_mut;
+    SetPropagatedClock: visit_set_propagated_clock, visit_set_propagated_clock_mut;
+    SetProperty: visit_set_property, visit_set_property_mut;
+    SetResistance: visit_set_resistance, visit_set_resistance_mut;
+    SetSense: visit_set_sense, visit_set_sense_mut;
+    SetTimeFormat: visit_set_time_format, visit_set_time_format_mut;
+    SetTimingDerate: visit_set_timing_derate, visit_set_timing_derate_mut;
+    SetUnits: visit_set_units, visit_set_units_mut;
+    SetVoltage: visit_set_voltage, visit_set_voltage_mut;
+    SetWireLoadMinBlockSize: visit_set_wire_load_min_block_size, visit_set_wire_load_min_block_size_mut;
+    SetWireLoadMode: visit_set_wire_load_mode, visit_set_wire_load_mode_mut;
+    SetWireLoadModel: visit_set_wire_load_model, visit_set_wire_load_model_mut;
+    SetWireLoadSelectionGroup: visit_set_wire_load_selection_group, visit_set_wire_load_selection_group_mut;
+    Unknown: visit_unknown, visit_unknown_mut;
+}
+
+pub fn walk_sdc<V: Visit + ?Sized>(visitor: &mut V, sdc: &Sdc) {
+    for command in &sdc.commands {
+        visitor.visit_command(command);
+    }
+}
+
+pub fn walk_argument<V: Visit + ?Sized>(visitor: &mut V, argument: &Argument) {
+    if let Argument::CommandSubstitution(command, _) = argument {
+        visitor.visit_command(command);
+    }
+}
+
+pub fn walk_arguments<V: Visit + ?Sized>(visitor: &mut V, arguments: Vec<&Argument>) {
+    for argument in arguments {
+        visitor.visit_argument(argument);
+    }
+}
+
+pub fn walk_sdc_mut<V: VisitMut + ?Sized>(visitor: &mut V, sdc: &mut Sdc) {
+    for command in &mut sdc.commands {
+        visitor.visit_command_mut(command);
+    }
+}
+
+pub fn walk_argument_mut<V: VisitMut + ?Sized>(visitor: &mut V, argument: &mut Argument) {
+    if let Argument::CommandSubstitution(command, _) = argument {
+        visitor.visit_command_mut(command);
+    }
+}
+
+pub fn walk_arguments_mut<V: VisitMut + ?Sized>(visitor: &mut V, arguments: Vec<&mut Argument>) {
+    for argument in arguments {
+        visitor.visit_argument_mut(argument);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Parser;
+
+    #[derive(Default)]
+    struct PortCounter {
+        ports: Vec<String>,
+    }
+
+    impl Visit for PortCounter {
+        fn visit_get_ports(&mut self, command: &GetPorts) {
+            if let Some(x) = &command.patterns {
+                self.ports.push(x.to_string());
+            }
+            walk_arguments(self, command.arguments());
+        }
+    }
+
+    struct ClockRenamer;
+
+    impl VisitMut for ClockRenamer {
+        fn visit_get_clocks_mut(&mut self, command: &mut GetClocks) {
+            if let Some(x) = &mut command.patterns {
+                *x = format!("blk_{x}").into();
+            }
+        }
+
+        fn visit_create_clock_mut(&mut self, command: &mut CreateClock) {
+            if let Some(x) = &mut command.name {
+                *x = format!("blk_{x}").into();
+            }
+            walk_arguments_mut(self, command.arguments_mut());
+        }
+    }
+
+    const CODE: &str = "create_clock -period 10 -name CLK [get_ports clk]
+set_input_delay 1 -clock [get_clocks CLK] [list [get_ports a] [get_ports b]]
+set_false_path -from [get_clocks CLK] -through [get_pins u0/a]
+";
+
+    #[test]
+    fn visit() {
+        let sdc = Parser::parse(CODE, &"").unwrap();
+        let mut visitor = PortCounter::default();
+        visitor.visit_sdc(&sdc);
+        assert_eq!(visitor.ports, vec!["clk", "a", "b"]);
+    }
+
+    #[test]
+    fn visit_mut() {
+        let mut sdc = Parser::parse(CODE, &"").unwrap();
+        ClockRenamer.visit_sdc_mut(&mut sdc);
+        assert_eq!(
+            sdc.to_string(),
+            "create_clock -period 10 -name blk_CLK [get_ports clk]
+set_input_delay -clock [get_clocks blk_CLK] 1 [list [get_ports a] [get_ports b]]
+set_false_path -from [get_clocks blk_CLK] -through [get_pins u0/a]
+"
+        );
+    }
+}
@@ -0,0 +1,13 @@
+module top (clk, din, dout);
+    input clk;
+    input [1:0] din;
+    output dout;
+    wire [1:0] n;
+    DFF u_ff0 (.CK(clk), .D(din[0]), .Q(n[0]));
+    DFF u_ff1 (.CK(clk), .D(din[1]), .Q(n[1]));
+    sub u_sub (.a(n), .y(dout));
+endmodule
+
+module sub (input [1:0] a, output y);
+    AND2 u_and (.A(a[1]), .B(a[0]), .Y(y));
+endmodule
@@ -0,0 +1,22 @@
+use std::process::Command;
+
+fn sdcx(args: &[&str]) -> std::process::Output {
+    Command::new(env!("CARGO_BIN_EXE_sdcx"))
+        .args(args)
+        .env("NO_COLOR", "1")
+        .output()
+        .unwrap()
+}
+
+#[test]
+fn lint_xdc() {
+    let output = sdcx(&["lint", "testcase/arty_a7.xdc"]);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "{stderr}");
+    assert!(!stderr.contains("could not parse"));
+
+    let output = sdcx(&["lint", "--dialect", "sdc", "testcase/arty_a7.xdc"]);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(!output.status.success());
+    assert!(stderr.contains("could not parse"));
+}